@@ -22,6 +22,9 @@ pub use domain::EvaluationDomain;
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod out_of_core;
+pub use out_of_core::SpilledVec;
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
@@ -0,0 +1,225 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Disk-backed vectors of field elements, and FFTs that operate on them in bounded memory.
+
+use crate::fft::EvaluationDomain;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{cfg_chunks_mut, cfg_iter_mut, BigInteger};
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The number of temporary files created by this process, used to give each file a unique name.
+static NUM_SPILLED_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// A vector of field elements that is stored in a temporary file instead of in memory.
+///
+/// The file is created in the directory returned by [`std::env::temp_dir`], and is removed on drop.
+#[derive(Debug)]
+pub struct SpilledVec<F: PrimeField> {
+    /// The temporary file.
+    file: File,
+    /// The path of the temporary file.
+    path: PathBuf,
+    /// The number of elements in the vector.
+    len: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> SpilledVec<F> {
+    /// Returns a new vector of `len` elements, all of which must be written before they are read.
+    pub fn with_len(len: usize) -> io::Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "snarkvm-spilled-{}-{}",
+            std::process::id(),
+            NUM_SPILLED_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+        let spilled = Self { file, path, len, _field: PhantomData };
+        spilled.file.set_len(Self::byte_offset(len)?)?;
+        Ok(spilled)
+    }
+
+    /// Writes the given elements to a new vector.
+    pub fn from_slice(values: &[F]) -> io::Result<Self> {
+        let mut spilled = Self::with_len(values.len())?;
+        spilled.write_range(0, values)?;
+        Ok(spilled)
+    }
+
+    /// Returns the number of elements in the vector.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reads `len` elements, starting at `start`.
+    /// Elements past the end of the vector are read as zero.
+    pub fn read_range(&mut self, start: usize, len: usize) -> io::Result<Vec<F>> {
+        let num_stored = self.len.saturating_sub(start).min(len);
+        let mut values = Vec::with_capacity(len);
+        if num_stored > 0 {
+            // The elements are decoded as they are read, so that the bytes are never held in memory in full.
+            self.file.seek(SeekFrom::Start(Self::byte_offset(start)?))?;
+            let mut reader = BufReader::new((&mut self.file).take(Self::byte_offset(num_stored)?));
+            for _ in 0..num_stored {
+                values.push(F::read_le(&mut reader)?);
+            }
+        }
+        values.resize(len, F::zero());
+        Ok(values)
+    }
+
+    /// Writes the given elements, starting at `start`.
+    pub fn write_range(&mut self, start: usize, values: &[F]) -> io::Result<()> {
+        if start.checked_add(values.len()).filter(|end| *end <= self.len).is_none() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Write past the end of a spilled vector"));
+        }
+        self.file.seek(SeekFrom::Start(Self::byte_offset(start)?))?;
+        let mut writer = BufWriter::new(&mut self.file);
+        values.iter().try_for_each(|value| value.write_le(&mut writer))?;
+        writer.flush()
+    }
+
+    /// Reads the entire vector into memory.
+    pub fn to_vec(&mut self) -> io::Result<Vec<F>> {
+        self.read_range(0, self.len)
+    }
+
+    /// Returns the number of bytes used to store each element.
+    fn element_size() -> usize {
+        F::BigInteger::NUM_LIMBS * 8
+    }
+
+    /// Returns the position in the file of the element at `index`.
+    fn byte_offset<T: TryFrom<usize>>(index: usize) -> io::Result<T> {
+        index
+            .checked_mul(Self::element_size())
+            .and_then(|offset| T::try_from(offset).ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Spilled vector is too large"))
+    }
+}
+
+impl<F: PrimeField> Drop for SpilledVec<F> {
+    fn drop(&mut self) {
+        // The file is only a cache, so there is nothing to do if it cannot be removed.
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Compute an FFT of the given coefficients, holding at most `max_chunk_len` elements in memory
+    /// (or about `sqrt(self.size())` elements, if that is larger) at any time.
+    pub fn fft_out_of_core(&self, coeffs: &mut SpilledVec<F>, max_chunk_len: usize) -> io::Result<SpilledVec<F>> {
+        self.four_step_fft(coeffs, max_chunk_len, false)
+    }
+
+    /// Compute an IFFT of the given evaluations, holding at most `max_chunk_len` elements in memory
+    /// (or about `sqrt(self.size())` elements, if that is larger) at any time.
+    pub fn ifft_out_of_core(&self, evals: &mut SpilledVec<F>, max_chunk_len: usize) -> io::Result<SpilledVec<F>> {
+        self.four_step_fft(evals, max_chunk_len, true)
+    }
+
+    /// Computes an (I)FFT of size `n = rows * cols` with the four-step algorithm.
+    ///
+    /// Writing the input index as `j = cols * j_1 + j_2` and the output index as `k = k_1 + rows * k_2`,
+    /// `X[k] = sum_{j_2} w_cols^{j_2 k_2} w^{j_2 k_1} sum_{j_1} w_rows^{j_1 k_1} x[j]`.
+    /// The inner sums are FFTs of size `rows` over stripes of columns, and the outer sums are FFTs of size
+    /// `cols` over panels of rows, so that neither needs the whole vector in memory.
+    /// Besides the stripes and panels, only the roots of unity of the two subdomains, of size about `sqrt(n)`,
+    /// and small buffers for the file I/O are held in memory.
+    fn four_step_fft(
+        &self,
+        input: &mut SpilledVec<F>,
+        max_chunk_len: usize,
+        inverse: bool,
+    ) -> io::Result<SpilledVec<F>> {
+        let size = self.size();
+        if input.len() > size {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "The vector is larger than the domain"));
+        }
+
+        let rows = 1 << (self.log_size_of_group / 2);
+        let cols = size / rows;
+        let inner_domain = EvaluationDomain::<F>::new(rows).expect("A subdomain of a valid domain is valid");
+        let outer_domain = EvaluationDomain::<F>::new(cols).expect("A subdomain of a valid domain is valid");
+        let root = if inverse { self.group_gen_inv } else { self.group_gen };
+
+        let inner_precomputation = (inner_domain.precompute_fft(), inner_domain.precompute_ifft());
+        let outer_precomputation = (outer_domain.precompute_fft(), outer_domain.precompute_ifft());
+        let inner_fft = |values: &mut [F]| match inverse {
+            true => inner_domain.in_order_ifft_in_place_with_pc(values, &inner_precomputation.1),
+            false => inner_domain.in_order_fft_in_place_with_pc(values, &inner_precomputation.0),
+        };
+        let outer_fft = |values: &mut [F]| match inverse {
+            true => outer_domain.in_order_ifft_in_place_with_pc(values, &outer_precomputation.1),
+            false => outer_domain.in_order_fft_in_place_with_pc(values, &outer_precomputation.0),
+        };
+
+        // Compute the inner FFTs over stripes of columns, and multiply by the twiddle factors `w^{j_2 k_1}`.
+        // The result is stored with `k_1` as the row, and `j_2` as the column.
+        let mut intermediate = SpilledVec::with_len(size)?;
+        let width = (max_chunk_len / rows).clamp(1, cols);
+        for start in (0..cols).step_by(width) {
+            let width = width.min(cols - start);
+            let mut columns = vec![vec![F::zero(); rows]; width];
+            for j_1 in 0..rows {
+                for (column, value) in columns.iter_mut().zip(input.read_range(cols * j_1 + start, width)?) {
+                    column[j_1] = value;
+                }
+            }
+            cfg_iter_mut!(columns).enumerate().for_each(|(j_2, column)| {
+                inner_fft(column);
+                let twiddle = root.pow([(start + j_2) as u64]);
+                let mut power = F::one();
+                for value in column.iter_mut() {
+                    *value *= power;
+                    power *= twiddle;
+                }
+            });
+            for k_1 in 0..rows {
+                let row = columns.iter().map(|column| column[k_1]).collect::<Vec<_>>();
+                intermediate.write_range(cols * k_1 + start, &row)?;
+            }
+        }
+
+        // Compute the outer FFTs over panels of rows, and write each output at `k_1 + rows * k_2`.
+        let mut output = SpilledVec::with_len(size)?;
+        let height = (max_chunk_len / cols).clamp(1, rows);
+        for start in (0..rows).step_by(height) {
+            let height = height.min(rows - start);
+            let mut panel = intermediate.read_range(cols * start, cols * height)?;
+            cfg_chunks_mut!(panel, cols).for_each(outer_fft);
+            for k_2 in 0..cols {
+                let values = (0..height).map(|k_1| panel[cols * k_1 + k_2]).collect::<Vec<_>>();
+                output.write_range(start + rows * k_2, &values)?;
+            }
+        }
+        Ok(output)
+    }
+}
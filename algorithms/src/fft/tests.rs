@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, SpilledVec};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
@@ -342,3 +342,48 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+#[test]
+fn out_of_core_fft_consistency() {
+    let rng = &mut TestRng::default();
+
+    for log_size in 0..10 {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+        // Include an input that is shorter than the domain, which is padded with zeros.
+        for num_coeffs in [domain.size(), domain.size() / 2 + 1] {
+            let v = (0..num_coeffs).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            // Include chunks that are smaller than the minimum of `sqrt(domain.size())`.
+            for max_chunk_len in [1, 3, 8, domain.size()] {
+                let mut spilled = SpilledVec::from_slice(&v).unwrap();
+                let mut evals = domain.fft_out_of_core(&mut spilled, max_chunk_len).unwrap();
+                assert_eq!(evals.to_vec().unwrap(), domain.fft(&v), "out-of-core fft != fft");
+
+                let mut coeffs = domain.ifft_out_of_core(&mut evals, max_chunk_len).unwrap();
+                let mut expected = v.clone();
+                expected.resize(domain.size(), Fr::zero());
+                assert_eq!(coeffs.to_vec().unwrap(), expected, "out-of-core ifft(fft(.)) != iden");
+            }
+        }
+    }
+}
+
+#[test]
+fn spilled_vec_round_trip() {
+    let rng = &mut TestRng::default();
+
+    let v = (0..100).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let mut spilled = SpilledVec::from_slice(&v).unwrap();
+    assert_eq!(spilled.len(), v.len());
+    assert_eq!(spilled.to_vec().unwrap(), v);
+    assert_eq!(spilled.read_range(10, 20).unwrap(), v[10..30]);
+
+    // Elements past the end are read as zero.
+    let mut expected = v[90..].to_vec();
+    expected.resize(20, Fr::zero());
+    assert_eq!(spilled.read_range(90, 20).unwrap(), expected);
+
+    // Writes past the end are rejected.
+    assert!(spilled.write_range(95, &v[..10]).is_err());
+    spilled.write_range(0, &v[50..60]).unwrap();
+    assert_eq!(spilled.read_range(0, 10).unwrap(), v[50..60]);
+}
@@ -92,7 +92,7 @@ impl<E: PairingEngine> UniversalParams<E> {
     }
 
     pub fn to_universal_prover(&self) -> Result<UniversalProver<E>> {
-        Ok(UniversalProver::<E> { max_degree: self.max_degree(), _unused: None })
+        Ok(UniversalProver::<E> { max_degree: self.max_degree(), memory_budget: None, _unused: None })
     }
 
     pub fn to_universal_verifier(&self) -> Result<UniversalVerifier<E>> {
@@ -396,6 +396,21 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// The pairing-product equation that remains after accumulating the openings of a batch check.
+///
+/// The equation holds if the product of `e(comm_d, h_d)` over each degree bound `d`,
+/// `e(-adjusted_witness, h)`, and `e(-witness, beta_h)` is one. Equations from different
+/// batch checks can be folded with a random linear combination, and checked with one multi-pairing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, grouped by degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined witness, adjusted by the points and values of the openings.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
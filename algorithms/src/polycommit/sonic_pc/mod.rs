@@ -24,6 +24,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
@@ -349,6 +350,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::prepare_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Accumulates the openings in `proof` into a single pairing check, without performing the pairings.
+    pub fn prepare_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -405,9 +422,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
     pub fn open_combinations<'a>(
@@ -483,6 +499,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::prepare_check_combinations(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Accumulates the claim that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `labeled_commitments` into a single pairing check, without performing the pairings.
+    pub fn prepare_check_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -540,7 +582,44 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::prepare_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Checks that all of the given pairing checks hold, using one multi-pairing.
+    ///
+    /// The pairing checks are folded with a random linear combination, with coefficients sampled from `rng`.
+    /// The coefficients must be unpredictable to the provers, or a failing check may be cancelled out by another.
+    pub fn check_pairings<R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        pairing_checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let fold_time = start_timer!(|| "Folding pairing checks");
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        for (i, pairing_check) in pairing_checks.into_iter().enumerate() {
+            // The first pairing check is not randomized, as only the ratios between randomizers matter.
+            // We don't need to sample randomizers from the full field, only from 128-bit strings.
+            let randomizer = match i {
+                0 => E::Fr::one(),
+                _ => u128::rand(rng).into(),
+            };
+            for (degree_bound, comm) in pairing_check.combined_comms {
+                *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += comm.mul(randomizer);
+            }
+            combined_witness += pairing_check.combined_witness.mul(randomizer);
+            combined_adjusted_witness += pairing_check.combined_adjusted_witness.mul(randomizer);
+        }
+        end_timer!(fold_time);
+
+        Self::check_pairing(vk, PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
+    }
+
+    /// Checks that the given pairing check holds.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        Self::check_elems(vk, pairing_check)
     }
 }
 
@@ -634,12 +713,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok(())
     }
 
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool> {
+    fn check_elems(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
         let check_time = start_timer!(|| "Checking elems");
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
@@ -0,0 +1,92 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fft::SpilledVec;
+use snarkvm_fields::PrimeField;
+
+use anyhow::Result;
+
+/// A vector of field elements that the prover holds from one round to a later one.
+pub(crate) enum RoundVec<F: PrimeField> {
+    /// The vector is held in memory.
+    InMemory(Vec<F>),
+    /// The vector did not fit in the memory budget, and was spilled to a temporary file.
+    Spilled(SpilledVec<F>),
+}
+
+impl<F: PrimeField> RoundVec<F> {
+    /// Returns the number of bytes that the vector holds in memory.
+    pub(crate) fn memory_size(&self) -> usize {
+        match self {
+            Self::InMemory(values) => values.capacity().saturating_mul(core::mem::size_of::<F>()),
+            Self::Spilled(_) => 0,
+        }
+    }
+
+    /// Returns the elements of the vector, reading them from disk if they were spilled.
+    pub(crate) fn into_vec(self) -> Result<Vec<F>> {
+        match self {
+            Self::InMemory(values) => Ok(values),
+            Self::Spilled(mut values) => Ok(values.to_vec()?),
+        }
+    }
+}
+
+/// The memory that the prover may use to hold state between rounds.
+///
+/// Vectors that are stored once the budget is exhausted are spilled to temporary files, and are read back
+/// when a later round consumes them. A vector that is held in memory is counted against the budget until
+/// the round that consumes it is done with it, and the out-of-core FFTs of a round only use the budget that
+/// remains. The prover therefore never holds more than the budget in addition to what it holds when all of
+/// the state is spilled, which is the working set of the current round: see [`UniversalProver::memory_budget`].
+///
+/// [`UniversalProver::memory_budget`]: crate::srs::UniversalProver::memory_budget
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct MemoryBudget {
+    /// The number of bytes that remain available, or `None` if there is no limit.
+    remaining: Option<usize>,
+}
+
+impl MemoryBudget {
+    /// Returns a budget of `budget` bytes, or an unlimited budget if `budget` is `None`.
+    pub(crate) const fn new(budget: Option<usize>) -> Self {
+        Self { remaining: budget }
+    }
+
+    /// Stores the given vector in memory if it fits in the remaining budget, and spills it to disk otherwise.
+    pub(crate) fn store<F: PrimeField>(&mut self, values: Vec<F>) -> Result<RoundVec<F>> {
+        let size = values.capacity().saturating_mul(core::mem::size_of::<F>());
+        match &mut self.remaining {
+            Some(remaining) if size > *remaining => Ok(RoundVec::Spilled(SpilledVec::from_slice(&values)?)),
+            Some(remaining) => {
+                *remaining -= size;
+                Ok(RoundVec::InMemory(values))
+            }
+            None => Ok(RoundVec::InMemory(values)),
+        }
+    }
+
+    /// Returns `num_bytes` of memory to the budget, once the vectors that held them have been consumed.
+    pub(crate) fn release(&mut self, num_bytes: usize) {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = remaining.saturating_add(num_bytes);
+        }
+    }
+
+    /// Returns the number of elements that each of `num_jobs` concurrent out-of-core FFTs may hold in memory.
+    pub(crate) fn chunk_len<F: PrimeField>(&self, num_jobs: usize) -> usize {
+        self.remaining.map_or(usize::MAX, |remaining| remaining / core::mem::size_of::<F>() / num_jobs.max(1))
+    }
+}
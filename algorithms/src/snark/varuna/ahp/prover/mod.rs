@@ -18,6 +18,9 @@
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
+mod memory;
+pub(crate) use memory::*;
+
 mod message;
 pub(crate) use message::*;
 
@@ -25,6 +28,7 @@ mod oracles;
 pub(crate) use oracles::*;
 
 mod round_functions;
+pub(crate) use round_functions::LinevalInstance;
 
 mod state;
-use state::*;
+pub(crate) use state::*;
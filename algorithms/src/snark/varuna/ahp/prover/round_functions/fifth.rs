@@ -48,20 +48,24 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let round_time = start_timer!(|| "AHP::Prover::FifthRound");
 
         let lhs_sum: DensePolynomial<F> = cfg_reduce!(
-            cfg_par_bridge!(verifier_message.into_iter().zip_eq(state.lhs_polys_into_iter())).map(
-                |(delta, mut lhs)| {
+            cfg_par_bridge!(verifier_message.into_iter().zip_eq(state.lhs_polys_into_iter())).map(|(delta, lhs)| {
+                lhs.map(|mut lhs| {
                     lhs *= delta;
                     lhs
-                }
-            ),
-            || DensePolynomial::zero(),
-            |mut a, mut b| {
-                if b != DensePolynomial::zero() {
-                    a += &std::mem::take(&mut b);
-                }
-                a
+                })
+            }),
+            || Ok(DensePolynomial::zero()),
+            |a, b| {
+                a.and_then(|mut a| {
+                    b.map(|mut b| {
+                        if b != DensePolynomial::zero() {
+                            a += &std::mem::take(&mut b);
+                        }
+                        a
+                    })
+                })
             }
-        );
+        )?;
         let h_2 = LabeledPolynomial::new("h_2", lhs_sum, None, None);
         let oracles = prover::FifthOracles { h_2 };
         assert!(oracles.matches_info(&Self::fifth_round_polynomial_info()));
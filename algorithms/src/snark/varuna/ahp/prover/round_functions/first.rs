@@ -18,6 +18,7 @@ use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
         ahp::{AHPError, AHPForR1CS},
+        distributed::Request,
         prover,
         witness_label,
         Circuit,
@@ -28,6 +29,7 @@ use crate::{
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::cfg_into_iter;
 
+use anyhow::Result;
 use itertools::Itertools;
 use rand_core::RngCore;
 use std::collections::BTreeMap;
@@ -63,25 +65,56 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         rng: &mut R,
     ) -> Result<prover::State<'a, F, SM>, AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
-        let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
-        for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
-            let batch_size = circuit_state.batch_size;
-
-            let private_variables = core::mem::take(&mut circuit_state.private_variables);
-            let x_polys = circuit_state.x_polys.clone();
-            assert_eq!(private_variables.len(), batch_size);
-            assert_eq!(x_polys.len(), batch_size);
-
-            let v_domain = circuit_state.variable_domain;
-            let i_domain = circuit_state.input_domain;
-
-            for (j, (private_vars, x_poly)) in itertools::izip!(private_variables, x_polys).enumerate() {
-                let w_label = witness_label(circuit.id, "w", j);
-                job_pool.add_job(move || Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit));
+        let w_polys = match &mut state.workers {
+            // The witness is held by the workers, which compute the witness polynomials of their instances.
+            Some(workers) => {
+                let mut requests = Vec::with_capacity(state.total_instances);
+                let mut labels = Vec::with_capacity(state.total_instances);
+                for (circuit, circuit_state) in state.circuit_specific_states.iter() {
+                    for j in 0..circuit_state.batch_size {
+                        requests.push(Request::FirstRound { circuit_id: circuit.id, index: j });
+                        labels.push(witness_label(circuit.id, "w", j));
+                    }
+                }
+                workers
+                    .execute(requests)?
+                    .into_iter()
+                    .zip_eq(labels)
+                    .map(|(response, label)| {
+                        let w_poly = response.into_polynomial()?;
+                        Ok::<_, anyhow::Error>(LabeledPolynomial::new(label, w_poly, None, Self::zk_bound()))
+                    })
+                    .collect_vec()
             }
-        }
+            None => {
+                let mut num_released_bytes = 0;
+                let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
+                for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
+                    let batch_size = circuit_state.batch_size;
+
+                    let private_variables = core::mem::take(&mut circuit_state.private_variables);
+                    let x_polys = circuit_state.x_polys.clone();
+                    assert_eq!(private_variables.len(), batch_size);
+                    assert_eq!(x_polys.len(), batch_size);
+
+                    let v_domain = circuit_state.variable_domain;
+                    let i_domain = circuit_state.input_domain;
+
+                    for (j, (private_vars, x_poly)) in itertools::izip!(private_variables, x_polys).enumerate() {
+                        num_released_bytes += private_vars.memory_size();
+                        let w_label = witness_label(circuit.id, "w", j);
+                        job_pool.add_job(move || {
+                            Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit)
+                        });
+                    }
+                }
+                let w_polys = job_pool.execute_all();
+                state.memory.release(num_released_bytes);
+                w_polys
+            }
+        };
         let mut batches =
-            job_pool.execute_all().into_iter().map(|w_poly| prover::WitnessPoly(w_poly)).collect::<Vec<_>>();
+            w_polys.into_iter().map(|w_poly| w_poly.map(prover::WitnessPoly)).collect::<Result<Vec<_>>>()?;
         assert_eq!(batches.len(), state.total_instances);
 
         let mut circuit_specific_batches = BTreeMap::new();
@@ -124,15 +157,16 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         LabeledPolynomial::new("mask_poly".to_string(), mask_poly, None, None)
     }
 
-    fn calculate_w(
+    /// Computes the witness polynomial of an instance from its private variables.
+    pub(crate) fn calculate_w(
         label: String,
-        private_variables: Vec<F>,
+        private_variables: prover::RoundVec<F>,
         x_poly: DensePolynomial<F>,
         variable_domain: EvaluationDomain<F>,
         input_domain: EvaluationDomain<F>,
         circuit: &Circuit<F, SM>,
-    ) -> Witness<F> {
-        let mut w_extended = private_variables;
+    ) -> Result<Witness<F>> {
+        let mut w_extended = private_variables.into_vec()?;
         let ratio = variable_domain.size() / input_domain.size();
         w_extended.resize(variable_domain.size() - input_domain.size(), F::zero());
 
@@ -157,7 +191,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         assert!(w_poly.degree() < variable_domain.size() - input_domain.size());
         end_timer!(w_poly_time);
-        LabeledPolynomial::new(label, w_poly, None, Self::zk_bound())
+        Ok(LabeledPolynomial::new(label, w_poly, None, Self::zk_bound()))
     }
 }
 
@@ -132,7 +132,12 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let (sum_c, lhs_c, g_c, a_poly_c, b_poly_c) = results_c?;
             let matrix_sum = prover::message::MatrixSums { sum_a, sum_b, sum_c };
             sums.push(matrix_sum);
-            state.circuit_specific_states.get_mut(circuit_a).unwrap().lhs_polynomials = Some([lhs_a, lhs_b, lhs_c]);
+            let lhs_polynomials = [
+                state.memory.store(lhs_a.coeffs)?,
+                state.memory.store(lhs_b.coeffs)?,
+                state.memory.store(lhs_c.coeffs)?,
+            ];
+            state.circuit_specific_states.get_mut(circuit_a).unwrap().lhs_polynomials = Some(lhs_polynomials);
             state.circuit_specific_states.get_mut(circuit_a).unwrap().a_polys = Some([a_poly_a, a_poly_b, a_poly_c]);
             state.circuit_specific_states.get_mut(circuit_a).unwrap().b_polys = Some([b_poly_a, b_poly_b, b_poly_c]);
             let matrix_gs = prover::MatrixGs { g_a, g_b, g_c };
@@ -17,11 +17,13 @@ use crate::{
     r1cs::ConstraintSynthesizer,
     snark::varuna::{
         ahp::{indexer::Circuit, AHPError, AHPForR1CS},
+        distributed::{Request, WorkerPool},
         prover,
         SNARKMode,
     },
 };
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::ToBytes;
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -41,6 +43,7 @@ mod first;
 mod fourth;
 mod second;
 mod third;
+pub(crate) use third::LinevalInstance;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Initialize the AHP prover.
@@ -50,6 +53,79 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     ) -> Result<prover::State<'a, F, SM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
+        let randomizing_assignments = Self::sample_randomizing_assignments(circuits_to_constraints, rng);
+
+        let indices_and_assignments = circuits_to_constraints
+            .iter()
+            .zip_eq(randomizing_assignments.into_iter())
+            .map(|((circuit, constraints), circuit_rand_assignments)| {
+                let assignments = cfg_iter!(constraints)
+                    .zip(circuit_rand_assignments)
+                    .enumerate()
+                    .map(|(i, (instance, rand_assignments))| {
+                        Self::synthesize_instance(circuit, instance, rand_assignments, i)
+                    })
+                    .collect::<Result<Vec<prover::Assignments<F>>, AHPError>>()?;
+                Ok((*circuit, assignments))
+            })
+            .collect::<Result<BTreeMap<&'a Circuit<F, SM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
+
+        let state = prover::State::initialize(indices_and_assignments)?;
+        end_timer!(init_time);
+
+        Ok(state)
+    }
+
+    /// Initialize the AHP prover, with the witnesses of the batch synthesized and held by the given workers.
+    pub(crate) fn init_distributed_prover<'a, C: ToBytes, R: Rng + CryptoRng>(
+        circuits_to_constraints: &BTreeMap<&'a Circuit<F, SM>, &[C]>,
+        mut workers: WorkerPool,
+        rng: &mut R,
+    ) -> Result<prover::State<'a, F, SM>, AHPError> {
+        let init_time = start_timer!(|| "AHP::Prover::InitDistributed");
+
+        let randomizing_assignments = Self::sample_randomizing_assignments(circuits_to_constraints, rng);
+
+        let mut requests = Vec::new();
+        for ((circuit, constraints), circuit_rand_assignments) in
+            circuits_to_constraints.iter().zip_eq(randomizing_assignments)
+        {
+            for (index, (instance, randomizer)) in constraints.iter().zip_eq(circuit_rand_assignments).enumerate() {
+                let instance = instance.to_bytes_le()?;
+                requests.push(Request::Witness { circuit_id: circuit.id, index, randomizer, instance });
+            }
+        }
+        let mut responses = workers.execute(requests)?.into_iter();
+
+        let indices_and_assignments = circuits_to_constraints
+            .iter()
+            .map(|(circuit, constraints)| {
+                let assignments = responses
+                    .by_ref()
+                    .take(constraints.len())
+                    .map(|response| {
+                        let padded_public_variables = response.into_public_input()?;
+                        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
+                        // The private variables and the z vectors are held by the workers.
+                        Ok(prover::Assignments(padded_public_variables, vec![], vec![], vec![], vec![]))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((*circuit, assignments))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        let mut state = prover::State::initialize(indices_and_assignments)?;
+        state.workers = Some(workers);
+        end_timer!(init_time);
+
+        Ok(state)
+    }
+
+    /// Samples the assignments to the randomizing variables of each instance, if the prover is zero-knowledge.
+    pub(crate) fn sample_randomizing_assignments<C, R: Rng + CryptoRng>(
+        circuits_to_constraints: &BTreeMap<&Circuit<F, SM>, &[C]>,
+        rng: &mut R,
+    ) -> Vec<Vec<Option<[F; 3]>>> {
         let mut randomizing_assignments = Vec::with_capacity(circuits_to_constraints.len());
         for constraints in circuits_to_constraints.values() {
             let mut circuit_assignments = Vec::with_capacity(constraints.len());
@@ -65,106 +141,85 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             }
             randomizing_assignments.push(circuit_assignments);
         }
+        randomizing_assignments
+    }
 
-        let indices_and_assignments = circuits_to_constraints
-            .iter()
-            .zip_eq(randomizing_assignments.into_iter())
-            .map(|((circuit, constraints), circuit_rand_assignments)| {
-                let num_non_zero_a = circuit.index_info.num_non_zero_a;
-                let num_non_zero_b = circuit.index_info.num_non_zero_b;
-                let num_non_zero_c = circuit.index_info.num_non_zero_c;
+    /// Generates the constraints and witness of the `_i`-th instance of `circuit`,
+    /// and evaluates the instance's `z_A`, `z_B` and `z_C` vectors.
+    pub(crate) fn synthesize_instance<C: ConstraintSynthesizer<F>>(
+        circuit: &Circuit<F, SM>,
+        instance: &C,
+        rand_assignments: Option<[F; 3]>,
+        _i: usize,
+    ) -> Result<prover::Assignments<F>, AHPError> {
+        let num_non_zero_a = circuit.index_info.num_non_zero_a;
+        let num_non_zero_b = circuit.index_info.num_non_zero_b;
+        let num_non_zero_c = circuit.index_info.num_non_zero_c;
+
+        let constraint_time =
+            start_timer!(|| format!("Generating constraints and witnesses for {:?} and index {_i}", circuit.id));
+        let mut pcs = prover::ConstraintSystem::new();
+        instance.generate_constraints(&mut pcs)?;
+        end_timer!(constraint_time);
+
+        let padding_time = start_timer!(|| format!("Padding matrices for {:?} and index {_i}", circuit.id));
+
+        SM::ZK.then(|| {
+            crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(&mut pcs, rand_assignments)
+        });
+        crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs)?;
+
+        end_timer!(padding_time);
+
+        let prover::ConstraintSystem {
+            public_variables: padded_public_variables,
+            private_variables,
+            num_constraints,
+            num_public_variables,
+            num_private_variables,
+            ..
+        } = pcs;
+
+        assert_eq!(padded_public_variables.len(), num_public_variables);
+        assert!(padded_public_variables[0].is_one());
+        assert_eq!(private_variables.len(), num_private_variables);
+
+        if cfg!(debug_assertions) {
+            println!("Number of padded public variables in Prover::Init: {num_public_variables}");
+            println!("Number of private variables: {num_private_variables}");
+            println!("Number of constraints: {num_constraints}");
+            println!("Number of non-zero entries in A: {num_non_zero_a}");
+            println!("Number of non-zero entries in B: {num_non_zero_b}");
+            println!("Number of non-zero entries in C: {num_non_zero_c}");
+        }
 
-                let assignments = cfg_iter!(constraints)
-                    .zip(circuit_rand_assignments)
-                    .enumerate()
-                    .map(|(_i, (instance, rand_assignments))| {
-                        let constraint_time = start_timer!(|| format!(
-                            "Generating constraints and witnesses for {:?} and index {_i}",
-                            circuit.id
-                        ));
-                        let mut pcs = prover::ConstraintSystem::new();
-                        instance.generate_constraints(&mut pcs)?;
-                        end_timer!(constraint_time);
-
-                        let padding_time =
-                            start_timer!(|| format!("Padding matrices for {:?} and index {_i}", circuit.id));
-
-                        SM::ZK.then(|| {
-                            crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(
-                                &mut pcs,
-                                rand_assignments,
-                            )
-                        });
-                        crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs)?;
-
-                        end_timer!(padding_time);
-
-                        let prover::ConstraintSystem {
-                            public_variables: padded_public_variables,
-                            private_variables,
-                            num_constraints,
-                            num_public_variables,
-                            num_private_variables,
-                            ..
-                        } = pcs;
-
-                        assert_eq!(padded_public_variables.len(), num_public_variables);
-                        assert!(padded_public_variables[0].is_one());
-                        assert_eq!(private_variables.len(), num_private_variables);
-
-                        if cfg!(debug_assertions) {
-                            println!("Number of padded public variables in Prover::Init: {num_public_variables}");
-                            println!("Number of private variables: {num_private_variables}");
-                            println!("Number of constraints: {num_constraints}");
-                            println!("Number of non-zero entries in A: {num_non_zero_a}");
-                            println!("Number of non-zero entries in B: {num_non_zero_b}");
-                            println!("Number of non-zero entries in C: {num_non_zero_c}");
-                        }
-
-                        if circuit.index_info.num_constraints != num_constraints
-                            || circuit.index_info.num_public_and_private_variables
-                                != (num_public_variables + num_private_variables)
-                        {
-                            return Err(AHPError::InstanceDoesNotMatchIndex);
-                        }
+        if circuit.index_info.num_constraints != num_constraints
+            || circuit.index_info.num_public_and_private_variables != (num_public_variables + num_private_variables)
+        {
+            return Err(AHPError::InstanceDoesNotMatchIndex);
+        }
 
-                        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
+        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
 
-                        let eval_z_a_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}", circuit.id));
-                        let z_a = cfg_iter!(circuit.a)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_a_time);
-
-                        let eval_z_b_time = start_timer!(|| format!("For {:?}, evaluating z_B_{_i}", circuit.id));
-                        let z_b = cfg_iter!(circuit.b)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_b_time);
-
-                        let eval_z_c_time = start_timer!(|| format!("For {:?}, evaluating z_C_{_i}", circuit.id));
-                        let z_c = cfg_iter!(circuit.c)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_c_time);
-
-                        Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
-                    })
-                    .collect::<Result<Vec<prover::Assignments<F>>, AHPError>>()?;
-                Ok((*circuit, assignments))
-            })
-            .collect::<Result<BTreeMap<&'a Circuit<F, SM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
+        let eval_z_a_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}", circuit.id));
+        let z_a = cfg_iter!(circuit.a)
+            .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
+            .collect();
+        end_timer!(eval_z_a_time);
 
-        let state = prover::State::initialize(indices_and_assignments)?;
-        end_timer!(init_time);
+        let eval_z_b_time = start_timer!(|| format!("For {:?}, evaluating z_B_{_i}", circuit.id));
+        let z_b = cfg_iter!(circuit.b)
+            .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
+            .collect();
+        end_timer!(eval_z_b_time);
 
-        Ok(state)
+        let eval_z_c_time = start_timer!(|| format!("For {:?}, evaluating z_C_{_i}", circuit.id));
+        let z_c = cfg_iter!(circuit.c)
+            .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
+            .collect();
+        end_timer!(eval_z_c_time);
+
+        Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
     }
 }
 
@@ -20,6 +20,7 @@ use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
         ahp::{verifier, AHPForR1CS},
+        distributed::{Request, Response},
         prover,
         selectors::apply_randomized_selector,
         witness_label,
@@ -28,7 +29,8 @@ use crate::{
         SNARKMode,
     },
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
+use itertools::Itertools;
 use rand_core::RngCore;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, cfg_reduce, ExecutionPool};
@@ -77,58 +79,69 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         state: &mut prover::State<F, SM>,
         batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
     ) -> Result<DensePolynomial<F>> {
-        let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
         let max_constraint_domain = state.max_constraint_domain;
 
-        for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
-            let z_a = circuit_specific_state.z_a.take().unwrap();
-            let z_b = circuit_specific_state.z_b.take().unwrap();
-            let z_c = circuit_specific_state.z_c.take().unwrap();
-
-            let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
-            let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
-            let constraint_domain = circuit_specific_state.constraint_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
-
-            let _circuit_id = &circuit.id; // seems like a compiler bug marks this as unused
-
-            for (j, (instance_combiner, z_a, z_b, z_c)) in
-                itertools::izip!(instance_combiners, z_a, z_b, z_c).enumerate()
-            {
-                job_pool.add_job(move || {
-                    let mut instance_lhs = DensePolynomial::zero();
-                    let za_label = witness_label(circuit.id, "z_a", j);
-                    let zb_label = witness_label(circuit.id, "z_b", j);
-                    let zc_label = witness_label(circuit.id, "z_c", j);
-                    let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit);
-                    let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit);
-                    let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit);
-                    let mut multiplier_2 = PolyMultiplier::new();
-                    multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
-                    multiplier_2.add_polynomial(z_a, "z_a");
-                    multiplier_2.add_polynomial(z_b, "z_b");
-                    let mut rowcheck = multiplier_2.multiply().unwrap();
-                    cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
-
-                    instance_lhs += &(&rowcheck * instance_combiner);
-
-                    let (h_0_i, remainder) = apply_randomized_selector(
-                        &mut instance_lhs,
-                        circuit_combiner,
-                        &max_constraint_domain,
-                        &constraint_domain,
-                        false,
-                    )?;
-                    assert!(remainder.is_none());
-                    Ok::<_, anyhow::Error>(h_0_i)
-                });
+        let h_0_polys = match &mut state.workers {
+            // The z vectors are held by the workers, which compute the rowcheck witnesses of their instances.
+            Some(workers) => {
+                let mut requests = Vec::with_capacity(state.total_instances);
+                for circuit in state.circuit_specific_states.keys() {
+                    let combiners = &batch_combiners[&circuit.id];
+                    for (index, &instance_combiner) in combiners.instance_combiners.iter().enumerate() {
+                        requests.push(Request::SecondRound {
+                            circuit_id: circuit.id,
+                            index,
+                            circuit_combiner: combiners.circuit_combiner,
+                            instance_combiner,
+                            max_constraint_domain,
+                        });
+                    }
+                }
+                workers.execute(requests)?.into_iter().map(Response::into_polynomial).collect_vec()
             }
-        }
+            None => {
+                // The z vectors that are held in memory are only released once they have been interpolated,
+                // so the out-of-core interpolations of the instances share the budget that remains.
+                let max_chunk_len = state.memory.chunk_len::<F>(state.total_instances);
+                let mut num_released_bytes = 0;
+                let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
+                for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
+                    let z_a = circuit_specific_state.z_a.take().unwrap();
+                    let z_b = circuit_specific_state.z_b.take().unwrap();
+                    let z_c = circuit_specific_state.z_c.take().unwrap();
+                    num_released_bytes +=
+                        z_a.iter().chain(&z_b).chain(&z_c).map(|z_m| z_m.memory_size()).sum::<usize>();
+
+                    let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
+                    let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
+                    let constraint_domain = circuit_specific_state.constraint_domain;
+
+                    for (j, (instance_combiner, z_a, z_b, z_c)) in
+                        itertools::izip!(instance_combiners, z_a, z_b, z_c).enumerate()
+                    {
+                        job_pool.add_job(move || {
+                            Self::calculate_rowcheck_instance_witness(
+                                circuit,
+                                j,
+                                [z_a, z_b, z_c],
+                                circuit_combiner,
+                                instance_combiner,
+                                constraint_domain,
+                                max_constraint_domain,
+                                max_chunk_len,
+                            )
+                        });
+                    }
+                }
+                let h_0_polys = job_pool.execute_all();
+                state.memory.release(num_released_bytes);
+                h_0_polys
+            }
+        };
 
         let h_sum_time = start_timer!(|| "AHP::Prover::SecondRound h_sum");
         let h_sum: DensePolynomial<F> =
-            cfg_reduce!(cfg_into_iter!(job_pool.execute_all()), || Ok(DensePolynomial::zero()), |a, b| {
+            cfg_reduce!(cfg_into_iter!(h_0_polys), || Ok(DensePolynomial::zero()), |a, b| {
                 a.and_then(|a| {
                     b.map(|mut b| {
                         b += &a;
@@ -141,31 +154,73 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(h_sum)
     }
 
+    /// Computes the rowcheck witness of the `j`-th instance of `circuit` from its `z_A`, `z_B` and `z_C` vectors.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn calculate_rowcheck_instance_witness(
+        circuit: &Circuit<F, SM>,
+        j: usize,
+        [z_a, z_b, z_c]: [prover::RoundVec<F>; 3],
+        circuit_combiner: F,
+        instance_combiner: F,
+        constraint_domain: EvaluationDomain<F>,
+        max_constraint_domain: EvaluationDomain<F>,
+        max_chunk_len: usize,
+    ) -> Result<DensePolynomial<F>> {
+        let mut instance_lhs = DensePolynomial::zero();
+        let za_label = witness_label(circuit.id, "z_a", j);
+        let zb_label = witness_label(circuit.id, "z_b", j);
+        let zc_label = witness_label(circuit.id, "z_c", j);
+        let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit, max_chunk_len)?;
+        let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit, max_chunk_len)?;
+        let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit, max_chunk_len)?;
+        let mut multiplier_2 = PolyMultiplier::new();
+        multiplier_2.add_precomputation(&circuit.fft_precomputation, &circuit.ifft_precomputation);
+        multiplier_2.add_polynomial(z_a, "z_a");
+        multiplier_2.add_polynomial(z_b, "z_b");
+        let mut rowcheck = multiplier_2.multiply().unwrap();
+        cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
+
+        instance_lhs += &(&rowcheck * instance_combiner);
+
+        let (h_0_i, remainder) = apply_randomized_selector(
+            &mut instance_lhs,
+            circuit_combiner,
+            &max_constraint_domain,
+            &constraint_domain,
+            false,
+        )?;
+        assert!(remainder.is_none());
+        Ok(h_0_i)
+    }
+
     fn calculate_z_m(
         label: impl ToString,
-        evaluations: Vec<F>,
+        evaluations: prover::RoundVec<F>,
         constraint_domain: EvaluationDomain<F>,
         circuit: &Circuit<F, SM>,
-    ) -> DensePolynomial<F> {
+        max_chunk_len: usize,
+    ) -> Result<DensePolynomial<F>> {
         let label = label.to_string();
         let poly_time = start_timer!(|| format!("Computing {label}"));
 
-        let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain);
-        let poly = evals.interpolate_with_pc_by_ref(&circuit.ifft_precomputation);
-
-        debug_assert!(
-            poly.evaluate_over_domain_by_ref(constraint_domain)
-                .evaluations
-                .into_iter()
-                .zip(&evals.evaluations)
-                .all(|(z, e)| *e == z),
-            "Label: {label}\n1: {:#?}\n2: {:#?}",
-            poly.evaluate_over_domain_by_ref(constraint_domain).evaluations,
-            &evals.evaluations,
-        );
+        let poly = match evaluations {
+            // The evaluations are interpolated in place, so that they are not held in memory twice.
+            prover::RoundVec::InMemory(evaluations) => {
+                EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain)
+                    .interpolate_with_pc(&circuit.ifft_precomputation)
+            }
+            // Spilled evaluations are interpolated out of core, so that only the coefficients are read back.
+            prover::RoundVec::Spilled(mut evaluations) => {
+                let coeffs = constraint_domain
+                    .ifft_out_of_core(&mut evaluations, max_chunk_len)
+                    .and_then(|mut coeffs| coeffs.to_vec())
+                    .with_context(|| format!("Failed to interpolate {label}"))?;
+                DensePolynomial::from_coefficients_vec(coeffs)
+            }
+        };
 
         end_timer!(poly_time);
 
-        poly
+        Ok(poly)
     }
 }
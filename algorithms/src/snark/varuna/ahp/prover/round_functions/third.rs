@@ -24,6 +24,7 @@ use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
         ahp::{indexer::CircuitId, verifier, AHPForR1CS},
+        distributed::{Request, Response},
         matrices::transpose,
         prover::{self, MatrixSums, ThirdMessage},
         selectors::apply_randomized_selector,
@@ -43,10 +44,11 @@ use std::collections::BTreeMap;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
-struct LinevalInstance<F: PrimeField> {
-    h_1_i: DensePolynomial<F>,
-    xg_1_i: DensePolynomial<F>,
-    sum: F,
+/// The lineval sumcheck witness of a single instance and matrix.
+pub(crate) struct LinevalInstance<F: PrimeField> {
+    pub(crate) h_1_i: DensePolynomial<F>,
+    pub(crate) xg_1_i: DensePolynomial<F>,
+    pub(crate) sum: F,
 }
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
@@ -82,18 +84,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let verifier::FirstMessage { batch_combiners } = verifier_message;
         let verifier::SecondMessage { alpha, eta_b, eta_c } = verifier_second_message;
 
-        let assignments = Self::calculate_assignments(&mut state)?;
-        let matrix_transposes = Self::calculate_matrix_transpose(&mut state)?;
-
-        let (h_1, x_g_1_sum, msg) = Self::calculate_lineval_sumcheck_witness(
-            &mut state,
-            batch_combiners,
-            assignments,
-            matrix_transposes,
-            alpha,
-            eta_b,
-            eta_c,
-        )?;
+        let linevals = Self::calculate_lineval_instance_witnesses(&mut state, batch_combiners, alpha, eta_b, eta_c)?;
+        let (h_1, x_g_1_sum, msg) = Self::calculate_lineval_sumcheck_witness(&mut state, batch_combiners, linevals)?;
 
         #[cfg(debug_assertions)]
         {
@@ -123,21 +115,44 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok((msg, oracles, state))
     }
 
-    fn calculate_lineval_sumcheck_witness(
+    fn calculate_lineval_instance_witnesses(
         state: &mut prover::State<F, SM>,
         batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
-        assignments: BTreeMap<CircuitId, Vec<DensePolynomial<F>>>,
-        matrix_transposes: BTreeMap<CircuitId, BTreeMap<String, Matrix<F>>>,
         alpha: &F,
         eta_b: &F,
         eta_c: &F,
-    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>, ThirdMessage<F>)> {
-        let num_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).collect_vec();
-        let total_instances = num_instances.iter().sum::<usize>();
-        let max_variable_domain = &state.max_variable_domain;
-        let matrix_labels = ["a", "b", "c"];
+    ) -> Result<Vec<Result<LinevalInstance<F>>>> {
+        let max_variable_domain = state.max_variable_domain;
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
+        // The witness polynomials are held by the workers, which compute the lineval witnesses of their instances.
+        if let Some(workers) = &mut state.workers {
+            let mut requests = Vec::with_capacity(state.total_instances);
+            for circuit in state.circuit_specific_states.keys() {
+                let combiners = &batch_combiners[&circuit.id];
+                for (index, &instance_combiner) in combiners.instance_combiners.iter().enumerate() {
+                    let combiner = combiners.circuit_combiner * instance_combiner;
+                    requests.push(Request::ThirdRound {
+                        circuit_id: circuit.id,
+                        index,
+                        alpha: *alpha,
+                        combiners: matrix_combiners.map(|matrix_combiner| combiner * matrix_combiner),
+                        max_variable_domain,
+                    });
+                }
+            }
+            let linevals =
+                workers.execute(requests)?.into_iter().map(Response::into_linevals).collect::<Result<Vec<_>>>()?;
+            return Ok(linevals.into_iter().flatten().map(Ok).collect());
+        }
+
+        let assignments = Self::calculate_assignments(state)?;
+        let matrix_transposes = Self::calculate_matrix_transpose(state)?;
+
+        let total_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).sum::<usize>();
+        let max_variable_domain = &max_variable_domain;
+        let matrix_labels = ["a", "b", "c"];
+
         // Compute lineval sumcheck witnesses
         let mut job_pool = ExecutionPool::with_capacity(total_instances * 3);
         for ((((circuit, circuit_specific_state), batch_combiner), assignments_i), matrix_transposes_i) in state
@@ -175,13 +190,23 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                 }
             }
         }
+        Ok(job_pool.execute_all())
+    }
+
+    fn calculate_lineval_sumcheck_witness(
+        state: &mut prover::State<F, SM>,
+        batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
+        mut linevals: Vec<Result<LinevalInstance<F>>>,
+    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>, ThirdMessage<F>)> {
+        let num_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).collect_vec();
+        let max_variable_domain = &state.max_variable_domain;
 
         let mut sums = num_instances.iter().map(|n| Vec::with_capacity(*n)).collect_vec();
         let mut h_1_sum = DensePolynomial::zero();
         let mut xg_1_sum = DensePolynomial::zero();
         let mut circuit_index = 0;
         let mut instances_seen = 0;
-        for (i, linevals) in job_pool.execute_all().chunks_exact_mut(3).enumerate() {
+        for (i, linevals) in linevals.chunks_exact_mut(3).enumerate() {
             if linevals[0].is_ok() && linevals[1].is_ok() && linevals[2].is_ok() {
                 let lineval_a = linevals[0].as_ref().unwrap();
                 let lineval_b = linevals[1].as_ref().unwrap();
@@ -233,10 +258,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     .enumerate()
                     .map(|(_j, (w_poly, x_poly))| {
                         let z_time = start_timer!(move || format!("Compute z poly for circuit {} {}", circuit.id, _j));
-                        let mut assignment =
-                            w_poly.0.polynomial().as_dense().unwrap().mul_by_vanishing_poly(*input_domain);
-                        // Zip safety: `x_poly` is smaller than `z_poly`.
-                        assignment.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
+                        let w_poly = w_poly.0.polynomial().as_dense().unwrap();
+                        let assignment = Self::calculate_assignment(w_poly, x_poly, input_domain);
                         end_timer!(z_time);
                         assignment
                     })
@@ -248,6 +271,18 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(assignments)
     }
 
+    /// Computes the polynomial of an instance's full assignment from its witness and public input polynomials.
+    pub(crate) fn calculate_assignment(
+        w_poly: &DensePolynomial<F>,
+        x_poly: &DensePolynomial<F>,
+        input_domain: &EvaluationDomain<F>,
+    ) -> DensePolynomial<F> {
+        let mut assignment = w_poly.mul_by_vanishing_poly(*input_domain);
+        // Zip safety: `x_poly` is smaller than `z_poly`.
+        assignment.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
+        assignment
+    }
+
     fn calculate_matrix_transpose(
         state: &mut prover::State<F, SM>,
     ) -> Result<BTreeMap<CircuitId, BTreeMap<String, Matrix<F>>>> {
@@ -276,8 +311,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(matrix_transposes)
     }
 
+    /// Computes the lineval sumcheck witness of an instance for the given matrix.
     #[allow(clippy::too_many_arguments)]
-    fn calculate_lineval_sumcheck_instance_witness(
+    pub(crate) fn calculate_lineval_sumcheck_instance_witness(
         _label: &str,
         constraint_domain: &EvaluationDomain<F>,
         variable_domain: &EvaluationDomain<F>,
@@ -19,9 +19,9 @@ use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::LabeledPolynomial,
     r1cs::{SynthesisError, SynthesisResult},
-    snark::varuna::{AHPError, AHPForR1CS, Circuit, SNARKMode},
+    snark::varuna::{distributed::WorkerPool, AHPError, AHPForR1CS, Circuit, SNARKMode},
 };
-use anyhow::anyhow;
+use anyhow::{anyhow, Result};
 use snarkvm_fields::PrimeField;
 
 /// Circuit Specific State of the Prover
@@ -42,19 +42,19 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// The list of private variables for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) private_variables: Vec<Vec<F>>,
+    pub(super) private_variables: Vec<super::RoundVec<F>>,
 
     /// The list of Az vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_a: Option<Vec<Vec<F>>>,
+    pub(super) z_a: Option<Vec<super::RoundVec<F>>>,
 
     /// The list of Bz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_b: Option<Vec<Vec<F>>>,
+    pub(super) z_b: Option<Vec<super::RoundVec<F>>>,
 
     /// The list of Cz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_c: Option<Vec<Vec<F>>>,
+    pub(super) z_c: Option<Vec<super::RoundVec<F>>>,
 
     /// A list of polynomials corresponding to the interpolation of the public input.
     /// The length of this list must be equal to the batch size.
@@ -66,8 +66,8 @@ pub struct CircuitSpecificState<F: PrimeField> {
     /// Intermediary polynomials of the matrix sumcheck.
     pub(in crate::snark) b_polys: Option<[LabeledPolynomial<F>; 3]>,
 
-    /// Intermediary polynomials of the matrix sumcheck.
-    pub(super) lhs_polynomials: Option<[DensePolynomial<F>; 3]>,
+    /// The coefficients of the intermediary polynomials of the matrix sumcheck.
+    pub(super) lhs_polynomials: Option<[super::RoundVec<F>; 3]>,
 }
 
 /// State for the AHP prover.
@@ -85,6 +85,10 @@ pub struct State<'a, F: PrimeField, SM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// The memory that the prover may use to hold state between rounds.
+    pub(super) memory: super::MemoryBudget,
+    /// The workers that hold the witnesses of the batch, if the witnesses are not held by this prover.
+    pub(super) workers: Option<WorkerPool>,
 }
 
 /// The public inputs for a single instance.
@@ -98,12 +102,12 @@ type Zb<F> = Vec<F>;
 /// The z_i_j*C_i vector for a single instance.
 type Zc<F> = Vec<F>;
 /// Assignments for a single instance.
-pub(super) struct Assignments<F>(
-    pub(super) PaddedPubInputs<F>,
-    pub(super) PrivateInputs<F>,
-    pub(super) Za<F>,
-    pub(super) Zb<F>,
-    pub(super) Zc<F>,
+pub(crate) struct Assignments<F>(
+    pub(crate) PaddedPubInputs<F>,
+    pub(crate) PrivateInputs<F>,
+    pub(crate) Za<F>,
+    pub(crate) Zb<F>,
+    pub(crate) Zc<F>,
 );
 
 impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
@@ -144,14 +148,14 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
                 let mut private_variables = Vec::with_capacity(batch_size);
 
                 for Assignments(padded_public_input, private_input, z_a, z_b, z_c) in variable_assignments {
-                    z_as.push(z_a);
-                    z_bs.push(z_b);
-                    z_cs.push(z_c);
+                    z_as.push(super::RoundVec::InMemory(z_a));
+                    z_bs.push(super::RoundVec::InMemory(z_b));
+                    z_cs.push(super::RoundVec::InMemory(z_c));
                     let x_poly = EvaluationsOnDomain::from_vec_and_domain(padded_public_input.clone(), input_domain)
                         .interpolate();
                     x_polys.push(x_poly);
                     padded_public_variables.push(padded_public_input);
-                    private_variables.push(private_input);
+                    private_variables.push(super::RoundVec::InMemory(private_input));
                 }
 
                 let state = CircuitSpecificState {
@@ -187,9 +191,31 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            memory: super::MemoryBudget::new(None),
+            workers: None,
         })
     }
 
+    /// Limits the state held between rounds to `budget` bytes, spilling the rest to temporary files.
+    /// The vectors that are consumed first are the first to be kept in memory.
+    pub(in crate::snark) fn limit_memory(&mut self, budget: Option<usize>) -> Result<()> {
+        let mut memory = super::MemoryBudget::new(budget);
+        for state in self.circuit_specific_states.values_mut() {
+            state.private_variables = core::mem::take(&mut state.private_variables)
+                .into_iter()
+                .map(|private_variables| memory.store(private_variables.into_vec()?))
+                .collect::<Result<_>>()?;
+        }
+        for state in self.circuit_specific_states.values_mut() {
+            for z_m in [&mut state.z_a, &mut state.z_b, &mut state.z_c].into_iter().flatten() {
+                *z_m =
+                    core::mem::take(z_m).into_iter().map(|z_m| memory.store(z_m.into_vec()?)).collect::<Result<_>>()?;
+            }
+        }
+        self.memory = memory;
+        Ok(())
+    }
+
     /// Get the batch size for a given circuit.
     pub fn batch_size(&self, circuit: &Circuit<F, SM>) -> Option<usize> {
         self.circuit_specific_states.get(circuit).map(|s| s.batch_size)
@@ -208,8 +234,11 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
         self.circuit_specific_states.get(circuit).map(|s| s.padded_public_variables.as_slice())
     }
 
-    /// Iterate over the lhs_polynomials
-    pub fn lhs_polys_into_iter(self) -> impl Iterator<Item = DensePolynomial<F>> + 'a {
-        self.circuit_specific_states.into_values().flat_map(|s| s.lhs_polynomials.unwrap().into_iter())
+    /// Iterate over the lhs_polynomials, reading them from disk if they were spilled.
+    pub fn lhs_polys_into_iter(self) -> impl Iterator<Item = Result<DensePolynomial<F>>> + 'a {
+        self.circuit_specific_states
+            .into_values()
+            .flat_map(|s| s.lhs_polynomials.unwrap().into_iter())
+            .map(|lhs| lhs.into_vec().map(DensePolynomial::from_coefficients_vec))
     }
 }
@@ -15,6 +15,11 @@
 
 use crate::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
 use snarkvm_fields::Field;
+use snarkvm_utilities::{
+    io::{self, Read, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use rand::{CryptoRng, Rng};
 
@@ -89,6 +94,40 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for TestCircuit<Cons
     }
 }
 
+impl<F: Field> ToBytes for TestCircuit<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for value in [self.a, self.b] {
+            match value {
+                Some(value) => {
+                    true.write_le(&mut writer)?;
+                    value.write_le(&mut writer)?;
+                }
+                None => false.write_le(&mut writer)?,
+            }
+        }
+        (self.num_constraints as u64).write_le(&mut writer)?;
+        (self.num_variables as u64).write_le(&mut writer)?;
+        (self.mul_depth as u64).write_le(&mut writer)
+    }
+}
+
+impl<F: Field> FromBytes for TestCircuit<F> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut read_value = || -> io::Result<Option<F>> {
+            match bool::read_le(&mut reader)? {
+                true => Ok(Some(F::read_le(&mut reader)?)),
+                false => Ok(None),
+            }
+        };
+        let a = read_value()?;
+        let b = read_value()?;
+        let num_constraints = u64::read_le(&mut reader)? as usize;
+        let num_variables = u64::read_le(&mut reader)? as usize;
+        let mul_depth = u64::read_le(&mut reader)? as usize;
+        Ok(Self { a, b, num_constraints, num_variables, mul_depth })
+    }
+}
+
 impl<F: Field> TestCircuit<F> {
     // Generate a test circuit with a random witness.
     pub fn gen_rand<R: Rng + CryptoRng>(
@@ -0,0 +1,87 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{read_message, write_message, Request, Response};
+use snarkvm_fields::PrimeField;
+
+use anyhow::{anyhow, ensure, Result};
+use itertools::Itertools;
+use std::net::{SocketAddr, TcpStream};
+
+/// The connections from the coordinator to the workers of a batch.
+///
+/// The `k`-th request of a round is sent to the worker `k mod n`. As the coordinator requests the instances of a batch
+/// in the same order in every round, each instance is handled by the worker that holds its witness.
+pub(crate) struct WorkerPool {
+    /// The connection to each worker.
+    streams: Vec<TcpStream>,
+}
+
+impl WorkerPool {
+    /// Connects to the workers at the given addresses, starting a new session with each of them.
+    pub(crate) fn connect(addresses: &[SocketAddr]) -> Result<Self> {
+        ensure!(!addresses.is_empty(), "The distributed prover requires at least one worker");
+        let streams = addresses
+            .iter()
+            .map(|address| {
+                let stream = TcpStream::connect(address)?;
+                stream.set_nodelay(true)?;
+                Ok(stream)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { streams })
+    }
+
+    /// Sends the requests to the workers, and returns the responses in the order of the requests.
+    /// The workers process their requests in parallel.
+    pub(crate) fn execute<F: PrimeField>(&mut self, requests: Vec<Request<F>>) -> Result<Vec<Response<F>>> {
+        let num_workers = self.streams.len();
+        let num_requests = requests.len();
+
+        let mut worker_requests = (0..num_workers).map(|_| Vec::new()).collect_vec();
+        for (k, request) in requests.into_iter().enumerate() {
+            worker_requests[k % num_workers].push(request);
+        }
+
+        let worker_responses = std::thread::scope(|scope| {
+            let handles = self
+                .streams
+                .iter_mut()
+                .zip_eq(worker_requests)
+                .map(|(stream, requests)| {
+                    scope.spawn(move || {
+                        requests
+                            .into_iter()
+                            .map(|request| {
+                                write_message(&mut *stream, &request)?;
+                                read_message(&mut *stream)?.ok_or_else(|| anyhow!("A worker closed the connection"))
+                            })
+                            .collect::<Result<Vec<Response<F>>>>()
+                    })
+                })
+                .collect_vec();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().map_err(|_| anyhow!("The connection to a worker panicked"))?)
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        // Restore the order of the requests.
+        let mut worker_responses = worker_responses.into_iter().map(Vec::into_iter).collect_vec();
+        (0..num_requests)
+            .map(|k| worker_responses[k % num_workers].next().ok_or_else(|| anyhow!("A worker response is missing")))
+            .collect()
+    }
+}
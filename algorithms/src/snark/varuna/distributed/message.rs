@@ -0,0 +1,401 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::{DensePolynomial, EvaluationDomain},
+    snark::varuna::{prover::LinevalInstance, CircuitId},
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{anyhow, ensure, Result};
+
+/// The maximum size of a message between the coordinator and a worker, in bytes.
+/// Note: This exceeds the size of the polynomials of the largest circuit within the constraint limit of a transition.
+pub(crate) const MAX_MESSAGE_SIZE: u64 = 1 << 30;
+
+/// The maximum length of an error message from a worker, in bytes.
+const MAX_ERROR_LENGTH: u64 = 1 << 16;
+
+/// A request from the coordinator to a worker.
+///
+/// Every request refers to an instance of the batch, by its circuit and by its index in the batch of the circuit.
+pub(crate) enum Request<F: PrimeField> {
+    /// Synthesizes the witness of the serialized `instance`, and returns its padded public input.
+    Witness { circuit_id: CircuitId, index: usize, randomizer: Option<[F; 3]>, instance: Vec<u8> },
+    /// Returns the witness polynomial of the instance.
+    FirstRound { circuit_id: CircuitId, index: usize },
+    /// Returns the rowcheck witness of the instance.
+    SecondRound {
+        circuit_id: CircuitId,
+        index: usize,
+        circuit_combiner: F,
+        instance_combiner: F,
+        max_constraint_domain: EvaluationDomain<F>,
+    },
+    /// Returns the lineval sumcheck witnesses of the instance, for the `A`, `B` and `C` matrices.
+    ThirdRound {
+        circuit_id: CircuitId,
+        index: usize,
+        alpha: F,
+        combiners: [F; 3],
+        max_variable_domain: EvaluationDomain<F>,
+    },
+}
+
+/// A response from a worker to the coordinator.
+pub(crate) enum Response<F: PrimeField> {
+    /// The padded public input of an instance.
+    PublicInput(Vec<F>),
+    /// A polynomial of an instance.
+    Polynomial(DensePolynomial<F>),
+    /// The lineval sumcheck witnesses of an instance, for the `A`, `B` and `C` matrices.
+    Linevals([LinevalInstance<F>; 3]),
+    /// The worker failed to process the request.
+    Error(String),
+}
+
+impl<F: PrimeField> Response<F> {
+    /// Returns the padded public input in the response.
+    pub(crate) fn into_public_input(self) -> Result<Vec<F>> {
+        match self {
+            Self::PublicInput(public_input) => Ok(public_input),
+            response => Err(response.into_error()),
+        }
+    }
+
+    /// Returns the polynomial in the response.
+    pub(crate) fn into_polynomial(self) -> Result<DensePolynomial<F>> {
+        match self {
+            Self::Polynomial(polynomial) => Ok(polynomial),
+            response => Err(response.into_error()),
+        }
+    }
+
+    /// Returns the lineval sumcheck witnesses in the response.
+    pub(crate) fn into_linevals(self) -> Result<[LinevalInstance<F>; 3]> {
+        match self {
+            Self::Linevals(linevals) => Ok(linevals),
+            response => Err(response.into_error()),
+        }
+    }
+
+    /// Returns the error for a response that is not of the expected kind.
+    fn into_error(self) -> anyhow::Error {
+        match self {
+            Self::Error(message) => anyhow!("The worker failed to process the request: {message}"),
+            _ => anyhow!("The worker sent an unexpected response"),
+        }
+    }
+}
+
+/// Writes the message to the stream, prefixed with its length in bytes.
+pub(crate) fn write_message<W: Write>(mut stream: W, message: &impl ToBytes) -> Result<()> {
+    let bytes = message.to_bytes_le()?;
+    let length = bytes.len() as u64;
+    ensure!(length <= MAX_MESSAGE_SIZE, "The message size ({length} bytes) exceeds the maximum of {MAX_MESSAGE_SIZE}");
+    length.write_le(&mut stream)?;
+    stream.write_all(&bytes)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Reads a message from the stream, or returns `None` if the stream was closed before the message.
+pub(crate) fn read_message<R: Read, T: FromBytes>(mut stream: R) -> Result<Option<T>> {
+    let mut length = [0u8; 8];
+    match stream.read_exact(&mut length) {
+        Ok(()) => (),
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    // Ensure the message size is within bounds, before allocating the message.
+    let length = u64::from_le_bytes(length);
+    ensure!(length <= MAX_MESSAGE_SIZE, "The message size ({length} bytes) exceeds the maximum of {MAX_MESSAGE_SIZE}");
+    let bytes = read_exact_bytes(&mut stream, length)?;
+    Ok(Some(T::from_bytes_le(&bytes)?))
+}
+
+/// Reads the given number of bytes from the stream.
+/// Note: The buffer grows with the bytes that are read, so a peer cannot allocate more than it sends.
+fn read_exact_bytes<R: Read>(stream: R, length: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    stream.take(length).read_to_end(&mut bytes)?;
+    match bytes.len() as u64 == length {
+        true => Ok(bytes),
+        false => Err(io::ErrorKind::UnexpectedEof.into()),
+    }
+}
+
+/// Reads a length-prefixed byte vector of at most `max_length` bytes.
+fn read_bytes<R: Read>(mut reader: R, max_length: u64) -> io::Result<Vec<u8>> {
+    let length = u64::read_le(&mut reader)?;
+    if length > max_length {
+        return Err(error("The length of a distributed prover message field is out of bounds"));
+    }
+    read_exact_bytes(reader, length)
+}
+
+/// Reads a vector of field elements, as serialized by `write_canonical`, within the maximum message size.
+fn read_elements<F: PrimeField, R: Read>(mut reader: R) -> io::Result<Vec<F>> {
+    let length: u64 = read_canonical(&mut reader)?;
+    if length > MAX_MESSAGE_SIZE / F::zero().compressed_size() as u64 {
+        return Err(error("The number of field elements in a distributed prover message is out of bounds"));
+    }
+    (0..length).map(|_| read_canonical(&mut reader)).collect()
+}
+
+/// Reads a polynomial, as serialized by `write_canonical`, within the maximum message size.
+fn read_polynomial<F: PrimeField, R: Read>(reader: R) -> io::Result<DensePolynomial<F>> {
+    Ok(DensePolynomial { coeffs: read_elements(reader)? })
+}
+
+impl<F: PrimeField> ToBytes for Request<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        match self {
+            Self::Witness { circuit_id, index, randomizer, instance } => {
+                0u8.write_le(&mut writer)?;
+                write_instance(circuit_id, *index, &mut writer)?;
+                match randomizer {
+                    Some(randomizer) => {
+                        true.write_le(&mut writer)?;
+                        randomizer.iter().try_for_each(|element| element.write_le(&mut writer))?;
+                    }
+                    None => false.write_le(&mut writer)?,
+                }
+                (instance.len() as u64).write_le(&mut writer)?;
+                writer.write_all(instance)
+            }
+            Self::FirstRound { circuit_id, index } => {
+                1u8.write_le(&mut writer)?;
+                write_instance(circuit_id, *index, &mut writer)
+            }
+            Self::SecondRound { circuit_id, index, circuit_combiner, instance_combiner, max_constraint_domain } => {
+                2u8.write_le(&mut writer)?;
+                write_instance(circuit_id, *index, &mut writer)?;
+                circuit_combiner.write_le(&mut writer)?;
+                instance_combiner.write_le(&mut writer)?;
+                write_canonical(max_constraint_domain, &mut writer)
+            }
+            Self::ThirdRound { circuit_id, index, alpha, combiners, max_variable_domain } => {
+                3u8.write_le(&mut writer)?;
+                write_instance(circuit_id, *index, &mut writer)?;
+                alpha.write_le(&mut writer)?;
+                combiners.iter().try_for_each(|combiner| combiner.write_le(&mut writer))?;
+                write_canonical(max_variable_domain, &mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for Request<F> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => {
+                let (circuit_id, index) = read_instance(&mut reader)?;
+                let randomizer = match bool::read_le(&mut reader)? {
+                    true => Some([F::read_le(&mut reader)?, F::read_le(&mut reader)?, F::read_le(&mut reader)?]),
+                    false => None,
+                };
+                let instance = read_bytes(&mut reader, MAX_MESSAGE_SIZE)?;
+                Ok(Self::Witness { circuit_id, index, randomizer, instance })
+            }
+            1 => {
+                let (circuit_id, index) = read_instance(&mut reader)?;
+                Ok(Self::FirstRound { circuit_id, index })
+            }
+            2 => {
+                let (circuit_id, index) = read_instance(&mut reader)?;
+                let circuit_combiner = F::read_le(&mut reader)?;
+                let instance_combiner = F::read_le(&mut reader)?;
+                let max_constraint_domain = read_canonical(&mut reader)?;
+                Ok(Self::SecondRound { circuit_id, index, circuit_combiner, instance_combiner, max_constraint_domain })
+            }
+            3 => {
+                let (circuit_id, index) = read_instance(&mut reader)?;
+                let alpha = F::read_le(&mut reader)?;
+                let combiners = [F::read_le(&mut reader)?, F::read_le(&mut reader)?, F::read_le(&mut reader)?];
+                let max_variable_domain = read_canonical(&mut reader)?;
+                Ok(Self::ThirdRound { circuit_id, index, alpha, combiners, max_variable_domain })
+            }
+            _ => Err(error("Invalid request variant")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for Response<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        match self {
+            Self::PublicInput(public_input) => {
+                0u8.write_le(&mut writer)?;
+                write_canonical(public_input, &mut writer)
+            }
+            Self::Polynomial(polynomial) => {
+                1u8.write_le(&mut writer)?;
+                write_canonical(polynomial, &mut writer)
+            }
+            Self::Linevals(linevals) => {
+                2u8.write_le(&mut writer)?;
+                linevals.iter().try_for_each(|lineval| {
+                    write_canonical(&lineval.h_1_i, &mut writer)?;
+                    write_canonical(&lineval.xg_1_i, &mut writer)?;
+                    lineval.sum.write_le(&mut writer)
+                })
+            }
+            Self::Error(message) => {
+                3u8.write_le(&mut writer)?;
+                (message.len() as u64).write_le(&mut writer)?;
+                writer.write_all(message.as_bytes())
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for Response<F> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::PublicInput(read_elements(&mut reader)?)),
+            1 => Ok(Self::Polynomial(read_polynomial(&mut reader)?)),
+            2 => {
+                let mut read_lineval = || -> io::Result<_> {
+                    let h_1_i = read_polynomial(&mut reader)?;
+                    let xg_1_i = read_polynomial(&mut reader)?;
+                    let sum = F::read_le(&mut reader)?;
+                    Ok(LinevalInstance { h_1_i, xg_1_i, sum })
+                };
+                Ok(Self::Linevals([read_lineval()?, read_lineval()?, read_lineval()?]))
+            }
+            3 => {
+                let message = read_bytes(&mut reader, MAX_ERROR_LENGTH)?;
+                Ok(Self::Error(String::from_utf8(message).map_err(|_| error("Invalid error message"))?))
+            }
+            _ => Err(error("Invalid response variant")),
+        }
+    }
+}
+
+/// Writes the circuit ID and the index of an instance.
+fn write_instance<W: Write>(circuit_id: &CircuitId, index: usize, mut writer: W) -> io::Result<()> {
+    circuit_id.0.write_le(&mut writer)?;
+    (index as u64).write_le(&mut writer)
+}
+
+/// Reads the circuit ID and the index of an instance.
+fn read_instance<R: Read>(mut reader: R) -> io::Result<(CircuitId, usize)> {
+    let circuit_id = CircuitId(FromBytes::read_le(&mut reader)?);
+    let index = usize::try_from(u64::read_le(&mut reader)?).map_err(|_| error("Invalid instance index"))?;
+    Ok((circuit_id, index))
+}
+
+fn write_canonical<W: Write>(value: &impl CanonicalSerialize, writer: W) -> io::Result<()> {
+    value.serialize_compressed(writer).map_err(|_| error("Could not serialize a distributed prover message"))
+}
+
+fn read_canonical<R: Read, T: CanonicalDeserialize>(reader: R) -> io::Result<T> {
+    T::deserialize_compressed(reader).map_err(|_| error("Could not deserialize a distributed prover message"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_request_bytes() {
+        let rng = &mut TestRng::default();
+        let circuit_id = CircuitId([7u8; 32]);
+        let requests = [
+            Request::Witness {
+                circuit_id,
+                index: 3,
+                randomizer: Some([Fr::rand(rng), Fr::rand(rng), Fr::rand(rng)]),
+                instance: vec![1, 2, 3],
+            },
+            Request::Witness { circuit_id, index: 0, randomizer: None, instance: vec![] },
+            Request::FirstRound { circuit_id, index: 5 },
+            Request::SecondRound {
+                circuit_id,
+                index: 1,
+                circuit_combiner: Fr::rand(rng),
+                instance_combiner: Fr::rand(rng),
+                max_constraint_domain: EvaluationDomain::new(64).unwrap(),
+            },
+            Request::ThirdRound {
+                circuit_id,
+                index: 2,
+                alpha: Fr::rand(rng),
+                combiners: [Fr::rand(rng), Fr::rand(rng), Fr::rand(rng)],
+                max_variable_domain: EvaluationDomain::new(128).unwrap(),
+            },
+        ];
+        for request in requests {
+            let bytes = request.to_bytes_le().unwrap();
+            let candidate = Request::<Fr>::from_bytes_le(&bytes).unwrap();
+            assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_response_framing() {
+        let rng = &mut TestRng::default();
+        let polynomial = DensePolynomial::<Fr>::rand(16, rng);
+
+        let mut stream = Vec::new();
+        write_message(&mut stream, &Response::Polynomial(polynomial.clone())).unwrap();
+        write_message(&mut stream, &Response::<Fr>::Error("failure".to_string())).unwrap();
+
+        let mut stream = &stream[..];
+        let response = read_message::<_, Response<Fr>>(&mut stream).unwrap().unwrap();
+        assert_eq!(response.into_polynomial().unwrap(), polynomial);
+        let response = read_message::<_, Response<Fr>>(&mut stream).unwrap().unwrap();
+        assert!(response.into_polynomial().is_err());
+        // The stream is closed after the last message.
+        assert!(read_message::<_, Response<Fr>>(&mut stream).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_message_bounds() {
+        // A frame that exceeds the maximum message size is rejected before it is read.
+        let stream = (MAX_MESSAGE_SIZE + 1).to_le_bytes();
+        assert!(read_message::<_, Response<Fr>>(&stream[..]).is_err());
+        // A frame that is longer than the stream is rejected.
+        let stream = [16u64.to_le_bytes().as_slice(), &[1u8; 8]].concat();
+        assert!(read_message::<_, Response<Fr>>(&stream[..]).is_err());
+
+        // An instance that exceeds the maximum message size is rejected.
+        let circuit_id = CircuitId([0u8; 32]);
+        let request = Request::<Fr>::Witness { circuit_id, index: 0, randomizer: None, instance: vec![] };
+        let mut bytes = request.to_bytes_le().unwrap();
+        let length = bytes.len();
+        bytes[length - 8..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Request::<Fr>::from_bytes_le(&bytes).is_err());
+
+        // An error message that exceeds the maximum length is rejected.
+        let mut bytes = Response::<Fr>::Error(String::new()).to_bytes_le().unwrap();
+        bytes[1..].copy_from_slice(&(MAX_ERROR_LENGTH + 1).to_le_bytes());
+        assert!(Response::<Fr>::from_bytes_le(&bytes).is_err());
+
+        // A polynomial that exceeds the maximum message size is rejected.
+        let mut bytes = Response::Polynomial(DensePolynomial::<Fr>::zero()).to_bytes_le().unwrap();
+        bytes[1..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Response::<Fr>::from_bytes_le(&bytes).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{read_message, write_message, Request, Response};
+use crate::{
+    fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    r1cs::{ConstraintSynthesizer, SynthesisError},
+    snark::varuna::{
+        ahp::{matrices::transpose, AHPForR1CS},
+        prover,
+        witness_label,
+        Circuit,
+        CircuitId,
+        Matrix,
+        SNARKMode,
+    },
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::FromBytes;
+
+use anyhow::{anyhow, Result};
+use core::marker::PhantomData;
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+};
+
+/// A worker of the distributed Varuna prover.
+///
+/// The worker synthesizes the witnesses of the instances that the coordinator assigns to it, and computes
+/// their contributions to the first three rounds of the prover. The witnesses never leave the worker.
+pub struct Worker<F: PrimeField, SM: SNARKMode, C> {
+    /// The circuits that the worker can prove, by ID.
+    circuits: BTreeMap<CircuitId, Arc<Circuit<F, SM>>>,
+    _instance: PhantomData<C>,
+}
+
+/// The state of a session with a coordinator.
+struct Session<F: PrimeField> {
+    /// The state of each instance assigned to the worker, by circuit ID and index.
+    instances: BTreeMap<(CircuitId, usize), InstanceState<F>>,
+    /// The transposes of the `A`, `B` and `C` matrices of each circuit.
+    matrix_transposes: BTreeMap<CircuitId, [Matrix<F>; 3]>,
+}
+
+/// The state of an instance assigned to the worker.
+struct InstanceState<F: PrimeField> {
+    input_domain: EvaluationDomain<F>,
+    x_poly: DensePolynomial<F>,
+    private_variables: Option<Vec<F>>,
+    z: Option<[Vec<F>; 3]>,
+    w_poly: Option<DensePolynomial<F>>,
+}
+
+impl<F: PrimeField, SM: SNARKMode, C: ConstraintSynthesizer<F> + FromBytes> Worker<F, SM, C> {
+    /// Initializes a worker for the given circuits.
+    pub fn new(circuits: impl IntoIterator<Item = Arc<Circuit<F, SM>>>) -> Self {
+        let circuits = circuits.into_iter().map(|circuit| (circuit.id, circuit)).collect();
+        Self { circuits, _instance: PhantomData }
+    }
+
+    /// Serves the sessions of the coordinators that connect to the listener, one at a time.
+    pub fn serve(&self, listener: &TcpListener) -> Result<()> {
+        for stream in listener.incoming() {
+            if let Err(error) = self.serve_session(stream?) {
+                warn!("Distributed prover session failed: {error}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Serves the session of the coordinator on the other end of the stream, until the coordinator closes it.
+    pub fn serve_session(&self, mut stream: TcpStream) -> Result<()> {
+        stream.set_nodelay(true)?;
+        let mut session = Session { instances: BTreeMap::new(), matrix_transposes: BTreeMap::new() };
+        while let Some(request) = read_message(&mut stream)? {
+            let response =
+                self.process(&mut session, request).unwrap_or_else(|error| Response::Error(error.to_string()));
+            write_message(&mut stream, &response)?;
+        }
+        Ok(())
+    }
+
+    /// Processes a request of the coordinator.
+    fn process(&self, session: &mut Session<F>, request: Request<F>) -> Result<Response<F>> {
+        match request {
+            Request::Witness { circuit_id, index, randomizer, instance } => {
+                let circuit = self.circuit(&circuit_id)?;
+                let instance = C::from_bytes_le(&instance)?;
+                let prover::Assignments(padded_public_variables, private_variables, z_a, z_b, z_c) =
+                    AHPForR1CS::<F, SM>::synthesize_instance(circuit, &instance, randomizer, index)?;
+                let input_domain =
+                    EvaluationDomain::new(padded_public_variables.len()).ok_or(SynthesisError::PolyTooLarge)?;
+                let x_poly = EvaluationsOnDomain::from_vec_and_domain(padded_public_variables.clone(), input_domain)
+                    .interpolate();
+                let state = InstanceState {
+                    input_domain,
+                    x_poly,
+                    private_variables: Some(private_variables),
+                    z: Some([z_a, z_b, z_c]),
+                    w_poly: None,
+                };
+                session.instances.insert((circuit_id, index), state);
+                Ok(Response::PublicInput(padded_public_variables))
+            }
+            Request::FirstRound { circuit_id, index } => {
+                let circuit = self.circuit(&circuit_id)?;
+                let state = session.instance(circuit_id, index)?;
+                let private_variables = state.private_variables.take().ok_or_else(|| anyhow!("Missing witness"))?;
+                let w_poly = AHPForR1CS::<F, SM>::calculate_w(
+                    witness_label(circuit_id, "w", index),
+                    prover::RoundVec::InMemory(private_variables),
+                    state.x_poly.clone(),
+                    variable_domain(circuit)?,
+                    state.input_domain,
+                    circuit,
+                )?
+                .polynomial()
+                .into_dense();
+                state.w_poly = Some(w_poly.clone());
+                Ok(Response::Polynomial(w_poly))
+            }
+            Request::SecondRound { circuit_id, index, circuit_combiner, instance_combiner, max_constraint_domain } => {
+                let circuit = self.circuit(&circuit_id)?;
+                let state = session.instance(circuit_id, index)?;
+                let z = state.z.take().ok_or_else(|| anyhow!("Missing z vectors"))?;
+                let constraint_domain =
+                    EvaluationDomain::new(circuit.index_info.num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
+                let h_0_i = AHPForR1CS::<F, SM>::calculate_rowcheck_instance_witness(
+                    circuit,
+                    index,
+                    z.map(prover::RoundVec::InMemory),
+                    circuit_combiner,
+                    instance_combiner,
+                    constraint_domain,
+                    max_constraint_domain,
+                    usize::MAX,
+                )?;
+                Ok(Response::Polynomial(h_0_i))
+            }
+            Request::ThirdRound { circuit_id, index, alpha, combiners, max_variable_domain } => {
+                let circuit = self.circuit(&circuit_id)?;
+                let constraint_domain =
+                    EvaluationDomain::new(circuit.index_info.num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
+                let variable_domain = variable_domain(circuit)?;
+                // This is the last request for the instance.
+                let state = session.remove_instance(circuit_id, index)?;
+                let input_domain = state.input_domain;
+                let w_poly = state.w_poly.ok_or_else(|| anyhow!("Missing witness polynomial"))?;
+                let assignment = AHPForR1CS::<F, SM>::calculate_assignment(&w_poly, &state.x_poly, &input_domain);
+
+                if let Entry::Vacant(entry) = session.matrix_transposes.entry(circuit_id) {
+                    entry.insert([
+                        transpose(&circuit.a, &variable_domain, &input_domain)?,
+                        transpose(&circuit.b, &variable_domain, &input_domain)?,
+                        transpose(&circuit.c, &variable_domain, &input_domain)?,
+                    ]);
+                }
+                let [transpose_a, transpose_b, transpose_c] = &session.matrix_transposes[&circuit_id];
+
+                let lineval = |label: &str, matrix_transpose: &Matrix<F>, combiner: F| {
+                    AHPForR1CS::<F, SM>::calculate_lineval_sumcheck_instance_witness(
+                        label,
+                        &constraint_domain,
+                        &variable_domain,
+                        &max_variable_domain,
+                        &circuit.fft_precomputation,
+                        &circuit.ifft_precomputation,
+                        &assignment,
+                        matrix_transpose,
+                        alpha,
+                        combiner,
+                    )
+                };
+                Ok(Response::Linevals([
+                    lineval("a", transpose_a, combiners[0])?,
+                    lineval("b", transpose_b, combiners[1])?,
+                    lineval("c", transpose_c, combiners[2])?,
+                ]))
+            }
+        }
+    }
+
+    /// Returns the circuit with the given ID.
+    fn circuit(&self, circuit_id: &CircuitId) -> Result<&Circuit<F, SM>> {
+        self.circuits.get(circuit_id).map(Arc::as_ref).ok_or_else(|| anyhow!("Unknown circuit {circuit_id}"))
+    }
+}
+
+impl<F: PrimeField> Session<F> {
+    /// Returns the state of the given instance.
+    fn instance(&mut self, circuit_id: CircuitId, index: usize) -> Result<&mut InstanceState<F>> {
+        self.instances.get_mut(&(circuit_id, index)).ok_or_else(|| anyhow!("Unknown instance {index} of {circuit_id}"))
+    }
+
+    /// Removes and returns the state of the given instance.
+    fn remove_instance(&mut self, circuit_id: CircuitId, index: usize) -> Result<InstanceState<F>> {
+        self.instances.remove(&(circuit_id, index)).ok_or_else(|| anyhow!("Unknown instance {index} of {circuit_id}"))
+    }
+}
+
+/// Returns the variable domain of the circuit.
+fn variable_domain<F: PrimeField, SM: SNARKMode>(
+    circuit: &Circuit<F, SM>,
+) -> Result<EvaluationDomain<F>, SynthesisError> {
+    EvaluationDomain::new(circuit.index_info.num_public_and_private_variables).ok_or(SynthesisError::PolyTooLarge)
+}
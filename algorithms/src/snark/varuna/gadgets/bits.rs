@@ -0,0 +1,154 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::one;
+use crate::r1cs::{ConstraintSystem, LinearCombination, SynthesisError};
+use snarkvm_fields::Field;
+
+/// A bit that is either a constant, or a variable constrained to be zero or one.
+#[derive(Clone, Debug)]
+pub struct Boolean<F: Field> {
+    lc: LinearCombination<F>,
+    value: Option<bool>,
+}
+
+impl<F: Field> Boolean<F> {
+    /// Returns a constant bit.
+    pub fn constant(value: bool) -> Self {
+        let lc = match value {
+            true => LinearCombination::zero() + one(),
+            false => LinearCombination::zero(),
+        };
+        Self { lc, value: Some(value) }
+    }
+
+    /// Allocates a bit, and enforces that it is zero or one.
+    pub fn alloc<CS: ConstraintSystem<F>>(mut cs: CS, value: Option<bool>) -> Result<Self, SynthesisError> {
+        let variable = cs.alloc(
+            || "bit",
+            || value.map(|bit| if bit { F::one() } else { F::zero() }).ok_or(SynthesisError::AssignmentMissing),
+        )?;
+        cs.enforce(|| "bit is boolean", |lc| lc + variable, |lc| lc + one() - variable, |lc| lc);
+        Ok(Self { lc: variable.into(), value })
+    }
+
+    /// Returns the linear combination assigned to this bit.
+    pub fn lc(&self) -> &LinearCombination<F> {
+        &self.lc
+    }
+
+    /// Returns the value of this bit, if it is known.
+    pub fn value(&self) -> Option<bool> {
+        self.value
+    }
+
+    /// Returns `true` if this bit is a constant.
+    pub fn is_constant(&self) -> bool {
+        self.lc.as_ref().iter().all(|(variable, _)| *variable == one())
+    }
+
+    /// Returns the conjunction of this bit and `other`.
+    pub fn and<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let value = self.value.zip(other.value).map(|(a, b)| a & b);
+        let variable = cs.alloc(
+            || "and",
+            || value.map(|bit| if bit { F::one() } else { F::zero() }).ok_or(SynthesisError::AssignmentMissing),
+        )?;
+        cs.enforce(|| "and constraint", |lc| lc + &self.lc, |lc| lc + &other.lc, |lc| lc + variable);
+        Ok(Self { lc: variable.into(), value })
+    }
+}
+
+/// Allocates `num_bits` bits, given the little-endian bits of their value.
+pub fn alloc_bits_le<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    values: Option<&[bool]>,
+    num_bits: usize,
+) -> Result<Vec<Boolean<F>>, SynthesisError> {
+    (0..num_bits)
+        .map(|i| Boolean::alloc(cs.ns(|| format!("bit {i}")), values.map(|values| values.get(i).copied().unwrap_or(false))))
+        .collect()
+}
+
+/// Returns the little-endian bits of the given bits' values, if they are all known.
+pub fn bits_value_le<F: Field>(bits: &[Boolean<F>]) -> Option<Vec<bool>> {
+    bits.iter().map(Boolean::value).collect()
+}
+
+/// Returns the field element `sum_i 2^i * bits[i]` of the given little-endian bits.
+pub fn field_from_bits_le<F: Field>(bits: &[bool]) -> F {
+    bits.iter().rev().fold(F::zero(), |acc, &bit| if bit { acc.double() + F::one() } else { acc.double() })
+}
+
+/// Returns the linear combination `sum_i 2^i * bits[i]` of the given little-endian bits.
+pub fn pack_bits_le<F: Field>(bits: &[Boolean<F>]) -> LinearCombination<F> {
+    let mut lc = LinearCombination::zero();
+    let mut coeff = F::one();
+    for bit in bits {
+        lc = lc + (coeff, bit.lc());
+        coeff.double_in_place();
+    }
+    lc
+}
+
+/// Enforces that the integer encoded by the little-endian `bits` is at most `bound`,
+/// where `bound` is given as little-endian bits of the same length.
+pub fn enforce_le_constant<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean<F>],
+    bound: &[bool],
+) -> Result<(), SynthesisError> {
+    assert_eq!(bits.len(), bound.len(), "The bits and the bound must have the same length");
+
+    // Walking down from the most significant bit, `last_run` is set iff `bits` matches `bound` on every set bit
+    // of `bound` seen so far. While it is set, `bits` must be unset wherever `bound` is unset.
+    let mut last_run: Option<Boolean<F>> = None;
+    let mut current_run = Vec::new();
+    let mut found_one = false;
+    for (i, (bit, &bound_bit)) in bits.iter().zip(bound).enumerate().rev() {
+        found_one |= bound_bit;
+        if !found_one {
+            // The bits above the most significant set bit of `bound` must be unset.
+            cs.enforce(|| format!("bit {i} is unset"), |lc| lc + bit.lc(), |lc| lc + one(), |lc| lc);
+            continue;
+        }
+
+        if bound_bit {
+            current_run.push(bit.clone());
+        } else {
+            if !current_run.is_empty() {
+                if let Some(last_run) = last_run.take() {
+                    current_run.push(last_run);
+                }
+                last_run = Some(kary_and(cs.ns(|| format!("run ending at bit {i}")), &current_run)?);
+                current_run.clear();
+            }
+            if let Some(run) = &last_run {
+                cs.enforce(|| format!("bit {i} is bounded"), |lc| lc + run.lc(), |lc| lc + bit.lc(), |lc| lc);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the conjunction of all the given bits.
+fn kary_and<F: Field, CS: ConstraintSystem<F>>(mut cs: CS, bits: &[Boolean<F>]) -> Result<Boolean<F>, SynthesisError> {
+    let (first, rest) = bits.split_first().expect("Cannot compute the conjunction of no bits");
+    let mut result = first.clone();
+    for (i, bit) in rest.iter().enumerate() {
+        result = result.and(cs.ns(|| format!("and {i}")), bit)?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,165 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{alloc_bits_le, bits_value_le, enforce_le_constant, field_from_bits_le, one, pack_bits_le, Boolean};
+use crate::r1cs::{ConstraintSystem, LinearCombination, SynthesisError};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::ToBits;
+
+/// An element of the native field, represented by a linear combination of variables.
+#[derive(Clone, Debug)]
+pub struct FpVar<F: PrimeField> {
+    lc: LinearCombination<F>,
+    value: Option<F>,
+}
+
+impl<F: PrimeField> FpVar<F> {
+    /// Returns a constant field element.
+    pub fn constant(value: F) -> Self {
+        Self { lc: LinearCombination::zero() + (value, one()), value: Some(value) }
+    }
+
+    /// Allocates a private field element.
+    pub fn alloc<CS: ConstraintSystem<F>>(mut cs: CS, value: Option<F>) -> Result<Self, SynthesisError> {
+        let variable = cs.alloc(|| "variable", || value.ok_or(SynthesisError::AssignmentMissing))?;
+        Ok(Self { lc: variable.into(), value })
+    }
+
+    /// Allocates a public field element.
+    pub fn alloc_input<CS: ConstraintSystem<F>>(mut cs: CS, value: Option<F>) -> Result<Self, SynthesisError> {
+        let variable = cs.alloc_input(|| "variable", || value.ok_or(SynthesisError::AssignmentMissing))?;
+        Ok(Self { lc: variable.into(), value })
+    }
+
+    /// Returns the field element encoded by the given little-endian bits.
+    /// The caller must ensure there are fewer bits than the capacity of the field.
+    pub fn from_bits_le(bits: &[Boolean<F>]) -> Self {
+        debug_assert!(bits.len() < F::size_in_bits());
+        Self { lc: pack_bits_le(bits), value: bits_value_le(bits).map(|bits| field_from_bits_le(&bits)) }
+    }
+
+    /// Returns the linear combination assigned to this field element.
+    pub fn lc(&self) -> &LinearCombination<F> {
+        &self.lc
+    }
+
+    /// Returns the value of this field element, if it is known.
+    pub fn value(&self) -> Option<F> {
+        self.value
+    }
+
+    /// Returns `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        Self { lc: &self.lc + &other.lc, value: self.value.zip(other.value).map(|(a, b)| a + b) }
+    }
+
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        Self { lc: &self.lc - &other.lc, value: self.value.zip(other.value).map(|(a, b)| a - b) }
+    }
+
+    /// Returns `-self`.
+    pub fn negate(&self) -> Self {
+        Self { lc: -self.lc.clone(), value: self.value.map(|a| -a) }
+    }
+
+    /// Returns `self + constant`.
+    pub fn add_constant(&self, constant: F) -> Self {
+        Self { lc: self.lc.clone() + (constant, one()), value: self.value.map(|a| a + constant) }
+    }
+
+    /// Returns `self * constant`.
+    pub fn scale(&self, constant: F) -> Self {
+        Self { lc: self.lc.clone() * constant, value: self.value.map(|a| a * constant) }
+    }
+
+    /// Returns `self * other`.
+    pub fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let product = Self::alloc(cs.ns(|| "product"), self.value.zip(other.value).map(|(a, b)| a * b))?;
+        cs.enforce(|| "product constraint", |lc| lc + &self.lc, |lc| lc + &other.lc, |lc| lc + &product.lc);
+        Ok(product)
+    }
+
+    /// Returns `self * self`.
+    pub fn square<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        self.mul(cs, self)
+    }
+
+    /// Returns the inverse of `self`, and enforces that `self` is nonzero.
+    pub fn inverse<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let value = self.value.map(|a| a.inverse().ok_or(SynthesisError::DivisionByZero)).transpose()?;
+        let inverse = Self::alloc(cs.ns(|| "inverse"), value)?;
+        cs.enforce(|| "inverse constraint", |lc| lc + &self.lc, |lc| lc + &inverse.lc, |lc| lc + one());
+        Ok(inverse)
+    }
+
+    /// Enforces that `self` is equal to `other`.
+    pub fn enforce_equal<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<(), SynthesisError> {
+        cs.enforce(|| "equality constraint", |lc| lc + &self.lc - &other.lc, |lc| lc + one(), |lc| lc);
+        Ok(())
+    }
+
+    /// Returns `first` if `condition` is set, and `second` otherwise.
+    pub fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        condition: &Boolean<F>,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let value = condition.value().and_then(|condition| if condition { first.value } else { second.value });
+        let result = Self::alloc(cs.ns(|| "result"), value)?;
+        cs.enforce(
+            || "selection constraint",
+            |lc| lc + condition.lc(),
+            |lc| lc + &first.lc - &second.lc,
+            |lc| lc + &result.lc - &second.lc,
+        );
+        Ok(result)
+    }
+
+    /// Returns the canonical little-endian bits of `self`.
+    pub fn to_bits_le<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        let num_bits = F::size_in_bits();
+        let bits = self.to_bits_le_unchecked(cs.ns(|| "decompose"), num_bits)?;
+        // The decomposition wraps around the modulus, so it is only unique if it encodes a canonical element.
+        let mut bound = (-F::one()).to_bigint().to_bits_le();
+        bound.truncate(num_bits);
+        enforce_le_constant(cs.ns(|| "canonical"), &bits, &bound)?;
+        Ok(bits)
+    }
+
+    /// Returns the little-endian bits of `self`, and enforces that `self` fits in `num_bits` bits.
+    /// The caller must ensure `num_bits` is less than the size of the field.
+    pub fn to_bits_le_with_size<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        assert!(num_bits < F::size_in_bits(), "Use `to_bits_le` to decompose into the full size of the field");
+        self.to_bits_le_unchecked(cs, num_bits)
+    }
+
+    /// Returns `num_bits` little-endian bits that sum to `self`, without checking for wrap-around.
+    fn to_bits_le_unchecked<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        let values = self.value.map(|a| a.to_bigint().to_bits_le());
+        let bits = alloc_bits_le(cs.ns(|| "bits"), values.as_deref(), num_bits)?;
+        cs.enforce(|| "packing constraint", |lc| lc + &pack_bits_le(&bits), |lc| lc + one(), |lc| lc + &self.lc);
+        Ok(bits)
+    }
+}
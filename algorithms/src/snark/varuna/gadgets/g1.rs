@@ -0,0 +1,247 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Boolean, FpVar};
+use crate::r1cs::{ConstraintSystem, SynthesisError};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377G1Parameters, Fq, G1Affine},
+    AffineCurve,
+    ProjectiveCurve,
+    ShortWeierstrassParameters,
+};
+use snarkvm_fields::{Field, One, Zero};
+
+/// A point of the BLS12-377 G1 curve in affine coordinates, which is never the point at infinity.
+#[derive(Clone, Debug)]
+pub struct G1AffineVar {
+    x: FpVar<Fq>,
+    y: FpVar<Fq>,
+}
+
+impl G1AffineVar {
+    /// Returns a constant point.
+    pub fn constant(point: G1Affine) -> Self {
+        assert!(!point.is_zero(), "The point at infinity has no affine coordinates");
+        Self { x: FpVar::constant(point.x), y: FpVar::constant(point.y) }
+    }
+
+    /// Allocates a point, and enforces that it is in the prime-order subgroup.
+    ///
+    /// The point is allocated as `[h] Q`, where `h` is the cofactor and `Q` is a witness point on the curve,
+    /// as the multiples of the cofactor are exactly the points of the prime-order subgroup.
+    pub fn alloc<CS: ConstraintSystem<Fq>>(mut cs: CS, point: Option<G1Affine>) -> Result<Self, SynthesisError> {
+        if point.map_or(false, |point| !point.is_in_correct_subgroup_assuming_on_curve()) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        // As the cofactor is coprime to the order of the subgroup, `Q = [h^-1 mod r] P` satisfies `[h] Q = P`.
+        let witness = Self::alloc_on_curve(cs.ns(|| "witness"), point.map(|point| point.mul_by_cofactor_inv()))?;
+        let cofactor_bits = Bls12_377G1Parameters::COFACTOR
+            .iter()
+            .flat_map(|limb| (0..64).map(move |i| Boolean::constant((limb >> i) & 1 == 1)))
+            .collect::<Vec<_>>();
+        Self::msm(cs.ns(|| "clear cofactor"), &[(&witness, &cofactor_bits)], &[])
+    }
+
+    /// Allocates a point, and enforces that it is on the curve.
+    fn alloc_on_curve<CS: ConstraintSystem<Fq>>(mut cs: CS, point: Option<G1Affine>) -> Result<Self, SynthesisError> {
+        if point.map_or(false, |point| point.is_zero()) {
+            return Err(SynthesisError::UnexpectedIdentity);
+        }
+        let x = FpVar::alloc(cs.ns(|| "x"), point.map(|point| point.x))?;
+        let y = FpVar::alloc(cs.ns(|| "y"), point.map(|point| point.y))?;
+
+        let x_squared = x.square(cs.ns(|| "x^2"))?;
+        let y_squared = y.square(cs.ns(|| "y^2"))?;
+        cs.enforce(
+            || "curve equation",
+            |lc| lc + x_squared.lc(),
+            |lc| lc + x.lc(),
+            |lc| lc + y_squared.sub(&FpVar::constant(Bls12_377G1Parameters::WEIERSTRASS_B)).lc(),
+        );
+        Ok(Self { x, y })
+    }
+
+    /// Returns the x-coordinate of this point.
+    pub fn x(&self) -> &FpVar<Fq> {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of this point.
+    pub fn y(&self) -> &FpVar<Fq> {
+        &self.y
+    }
+
+    /// Returns the value of this point, if it is known.
+    pub fn value(&self) -> Option<G1Affine> {
+        self.x.value().zip(self.y.value()).map(|(x, y)| G1Affine::new(x, y, false))
+    }
+
+    /// Returns the field elements of this point, as they are absorbed by the native sponge.
+    pub fn to_field_elements(&self) -> Vec<FpVar<Fq>> {
+        // The last element is the flag for the point at infinity.
+        vec![self.x.clone(), self.y.clone(), FpVar::constant(Fq::zero())]
+    }
+
+    /// Returns `-self`.
+    pub fn negate(&self) -> Self {
+        Self { x: self.x.clone(), y: self.y.negate() }
+    }
+
+    /// Returns `self + other`, and enforces that the points have distinct x-coordinates.
+    pub fn add<CS: ConstraintSystem<Fq>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        // As the x-coordinates are distinct, the slope is uniquely determined.
+        let x_difference_inverse = other.x.sub(&self.x).inverse(cs.ns(|| "x difference inverse"))?;
+        let lambda = other.y.sub(&self.y).mul(cs.ns(|| "lambda"), &x_difference_inverse)?;
+        Self::from_slope(cs.ns(|| "sum"), &lambda, &self.x, &other.x, &self.y)
+    }
+
+    /// Returns `self + self`.
+    pub fn double<CS: ConstraintSystem<Fq>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        // The curve has no point with y = 0, so the slope is always uniquely determined.
+        let x_squared = self.x.square(cs.ns(|| "x^2"))?;
+        let lambda_value = match (x_squared.value(), self.y.value()) {
+            (Some(x_squared), Some(y)) => {
+                Some(x_squared * Fq::from(3u128) * y.double().inverse().ok_or(SynthesisError::DivisionByZero)?)
+            }
+            _ => None,
+        };
+        let lambda = FpVar::alloc(cs.ns(|| "lambda"), lambda_value)?;
+        cs.enforce(
+            || "lambda constraint",
+            |lc| lc + lambda.lc(),
+            |lc| lc + self.y.scale(Fq::from(2u128)).lc(),
+            |lc| lc + x_squared.scale(Fq::from(3u128)).lc(),
+        );
+        Self::from_slope(cs.ns(|| "sum"), &lambda, &self.x, &self.x, &self.y)
+    }
+
+    /// Returns the third point on the line with slope `lambda` through the points with x-coordinates `x_1` and `x_2`,
+    /// reflected over the x-axis, given the y-coordinate `y_1` of the first point.
+    fn from_slope<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        lambda: &FpVar<Fq>,
+        x_1: &FpVar<Fq>,
+        x_2: &FpVar<Fq>,
+        y_1: &FpVar<Fq>,
+    ) -> Result<Self, SynthesisError> {
+        let x_3_value =
+            lambda.value().zip(x_1.value()).zip(x_2.value()).map(|((lambda, x_1), x_2)| lambda.square() - x_1 - x_2);
+        let x = FpVar::alloc(cs.ns(|| "x"), x_3_value)?;
+        cs.enforce(|| "x constraint", |lc| lc + lambda.lc(), |lc| lc + lambda.lc(), |lc| lc + x.add(x_1).add(x_2).lc());
+
+        let y_3_value = lambda
+            .value()
+            .zip(x_1.value())
+            .zip(x.value())
+            .zip(y_1.value())
+            .map(|(((lambda, x_1), x_3), y_1)| lambda * (x_1 - x_3) - y_1);
+        let y = FpVar::alloc(cs.ns(|| "y"), y_3_value)?;
+        cs.enforce(|| "y constraint", |lc| lc + lambda.lc(), |lc| lc + x_1.sub(&x).lc(), |lc| lc + y.add(y_1).lc());
+        Ok(Self { x, y })
+    }
+
+    /// Returns `first` if `condition` is set, and `second` otherwise.
+    pub fn conditionally_select<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        condition: &Boolean<Fq>,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            x: FpVar::conditionally_select(cs.ns(|| "x"), condition, &first.x, &second.x)?,
+            y: FpVar::conditionally_select(cs.ns(|| "y"), condition, &first.y, &second.y)?,
+        })
+    }
+
+    /// Returns the multi-scalar multiplication of the given variable and constant bases, with scalars given as
+    /// little-endian bits. Constant bases at infinity are skipped.
+    ///
+    /// The additions are incomplete, so an honest prover fails to synthesize with negligible probability,
+    /// including when the result is the point at infinity. A dishonest prover gains nothing from this,
+    /// as every addition enforces that its result is correct.
+    pub fn msm<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        variable_terms: &[(&Self, &[Boolean<Fq>])],
+        constant_terms: &[(G1Affine, &[Boolean<Fq>])],
+    ) -> Result<Self, SynthesisError> {
+        // The accumulator starts at an offset, so that it never meets the multiples of the bases.
+        let offset = offset_point();
+        let mut accumulator = Self::constant(offset);
+
+        for (i, (base, bits)) in variable_terms.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("variable term {i}"));
+            let mut power = (*base).clone();
+            for (j, bit) in Self::significant_bits(bits).iter().enumerate() {
+                if j > 0 {
+                    power = power.double(cs.ns(|| format!("double {j}")))?;
+                }
+                accumulator = accumulator.add_if(cs.ns(|| format!("add {j}")), bit, &power)?;
+            }
+        }
+
+        for (i, (base, bits)) in constant_terms.iter().enumerate() {
+            if base.is_zero() {
+                continue;
+            }
+            let mut cs = cs.ns(|| format!("constant term {i}"));
+            let mut power = base.to_projective();
+            for (j, bit) in Self::significant_bits(bits).iter().enumerate() {
+                if j > 0 {
+                    power.double_in_place();
+                }
+                accumulator =
+                    accumulator.add_if(cs.ns(|| format!("add {j}")), bit, &Self::constant(power.to_affine()))?;
+            }
+        }
+
+        accumulator.add(cs.ns(|| "remove offset"), &Self::constant(-offset))
+    }
+
+    /// Returns `self + other` if `condition` is set, and `self` otherwise.
+    fn add_if<CS: ConstraintSystem<Fq>>(
+        &self,
+        mut cs: CS,
+        condition: &Boolean<Fq>,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        match (condition.is_constant(), condition.value()) {
+            (true, Some(true)) => self.add(cs, other),
+            (true, _) => Ok(self.clone()),
+            (false, _) => {
+                let sum = self.add(cs.ns(|| "sum"), other)?;
+                Self::conditionally_select(cs.ns(|| "select"), condition, &sum, self)
+            }
+        }
+    }
+
+    /// Returns the given little-endian bits, without the most significant bits that are constant zeros.
+    fn significant_bits(bits: &[Boolean<Fq>]) -> &[Boolean<Fq>] {
+        let is_constant_zero = |bit: &Boolean<Fq>| bit.is_constant() && bit.value() == Some(false);
+        let length = bits.iter().rposition(|bit| !is_constant_zero(bit)).map_or(0, |i| i + 1);
+        &bits[..length]
+    }
+}
+
+/// Returns a point on the curve whose discrete logarithm is unknown, which is used to offset accumulators.
+fn offset_point() -> G1Affine {
+    // The point is the first one found by incrementing the x-coordinate from a fixed constant.
+    let mut x = Fq::from(u128::from_le_bytes(*b"varuna_g1_offset"));
+    loop {
+        if let Some(point) = G1Affine::from_x_coordinate(x, false) {
+            return point;
+        }
+        x += Fq::one();
+    }
+}
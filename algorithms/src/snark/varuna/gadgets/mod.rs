@@ -0,0 +1,56 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constraint-system gadgets for verifying Varuna proofs over BLS12-377.
+//!
+//! The gadgets synthesize over `Fq`, the base field of BLS12-377 (and the scalar field of BW6-761),
+//! so that a proof over BW6-761 can attest to the validity of a Varuna proof.
+//! Scalar-field arithmetic is emulated with [`NonNativeFieldVar`], the Fiat-Shamir transcript is
+//! replayed with [`PoseidonSpongeVar`], and the KZG openings are folded into a [`PairingCheckVar`].
+//! The final pairing is not computed in the circuit, and must be checked by the party consuming the outer proof.
+
+/// Boolean variables and bit decompositions.
+mod bits;
+pub use bits::*;
+
+/// Native field variables.
+mod fp;
+pub use fp::*;
+
+/// Points of the BLS12-377 G1 group.
+mod g1;
+pub use g1::*;
+
+/// Emulated variables of the BLS12-377 scalar field.
+mod nonnative;
+pub use nonnative::*;
+
+/// The Poseidon sponge used for Fiat-Shamir.
+mod poseidon;
+pub use poseidon::*;
+
+/// The Varuna verifier.
+mod verifier;
+pub use verifier::*;
+
+#[cfg(all(test, feature = "test"))]
+mod tests;
+
+use crate::r1cs::{Index, Variable};
+
+/// Returns the variable that is always assigned to one.
+fn one() -> Variable {
+    Variable::new_unchecked(Index::Public(0))
+}
@@ -0,0 +1,236 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{alloc_bits_le, bits_value_le, enforce_le_constant, field_from_bits_le, Boolean, FpVar};
+use crate::r1cs::{ConstraintSystem, SynthesisError};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::ToBits;
+
+/// The number of bits in the lower limb of an element, when multiplying.
+const LOW_LIMB_BITS: usize = 128;
+/// The number of bits used to range check a carry, when multiplying.
+const CARRY_BITS: usize = 130;
+
+/// An element of the BLS12-377 scalar field, emulated in its base field by its canonical little-endian bits.
+#[derive(Clone, Debug)]
+pub struct NonNativeFieldVar {
+    bits: Vec<Boolean<Fq>>,
+    value: Option<Fr>,
+}
+
+impl NonNativeFieldVar {
+    /// Returns a constant element.
+    pub fn constant(value: Fr) -> Self {
+        let bits = value.to_bigint().to_bits_le().into_iter().take(Fr::size_in_bits()).map(Boolean::constant).collect();
+        Self { bits, value: Some(value) }
+    }
+
+    /// Allocates a private element, and enforces that it is canonical.
+    pub fn alloc<CS: ConstraintSystem<Fq>>(mut cs: CS, value: Option<Fr>) -> Result<Self, SynthesisError> {
+        let values = value.map(|value| value.to_bigint().to_bits_le());
+        let bits = alloc_bits_le(cs.ns(|| "bits"), values.as_deref(), Fr::size_in_bits())?;
+        let mut bound = (-Fr::one()).to_bigint().to_bits_le();
+        bound.truncate(Fr::size_in_bits());
+        enforce_le_constant(cs.ns(|| "canonical"), &bits, &bound)?;
+        Ok(Self { bits, value })
+    }
+
+    /// Returns the element encoded by the given little-endian bits.
+    /// There must be fewer bits than the capacity of the scalar field, so that the encoding is canonical.
+    pub fn from_bits_le(mut bits: Vec<Boolean<Fq>>) -> Self {
+        assert!(bits.len() < Fr::size_in_bits(), "The bits may not encode a canonical element");
+        let value = bits_value_le(&bits).map(|bits| field_from_bits_le(&bits));
+        bits.resize(Fr::size_in_bits(), Boolean::constant(false));
+        Self { bits, value }
+    }
+
+    /// Returns the canonical little-endian bits of this element.
+    pub fn to_bits_le(&self) -> &[Boolean<Fq>] {
+        &self.bits
+    }
+
+    /// Returns the value of this element, if it is known.
+    pub fn value(&self) -> Option<Fr> {
+        self.value
+    }
+
+    /// Returns `self + other`.
+    pub fn add<CS: ConstraintSystem<Fq>>(&self, cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        Self::linear_sum(cs, &[self, other], &[])
+    }
+
+    /// Returns `self - other`.
+    pub fn sub<CS: ConstraintSystem<Fq>>(&self, cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        Self::linear_sum(cs, &[self], &[other])
+    }
+
+    /// Returns `-self`.
+    pub fn negate<CS: ConstraintSystem<Fq>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        Self::linear_sum(cs, &[], &[self])
+    }
+
+    /// Returns the sum of the `positive` elements, minus the sum of the `negative` elements.
+    pub fn linear_sum<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        positive: &[&Self],
+        negative: &[&Self],
+    ) -> Result<Self, SynthesisError> {
+        let sum = |terms: &[&Self]| terms.iter().map(|term| term.value).sum::<Option<Fr>>();
+        let value = sum(positive).zip(sum(negative)).map(|(positive, negative)| positive - negative);
+        let result = Self::alloc(cs.ns(|| "result"), value)?;
+
+        // Over the integers, `positive - negative = result + (k - negative.len()) * modulus` for some `k`,
+        // which is bounded by `positive.len() + negative.len()`.
+        let integer_sum = |terms: &[&Self]| terms.iter().map(|term| term.value.map(fr_to_fq)).sum::<Option<Fq>>();
+        let num_negative = Fq::from(negative.len() as u128);
+        let quotient_value = integer_sum(positive)
+            .zip(integer_sum(negative))
+            .zip(value)
+            .map(|((positive, negative), result)| (positive - negative - fr_to_fq(result)) * modulus_inverse() + num_negative);
+        let num_quotient_bits = (usize::BITS - (positive.len() + negative.len()).leading_zeros()) as usize;
+        let quotient = alloc_bits_le(
+            cs.ns(|| "quotient"),
+            quotient_value.map(|quotient| quotient.to_bigint().to_bits_le()).as_deref(),
+            num_quotient_bits,
+        )?;
+
+        let packed_sum = |terms: &[&Self]| terms.iter().fold(FpVar::constant(Fq::zero()), |acc, term| acc.add(&term.packed()));
+        let lhs = packed_sum(positive).sub(&packed_sum(negative)).add_constant(modulus() * num_negative);
+        let rhs = result.packed().add(&FpVar::from_bits_le(&quotient).scale(modulus()));
+        lhs.enforce_equal(cs.ns(|| "sum constraint"), &rhs)?;
+        Ok(result)
+    }
+
+    /// Returns `self * other`.
+    pub fn mul<CS: ConstraintSystem<Fq>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let value = self.value.zip(other.value).map(|(a, b)| a * b);
+        let result = Self::alloc(cs.ns(|| "result"), value)?;
+
+        // Over the integers, `self * other = quotient * modulus + result`, where `quotient` is less than the modulus.
+        let quotient_value = self
+            .value
+            .zip(other.value)
+            .zip(value)
+            .map(|((a, b), c)| (fr_to_fq(a) * fr_to_fq(b) - fr_to_fq(c)) * modulus_inverse());
+        let quotient_bits = alloc_bits_le(
+            cs.ns(|| "quotient"),
+            quotient_value.map(|quotient| quotient.to_bigint().to_bits_le()).as_deref(),
+            Fr::size_in_bits(),
+        )?;
+
+        // Check the identity with two limbs per element, so that no intermediate value wraps around the base field.
+        let (a_0, a_1) = Self::limbs(&self.bits);
+        let (b_0, b_1) = Self::limbs(&other.bits);
+        let (q_0, q_1) = Self::limbs(&quotient_bits);
+        let (c_0, c_1) = Self::limbs(&result.bits);
+        let (r_0, r_1) = {
+            let modulus = Fr::modulus().to_bits_le();
+            (field_from_bits_le::<Fq>(&modulus[..LOW_LIMB_BITS]), field_from_bits_le::<Fq>(&modulus[LOW_LIMB_BITS..]))
+        };
+
+        let a_0_b_0 = a_0.mul(cs.ns(|| "a_0 * b_0"), &b_0)?;
+        let a_0_b_1 = a_0.mul(cs.ns(|| "a_0 * b_1"), &b_1)?;
+        let a_1_b_0 = a_1.mul(cs.ns(|| "a_1 * b_0"), &b_0)?;
+        let a_1_b_1 = a_1.mul(cs.ns(|| "a_1 * b_1"), &b_1)?;
+
+        let shift = Fq::from(2u128).pow([LOW_LIMB_BITS as u64]);
+        let shift_inverse = shift.inverse().unwrap();
+        let carry_offset = Fq::from(2u128).pow([CARRY_BITS as u64 - 1]);
+
+        // The lowest limb of the difference must be divisible by the shift.
+        let column_0 = a_0_b_0.sub(&q_0.scale(r_0)).sub(&c_0);
+        let carry_0 = FpVar::alloc(cs.ns(|| "carry_0"), column_0.value().map(|column| column * shift_inverse))?;
+        column_0.enforce_equal(cs.ns(|| "column_0"), &carry_0.scale(shift))?;
+        carry_0.add_constant(carry_offset).to_bits_le_with_size(cs.ns(|| "carry_0 range"), CARRY_BITS)?;
+
+        // The middle limb, with the carry, must be divisible by the shift.
+        let column_1 = a_0_b_1.add(&a_1_b_0).sub(&q_0.scale(r_1)).sub(&q_1.scale(r_0)).sub(&c_1).add(&carry_0);
+        let carry_1 = FpVar::alloc(cs.ns(|| "carry_1"), column_1.value().map(|column| column * shift_inverse))?;
+        column_1.enforce_equal(cs.ns(|| "column_1"), &carry_1.scale(shift))?;
+        carry_1.add_constant(carry_offset).to_bits_le_with_size(cs.ns(|| "carry_1 range"), CARRY_BITS)?;
+
+        // The highest limb, with the carry, must vanish.
+        let column_2 = a_1_b_1.sub(&q_1.scale(r_1)).add(&carry_1);
+        column_2.enforce_equal(cs.ns(|| "column_2"), &FpVar::constant(Fq::zero()))?;
+
+        Ok(result)
+    }
+
+    /// Returns `self * self`.
+    pub fn square<CS: ConstraintSystem<Fq>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        self.mul(cs, self)
+    }
+
+    /// Returns `self` raised to the power of `exponent`.
+    pub fn pow_by_constant<CS: ConstraintSystem<Fq>>(&self, mut cs: CS, exponent: u64) -> Result<Self, SynthesisError> {
+        let mut result = Self::constant(Fr::one());
+        let mut found_one = false;
+        for i in (0..u64::BITS).rev() {
+            if found_one {
+                result = result.square(cs.ns(|| format!("square {i}")))?;
+            }
+            if exponent & (1 << i) != 0 {
+                result = match found_one {
+                    true => result.mul(cs.ns(|| format!("mul {i}")), self)?,
+                    false => self.clone(),
+                };
+                found_one = true;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the inverse of `self`, and enforces that `self` is nonzero.
+    pub fn inverse<CS: ConstraintSystem<Fq>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let value = self.value.map(|a| a.inverse().ok_or(SynthesisError::DivisionByZero)).transpose()?;
+        let inverse = Self::alloc(cs.ns(|| "inverse"), value)?;
+        let product = self.mul(cs.ns(|| "product"), &inverse)?;
+        product.enforce_equal(cs.ns(|| "inverse constraint"), &Self::constant(Fr::one()))?;
+        Ok(inverse)
+    }
+
+    /// Enforces that `self` is equal to `other`.
+    pub fn enforce_equal<CS: ConstraintSystem<Fq>>(&self, cs: CS, other: &Self) -> Result<(), SynthesisError> {
+        // Both elements are canonical, so it suffices to compare their integer encodings.
+        self.packed().enforce_equal(cs, &other.packed())
+    }
+
+    /// Returns the integer encoding of `self` as a base field element.
+    fn packed(&self) -> FpVar<Fq> {
+        FpVar::from_bits_le(&self.bits)
+    }
+
+    /// Returns the low and high limbs of the given little-endian bits.
+    fn limbs(bits: &[Boolean<Fq>]) -> (FpVar<Fq>, FpVar<Fq>) {
+        let (low, high) = bits.split_at(LOW_LIMB_BITS);
+        (FpVar::from_bits_le(low), FpVar::from_bits_le(high))
+    }
+}
+
+/// Returns the integer encoding of the given scalar field element as a base field element.
+fn fr_to_fq(value: Fr) -> Fq {
+    field_from_bits_le(&value.to_bigint().to_bits_le())
+}
+
+/// Returns the scalar field modulus as a base field element.
+fn modulus() -> Fq {
+    field_from_bits_le(&Fr::modulus().to_bits_le())
+}
+
+/// Returns the inverse of the scalar field modulus in the base field.
+fn modulus_inverse() -> Fq {
+    modulus().inverse().unwrap()
+}
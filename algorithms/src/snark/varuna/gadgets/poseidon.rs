@@ -0,0 +1,305 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{field_from_bits_le, Boolean, FpVar, NonNativeFieldVar};
+use crate::{
+    nonnative_params::{get_params, OptimizationType},
+    r1cs::{ConstraintSystem, SynthesisError},
+    DuplexSpongeMode,
+};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{Field, One, PoseidonParameters, PrimeField, Zero};
+
+use std::sync::Arc;
+
+/// The rate of the sponge.
+const RATE: usize = 2;
+
+/// The Fiat-Shamir sponge of Varuna, as a gadget.
+///
+/// This mirrors `PoseidonSponge<Fq, 2, 1>` exactly, so that the gadget derives the same challenges as the native verifier.
+#[derive(Clone)]
+pub struct PoseidonSpongeVar {
+    /// The sponge parameters.
+    parameters: Arc<PoseidonParameters<Fq, RATE, 1>>,
+    /// The capacity element, followed by the rate elements.
+    state: Vec<FpVar<Fq>>,
+    /// The current mode of the sponge.
+    mode: DuplexSpongeMode,
+}
+
+impl PoseidonSpongeVar {
+    /// Initializes a new sponge with the given parameters.
+    pub fn new(parameters: &Arc<PoseidonParameters<Fq, RATE, 1>>) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: vec![FpVar::constant(Fq::zero()); 1 + RATE],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Absorbs the given bytes, which are known at synthesis time.
+    pub fn absorb_bytes<CS: ConstraintSystem<Fq>>(&mut self, cs: CS, bytes: &[u8]) -> Result<(), SynthesisError> {
+        let bits = bytes.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let elements = bits
+            .chunks(Fq::size_in_bits() - 1)
+            .map(|chunk| FpVar::constant(field_from_bits_le(&chunk.iter().rev().copied().collect::<Vec<_>>())))
+            .collect::<Vec<_>>();
+        self.absorb_native(cs, &elements)
+    }
+
+    /// Absorbs the given base field elements.
+    pub fn absorb_native<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        mut cs: CS,
+        elements: &[FpVar<Fq>],
+    ) -> Result<(), SynthesisError> {
+        if elements.is_empty() {
+            return Ok(());
+        }
+        match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } => {
+                let mut next_absorb_index = next_absorb_index;
+                if next_absorb_index == RATE {
+                    self.permute(cs.ns(|| "permute before absorbing"))?;
+                    next_absorb_index = 0;
+                }
+                self.absorb_internal(cs.ns(|| "absorb"), next_absorb_index, elements)
+            }
+            DuplexSpongeMode::Squeezing { .. } => {
+                self.permute(cs.ns(|| "permute before absorbing"))?;
+                self.absorb_internal(cs.ns(|| "absorb"), 0, elements)
+            }
+        }
+    }
+
+    /// Absorbs the given scalar field elements, packed into limbs as in the native sponge.
+    pub fn absorb_nonnative<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        cs: CS,
+        elements: &[NonNativeFieldVar],
+    ) -> Result<(), SynthesisError> {
+        let params = get_params(Fr::size_in_bits(), Fq::size_in_bits(), OptimizationType::Weight);
+        // Each element is split into limbs, highest limb first.
+        let limbs = elements
+            .iter()
+            .flat_map(|element| {
+                let bits = element.to_bits_le();
+                let mut limbs = (0..params.num_limbs)
+                    .map(|i| {
+                        let start = (i * params.bits_per_limb).min(bits.len());
+                        let end = ((i + 1) * params.bits_per_limb).min(bits.len());
+                        FpVar::from_bits_le(&bits[start..end])
+                    })
+                    .collect::<Vec<_>>();
+                limbs.reverse();
+                limbs
+            })
+            .collect::<Vec<_>>();
+
+        // As every limb is fresh, each one has the same bound, and consecutive limbs are packed in pairs if they fit.
+        let limb_bits = params.bits_per_limb + crate::overhead!(Fq::one() + Fq::one(), &mut Vec::new());
+        let packed_limbs = match 2 * limb_bits < Fq::size_in_bits() {
+            true => {
+                let shift = Fq::from(2u128).pow([limb_bits as u64]);
+                limbs
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [first, second] => first.scale(shift).add(second),
+                        [first] => first.clone(),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>()
+            }
+            false => limbs,
+        };
+        self.absorb_native(cs, &packed_limbs)
+    }
+
+    /// Squeezes the given number of base field elements.
+    pub fn squeeze_native<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        mut cs: CS,
+        num_elements: usize,
+    ) -> Result<Vec<FpVar<Fq>>, SynthesisError> {
+        if num_elements == 0 {
+            return Ok(Vec::new());
+        }
+        match self.mode {
+            DuplexSpongeMode::Absorbing { .. } => {
+                self.permute(cs.ns(|| "permute before squeezing"))?;
+                self.squeeze_internal(cs.ns(|| "squeeze"), 0, num_elements)
+            }
+            DuplexSpongeMode::Squeezing { next_squeeze_index } => {
+                let mut next_squeeze_index = next_squeeze_index;
+                if next_squeeze_index == RATE {
+                    self.permute(cs.ns(|| "permute before squeezing"))?;
+                    next_squeeze_index = 0;
+                }
+                self.squeeze_internal(cs.ns(|| "squeeze"), next_squeeze_index, num_elements)
+            }
+        }
+    }
+
+    /// Squeezes the given number of scalar field elements, each of which has one bit less than the modulus.
+    pub fn squeeze_nonnative<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        cs: CS,
+        num_elements: usize,
+    ) -> Result<Vec<NonNativeFieldVar>, SynthesisError> {
+        self.squeeze_nonnative_with_size(cs, num_elements, Fr::size_in_bits() - 1)
+    }
+
+    /// Squeezes the given number of 168-bit scalar field elements.
+    pub fn squeeze_short_nonnative<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        cs: CS,
+        num_elements: usize,
+    ) -> Result<Vec<NonNativeFieldVar>, SynthesisError> {
+        self.squeeze_nonnative_with_size(cs, num_elements, 168)
+    }
+
+    /// Squeezes the given number of scalar field elements, with `num_bits` bits each.
+    fn squeeze_nonnative_with_size<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        cs: CS,
+        num_elements: usize,
+        num_bits: usize,
+    ) -> Result<Vec<NonNativeFieldVar>, SynthesisError> {
+        let bits = self.squeeze_bits(cs, num_bits * num_elements)?;
+        // The bits of each element are big-endian.
+        Ok(bits.chunks_exact(num_bits).map(|chunk| NonNativeFieldVar::from_bits_le(chunk.iter().rev().cloned().collect())).collect())
+    }
+
+    /// Squeezes the given number of big-endian bits, discarding the most significant bit of each base field element.
+    fn squeeze_bits<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean<Fq>>, SynthesisError> {
+        let bits_per_element = Fq::size_in_bits() - 1;
+        let num_elements = num_bits.div_ceil(bits_per_element);
+        let elements = self.squeeze_native(cs.ns(|| "squeeze elements"), num_elements)?;
+
+        let mut bits = Vec::with_capacity(num_elements * bits_per_element);
+        for (i, element) in elements.iter().enumerate() {
+            let mut element_bits = element.to_bits_le(cs.ns(|| format!("element {i} to bits")))?;
+            element_bits.truncate(bits_per_element);
+            bits.extend(element_bits.into_iter().rev());
+        }
+        bits.truncate(num_bits);
+        Ok(bits)
+    }
+
+    /// Adds the given elements into the rate, permuting whenever the rate is full and more elements remain.
+    fn absorb_internal<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        mut cs: CS,
+        mut rate_start: usize,
+        elements: &[FpVar<Fq>],
+    ) -> Result<(), SynthesisError> {
+        let mut remaining = elements;
+        for i in 0.. {
+            let (chunk, rest) = remaining.split_at((RATE - rate_start).min(remaining.len()));
+            for (j, element) in chunk.iter().enumerate() {
+                self.state[1 + rate_start + j] = self.state[1 + rate_start + j].add(element);
+            }
+            if rest.is_empty() {
+                self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: rate_start + chunk.len() };
+                break;
+            }
+            self.permute(cs.ns(|| format!("permute {i}")))?;
+            remaining = rest;
+            rate_start = 0;
+        }
+        Ok(())
+    }
+
+    /// Reads elements from the rate, permuting whenever the rate is exhausted and more elements are needed.
+    fn squeeze_internal<CS: ConstraintSystem<Fq>>(
+        &mut self,
+        mut cs: CS,
+        mut rate_start: usize,
+        num_elements: usize,
+    ) -> Result<Vec<FpVar<Fq>>, SynthesisError> {
+        let mut output = Vec::with_capacity(num_elements);
+        for i in 0.. {
+            let chunk_size = (RATE - rate_start).min(num_elements - output.len());
+            output.extend_from_slice(&self.state[1 + rate_start..1 + rate_start + chunk_size]);
+            if output.len() == num_elements {
+                self.mode = DuplexSpongeMode::Squeezing { next_squeeze_index: rate_start + chunk_size };
+                break;
+            }
+            self.permute(cs.ns(|| format!("permute {i}")))?;
+            rate_start = 0;
+        }
+        Ok(output)
+    }
+
+    /// Applies the Poseidon permutation to the state.
+    fn permute<CS: ConstraintSystem<Fq>>(&mut self, mut cs: CS) -> Result<(), SynthesisError> {
+        let full_rounds_over_2 = self.parameters.full_rounds / 2;
+        let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.parameters.partial_rounds);
+
+        for i in 0..(self.parameters.partial_rounds + self.parameters.full_rounds) {
+            let mut cs = cs.ns(|| format!("round {i}"));
+
+            // Add the round constants.
+            for (element, constant) in self.state.iter_mut().zip(&self.parameters.ark[i]) {
+                *element = element.add_constant(*constant);
+            }
+
+            // Apply the S-box to every element in a full round, and to the first element in a partial round.
+            let num_s_boxes = if partial_round_range.contains(&i) { 1 } else { self.state.len() };
+            for (j, element) in self.state.iter_mut().take(num_s_boxes).enumerate() {
+                *element = Self::s_box(cs.ns(|| format!("s-box {j}")), element, self.parameters.alpha)?;
+            }
+
+            // Apply the MDS matrix.
+            self.state = self
+                .parameters
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(&self.state)
+                        .fold(FpVar::constant(Fq::zero()), |acc, (coeff, element)| acc.add(&element.scale(*coeff)))
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    /// Returns `element` raised to the power of `alpha`.
+    fn s_box<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        element: &FpVar<Fq>,
+        alpha: u64,
+    ) -> Result<FpVar<Fq>, SynthesisError> {
+        let mut result: Option<FpVar<Fq>> = None;
+        for i in (0..u64::BITS).rev() {
+            if let Some(current) = &result {
+                result = Some(current.square(cs.ns(|| format!("square {i}")))?);
+            }
+            if alpha & (1 << i) != 0 {
+                result = Some(match &result {
+                    Some(current) => current.mul(cs.ns(|| format!("mul {i}")), element)?,
+                    None => element.clone(),
+                });
+            }
+        }
+        Ok(result.expect("The S-box exponent must be nonzero"))
+    }
+}
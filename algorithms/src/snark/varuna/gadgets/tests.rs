@@ -0,0 +1,229 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    crypto_hash::PoseidonSponge,
+    r1cs::{ConstraintSystem, TestConstraintSystem},
+    snark::varuna::{
+        test_circuit::TestCircuit,
+        AHPForR1CS,
+        SNARKMode,
+        VarunaHidingMode,
+        VarunaNonHidingMode,
+        VarunaSNARK,
+    },
+    traits::{AlgebraicSponge, SNARK},
+};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fq, Fr, G1Affine, G1Projective},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::rand::{TestRng, Uniform};
+
+use std::collections::BTreeMap;
+
+type FS = PoseidonSponge<Fq, 2, 1>;
+
+fn test_verifier_gadget<SM: SNARKMode>() {
+    let rng = &mut TestRng::default();
+
+    let max_degree = AHPForR1CS::<Fr, SM>::max_degree(100, 25, 300).unwrap();
+    let universal_srs = VarunaSNARK::<Bls12_377, FS, SM>::universal_setup(max_degree).unwrap();
+    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let (circuit, public_input) = TestCircuit::gen_rand(2, 40, 20, rng);
+    let (proving_key, verifying_key) =
+        VarunaSNARK::<Bls12_377, FS, SM>::circuit_setup(&universal_srs, &circuit).unwrap();
+    let proof =
+        VarunaSNARK::<Bls12_377, FS, SM>::prove(universal_prover, &fs_parameters, &proving_key, &circuit, rng).unwrap();
+
+    let mut fake_input = public_input.clone();
+    *fake_input.last_mut().unwrap() = Fr::rand(rng);
+
+    for (input, is_valid) in [(public_input, true), (fake_input, false)] {
+        let inputs = [input.as_slice()];
+        let keys_to_inputs = BTreeMap::from([(&verifying_key, inputs.as_slice())]);
+        let expected = VarunaSNARK::<Bls12_377, FS, SM>::prepare_verify_batch(
+            universal_verifier,
+            &fs_parameters,
+            &keys_to_inputs,
+            &proof,
+        )
+        .unwrap()
+        .unwrap();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let input = input
+            .iter()
+            .enumerate()
+            .map(|(i, x)| NonNativeFieldVar::alloc(cs.ns(|| format!("input {i}")), Some(*x)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let proof = ProofVar::alloc::<SM, _>(cs.ns(|| "proof"), Some(&proof)).unwrap();
+        let pairing_check = VarunaVerifierGadget::<SM>::prepare_verify(
+            cs.ns(|| "verify"),
+            universal_verifier,
+            &fs_parameters,
+            &verifying_key,
+            &input,
+            &proof,
+        )
+        .unwrap();
+
+        assert!(cs.is_satisfied(), "Unsatisfied constraint: {:?}", cs.which_is_unsatisfied());
+
+        let pairing_check = pairing_check.value().unwrap();
+        assert_eq!(pairing_check, expected);
+        assert_eq!(
+            VarunaSNARK::<Bls12_377, FS, SM>::verify_pairing_check(universal_verifier, pairing_check).unwrap(),
+            is_valid
+        );
+    }
+}
+
+#[test]
+fn test_verifier_gadget_non_hiding() {
+    test_verifier_gadget::<VarunaNonHidingMode>();
+}
+
+#[test]
+fn test_verifier_gadget_hiding() {
+    test_verifier_gadget::<VarunaHidingMode>();
+}
+
+#[test]
+fn test_nonnative_arithmetic() {
+    let rng = &mut TestRng::default();
+
+    for _ in 0..10 {
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a_var = NonNativeFieldVar::alloc(cs.ns(|| "a"), Some(a)).unwrap();
+        let b_var = NonNativeFieldVar::alloc(cs.ns(|| "b"), Some(b)).unwrap();
+
+        let sum = a_var.add(cs.ns(|| "sum"), &b_var).unwrap();
+        let difference = a_var.sub(cs.ns(|| "difference"), &b_var).unwrap();
+        let product = a_var.mul(cs.ns(|| "product"), &b_var).unwrap();
+        let power = a_var.pow_by_constant(cs.ns(|| "power"), 1 << 10).unwrap();
+        let inverse = a_var.inverse(cs.ns(|| "inverse")).unwrap();
+
+        assert!(cs.is_satisfied());
+        assert_eq!(sum.value(), Some(a + b));
+        assert_eq!(difference.value(), Some(a - b));
+        assert_eq!(product.value(), Some(a * b));
+        assert_eq!(power.value(), Some(a.pow([1 << 10])));
+        assert_eq!(inverse.value(), a.inverse());
+    }
+}
+
+#[test]
+fn test_sponge_matches_native() {
+    let rng = &mut TestRng::default();
+    let parameters = FS::sample_parameters();
+
+    let native_elements = (0..5).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+    let nonnative_elements = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+    let mut native = FS::new_with_parameters(&parameters);
+    native.absorb_bytes(b"varuna");
+    native.absorb_native_field_elements(&native_elements);
+    native.absorb_nonnative_field_elements(nonnative_elements.iter().copied());
+    let expected = native.squeeze_nonnative_field_elements::<Fr>(2);
+    let expected_short = native.squeeze_short_nonnative_field_elements::<Fr>(2);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let native_elements = native_elements
+        .iter()
+        .enumerate()
+        .map(|(i, x)| FpVar::alloc(cs.ns(|| format!("native {i}")), Some(*x)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let nonnative_elements = nonnative_elements
+        .iter()
+        .enumerate()
+        .map(|(i, x)| NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative {i}")), Some(*x)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let mut sponge = PoseidonSpongeVar::new(&parameters);
+    sponge.absorb_bytes(cs.ns(|| "absorb bytes"), b"varuna").unwrap();
+    sponge.absorb_native(cs.ns(|| "absorb native"), &native_elements).unwrap();
+    sponge.absorb_nonnative(cs.ns(|| "absorb nonnative"), &nonnative_elements).unwrap();
+    let challenges = sponge.squeeze_nonnative(cs.ns(|| "squeeze"), 2).unwrap();
+    let short_challenges = sponge.squeeze_short_nonnative(cs.ns(|| "squeeze short"), 2).unwrap();
+
+    assert!(cs.is_satisfied());
+    assert_eq!(challenges.iter().map(|c| c.value().unwrap()).collect::<Vec<_>>(), expected.to_vec());
+    assert_eq!(short_challenges.iter().map(|c| c.value().unwrap()).collect::<Vec<_>>(), expected_short.to_vec());
+}
+
+#[test]
+fn test_msm() {
+    let rng = &mut TestRng::default();
+
+    let bases = (0..3).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+    let scalars = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let expected = bases.iter().zip(&scalars).fold(G1Projective::zero(), |acc, (base, scalar)| acc + *base * *scalar);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let base_vars = bases[..2]
+        .iter()
+        .enumerate()
+        .map(|(i, base)| G1AffineVar::alloc(cs.ns(|| format!("base {i}")), Some(*base)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let scalar_vars = scalars
+        .iter()
+        .enumerate()
+        .map(|(i, scalar)| NonNativeFieldVar::alloc(cs.ns(|| format!("scalar {i}")), Some(*scalar)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let variable_terms =
+        base_vars.iter().zip(&scalar_vars).map(|(base, scalar)| (base, scalar.to_bits_le())).collect::<Vec<_>>();
+    let constant_terms = [(bases[2], scalar_vars[2].to_bits_le())];
+    let result = G1AffineVar::msm(cs.ns(|| "msm"), &variable_terms, &constant_terms).unwrap();
+
+    assert!(cs.is_satisfied());
+    assert_eq!(result.value().unwrap(), expected.to_affine());
+}
+
+#[test]
+fn test_alloc_rejects_points_outside_the_subgroup() {
+    let rng = &mut TestRng::default();
+
+    // A point in the subgroup is allocated as a multiple of the cofactor.
+    let point = G1Affine::rand(rng);
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let point_var = G1AffineVar::alloc(cs.ns(|| "point"), Some(point)).unwrap();
+    assert!(cs.is_satisfied());
+    assert_eq!(point_var.value().unwrap(), point);
+
+    // A point on the curve, outside the subgroup, is rejected.
+    let mut x = Fq::zero();
+    let point = loop {
+        match G1Affine::from_x_coordinate(x, false) {
+            Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => break point,
+            _ => x += Fq::one(),
+        }
+    };
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    assert!(G1AffineVar::alloc(cs.ns(|| "point"), Some(point)).is_err());
+}
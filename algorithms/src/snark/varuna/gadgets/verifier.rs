@@ -0,0 +1,565 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{FpVar, G1AffineVar, NonNativeFieldVar, PoseidonSpongeVar};
+use crate::{
+    crypto_hash::PoseidonSponge,
+    fft::EvaluationDomain,
+    polycommit::sonic_pc::PairingCheck,
+    r1cs::{ConstraintSystem, SynthesisError},
+    snark::varuna::{AHPForR1CS, CircuitVerifyingKey, Proof, SNARKMode, VarunaSNARK},
+    srs::UniversalVerifier,
+    SNARKError,
+};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fq, Fr, G1Affine},
+    AffineCurve,
+};
+use snarkvm_fields::{One, PoseidonParameters, ToConstraintField, Zero};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use itertools::Itertools;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The number of points at which the polynomials of a proof are opened.
+const NUM_QUERY_POINTS: usize = 3;
+
+/// A Varuna proof of a single instance of a single circuit.
+#[derive(Clone, Debug)]
+pub struct ProofVar {
+    /// The commitment to the `w` polynomial.
+    witness_commitment: G1AffineVar,
+    /// The commitment to the masking polynomial, in hiding mode.
+    mask_poly_commitment: Option<G1AffineVar>,
+    /// The commitment to the `h_0` polynomial.
+    h_0: G1AffineVar,
+    /// The commitment to the `g_1` polynomial.
+    g_1: G1AffineVar,
+    /// The commitment to the `h_1` polynomial.
+    h_1: G1AffineVar,
+    /// The commitment to the `g_a` polynomial.
+    g_a: G1AffineVar,
+    /// The commitment to the `g_b` polynomial.
+    g_b: G1AffineVar,
+    /// The commitment to the `g_c` polynomial.
+    g_c: G1AffineVar,
+    /// The commitment to the `h_2` polynomial.
+    h_2: G1AffineVar,
+    /// The sums `sum_a`, `sum_b`, `sum_c` of the third prover message.
+    third_sums: [NonNativeFieldVar; 3],
+    /// The sums `sum_a`, `sum_b`, `sum_c` of the fourth prover message.
+    fourth_sums: [NonNativeFieldVar; 3],
+    /// The evaluation of `g_1` at `beta`.
+    g_1_eval: NonNativeFieldVar,
+    /// The evaluation of `g_a` at `gamma`.
+    g_a_eval: NonNativeFieldVar,
+    /// The evaluation of `g_b` at `gamma`.
+    g_b_eval: NonNativeFieldVar,
+    /// The evaluation of `g_c` at `gamma`.
+    g_c_eval: NonNativeFieldVar,
+    /// The KZG opening proofs at `alpha`, `beta` and `gamma`, with the evaluations of the random polynomials in hiding mode.
+    opening_proofs: Vec<(G1AffineVar, Option<NonNativeFieldVar>)>,
+}
+
+impl ProofVar {
+    /// Allocates a proof of a single instance of a single circuit, in the SNARK mode `SM`.
+    /// Returns an error if the given proof has a different shape.
+    pub fn alloc<SM: SNARKMode, CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        proof: Option<&Proof<Bls12_377>>,
+    ) -> Result<Self, SynthesisError> {
+        if proof.map_or(false, |proof| !Self::has_expected_shape::<SM>(proof)) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let commitments = proof.map(|proof| &proof.commitments);
+        let witness_commitment = G1AffineVar::alloc(cs.ns(|| "w"), commitments.map(|c| c.witness_commitments[0].w.0))?;
+        let mask_poly_commitment = match SM::ZK {
+            true => {
+                Some(G1AffineVar::alloc(cs.ns(|| "mask_poly"), commitments.and_then(|c| c.mask_poly).map(|c| c.0))?)
+            }
+            false => None,
+        };
+        let h_0 = G1AffineVar::alloc(cs.ns(|| "h_0"), commitments.map(|c| c.h_0.0))?;
+        let g_1 = G1AffineVar::alloc(cs.ns(|| "g_1"), commitments.map(|c| c.g_1.0))?;
+        let h_1 = G1AffineVar::alloc(cs.ns(|| "h_1"), commitments.map(|c| c.h_1.0))?;
+        let g_a = G1AffineVar::alloc(cs.ns(|| "g_a"), commitments.map(|c| c.g_a_commitments[0].0))?;
+        let g_b = G1AffineVar::alloc(cs.ns(|| "g_b"), commitments.map(|c| c.g_b_commitments[0].0))?;
+        let g_c = G1AffineVar::alloc(cs.ns(|| "g_c"), commitments.map(|c| c.g_c_commitments[0].0))?;
+        let h_2 = G1AffineVar::alloc(cs.ns(|| "h_2"), commitments.map(|c| c.h_2.0))?;
+
+        let third_sums = proof.map(|proof| &proof.third_msg.sums[0][0]);
+        let third_sums = [
+            NonNativeFieldVar::alloc(cs.ns(|| "third sum_a"), third_sums.map(|sums| sums.sum_a))?,
+            NonNativeFieldVar::alloc(cs.ns(|| "third sum_b"), third_sums.map(|sums| sums.sum_b))?,
+            NonNativeFieldVar::alloc(cs.ns(|| "third sum_c"), third_sums.map(|sums| sums.sum_c))?,
+        ];
+        let fourth_sums = proof.map(|proof| &proof.fourth_msg.sums[0]);
+        let fourth_sums = [
+            NonNativeFieldVar::alloc(cs.ns(|| "fourth sum_a"), fourth_sums.map(|sums| sums.sum_a))?,
+            NonNativeFieldVar::alloc(cs.ns(|| "fourth sum_b"), fourth_sums.map(|sums| sums.sum_b))?,
+            NonNativeFieldVar::alloc(cs.ns(|| "fourth sum_c"), fourth_sums.map(|sums| sums.sum_c))?,
+        ];
+
+        let evaluations = proof.map(|proof| &proof.evaluations);
+        let g_1_eval = NonNativeFieldVar::alloc(cs.ns(|| "g_1 eval"), evaluations.map(|e| e.g_1_eval))?;
+        let g_a_eval = NonNativeFieldVar::alloc(cs.ns(|| "g_a eval"), evaluations.map(|e| e.g_a_evals[0]))?;
+        let g_b_eval = NonNativeFieldVar::alloc(cs.ns(|| "g_b eval"), evaluations.map(|e| e.g_b_evals[0]))?;
+        let g_c_eval = NonNativeFieldVar::alloc(cs.ns(|| "g_c eval"), evaluations.map(|e| e.g_c_evals[0]))?;
+
+        let opening_proofs = (0..NUM_QUERY_POINTS)
+            .map(|i| {
+                let opening_proof = proof.map(|proof| &proof.pc_proof.proof.0[i]);
+                let w = G1AffineVar::alloc(cs.ns(|| format!("opening proof {i}")), opening_proof.map(|p| p.w))?;
+                // In hiding mode, a missing evaluation contributes nothing to the pairing check, like a zero one.
+                let random_v = match SM::ZK {
+                    true => Some(NonNativeFieldVar::alloc(
+                        cs.ns(|| format!("opening proof {i} random_v")),
+                        opening_proof.map(|p| p.random_v.unwrap_or_else(Fr::zero)),
+                    )?),
+                    false => None,
+                };
+                Ok((w, random_v))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Ok(Self {
+            witness_commitment,
+            mask_poly_commitment,
+            h_0,
+            g_1,
+            h_1,
+            g_a,
+            g_b,
+            g_c,
+            h_2,
+            third_sums,
+            fourth_sums,
+            g_1_eval,
+            g_a_eval,
+            g_b_eval,
+            g_c_eval,
+            opening_proofs,
+        })
+    }
+
+    /// Returns `true` if the given proof is of a single instance of a single circuit, in the SNARK mode `SM`.
+    fn has_expected_shape<SM: SNARKMode>(proof: &Proof<Bls12_377>) -> bool {
+        proof.check_batch_sizes().is_ok()
+            && proof.batch_sizes() == [1]
+            && proof.pc_proof.proof.0.len() == NUM_QUERY_POINTS
+            && proof.commitments.mask_poly.is_some() == SM::ZK
+            && proof.pc_proof.is_hiding() == SM::ZK
+    }
+}
+
+/// The pairing check that remains after the verification of a proof, as a gadget.
+///
+/// The points are not checked to lie in the prime-order subgroup, so the party computing the pairings must check this.
+#[derive(Clone, Debug)]
+pub struct PairingCheckVar {
+    /// The combined commitments, grouped by degree bound.
+    pub combined_comms: BTreeMap<Option<usize>, G1AffineVar>,
+    /// The combined witness.
+    pub combined_witness: G1AffineVar,
+    /// The combined witness, adjusted by the points and values of the openings.
+    pub combined_adjusted_witness: G1AffineVar,
+}
+
+impl PairingCheckVar {
+    /// Returns the value of this pairing check, if it is known.
+    pub fn value(&self) -> Option<PairingCheck<Bls12_377>> {
+        let combined_comms = self
+            .combined_comms
+            .iter()
+            .map(|(degree_bound, comm)| Some((*degree_bound, comm.value()?.to_projective())))
+            .collect::<Option<BTreeMap<_, _>>>()?;
+        Some(PairingCheck {
+            combined_comms,
+            combined_witness: self.combined_witness.value()?.to_projective(),
+            combined_adjusted_witness: self.combined_adjusted_witness.value()?.to_projective(),
+        })
+    }
+}
+
+/// The terms of a multi-scalar multiplication.
+#[derive(Default)]
+struct Terms<'a> {
+    /// The terms with variable bases.
+    variable: Vec<(&'a G1AffineVar, NonNativeFieldVar)>,
+    /// The terms with constant bases.
+    constant: Vec<(G1Affine, NonNativeFieldVar)>,
+}
+
+impl Terms<'_> {
+    /// Returns the sum of the terms.
+    fn msm<CS: ConstraintSystem<Fq>>(&self, cs: CS) -> Result<G1AffineVar, SynthesisError> {
+        let variable = self.variable.iter().map(|(base, scalar)| (*base, scalar.to_bits_le())).collect::<Vec<_>>();
+        let constant = self.constant.iter().map(|(base, scalar)| (*base, scalar.to_bits_le())).collect::<Vec<_>>();
+        G1AffineVar::msm(cs, &variable, &constant)
+    }
+}
+
+/// A linear combination of commitments, which is opened at a query point.
+struct Combination<'a> {
+    /// The degree bound of the combination.
+    degree_bound: Option<usize>,
+    /// The commitments, with their coefficients.
+    terms: Terms<'a>,
+    /// The claimed evaluation of the combination, minus its constant term.
+    value: NonNativeFieldVar,
+}
+
+impl<'a> Combination<'a> {
+    /// Returns the combination of the given commitment, which has the given degree bound and evaluation.
+    fn single(commitment: &'a G1AffineVar, degree_bound: Option<usize>, value: &NonNativeFieldVar) -> Self {
+        let terms = Terms { variable: vec![(commitment, NonNativeFieldVar::constant(Fr::one()))], constant: vec![] };
+        Self { degree_bound, terms, value: value.clone() }
+    }
+}
+
+/// The Varuna verifier for a single instance of a single circuit, as a gadget over the base field of BLS12-377.
+pub struct VarunaVerifierGadget<SM: SNARKMode>(PhantomData<SM>);
+
+impl<SM: SNARKMode> VarunaVerifierGadget<SM> {
+    /// Performs the checks of `VarunaSNARK::prepare_verify_batch` for the given proof, and returns the pairing check.
+    ///
+    /// The public input must include the leading one. The verifying key, and the sizes of the circuit,
+    /// are fixed when the constraints are synthesized.
+    #[allow(non_snake_case)]
+    pub fn prepare_verify<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        universal_verifier: &UniversalVerifier<Bls12_377>,
+        fs_parameters: &Arc<PoseidonParameters<Fq, 2, 1>>,
+        verifying_key: &CircuitVerifyingKey<Bls12_377>,
+        public_input: &[NonNativeFieldVar],
+        proof: &ProofVar,
+    ) -> Result<PairingCheckVar, SynthesisError> {
+        let circuit_info = &verifying_key.circuit_info;
+        let input_domain = Self::domain(circuit_info.num_public_inputs)?;
+        let constraint_domain = Self::domain(circuit_info.num_constraints)?;
+        let variable_domain = Self::domain(circuit_info.num_public_and_private_variables)?;
+        let non_zero_domains = AHPForR1CS::<Fr, SM>::cmp_non_zero_domains(circuit_info, None)?;
+        let max_non_zero_domain = non_zero_domains.max_non_zero_domain.ok_or(SynthesisError::PolyTooLarge)?;
+        let matrix_domains = [non_zero_domains.domain_a, non_zero_domains.domain_b, non_zero_domains.domain_c];
+
+        let index_labels = AHPForR1CS::<Fr, SM>::index_polynomial_info(std::iter::once(&verifying_key.id));
+        if index_labels.len() != verifying_key.circuit_commitments.len() {
+            return Err(SynthesisError::MalformedVerifyingKey(
+                index_labels.len(),
+                verifying_key.circuit_commitments.len(),
+            ));
+        }
+        let index_commitments = index_labels
+            .into_keys()
+            .zip_eq(verifying_key.circuit_commitments.iter().map(|commitment| commitment.0))
+            .collect::<BTreeMap<_, _>>();
+
+        if public_input.is_empty() || public_input.len() > input_domain.size() {
+            return Err(anyhow!(SNARKError::PublicInputSizeMismatch).into());
+        }
+        let one = NonNativeFieldVar::constant(Fr::one());
+        public_input[0].enforce_equal(cs.ns(|| "public input starts with one"), &one)?;
+        let mut padded_public_input = public_input.to_vec();
+        padded_public_input.resize(input_domain.size(), NonNativeFieldVar::constant(Fr::zero()));
+
+        // --------------------------------------------------------------------
+        // Replay the transcript.
+        let mut sponge = PoseidonSpongeVar::new(fs_parameters);
+        sponge.absorb_bytes(
+            cs.ns(|| "absorb protocol name"),
+            VarunaSNARK::<Bls12_377, PoseidonSponge<Fq, 2, 1>, SM>::PROTOCOL_NAME,
+        )?;
+        sponge.absorb_bytes(cs.ns(|| "absorb batch size"), &1u64.to_le_bytes())?;
+        sponge.absorb_nonnative(cs.ns(|| "absorb public input"), &padded_public_input)?;
+        let circuit_commitments = verifying_key
+            .circuit_commitments
+            .iter()
+            .map(|commitment| commitment.to_field_elements())
+            .flatten_ok()
+            .map_ok(FpVar::constant)
+            .collect::<Result<Vec<_>, _>>()?;
+        sponge.absorb_native(cs.ns(|| "absorb circuit commitments"), &circuit_commitments)?;
+
+        // First round
+        let first_commitments = [Some(&proof.witness_commitment), proof.mask_poly_commitment.as_ref()];
+        let first_elements = first_commitments.into_iter().flatten().flat_map(G1AffineVar::to_field_elements);
+        sponge.absorb_native(cs.ns(|| "absorb first round"), &first_elements.collect::<Vec<_>>())?;
+
+        // Second round
+        sponge.absorb_native(cs.ns(|| "absorb second round"), &proof.h_0.to_field_elements())?;
+        let challenges = sponge.squeeze_nonnative(cs.ns(|| "squeeze second round"), 3)?;
+        let (alpha, eta_b, eta_c) = (&challenges[0], &challenges[1], &challenges[2]);
+        let v_R_at_alpha = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_R(alpha)"), &constraint_domain, alpha)?;
+        v_R_at_alpha.inverse(cs.ns(|| "v_R(alpha) is nonzero"))?;
+
+        // Third round
+        let third_elements = [&proof.g_1, &proof.h_1].into_iter().flat_map(G1AffineVar::to_field_elements);
+        sponge.absorb_native(cs.ns(|| "absorb third round commitments"), &third_elements.collect::<Vec<_>>())?;
+        sponge.absorb_nonnative(cs.ns(|| "absorb third round sums"), &proof.third_sums)?;
+        let beta = sponge.squeeze_nonnative(cs.ns(|| "squeeze third round"), 1)?.remove(0);
+        let v_C_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_C(beta)"), &variable_domain, &beta)?;
+        v_C_at_beta.inverse(cs.ns(|| "v_C(beta) is nonzero"))?;
+
+        // Fourth round
+        let fourth_elements = [&proof.g_a, &proof.g_b, &proof.g_c].into_iter().flat_map(G1AffineVar::to_field_elements);
+        sponge.absorb_native(cs.ns(|| "absorb fourth round commitments"), &fourth_elements.collect::<Vec<_>>())?;
+        sponge.absorb_nonnative(cs.ns(|| "absorb fourth round sums"), &proof.fourth_sums)?;
+        let mut deltas = sponge.squeeze_nonnative(cs.ns(|| "squeeze fourth round"), 2)?;
+        deltas.insert(0, one.clone());
+
+        // Fifth round
+        sponge.absorb_native(cs.ns(|| "absorb fifth round"), &proof.h_2.to_field_elements())?;
+        let gamma = sponge.squeeze_nonnative(cs.ns(|| "squeeze fifth round"), 1)?.remove(0);
+        let v_K_at_gamma = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_K(gamma)"), &max_non_zero_domain, &gamma)?;
+        v_K_at_gamma.inverse(cs.ns(|| "v_K(gamma) is nonzero"))?;
+
+        let evaluations =
+            [proof.g_1_eval.clone(), proof.g_a_eval.clone(), proof.g_b_eval.clone(), proof.g_c_eval.clone()];
+        sponge.absorb_nonnative(cs.ns(|| "absorb evaluations"), &evaluations)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Construct the linear combinations.
+
+        // Rowcheck
+        let [sum_a_third, sum_b_third, sum_c_third] = &proof.third_sums;
+        let rowcheck_constant = sum_a_third
+            .mul(cs.ns(|| "sum_a * sum_b"), sum_b_third)?
+            .sub(cs.ns(|| "sum_a * sum_b - sum_c"), sum_c_third)?;
+        let rowcheck_zerocheck = Combination {
+            degree_bound: None,
+            terms: Terms {
+                variable: vec![(&proof.h_0, v_R_at_alpha.negate(cs.ns(|| "-v_R(alpha)"))?)],
+                constant: vec![],
+            },
+            value: rowcheck_constant.negate(cs.ns(|| "rowcheck value"))?,
+        };
+
+        // Lineval sumcheck
+        let v_X_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_X(beta)"), &input_domain, &beta)?;
+        let x_at_beta = {
+            // The Lagrange coefficient of the i-th element `w_i` is `v_X(beta) * w_i / (|X| * (beta - w_i))`.
+            let mut cs = cs.ns(|| "x(beta)");
+            let weighted_inputs = public_input
+                .iter()
+                .zip(input_domain.elements())
+                .enumerate()
+                .map(|(i, (x_i, element))| {
+                    let mut cs = cs.ns(|| format!("input {i}"));
+                    let difference = beta.sub(cs.ns(|| "difference"), &NonNativeFieldVar::constant(element))?;
+                    let weight = difference
+                        .inverse(cs.ns(|| "inverse"))?
+                        .mul(cs.ns(|| "weight"), &NonNativeFieldVar::constant(element * input_domain.size_inv))?;
+                    x_i.mul(cs.ns(|| "weighted input"), &weight)
+                })
+                .collect::<Result<Vec<_>, SynthesisError>>()?;
+            NonNativeFieldVar::linear_sum(cs.ns(|| "sum"), &weighted_inputs.iter().collect::<Vec<_>>(), &[])?
+                .mul(cs.ns(|| "product"), &v_X_at_beta)?
+        };
+
+        let [sum_a_fourth, sum_b_fourth, sum_c_fourth] = &proof.fourth_sums;
+        let scaled_sums = [sum_a_fourth, sum_b_fourth, sum_c_fourth]
+            .into_iter()
+            .zip_eq(&matrix_domains)
+            .enumerate()
+            .map(|(i, (sum, domain))| {
+                sum.mul(
+                    cs.ns(|| format!("scaled fourth sum {i}")),
+                    &NonNativeFieldVar::constant(domain.size_as_field_element),
+                )
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+        let combined_sum = Self::combine_sums(cs.ns(|| "combined fourth sums"), &scaled_sums, eta_b, eta_c)?;
+        let third_sum = Self::combine_sums(cs.ns(|| "combined third sums"), &proof.third_sums, eta_b, eta_c)?;
+        let batch_lineval_sum =
+            third_sum.mul(cs.ns(|| "batch lineval sum"), &NonNativeFieldVar::constant(variable_domain.size_inv))?;
+        let lineval_constant = {
+            let mut cs = cs.ns(|| "lineval constant");
+            let x_term = combined_sum.mul(cs.ns(|| "x term"), &x_at_beta)?;
+            let g_1_term = beta.mul(cs.ns(|| "g_1 term"), &proof.g_1_eval)?;
+            NonNativeFieldVar::linear_sum(cs.ns(|| "sum"), &[&x_term], &[&g_1_term, &batch_lineval_sum])?
+        };
+        let mut lineval_terms = Terms::default();
+        lineval_terms.variable.push((&proof.witness_commitment, combined_sum.mul(cs.ns(|| "w term"), &v_X_at_beta)?));
+        lineval_terms.variable.push((&proof.h_1, v_C_at_beta.negate(cs.ns(|| "-v_C(beta)"))?));
+        if let Some(mask_poly_commitment) = &proof.mask_poly_commitment {
+            lineval_terms.variable.push((mask_poly_commitment, one.clone()));
+        }
+        let lineval_sumcheck = Combination {
+            degree_bound: None,
+            terms: lineval_terms,
+            value: lineval_constant.negate(cs.ns(|| "lineval value"))?,
+        };
+
+        // Matrix sumcheck
+        let v_rc = v_R_at_alpha.mul(cs.ns(|| "v_R(alpha) * v_C(beta)"), &v_C_at_beta)?;
+        let rc = NonNativeFieldVar::constant(
+            constraint_domain.size_as_field_element * variable_domain.size_as_field_element,
+        );
+        let alpha_beta = alpha.mul(cs.ns(|| "alpha * beta"), &beta)?;
+        let g_m_evals = [&proof.g_a_eval, &proof.g_b_eval, &proof.g_c_eval];
+        let mut matrix_terms = Terms::default();
+        let mut matrix_values = Vec::with_capacity(3);
+        for (i, m) in ["a", "b", "c"].into_iter().enumerate() {
+            let mut cs = cs.ns(|| format!("matrix {m}"));
+            let (domain, delta, sum, g_m_eval) = (&matrix_domains[i], &deltas[i], &proof.fourth_sums[i], g_m_evals[i]);
+            // The selector of the non-zero domain of this matrix, within the largest non-zero domain.
+            let selector = match domain.size == max_non_zero_domain.size {
+                true => one.clone(),
+                false => {
+                    let v_K_m_at_gamma = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_K_m(gamma)"), domain, &gamma)?;
+                    let ratio = domain.size_as_field_element * max_non_zero_domain.size_inv;
+                    v_K_m_at_gamma
+                        .inverse(cs.ns(|| "v_K_m(gamma) inverse"))?
+                        .mul(cs.ns(|| "selector numerator"), &v_K_at_gamma)?
+                        .mul(cs.ns(|| "selector"), &NonNativeFieldVar::constant(ratio))?
+                }
+            };
+            let factor = delta.mul(cs.ns(|| "factor"), &selector)?;
+            let b_term = {
+                let product = gamma.mul(cs.ns(|| "gamma * g_m(gamma)"), g_m_eval)?;
+                product.add(cs.ns(|| "b term"), sum)?
+            };
+            let u = factor.mul(cs.ns(|| "factor * b term"), &b_term)?.mul(cs.ns(|| "u"), &rc)?;
+
+            let index_commitment = |poly: &str| index_commitments[&format!("circuit_{}_{poly}_{m}", verifying_key.id)];
+            matrix_terms.constant.push((index_commitment("row_col_val"), factor.mul(cs.ns(|| "row_col_val"), &v_rc)?));
+            matrix_terms.constant.push((index_commitment("col"), u.mul(cs.ns(|| "col"), alpha)?));
+            matrix_terms.constant.push((index_commitment("row"), u.mul(cs.ns(|| "row"), &beta)?));
+            matrix_terms.constant.push((index_commitment("row_col"), u.negate(cs.ns(|| "row_col"))?));
+            matrix_values.push(u.mul(cs.ns(|| "value"), &alpha_beta)?);
+        }
+        matrix_terms.variable.push((&proof.h_2, v_K_at_gamma.negate(cs.ns(|| "-v_K(gamma)"))?));
+        let matrix_sumcheck = Combination {
+            degree_bound: None,
+            terms: matrix_terms,
+            value: NonNativeFieldVar::linear_sum(
+                cs.ns(|| "matrix value"),
+                &matrix_values.iter().collect::<Vec<_>>(),
+                &[],
+            )?,
+        };
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Accumulate the openings into a pairing check, as `SonicKZG10::prepare_batch_check` does.
+        // The query points and labels are visited in the order of their names.
+        let g_1_degree_bound = Some(variable_domain.size() - 2);
+        let [g_a_degree_bound, g_b_degree_bound, g_c_degree_bound] = matrix_domains.map(|d| Some(d.size() - 2));
+        let queries = [
+            (alpha, vec![rowcheck_zerocheck]),
+            (&beta, vec![Combination::single(&proof.g_1, g_1_degree_bound, &proof.g_1_eval), lineval_sumcheck]),
+            (&gamma, vec![
+                Combination::single(&proof.g_a, g_a_degree_bound, &proof.g_a_eval),
+                Combination::single(&proof.g_b, g_b_degree_bound, &proof.g_b_eval),
+                Combination::single(&proof.g_c, g_c_degree_bound, &proof.g_c_eval),
+                matrix_sumcheck,
+            ]),
+        ];
+
+        let mut combined_comms = BTreeMap::<_, Terms>::new();
+        let mut combined_witness = Terms::default();
+        let mut combined_adjusted_witness = Terms::default();
+        let mut g_scalars = Vec::with_capacity(NUM_QUERY_POINTS);
+        let mut gamma_g_scalars = Vec::with_capacity(NUM_QUERY_POINTS);
+        let mut randomizer = one.clone();
+        for (i, ((point, combinations), (w, random_v))) in queries.into_iter().zip_eq(&proof.opening_proofs).enumerate()
+        {
+            let mut cs = cs.ns(|| format!("query {i}"));
+            let mut values = Vec::with_capacity(combinations.len());
+            for (j, combination) in combinations.into_iter().enumerate() {
+                let mut cs = cs.ns(|| format!("combination {j}"));
+                let challenge = sponge.squeeze_short_nonnative(cs.ns(|| "challenge"), 1)?.remove(0);
+                values.push(combination.value.mul(cs.ns(|| "value"), &challenge)?);
+
+                let coefficient = randomizer.mul(cs.ns(|| "coefficient"), &challenge)?;
+                let terms = combined_comms.entry(combination.degree_bound).or_default();
+                for (k, (base, scalar)) in combination.terms.variable.into_iter().enumerate() {
+                    terms.variable.push((base, scalar.mul(cs.ns(|| format!("variable term {k}")), &coefficient)?));
+                }
+                for (k, (base, scalar)) in combination.terms.constant.into_iter().enumerate() {
+                    terms.constant.push((base, scalar.mul(cs.ns(|| format!("constant term {k}")), &coefficient)?));
+                }
+            }
+            let value = NonNativeFieldVar::linear_sum(cs.ns(|| "value"), &values.iter().collect::<Vec<_>>(), &[])?;
+            g_scalars.push(value.mul(cs.ns(|| "g scalar"), &randomizer)?);
+            let w_scalar = point.mul(cs.ns(|| "point"), &randomizer)?.negate(cs.ns(|| "w scalar"))?;
+            combined_adjusted_witness.variable.push((w, w_scalar));
+            if let Some(random_v) = random_v {
+                gamma_g_scalars.push(random_v.mul(cs.ns(|| "gamma_g scalar"), &randomizer)?);
+            }
+            combined_witness.variable.push((w, randomizer));
+
+            // The native verifier also squeezes a randomizer after the last point, which does not affect the result.
+            randomizer = match i + 1 < NUM_QUERY_POINTS {
+                true => sponge.squeeze_short_nonnative(cs.ns(|| "randomizer"), 1)?.remove(0),
+                false => one.clone(),
+            };
+        }
+
+        let g_scalar = NonNativeFieldVar::linear_sum(cs.ns(|| "g scalar"), &g_scalars.iter().collect::<Vec<_>>(), &[])?;
+        combined_adjusted_witness.constant.push((universal_verifier.vk.g, g_scalar));
+        if SM::ZK {
+            let gamma_g_scalar = NonNativeFieldVar::linear_sum(
+                cs.ns(|| "gamma_g scalar"),
+                &gamma_g_scalars.iter().collect::<Vec<_>>(),
+                &[],
+            )?;
+            combined_adjusted_witness.constant.push((universal_verifier.vk.gamma_g, gamma_g_scalar));
+        }
+
+        let combined_comms = combined_comms
+            .into_iter()
+            .map(|(degree_bound, terms)| {
+                let comm = terms.msm(cs.ns(|| format!("combined commitment {degree_bound:?}")))?;
+                Ok((degree_bound, comm))
+            })
+            .collect::<Result<BTreeMap<_, _>, SynthesisError>>()?;
+        Ok(PairingCheckVar {
+            combined_comms,
+            combined_witness: combined_witness.msm(cs.ns(|| "combined witness"))?,
+            combined_adjusted_witness: combined_adjusted_witness.msm(cs.ns(|| "combined adjusted witness"))?,
+        })
+    }
+
+    /// Returns the evaluation domain of the given size.
+    fn domain(size: usize) -> Result<EvaluationDomain<Fr>, SynthesisError> {
+        EvaluationDomain::new(size).ok_or(SynthesisError::PolyTooLarge)
+    }
+
+    /// Returns the vanishing polynomial of `domain`, evaluated at `point`.
+    fn evaluate_vanishing_polynomial<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<Fr>,
+        point: &NonNativeFieldVar,
+    ) -> Result<NonNativeFieldVar, SynthesisError> {
+        let power = point.pow_by_constant(cs.ns(|| "power"), domain.size)?;
+        power.sub(cs.ns(|| "difference"), &NonNativeFieldVar::constant(Fr::one()))
+    }
+
+    /// Returns `sum_a + eta_b * sum_b + eta_c * sum_c`.
+    fn combine_sums<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        sums: &[NonNativeFieldVar],
+        eta_b: &NonNativeFieldVar,
+        eta_c: &NonNativeFieldVar,
+    ) -> Result<NonNativeFieldVar, SynthesisError> {
+        let sum_b = sums[1].mul(cs.ns(|| "eta_b * sum_b"), eta_b)?;
+        let sum_c = sums[2].mul(cs.ns(|| "eta_c * sum_c"), eta_c)?;
+        NonNativeFieldVar::linear_sum(cs.ns(|| "sum"), &[&sums[0], &sum_b, &sum_c], &[])
+    }
+}
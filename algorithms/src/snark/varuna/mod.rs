@@ -28,5 +28,11 @@ pub use varuna::*;
 mod mode;
 pub use mode::*;
 
+/// Distributes the witness generation and the per-instance round computations of the prover across workers.
+pub mod distributed;
+
+/// Constraint-system gadgets for verifying Varuna proofs.
+pub mod gadgets;
+
 #[cfg(test)]
 pub mod tests;
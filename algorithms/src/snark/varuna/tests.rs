@@ -306,11 +306,13 @@ mod varuna_hiding {
         crypto_hash::PoseidonSponge,
         snark::varuna::{
             ahp::AHPForR1CS,
+            distributed::Worker,
             test_circuit::TestCircuit,
             CircuitVerifyingKey,
             VarunaHidingMode,
             VarunaSNARK,
         },
+        srs::UniversalProver,
         traits::{AlgebraicSponge, SNARK},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
@@ -320,7 +322,7 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, net::TcpListener, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -490,6 +492,141 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_verify_pairing_checks() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prepare the pairing checks for the proofs and certificates of circuits with different sizes.
+        let mut pairing_checks = Vec::new();
+        let mut fake_pairing_checks = Vec::new();
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let mul_depth = 2;
+            let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+            let mut fake_inputs = public_inputs.clone();
+            fake_inputs[public_inputs.len() - 1] = Fr::rand(rng);
+
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            let certificate = VarunaInst::prove_vk(universal_prover, &fs_parameters, &index_vk, &index_pk).unwrap();
+
+            let inputs = [public_inputs];
+            let keys_to_inputs = BTreeMap::from([(&index_vk, inputs.as_slice())]);
+            let pairing_check =
+                VarunaInst::prepare_verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap();
+            pairing_checks.push(pairing_check.unwrap());
+            let pairing_check =
+                VarunaInst::prepare_verify_vk(universal_verifier, &fs_parameters, &circuit, &index_vk, &certificate);
+            pairing_checks.push(pairing_check.unwrap());
+
+            let fake_inputs = [fake_inputs];
+            let keys_to_inputs = BTreeMap::from([(&index_vk, fake_inputs.as_slice())]);
+            let pairing_check =
+                VarunaInst::prepare_verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap();
+            fake_pairing_checks.push(pairing_check.unwrap());
+        }
+
+        // Ensure the pairing checks hold together.
+        assert!(VarunaInst::verify_pairing_checks(universal_verifier, pairing_checks.clone(), rng).unwrap());
+        // Ensure an empty set of pairing checks holds.
+        assert!(VarunaInst::verify_pairing_checks(universal_verifier, vec![], rng).unwrap());
+
+        // Ensure a failing pairing check is detected, at any position.
+        for (i, fake_pairing_check) in fake_pairing_checks.into_iter().enumerate() {
+            let mut candidate = pairing_checks.clone();
+            candidate.insert(i, fake_pairing_check.clone());
+            assert!(!VarunaInst::verify_pairing_checks(universal_verifier, candidate, rng).unwrap());
+            assert!(!VarunaInst::verify_pairing_checks(universal_verifier, [fake_pairing_check], rng).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_memory_budget() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(1000, 1000, 3000).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let mul_depth = 2;
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, 1000, 1000, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Prove in memory, and with budgets that spill some or all of the round state.
+        let expected =
+            VarunaInst::prove(&universal_prover, &fs_parameters, &index_pk, &circuit, &mut TestRng::fixed(1))
+                .unwrap()
+                .to_bytes_le()
+                .unwrap();
+        for memory_budget in [0, 1 << 15, 1 << 17] {
+            let universal_prover = UniversalProver { memory_budget: Some(memory_budget), ..universal_prover.clone() };
+            let proof =
+                VarunaInst::prove(&universal_prover, &fs_parameters, &index_pk, &circuit, &mut TestRng::fixed(1))
+                    .unwrap();
+            assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof)
+                .unwrap());
+            // Ensure the proof is identical to the one computed in memory.
+            assert_eq!(proof.to_bytes_le().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_prove_batch_distributed() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(300, 300, 900).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Batch three instances of one circuit with two instances of another.
+        let (circuits1, inputs1): (Vec<_>, Vec<_>) = (0..3).map(|_| TestCircuit::gen_rand(2, 200, 200, rng)).unzip();
+        let (circuits2, inputs2): (Vec<_>, Vec<_>) = (0..2).map(|_| TestCircuit::gen_rand(3, 300, 250, rng)).unzip();
+        let (pk1, vk1) = VarunaInst::circuit_setup(&universal_srs, &circuits1[0]).unwrap();
+        let (pk2, vk2) = VarunaInst::circuit_setup(&universal_srs, &circuits2[0]).unwrap();
+        let keys_to_constraints = BTreeMap::from([(&pk1, circuits1.as_slice()), (&pk2, circuits2.as_slice())]);
+        let keys_to_inputs = BTreeMap::from([(&vk1, inputs1.as_slice()), (&vk2, inputs2.as_slice())]);
+
+        // Start two workers on localhost, which serve a single session each.
+        let (addresses, workers): (Vec<_>, Vec<_>) = (0..2)
+            .map(|_| {
+                let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+                let address = listener.local_addr().unwrap();
+                let worker =
+                    Worker::<Fr, VarunaHidingMode, TestCircuit<Fr>>::new([pk1.circuit.clone(), pk2.circuit.clone()]);
+                (address, std::thread::spawn(move || worker.serve_session(listener.accept().unwrap().0)))
+            })
+            .unzip();
+
+        let proof = VarunaInst::prove_batch_distributed(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &addresses,
+            &mut TestRng::fixed(1),
+        )
+        .unwrap();
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap());
+        // Ensure the workers ended their sessions cleanly.
+        for worker in workers {
+            worker.join().unwrap().unwrap();
+        }
+
+        // Ensure the proof is identical to the one computed by a single prover.
+        let expected =
+            VarunaInst::prove_batch(universal_prover, &fs_parameters, &keys_to_constraints, &mut TestRng::fixed(1))
+                .unwrap();
+        assert_eq!(proof.to_bytes_le().unwrap(), expected.to_bytes_le().unwrap());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
@@ -21,6 +21,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -28,6 +29,7 @@ use crate::{
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
         ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
+        distributed::WorkerPool,
         proof,
         prover,
         witness_label,
@@ -51,7 +53,7 @@ use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap, net::SocketAddr, ops::Deref, sync::Arc};
 
 use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
@@ -285,50 +287,9 @@ where
         verifying_key: &Self::VerifyingKey,
         certificate: &Self::Certificate,
     ) -> Result<bool> {
-        // Ensure the VerifyingKey encodes the expected circuit.
-        let circuit_id = &verifying_key.id;
-        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
-        if state.index_info != verifying_key.circuit_info {
-            bail!(SNARKError::CircuitNotFound);
-        }
-        if state.id != *circuit_id {
-            bail!(SNARKError::CircuitNotFound);
-        }
-
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let (lc, evaluation) =
-            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
-
-        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(lc.terms.keys())
-            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
-            .collect_vec();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
+        let pairing_check =
+            Self::prepare_verify_vk(universal_verifier, fs_parameters, circuit, verifying_key, certificate)?;
+        Self::verify_pairing_check(universal_verifier, pairing_check)
     }
 
     /// This is the main entrypoint for creating proofs.
@@ -349,8 +310,88 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let mut prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        prover_state.limit_memory(universal_prover.memory_budget)?;
+
+        let proof = Self::prove_with_state(universal_prover, fs_parameters, keys_to_constraints, prover_state, zk_rng)?;
+
+        end_timer!(prover_time);
+        Ok(proof)
+    }
+
+    /// This is the main entrypoint for verifying proofs.
+    /// You can find a specification of the verifier algorithm in:
+    /// https://github.com/AleoNet/protocol-docs
+    fn verify_batch<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        // Perform all checks, except for the final pairing check.
+        let Some(pairing_check) = Self::prepare_verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = Self::verify_pairing_check(universal_verifier, pairing_check)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed for the AHP verifier linear equations");
+        }
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Proves the batch as `prove_batch` does, with the witnesses synthesized and held by the given workers.
+    ///
+    /// The instances of the batch are sharded across the workers, which compute their contributions to the
+    /// first three rounds of the prover. This prover performs the Fiat-Shamir transcript, the remaining rounds,
+    /// and all commitments and opening proofs. Each worker must be serving the circuits of the batch,
+    /// see [`Worker`](super::distributed::Worker).
+    pub fn prove_batch_distributed<C: ToBytes, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        workers: &[SocketAddr],
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>> {
+        let prover_time = start_timer!(|| "Varuna::DistributedProver");
+        if keys_to_constraints.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
 
+        let mut circuits_to_constraints = BTreeMap::new();
+        for (pk, constraints) in keys_to_constraints {
+            circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
+        }
+        let workers = WorkerPool::connect(workers)?;
+        let prover_state = AHPForR1CS::<_, SM>::init_distributed_prover(&circuits_to_constraints, workers, zk_rng)?;
+
+        let proof = Self::prove_with_state(universal_prover, fs_parameters, keys_to_constraints, prover_state, zk_rng)?;
+
+        end_timer!(prover_time);
+        Ok(proof)
+    }
+
+    /// Runs the rounds of the prover on the initialized prover state, and returns the proof.
+    fn prove_with_state<C, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        prover_state: prover::State<'_, E::Fr, SM>,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>> {
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
         let mut circuit_infos = BTreeMap::new();
@@ -616,19 +657,69 @@ where
         proof.check_batch_sizes()?;
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
 
-        end_timer!(prover_time);
         Ok(proof)
     }
+    /// Performs the checks in `verify_vk`, except for the final pairing check, which is returned instead.
+    pub fn prepare_verify_vk<C: ConstraintSynthesizer<E::Fr>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        circuit: &C,
+        verifying_key: &CircuitVerifyingKey<E>,
+        certificate: &Certificate<E>,
+    ) -> Result<PairingCheck<E>> {
+        // Ensure the VerifyingKey encodes the expected circuit.
+        let circuit_id = &verifying_key.id;
+        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
+        if state.index_info != verifying_key.circuit_info {
+            bail!(SNARKError::CircuitNotFound);
+        }
+        if state.id != *circuit_id {
+            bail!(SNARKError::CircuitNotFound);
+        }
 
-    /// This is the main entrypoint for verifying proofs.
-    /// You can find a specification of the verifier algorithm in:
-    /// https://github.com/AleoNet/protocol-docs
-    fn verify_batch<B: Borrow<Self::VerifierInput>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
-        proof: &Self::Proof,
-    ) -> Result<bool> {
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let (lc, evaluation) =
+            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
+
+        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(lc.terms.keys())
+            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
+            .collect_vec();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+
+        SonicKZG10::<E, FS>::prepare_check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+    }
+
+    /// Performs the checks in `verify_batch`, except for the final pairing check, which is returned instead.
+    /// Returns `None` if the proof is rejected before the pairing check.
+    pub fn prepare_verify_batch<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -724,7 +815,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -897,8 +988,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let pairing_check = SonicKZG10::<E, FS>::prepare_check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -909,15 +1000,30 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
+    }
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+    /// Returns `true` if the given pairing check holds.
+    pub fn verify_pairing_check(
+        universal_verifier: &UniversalVerifier<E>,
+        pairing_check: PairingCheck<E>,
+    ) -> Result<bool> {
+        SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)
+    }
+
+    /// Returns `true` if all of the given pairing checks hold, checking them with one multi-pairing.
+    ///
+    /// This allows the proofs and certificates of different circuits to be verified together.
+    /// The pairing checks are folded with coefficients sampled from `rng`, which must be unpredictable to the provers.
+    pub fn verify_pairing_checks<R: RngCore>(
+        universal_verifier: &UniversalVerifier<E>,
+        pairing_checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let verifier_time = start_timer!(|| "Varuna::VerifyPairingChecks");
+        let is_valid = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_checks, rng)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
     }
 }
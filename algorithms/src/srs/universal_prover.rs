@@ -20,5 +20,14 @@ use snarkvm_curves::PairingEngine;
 pub struct UniversalProver<E: PairingEngine> {
     /// The maximum degree supported by the universal SRS.
     pub max_degree: usize,
+    /// The number of bytes of round state that the Varuna prover may hold in memory,
+    /// beyond which the state is spilled to temporary files. `None` means that there is no limit.
+    ///
+    /// The budget does not bound the working set of a round: the witnesses while they are synthesized,
+    /// the polynomial products that a round computes, and the oracles that have been committed, which are
+    /// held until they are opened at the end of the proof. The peak memory of a proof with a budget of
+    /// `b` bytes is at most `b` bytes more than the peak memory of a proof with a budget of zero, for which
+    /// the prover holds only this working set.
+    pub memory_budget: Option<usize>,
     pub _unused: Option<E>,
 }
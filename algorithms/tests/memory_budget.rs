@@ -0,0 +1,134 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measures the peak memory of the out-of-core FFT and of the Varuna prover with a memory budget.
+//! The allocations of the whole process are counted, so the tests of this file must not run concurrently.
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    fft::{EvaluationDomain, SpilledVec},
+    snark::varuna::{ahp::AHPForR1CS, TestCircuit, VarunaHidingMode, VarunaSNARK},
+    srs::UniversalProver,
+    AlgebraicSponge,
+    SNARK,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::{TestRng, Uniform};
+
+use serial_test::serial;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+type FS = PoseidonSponge<Fq, 2, 1>;
+
+/// The number of bytes that are currently allocated.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+/// The largest number of bytes allocated since the start of the current measurement.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator that keeps track of the number of bytes allocated, and of its peak.
+struct PeakAllocator;
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+/// Runs `f` on a single thread, so that its allocations are deterministic,
+/// and returns its output along with the peak number of bytes that it held.
+fn measure_peak<T: Send>(f: impl FnOnce() -> T + Send) -> (T, usize) {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    pool.install(|| {
+        let baseline = ALLOCATED.load(Ordering::SeqCst);
+        PEAK.store(baseline, Ordering::SeqCst);
+        let output = f();
+        (output, PEAK.load(Ordering::SeqCst) - baseline)
+    })
+}
+
+#[test]
+#[serial]
+fn test_out_of_core_fft_peak_memory() {
+    let rng = &mut TestRng::default();
+
+    let domain = EvaluationDomain::<Fr>::new(1 << 16).unwrap();
+    let evals = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let mut spilled = SpilledVec::from_slice(&evals).unwrap();
+
+    // Use chunks of a sixteenth of the vector, which is larger than the minimum of `sqrt(domain.size())`.
+    let max_chunk_len = domain.size() / 16;
+    let (mut coeffs, peak) = measure_peak(|| domain.ifft_out_of_core(&mut spilled, max_chunk_len).unwrap());
+
+    // Besides a chunk, the IFFT only holds the roots of unity of the subdomains and the I/O buffers,
+    // which are smaller than a chunk.
+    let budget = 2 * max_chunk_len * core::mem::size_of::<Fr>();
+    assert!(peak <= budget, "The out-of-core IFFT held {peak} bytes, which is more than {budget} bytes");
+    assert_eq!(coeffs.to_vec().unwrap(), domain.ifft(&evals));
+}
+
+#[test]
+#[serial]
+fn test_prover_peak_memory() {
+    // Allows for the bookkeeping of the temporary files, whose paths differ in length from one proof to the next.
+    const SLACK: usize = 1 << 12;
+
+    let rng = &mut TestRng::default();
+
+    let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(1000, 1000, 3000).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let universal_prover = universal_srs.to_universal_prover().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let mul_depth = 2;
+    let (circuit, _) = TestCircuit::gen_rand(mul_depth, 1000, 1000, rng);
+    let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+    let peak_with_budget = |memory_budget| {
+        let universal_prover = UniversalProver { memory_budget, ..universal_prover.clone() };
+        let (_, peak) = measure_peak(|| {
+            VarunaInst::prove(&universal_prover, &fs_parameters, &index_pk, &circuit, &mut TestRng::fixed(1)).unwrap()
+        });
+        peak
+    };
+
+    // With a budget of zero, all of the round state is spilled, and the prover only holds the working set of a round.
+    let peak_without_state = peak_with_budget(Some(0));
+    for memory_budget in [1 << 15, 1 << 17, 1 << 20] {
+        // Ensure the round state that is held in memory stays within the budget.
+        let peak = peak_with_budget(Some(memory_budget));
+        assert!(
+            peak <= peak_without_state + memory_budget + SLACK,
+            "The prover held {peak} bytes with a budget of {memory_budget} bytes, and {peak_without_state} bytes \
+             with a budget of zero"
+        );
+    }
+}
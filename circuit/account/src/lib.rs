@@ -25,6 +25,9 @@ pub use compute_key::*;
 pub mod graph_key;
 pub use graph_key::*;
 
+pub mod multisig;
+pub use multisig::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -0,0 +1,201 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod to_address;
+mod verify;
+
+use crate::{ComputeKey, Signature};
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U8};
+
+/// A `k`-of-`n` multisig account key.
+///
+/// The signers are padded to `MAX_SIGNERS` with the zero compute key, so the circuit has the same shape
+/// for every threshold and number of signers.
+#[derive(Clone)]
+pub struct MultisigKey<A: Aleo> {
+    /// The compute key of the shared account.
+    compute_key: ComputeKey<A>,
+    /// The number of signers required to authorize a request.
+    threshold: U8<A>,
+    /// The number of signers.
+    num_signers: U8<A>,
+    /// The compute keys of the signers, padded to `MAX_SIGNERS`.
+    signers: Vec<ComputeKey<A>>,
+    /// The PRF secret key `sk_prf` := HashToScalar(pk_sig || pr_sig || threshold || num_signers || signers).
+    sk_prf: Scalar<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo> MultisigKey<A> {
+    /// The maximum number of signers in a multisig account.
+    pub const MAX_SIGNERS: usize = console::MultisigKey::<A::Network>::MAX_SIGNERS;
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for MultisigKey<A> {
+    type Primitive = console::MultisigKey<A::Network>;
+
+    /// Initializes a multisig key from the given mode and native multisig key.
+    fn new(mode: Mode, multisig_key: Self::Primitive) -> Self {
+        // Inject the compute key of the shared account.
+        let compute_key = ComputeKey::new(mode, *multisig_key.compute_key());
+        // Inject the threshold.
+        let threshold = U8::new(mode, console::U8::new(multisig_key.threshold()));
+        // Inject the number of signers.
+        let num_signers = U8::new(mode, console::U8::new(multisig_key.signers().len() as u8));
+        // Construct the zero compute key, which pads the signers.
+        let padding = match console::ComputeKey::try_from((console::Group::zero(), console::Group::zero())) {
+            Ok(padding) => padding,
+            Err(error) => A::halt(format!("Failed to construct the padding compute key: {error}")),
+        };
+        // Inject the compute keys of the signers, padded to `MAX_SIGNERS`.
+        let signers = (0..Self::MAX_SIGNERS)
+            .map(|index| ComputeKey::new(mode, multisig_key.signers().get(index).copied().unwrap_or(padding)))
+            .collect();
+        // Output the multisig key.
+        Self::from((compute_key, threshold, num_signers, signers))
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> From<(ComputeKey<A>, U8<A>, U8<A>, Vec<ComputeKey<A>>)> for MultisigKey<A> {
+    /// Derives the multisig key from a tuple `(compute_key, threshold, num_signers, signers)`.
+    fn from((compute_key, threshold, num_signers, signers): (ComputeKey<A>, U8<A>, U8<A>, Vec<ComputeKey<A>>)) -> Self {
+        // Ensure the signers are padded to `MAX_SIGNERS`.
+        if signers.len() != Self::MAX_SIGNERS {
+            A::halt("The multisig signers must be padded to the maximum number of signers")
+        }
+        // Construct the preimage as `(domain || pk_sig || pr_sig || threshold || num_signers || signers)`.
+        // Note: The padded signers are the zero compute key, whose x-coordinates are zero, as in the native derivation.
+        let mut preimage = Vec::with_capacity(5 + 2 * signers.len());
+        preimage.push(Field::constant(console::Field::new_domain_separator("AleoMultisigKey0")));
+        preimage.push(compute_key.pk_sig().to_x_coordinate());
+        preimage.push(compute_key.pr_sig().to_x_coordinate());
+        preimage.push(threshold.to_field());
+        preimage.push(num_signers.to_field());
+        for signer in &signers {
+            preimage.push(signer.pk_sig().to_x_coordinate());
+            preimage.push(signer.pr_sig().to_x_coordinate());
+        }
+        // Compute sk_prf := HashToScalar(domain || pk_sig || pr_sig || threshold || num_signers || signers).
+        let sk_prf = A::hash_to_scalar_psd8(&preimage);
+        // Output the multisig key.
+        Self { compute_key, threshold, num_signers, signers, sk_prf }
+    }
+}
+
+impl<A: Aleo> MultisigKey<A> {
+    /// Returns the compute key of the shared account.
+    pub const fn compute_key(&self) -> &ComputeKey<A> {
+        &self.compute_key
+    }
+
+    /// Returns the number of signers required to authorize a request.
+    pub const fn threshold(&self) -> &U8<A> {
+        &self.threshold
+    }
+
+    /// Returns the number of signers.
+    pub const fn num_signers(&self) -> &U8<A> {
+        &self.num_signers
+    }
+
+    /// Returns the compute keys of the signers, padded to `MAX_SIGNERS`.
+    pub fn signers(&self) -> &[ComputeKey<A>] {
+        &self.signers
+    }
+
+    /// Returns the PRF secret key.
+    pub const fn sk_prf(&self) -> &Scalar<A> {
+        &self.sk_prf
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for MultisigKey<A> {
+    type Primitive = console::MultisigKey<A::Network>;
+
+    /// Ejects the mode of the multisig key.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(self.compute_key.eject_mode(), [
+            self.threshold.eject_mode(),
+            self.num_signers.eject_mode(),
+            self.signers.eject_mode(),
+            self.sk_prf.eject_mode(),
+        ])
+    }
+
+    /// Ejects the multisig key.
+    fn eject_value(&self) -> Self::Primitive {
+        // Retrieve the signers, without the padding.
+        let num_signers = (*self.num_signers.eject_value() as usize).min(self.signers.len());
+        let signers = self.signers[..num_signers].iter().map(Eject::eject_value).collect();
+        match Self::Primitive::new(self.compute_key.eject_value(), *self.threshold.eject_value(), signers) {
+            Ok(multisig_key) => multisig_key,
+            Err(error) => A::halt(format!("Failed to eject the multisig key: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+pub(crate) mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::TestRng;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    /// Samples a `threshold`-of-`num_signers` multisig key, and returns it with the shared and signer private keys.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn sample_multisig_key(
+        threshold: u8,
+        num_signers: usize,
+        rng: &mut TestRng,
+    ) -> Result<(
+        console::MultisigKey<CurrentNetwork>,
+        console::PrivateKey<CurrentNetwork>,
+        Vec<console::PrivateKey<CurrentNetwork>>,
+    )> {
+        // Sample the shared account.
+        let private_key = console::PrivateKey::new(rng)?;
+        // Sample the signers.
+        let signer_keys = (0..num_signers).map(|_| console::PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let signers = signer_keys.iter().map(console::ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        // Construct the multisig key.
+        let multisig_key = console::MultisigKey::new(console::ComputeKey::try_from(&private_key)?, threshold, signers)?;
+        Ok((multisig_key, private_key, signer_keys))
+    }
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a new multisig key.
+            let (multisig_key, _, _) = sample_multisig_key(2, 3, rng)?;
+
+            Circuit::scope(format!("New {mode}"), || {
+                let candidate = MultisigKey::<Circuit>::new(mode, multisig_key.clone());
+                assert_eq!(multisig_key, candidate.eject_value());
+                assert_eq!(multisig_key.sk_prf(), candidate.sk_prf().eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
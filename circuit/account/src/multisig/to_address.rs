@@ -0,0 +1,59 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> MultisigKey<A> {
+    /// Returns the address for this multisig key.
+    pub fn to_address(&self) -> Address<A> {
+        // Compute pk_prf := G^sk_prf.
+        let pk_prf = A::g_scalar_multiply(&self.sk_prf);
+        // Compute the address := pk_sig + pr_sig + pk_prf.
+        Address::from_group(self.compute_key.pk_sig() + self.compute_key.pr_sig() + pk_prf)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{multisig::tests::sample_multisig_key, Circuit};
+    use snarkvm_utilities::TestRng;
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a new multisig key.
+                let (multisig_key, _, _) = sample_multisig_key(2, 3, rng)?;
+
+                // Initialize the multisig key.
+                let candidate = MultisigKey::<Circuit>::new(mode, multisig_key.clone());
+
+                Circuit::scope(&format!("{mode} {i}"), || {
+                    let candidate = candidate.to_address();
+                    assert_eq!(multisig_key.to_address(), candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> MultisigKey<A> {
+    /// Returns `true` if the given signatures are a quorum of valid signatures on the message.
+    ///
+    /// The signatures are given as one `(is_signed, signature)` slot per signer, padded to `MAX_SIGNERS`.
+    /// A slot is counted if it is signed and belongs to a signer, and every counted slot must hold a valid signature.
+    /// Note: The circuit has the same shape for every threshold, number of signers, and set of cosigners.
+    pub fn verify(&self, message: &[Field<A>], signatures: &[(Boolean<A>, Signature<A>)]) -> Boolean<A> {
+        // Ensure there is one slot per signer.
+        if signatures.len() != self.signers.len() {
+            return Boolean::constant(false);
+        }
+
+        // Count the signed slots, and ensure each of them holds a valid signature.
+        let (is_valid, count, _) = self.signers.iter().zip_eq(signatures).fold(
+            (Boolean::constant(true), U8::zero(), U8::zero()),
+            |(is_valid, count, index), (signer, (is_signed, signature))| {
+                // Determine whether the slot belongs to a signer.
+                let is_signer = index.is_less_than(&self.num_signers);
+                // Determine whether the slot is counted.
+                let is_counted = is_signed & is_signer;
+                // Ensure the signature is valid, if the slot is counted.
+                let is_valid = is_valid & (!&is_counted | signature.verify(&signer.to_address(), message));
+                // Increment the count, if the slot is counted.
+                let count = count.add_wrapped(&U8::ternary(&is_counted, &U8::one(), &U8::zero()));
+                (is_valid, count, index.add_wrapped(&U8::one()))
+            },
+        );
+        // Ensure the number of counted slots meets the threshold.
+        is_valid & count.is_greater_than_or_equal(&self.threshold)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{multisig::tests::sample_multisig_key, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    /// Returns one `(is_signed, signature)` slot per signer, padded with the given placeholder signature.
+    fn to_slots(
+        mode: Mode,
+        signatures: &[(u8, console::Signature<CurrentNetwork>)],
+        placeholder: console::Signature<CurrentNetwork>,
+    ) -> Vec<(Boolean<Circuit>, Signature<Circuit>)> {
+        (0..console::MultisigKey::<CurrentNetwork>::MAX_SIGNERS)
+            .map(|index| match signatures.iter().find(|(signer, _)| *signer as usize == index) {
+                Some((_, signature)) => (Boolean::new(mode, true), Signature::new(mode, *signature)),
+                None => (Boolean::new(mode, false), Signature::new(mode, placeholder)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a new multisig key.
+            let (multisig_key, _, signer_keys) = sample_multisig_key(2, 3, rng)?;
+
+            // Sign a message with every signer.
+            let message = [Field::new(mode, Uniform::rand(rng)), Field::new(mode, Uniform::rand(rng))];
+            let signatures = signer_keys
+                .iter()
+                .map(|private_key| multisig_key.sign(private_key, &message.eject_value(), rng))
+                .collect::<Result<Vec<_>>>()?;
+            // Sample a signature from a non-signer, to use as the placeholder.
+            let placeholder = console::Signature::sign(&console::PrivateKey::new(rng)?, &message.eject_value(), rng)?;
+
+            // Initialize the multisig key.
+            let multisig_key = MultisigKey::<Circuit>::new(mode, multisig_key);
+
+            Circuit::scope(format!("{mode}"), || {
+                // Ensure a quorum verifies.
+                let slots = to_slots(mode, &signatures, placeholder);
+                assert!(multisig_key.verify(&message, &slots).eject_value());
+                let slots = to_slots(mode, &signatures[1..], placeholder);
+                assert!(multisig_key.verify(&message, &slots).eject_value());
+                // Ensure fewer than `threshold` signatures fail.
+                let slots = to_slots(mode, &signatures[..1], placeholder);
+                assert!(!multisig_key.verify(&message, &slots).eject_value());
+                // Ensure a signature assigned to the wrong signer fails.
+                let slots = to_slots(mode, &[signatures[0], (2, signatures[1].1)], placeholder);
+                assert!(!multisig_key.verify(&message, &slots).eject_value());
+                // Ensure a signed padding slot is not counted.
+                let mut slots = to_slots(mode, &signatures[..1], placeholder);
+                slots[3] = (Boolean::new(mode, true), Signature::new(mode, signatures[1].1));
+                assert!(!multisig_key.verify(&message, &slots).eject_value());
+                // Ensure the slots must be padded.
+                let slots = to_slots(mode, &signatures, placeholder);
+                assert!(!multisig_key.verify(&message, &slots[..3]).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_has_a_fixed_shape() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sign a message by a 1-of-1 and a 3-of-4 multisig key, and count the constraints of each.
+        let mut num_constraints = vec![];
+        for (threshold, num_signers) in [(1, 1), (3, 4)] {
+            // Sample a new multisig key.
+            let (multisig_key, _, signer_keys) = sample_multisig_key(threshold, num_signers, rng)?;
+
+            // Sign a message with every signer.
+            let message = [Field::new(Mode::Private, Uniform::rand(rng))];
+            let signatures = signer_keys
+                .iter()
+                .map(|private_key| multisig_key.sign(private_key, &message.eject_value(), rng))
+                .collect::<Result<Vec<_>>>()?;
+
+            Circuit::scope(format!("{threshold}-of-{num_signers}"), || {
+                let multisig_key = MultisigKey::<Circuit>::new(Mode::Private, multisig_key);
+                let slots = to_slots(Mode::Private, &signatures, signatures[0].1);
+                assert!(multisig_key.verify(&message, &slots).eject_value());
+                num_constraints.push(Circuit::num_constraints_in_scope());
+            });
+            Circuit::reset();
+        }
+        // Ensure the number of constraints does not depend on the threshold or the number of signers.
+        assert_eq!(num_constraints[0], num_constraints[1]);
+        Ok(())
+    }
+}
@@ -15,6 +15,12 @@
 
 use crate::Index;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use indexmap::IndexMap;
 use std::sync::Arc;
@@ -99,6 +105,13 @@ pub struct Assignment<F: PrimeField> {
 impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
     /// Converts an R1CS to an assignment.
     fn from(r1cs: crate::R1CS<F>) -> Self {
+        Self::from(&r1cs)
+    }
+}
+
+impl<F: PrimeField> From<&crate::R1CS<F>> for Assignment<F> {
+    /// Converts an R1CS to an assignment.
+    fn from(r1cs: &crate::R1CS<F>) -> Self {
         Self {
             public: FromIterator::from_iter(
                 r1cs.to_public_variables().iter().map(|variable| (variable.index(), variable.value())),
@@ -160,6 +173,131 @@ impl<F: PrimeField> Assignment<F> {
     }
 }
 
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        write_sequence(&self.terms, &mut writer, |(variable, coefficient), writer| {
+            variable.write_le(&mut *writer)?;
+            coefficient.write_le(writer)
+        })
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let terms =
+            read_sequence(&mut reader, |reader| Ok((AssignmentVariable::read_le(&mut *reader)?, F::read_le(reader)?)))?;
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for Assignment<F> {
+    /// Writes the assignment to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the public and private variables.
+        for variables in [&self.public, &self.private] {
+            write_sequence(variables, &mut writer, |(index, value), writer| {
+                index.write_le(&mut *writer)?;
+                value.write_le(writer)
+            })?;
+        }
+        // Write the constraints.
+        write_sequence(&self.constraints, &mut writer, |(a, b, c), writer| {
+            a.write_le(&mut *writer)?;
+            b.write_le(&mut *writer)?;
+            c.write_le(writer)
+        })?;
+        // Write the number of variables.
+        self.num_variables.write_le(&mut writer)
+    }
+}
+
+impl<F: PrimeField> FromBytes for Assignment<F> {
+    /// Reads the assignment from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid assignment version"));
+        }
+        // Read the public and private variables.
+        let read_variable =
+            |reader: &mut R| -> IoResult<_> { Ok((Index::read_le(&mut *reader)?, F::read_le(reader)?)) };
+        let public = read_sequence(&mut reader, read_variable)?;
+        let private = read_sequence(&mut reader, read_variable)?;
+        // Read the constraints.
+        let constraints = read_sequence(&mut reader, |reader| {
+            Ok((
+                AssignmentLC::read_le(&mut *reader)?,
+                AssignmentLC::read_le(&mut *reader)?,
+                AssignmentLC::read_le(reader)?,
+            ))
+        })?;
+        // Read the number of variables.
+        let num_variables = u64::read_le(&mut reader)?;
+        Ok(Self { public: public.into(), private: private.into(), constraints: constraints.into(), num_variables })
+    }
+}
+
+/// Writes the length of the sequence, followed by each of its elements.
+fn write_sequence<T, W: Write>(
+    sequence: &[T],
+    writer: &mut W,
+    write_element: impl Fn(&T, &mut W) -> IoResult<()>,
+) -> IoResult<()> {
+    (sequence.len() as u64).write_le(&mut *writer)?;
+    sequence.iter().try_for_each(|element| write_element(element, writer))
+}
+
+/// Reads the length of a sequence, followed by each of its elements.
+/// Note: The sequence grows with the elements that are read, so the length prefix cannot force an allocation.
+fn read_sequence<T, R: Read>(reader: &mut R, read_element: impl Fn(&mut R) -> IoResult<T>) -> IoResult<Vec<T>> {
+    let length = u64::read_le(&mut *reader)?;
+    let mut sequence = Vec::new();
+    for _ in 0..length {
+        sequence.push(read_element(reader)?);
+    }
+    Ok(sequence)
+}
+
 impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
@@ -284,9 +422,11 @@ impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assig
 
 #[cfg(test)]
 mod tests {
+    use super::Assignment;
     use snarkvm_algorithms::{r1cs::ConstraintSynthesizer, AlgebraicSponge, SNARK};
     use snarkvm_circuit::prelude::*;
     use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{FromBytes, ToBytes};
 
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
     fn create_example_circuit<E: Environment>() -> Field<E> {
@@ -332,6 +472,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_assignment_bytes() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        // Ensure the assignment round-trips through its byte representation.
+        let bytes = assignment.to_bytes_le().unwrap();
+        let candidate = Assignment::<Fr>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+        assert_eq!(assignment.num_public(), candidate.num_public());
+        assert_eq!(assignment.num_private(), candidate.num_private());
+        assert_eq!(assignment.num_constraints(), candidate.num_constraints());
+        assert_eq!(assignment.num_variables(), candidate.num_variables());
+        assert_eq!(assignment.num_nonzeros(), candidate.num_nonzeros());
+
+        // Ensure the recovered assignment is satisfied.
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        candidate.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        // Ensure a truncated assignment is rejected.
+        assert!(Assignment::<Fr>::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -0,0 +1,512 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Assignment, AssignmentLC, AssignmentVariable, R1CS};
+use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem, LinearCombination, SynthesisError};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{error, BigInteger, FromBytes, ToBytes};
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+};
+
+/// The magic bytes of an `.r1cs` file.
+const R1CS_MAGIC: [u8; 4] = *b"r1cs";
+/// The version of the `.r1cs` format.
+const R1CS_VERSION: u32 = 1;
+/// The magic bytes of a `.wtns` file.
+const WTNS_MAGIC: [u8; 4] = *b"wtns";
+/// The version of the `.wtns` format.
+const WTNS_VERSION: u32 = 2;
+
+/// The section of an `.r1cs` or `.wtns` file that describes its contents.
+const HEADER_SECTION: u32 = 1;
+/// The section of an `.r1cs` file that contains the constraints.
+const CONSTRAINTS_SECTION: u32 = 2;
+/// The section of an `.r1cs` file that maps wires to labels.
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+/// The section of a `.wtns` file that contains the values of the wires.
+const WITNESS_SECTION: u32 = 2;
+
+/// A constraint, as three sparse linear combinations of wires.
+type WireConstraint<F> = [Vec<(u32, F)>; 3];
+
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the constraints of the assignment in the `.r1cs` format of circom and snarkjs.
+    ///
+    /// Wire 0 is the constant one, followed by the public inputs, and then by the private variables.
+    /// As the private variables do not distinguish inputs from intermediate values, they are all written
+    /// as intermediate wires, and the file declares no public outputs and no private inputs.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let num_wires = self.num_wires()?;
+        let num_public_inputs = to_u32(self.num_public().saturating_sub(1))?;
+        let num_constraints = to_u32(self.num_constraints())?;
+
+        writer.write_all(&R1CS_MAGIC)?;
+        R1CS_VERSION.write_le(&mut writer)?;
+        // The file has a header, constraints, and a map from wires to labels.
+        3u32.write_le(&mut writer)?;
+
+        let mut header = field_header::<F>()?;
+        num_wires.write_le(&mut header)?;
+        // The number of public outputs.
+        0u32.write_le(&mut header)?;
+        num_public_inputs.write_le(&mut header)?;
+        // The number of private inputs.
+        0u32.write_le(&mut header)?;
+        // The number of labels.
+        u64::from(num_wires).write_le(&mut header)?;
+        num_constraints.write_le(&mut header)?;
+        write_section(&mut writer, HEADER_SECTION, &header)?;
+
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints().iter() {
+            for lc in [a, b, c] {
+                let terms = self.to_wire_terms(lc)?;
+                to_u32(terms.len() as u64)?.write_le(&mut constraints)?;
+                for (wire, coefficient) in terms {
+                    wire.write_le(&mut constraints)?;
+                    coefficient.to_bigint().write_le(&mut constraints)?;
+                }
+            }
+        }
+        write_section(&mut writer, CONSTRAINTS_SECTION, &constraints)?;
+
+        // Each wire is labeled with its own index.
+        let mut labels = Vec::with_capacity(num_wires as usize * 8);
+        for wire in 0..u64::from(num_wires) {
+            wire.write_le(&mut labels)?;
+        }
+        write_section(&mut writer, WIRE_TO_LABEL_SECTION, &labels)
+    }
+
+    /// Writes the values of the wires of the assignment in the `.wtns` format of circom and snarkjs.
+    ///
+    /// The wires are ordered as in [`Assignment::write_r1cs`].
+    pub fn write_wtns<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let num_wires = self.num_wires()?;
+
+        writer.write_all(&WTNS_MAGIC)?;
+        WTNS_VERSION.write_le(&mut writer)?;
+        // The file has a header, and the values of the wires.
+        2u32.write_le(&mut writer)?;
+
+        let mut header = field_header::<F>()?;
+        num_wires.write_le(&mut header)?;
+        write_section(&mut writer, HEADER_SECTION, &header)?;
+
+        let mut witness = Vec::new();
+        for (_, value) in self.public_inputs().iter().chain(self.private_inputs().iter()) {
+            value.to_bigint().write_le(&mut witness)?;
+        }
+        write_section(&mut writer, WITNESS_SECTION, &witness)
+    }
+
+    /// Returns the number of wires, which are the public variables followed by the private variables.
+    fn num_wires(&self) -> io::Result<u32> {
+        to_u32(self.num_public().saturating_add(self.num_private()))
+    }
+
+    /// Returns the terms of the given linear combination as wires, in increasing order and without zero coefficients.
+    fn to_wire_terms(&self, lc: &AssignmentLC<F>) -> io::Result<BTreeMap<u32, F>> {
+        let mut terms = BTreeMap::new();
+        // The constant term is a multiple of wire 0.
+        let constant_term = (AssignmentVariable::Constant(F::one()), lc.constant());
+        for (variable, coefficient) in lc.terms().iter().chain([&constant_term]) {
+            let (wire, coefficient) = match variable {
+                AssignmentVariable::Constant(value) => (0, *value * coefficient),
+                AssignmentVariable::Public(index) => (to_u32(*index)?, *coefficient),
+                AssignmentVariable::Private(index) => (to_u32(self.num_public().saturating_add(*index))?, *coefficient),
+            };
+            *terms.entry(wire).or_insert_with(F::zero) += coefficient;
+        }
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+        Ok(terms)
+    }
+}
+
+impl<F: PrimeField> R1CS<F> {
+    /// Writes the constraints of the R1CS in the `.r1cs` format of circom and snarkjs.
+    /// See [`Assignment::write_r1cs`] for the layout of the wires.
+    pub fn write_r1cs<W: Write>(&self, writer: W) -> io::Result<()> {
+        Assignment::from(self).write_r1cs(writer)
+    }
+
+    /// Writes the values of the wires of the R1CS in the `.wtns` format of circom and snarkjs.
+    pub fn write_wtns<W: Write>(&self, writer: W) -> io::Result<()> {
+        Assignment::from(self).write_wtns(writer)
+    }
+}
+
+/// A constraint system read from the `.r1cs` format of circom and snarkjs, with the values of its wires
+/// if a `.wtns` file was read.
+#[derive(Clone, Debug)]
+pub struct CircomR1CS<F: PrimeField> {
+    /// The number of public wires, including wire 0.
+    num_public: u32,
+    /// The number of wires.
+    num_wires: u32,
+    /// The constraints.
+    constraints: Vec<WireConstraint<F>>,
+    /// The values of the wires.
+    witness: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> CircomR1CS<F> {
+    /// Reads a constraint system from the `.r1cs` format.
+    pub fn read_r1cs<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut sections = read_sections(&mut reader, R1CS_MAGIC, R1CS_VERSION)?;
+
+        let header = sections.remove(&HEADER_SECTION).ok_or_else(|| error("Missing the .r1cs header"))?;
+        let mut header = header.as_slice();
+        read_field_header::<F, _>(&mut header)?;
+        let num_wires = u32::read_le(&mut header)?;
+        let num_public_outputs = u32::read_le(&mut header)?;
+        let num_public_inputs = u32::read_le(&mut header)?;
+        let _num_private_inputs = u32::read_le(&mut header)?;
+        let _num_labels = u64::read_le(&mut header)?;
+        let num_constraints = u32::read_le(&mut header)?;
+        ensure_consumed(header)?;
+
+        // The map from wires to labels has one label per wire, which bounds the number of wires by the file size.
+        let labels = sections.remove(&WIRE_TO_LABEL_SECTION).ok_or_else(|| error("Missing the .r1cs wire labels"))?;
+        if labels.len() as u64 != u64::from(num_wires) * 8 {
+            return Err(error(format!("The .r1cs file does not label each of its {num_wires} wires")));
+        }
+
+        let num_public = num_public_outputs
+            .checked_add(num_public_inputs)
+            .and_then(|num_public| num_public.checked_add(1))
+            .filter(|num_public| *num_public <= num_wires)
+            .ok_or_else(|| error("The .r1cs file has more public wires than wires"))?;
+
+        let constraints_section =
+            sections.remove(&CONSTRAINTS_SECTION).ok_or_else(|| error("Missing the .r1cs constraints"))?;
+        let mut constraints_section = constraints_section.as_slice();
+        let mut constraints = Vec::new();
+        for _ in 0..num_constraints {
+            let mut read_lc = || -> io::Result<Vec<(u32, F)>> {
+                let num_terms = u32::read_le(&mut constraints_section)?;
+                (0..num_terms)
+                    .map(|_| {
+                        let wire = u32::read_le(&mut constraints_section)?;
+                        if wire >= num_wires {
+                            return Err(error(format!("The .r1cs file refers to wire {wire} of {num_wires}")));
+                        }
+                        Ok((wire, read_field_element(&mut constraints_section)?))
+                    })
+                    .collect()
+            };
+            constraints.push([read_lc()?, read_lc()?, read_lc()?]);
+        }
+        ensure_consumed(constraints_section)?;
+
+        Ok(Self { num_public, num_wires, constraints, witness: None })
+    }
+
+    /// Reads the values of the wires from the `.wtns` format.
+    pub fn with_witness<R: Read>(mut self, mut reader: R) -> io::Result<Self> {
+        let mut sections = read_sections(&mut reader, WTNS_MAGIC, WTNS_VERSION)?;
+
+        let header = sections.remove(&HEADER_SECTION).ok_or_else(|| error("Missing the .wtns header"))?;
+        let mut header = header.as_slice();
+        read_field_header::<F, _>(&mut header)?;
+        let num_values = u32::read_le(&mut header)?;
+        ensure_consumed(header)?;
+        if num_values != self.num_wires {
+            return Err(error(format!("The .wtns file has {num_values} values for {} wires", self.num_wires)));
+        }
+
+        let witness_section = sections.remove(&WITNESS_SECTION).ok_or_else(|| error("Missing the .wtns values"))?;
+        let mut witness_section = witness_section.as_slice();
+        let witness =
+            (0..num_values).map(|_| read_field_element(&mut witness_section)).collect::<io::Result<Vec<F>>>()?;
+        ensure_consumed(witness_section)?;
+        if witness.first() != Some(&F::one()) {
+            return Err(error("The .wtns file does not assign one to wire 0"));
+        }
+
+        self.witness = Some(witness);
+        Ok(self)
+    }
+
+    /// Returns the number of public wires, including wire 0.
+    pub const fn num_public(&self) -> u32 {
+        self.num_public
+    }
+
+    /// Returns the number of wires.
+    pub const fn num_wires(&self) -> u32 {
+        self.num_wires
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns the values of the public wires, excluding wire 0, if a witness was read.
+    pub fn public_inputs(&self) -> Option<&[F]> {
+        self.witness.as_ref().map(|witness| &witness[1..self.num_public as usize])
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CircomR1CS<F> {
+    /// Synthesizes the constraints into a `snarkvm_algorithms::r1cs`-compliant constraint system.
+    /// The public wires are allocated as public inputs, and the remaining wires as private variables.
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        // Ensure the given `cs` is starting off clean.
+        assert_eq!(1, cs.num_public_variables());
+        assert_eq!(0, cs.num_private_variables());
+        assert_eq!(0, cs.num_constraints());
+
+        let value = |wire: u32| {
+            self.witness.as_ref().map(|witness| witness[wire as usize]).ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        // Wire 0 is the constant one, which is already allocated in the `ConstraintSystem` constructor.
+        let mut variables = Vec::with_capacity(self.num_wires as usize);
+        variables.push(CS::one());
+        for wire in 1..self.num_wires {
+            let variable = match wire < self.num_public {
+                true => cs.alloc_input(|| format!("Public {wire}"), || value(wire))?,
+                false => cs.alloc(|| format!("Private {wire}"), || value(wire))?,
+            };
+            variables.push(variable);
+        }
+
+        for (i, [a, b, c]) in self.constraints.iter().enumerate() {
+            let convert_linear_combination = |terms: &[(u32, F)]| {
+                terms.iter().fold(LinearCombination::zero(), |lc, (wire, coefficient)| {
+                    lc + (*coefficient, variables[*wire as usize])
+                })
+            };
+            cs.enforce(
+                || format!("Constraint {i}"),
+                |lc| lc + convert_linear_combination(a),
+                |lc| lc + convert_linear_combination(b),
+                |lc| lc + convert_linear_combination(c),
+            );
+        }
+
+        // Ensure the given `cs` matches in size with the file.
+        assert_eq!(self.num_public as usize, cs.num_public_variables());
+        assert_eq!((self.num_wires - self.num_public) as usize, cs.num_private_variables());
+        assert_eq!(self.constraints.len(), cs.num_constraints());
+
+        Ok(())
+    }
+}
+
+/// Returns the given count as a `u32`, which is the width of wire indices and counts in the file formats.
+fn to_u32(count: u64) -> io::Result<u32> {
+    u32::try_from(count).map_err(|_| error(format!("{count} does not fit in the 32-bit fields of the file format")))
+}
+
+/// Returns the number of bytes of a field element.
+fn field_size<F: PrimeField>() -> u32 {
+    (F::BigInteger::NUM_LIMBS * 8) as u32
+}
+
+/// Returns the start of a header section, which describes the field.
+fn field_header<F: PrimeField>() -> io::Result<Vec<u8>> {
+    let mut header = Vec::new();
+    field_size::<F>().write_le(&mut header)?;
+    F::modulus().write_le(&mut header)?;
+    Ok(header)
+}
+
+/// Reads the start of a header section, and ensures that it describes the field.
+fn read_field_header<F: PrimeField, R: Read>(mut reader: R) -> io::Result<()> {
+    let size = u32::read_le(&mut reader)?;
+    if size != field_size::<F>() {
+        return Err(error(format!("Expected field elements of {} bytes, found {size}", field_size::<F>())));
+    }
+    if F::BigInteger::read_le(&mut reader)? != F::modulus() {
+        return Err(error("The file is defined over a different field"));
+    }
+    Ok(())
+}
+
+/// Reads a field element, and ensures that it is canonical.
+fn read_field_element<F: PrimeField, R: Read>(reader: R) -> io::Result<F> {
+    F::from_bigint(F::BigInteger::read_le(reader)?).ok_or_else(|| error("Found a field element that is not canonical"))
+}
+
+/// Writes a section, with its type and size.
+fn write_section<W: Write>(mut writer: W, section_type: u32, content: &[u8]) -> io::Result<()> {
+    section_type.write_le(&mut writer)?;
+    (content.len() as u64).write_le(&mut writer)?;
+    writer.write_all(content)
+}
+
+/// Reads the magic bytes and version of a file, followed by its sections, which must have distinct types.
+fn read_sections<R: Read>(mut reader: R, magic: [u8; 4], version: u32) -> io::Result<BTreeMap<u32, Vec<u8>>> {
+    let mut file_magic = [0u8; 4];
+    reader.read_exact(&mut file_magic)?;
+    if file_magic != magic {
+        return Err(error("Invalid magic bytes"));
+    }
+    let file_version = u32::read_le(&mut reader)?;
+    if file_version != version {
+        return Err(error(format!("Unsupported version {file_version}")));
+    }
+
+    let num_sections = u32::read_le(&mut reader)?;
+    let mut sections = BTreeMap::new();
+    for _ in 0..num_sections {
+        let section_type = u32::read_le(&mut reader)?;
+        let size = u64::read_le(&mut reader)?;
+        // The content is read incrementally, so that a corrupt size does not cause a large allocation.
+        let mut content = Vec::new();
+        (&mut reader).take(size).read_to_end(&mut content)?;
+        if content.len() as u64 != size {
+            return Err(error(format!("Section {section_type} is truncated")));
+        }
+        if sections.insert(section_type, content).is_some() {
+            return Err(error(format!("Section {section_type} appears more than once")));
+        }
+    }
+    Ok(sections)
+}
+
+/// Ensures that a section was read to its end.
+fn ensure_consumed(remaining: &[u8]) -> io::Result<()> {
+    match remaining.is_empty() {
+        true => Ok(()),
+        false => Err(error(format!("Found {} unexpected bytes at the end of a section", remaining.len()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_size, CircomR1CS};
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_curves::bls12_377::Fq;
+
+    /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+    fn create_example_circuit<E: Environment>() -> Field<E> {
+        let one = snarkvm_console_types::Field::<E::Network>::one();
+        let two = one + one;
+
+        const EXPONENT: u64 = 64;
+
+        let mut candidate = Field::<E>::new(Mode::Public, one);
+        let mut accumulator = Field::new(Mode::Private, two);
+        for _ in 0..EXPONENT {
+            candidate += &accumulator;
+            accumulator *= Field::new(Mode::Private, two);
+        }
+        assert!(E::is_satisfied());
+
+        candidate
+    }
+
+    #[test]
+    fn test_r1cs_and_wtns_round_trip() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = Vec::new();
+        assignment.write_wtns(&mut wtns).unwrap();
+        assert_eq!(&r1cs[..4], b"r1cs");
+        assert_eq!(&wtns[..4], b"wtns");
+
+        let imported = CircomR1CS::<<Circuit as Environment>::BaseField>::read_r1cs(&r1cs[..])
+            .unwrap()
+            .with_witness(&wtns[..])
+            .unwrap();
+        assert_eq!(assignment.num_public(), imported.num_public() as u64);
+        assert_eq!(assignment.num_public() + assignment.num_private(), imported.num_wires() as u64);
+        assert_eq!(assignment.num_constraints(), imported.num_constraints() as u64);
+        let expected_inputs = assignment.public_inputs().iter().skip(1).map(|(_, value)| *value).collect::<Vec<_>>();
+        assert_eq!(imported.public_inputs().unwrap(), expected_inputs.as_slice());
+
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        imported.generate_constraints(&mut cs).unwrap();
+        assert_eq!(assignment.num_public(), cs.num_public_variables() as u64);
+        assert_eq!(assignment.num_private(), cs.num_private_variables() as u64);
+        assert_eq!(assignment.num_constraints(), cs.num_constraints() as u64);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_r1cs_matches_circuit() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let r1cs = Circuit::eject_r1cs_and_reset();
+
+        let mut from_r1cs = Vec::new();
+        r1cs.write_r1cs(&mut from_r1cs).unwrap();
+        let mut from_assignment = Vec::new();
+        Assignment::from(r1cs).write_r1cs(&mut from_assignment).unwrap();
+        assert_eq!(from_r1cs, from_assignment);
+    }
+
+    #[test]
+    fn test_invalid_witness_is_unsatisfied() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = Vec::new();
+        assignment.write_wtns(&mut wtns).unwrap();
+
+        // Increment the value of the last wire.
+        let last_byte = wtns.len() - field_size::<<Circuit as Environment>::BaseField>() as usize;
+        wtns[last_byte] = wtns[last_byte].wrapping_add(1);
+
+        let imported = CircomR1CS::<<Circuit as Environment>::BaseField>::read_r1cs(&r1cs[..])
+            .unwrap()
+            .with_witness(&wtns[..])
+            .unwrap();
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        imported.generate_constraints(&mut cs).unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_rejects_malformed_files() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs).unwrap();
+        type F = <Circuit as Environment>::BaseField;
+
+        // A truncated file is rejected.
+        assert!(CircomR1CS::<F>::read_r1cs(&r1cs[..r1cs.len() - 1]).is_err());
+        // A file with different magic bytes is rejected.
+        let mut wrong_magic = r1cs.clone();
+        wrong_magic[0] = b'x';
+        assert!(CircomR1CS::<F>::read_r1cs(&wrong_magic[..]).is_err());
+        // A file over a different field is rejected.
+        assert!(CircomR1CS::<Fq>::read_r1cs(&r1cs[..]).is_err());
+        // A witness with the wrong number of values is rejected.
+        let imported = CircomR1CS::<F>::read_r1cs(&r1cs[..]).unwrap();
+        let mut wtns = Vec::new();
+        Circuit::eject_assignment_and_reset().write_wtns(&mut wtns).unwrap();
+        assert!(imported.with_witness(&wtns[..]).is_err());
+        // A header that declares more wires than the file labels is rejected.
+        let num_wires_offset = 24 + field_size::<F>() as usize + 4;
+        let mut too_many_wires = r1cs.clone();
+        too_many_wires[num_wires_offset..num_wires_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(CircomR1CS::<F>::read_r1cs(&too_many_wires[..]).is_err());
+    }
+}
@@ -16,6 +16,9 @@
 mod assignment;
 pub use assignment::*;
 
+pub mod circom;
+pub use circom::*;
+
 pub mod circuit_type;
 pub use circuit_type::*;
 
@@ -42,6 +42,8 @@ impl<A: Aleo> Inject for Access<A> {
         match plaintext {
             Self::Primitive::Member(identifier) => Self::Member(Identifier::new(_m, identifier)),
             Self::Primitive::Index(index) => Self::Index(U32::new(_m, index)),
+            // Note: Register accesses are resolved to an index before they are injected.
+            Self::Primitive::Register(locator) => A::halt(format!("Register access 'r{locator}' must be resolved")),
         }
     }
 }
@@ -21,7 +21,7 @@ mod find;
 mod to_bits;
 mod to_fields;
 
-use crate::{Access, Identifier, Plaintext, ProgramID, Value};
+use crate::{Access, Identifier, Literal, Plaintext, ProgramID, Value};
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U16};
 
@@ -77,6 +77,13 @@ impl<A: Aleo> Future<A> {
         Self { program_id, function_name, arguments }
     }
 
+    /// Returns the future of a dynamic call, as seen by the caller, given the interface function
+    /// and the output ID of the callee's future.
+    #[inline]
+    pub fn new_dynamic(program_id: ProgramID<A>, function_name: Identifier<A>, future_id: Field<A>) -> Self {
+        Self::from(program_id, function_name, vec![Argument::Plaintext(Plaintext::from(Literal::Field(future_id)))])
+    }
+
     /// Returns the program ID.
     #[inline]
     pub const fn program_id(&self) -> &ProgramID<A> {
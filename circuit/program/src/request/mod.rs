@@ -20,7 +20,7 @@ mod to_tpk;
 mod verify;
 
 use crate::{compute_function_id, Identifier, Plaintext, ProgramID, Record, Value};
-use snarkvm_circuit_account::Signature;
+use snarkvm_circuit_account::{MultisigKey, Signature};
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, U16};
 
@@ -139,6 +139,10 @@ pub struct Request<A: Aleo> {
     tcm: Field<A>,
     /// The signer commitment.
     scm: Field<A>,
+    /// The multisig key, if the signer is a multisig account.
+    multisig_key: Option<MultisigKey<A>>,
+    /// The `(is_signed, signature)` slot of each signer, padded to `MAX_SIGNERS`, if the signer is a multisig account.
+    cosignatures: Vec<(Boolean<A>, Signature<A>)>,
 }
 
 #[cfg(console)]
@@ -225,6 +229,17 @@ impl<A: Aleo> Inject for Request<A> {
             tvk: Field::new(mode, *request.tvk()),
             tcm,
             scm,
+            multisig_key: request.multisig_key().map(|multisig_key| MultisigKey::new(mode, multisig_key.clone())),
+            cosignatures: match request.multisig_key() {
+                // Note: An unsigned slot holds the request signature as a placeholder, and is not counted.
+                Some(_) => (0..MultisigKey::<A>::MAX_SIGNERS)
+                    .map(|index| match request.cosignatures().iter().find(|(signer, _)| *signer as usize == index) {
+                        Some((_, signature)) => (Boolean::new(mode, true), Signature::new(mode, *signature)),
+                        None => (Boolean::new(mode, false), Signature::new(mode, *request.signature())),
+                    })
+                    .collect(),
+                None => vec![],
+            },
         }
     }
 }
@@ -284,6 +299,16 @@ impl<A: Aleo> Request<A> {
     pub const fn scm(&self) -> &Field<A> {
         &self.scm
     }
+
+    /// Returns the multisig key, if the signer is a multisig account.
+    pub const fn multisig_key(&self) -> Option<&MultisigKey<A>> {
+        self.multisig_key.as_ref()
+    }
+
+    /// Returns the `(is_signed, signature)` slot of each signer, if the signer is a multisig account.
+    pub fn cosignatures(&self) -> &[(Boolean<A>, Signature<A>)] {
+        &self.cosignatures
+    }
 }
 
 #[cfg(console)]
@@ -321,5 +346,14 @@ impl<A: Aleo> Eject for Request<A> {
             self.tcm.eject_value(),
             self.scm.eject_value(),
         ))
+        .with_multisig(
+            self.multisig_key.as_ref().map(|multisig_key| multisig_key.eject_value()),
+            self.cosignatures
+                .iter()
+                .enumerate()
+                .filter(|(_, (is_signed, _))| is_signed.eject_value())
+                .map(|(index, (_, signature))| (index as u8, signature.eject_value()))
+                .collect(),
+        )
     }
 }
@@ -17,7 +17,7 @@ use super::*;
 
 impl<A: Aleo> Request<A> {
     /// Returns `true` if the input IDs are derived correctly, the input records all belong to the signer,
-    /// and the signature is valid.
+    /// and the signature is valid. If the signer is a multisig account, the cosignatures must also form a quorum.
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
@@ -94,17 +94,36 @@ impl<A: Aleo> Request<A> {
 
             // Compute the candidate verifier challenge.
             let candidate_challenge = A::hash_to_scalar_psd8(&preimage);
-            // Compute the candidate address.
-            let candidate_address = self.signature.compute_key().to_address();
+            // Compute the candidate address, and for a multisig account, ensure the signature is from
+            // its shared account and the cosignatures form a quorum.
+            // Note: For a single account, `multisig_checks` is a constant and adds no constraints.
+            let (candidate_address, multisig_checks) = match &self.multisig_key {
+                Some(multisig_key) => (
+                    multisig_key.to_address(),
+                    self.signature.compute_key().is_equal(multisig_key.compute_key())
+                        & multisig_key.verify(&self.to_multisig_message(), &self.cosignatures),
+                ),
+                None => (self.signature.compute_key().to_address(), Boolean::constant(self.cosignatures.is_empty())),
+            };
 
             // Return `true` if the challenge and address is valid.
-            self.signature.challenge().is_equal(&candidate_challenge) & self.signer.is_equal(&candidate_address)
+            self.signature.challenge().is_equal(&candidate_challenge)
+                & self.signer.is_equal(&candidate_address)
+                & multisig_checks
         };
 
         // Verify the signature, inputs, and `tpk` are valid.
         signature_checks & input_checks & tpk_checks
     }
 
+    /// Returns the message that each cosigner signs, as `[domain, challenge]`.
+    pub fn to_multisig_message(&self) -> Vec<Field<A>> {
+        vec![
+            Field::constant(console::Field::new_domain_separator("AleoMultisigRequest0")),
+            self.signature.challenge().to_field(),
+        ]
+    }
+
     /// Returns `true` if the inputs match their input IDs.
     /// Note: This method does **not** perform signature checks.
     pub fn check_input_ids<const CREATE_MESSAGE: bool>(
@@ -306,6 +325,59 @@ impl<A: Aleo> Request<A> {
             },
         }
     }
+
+    /// Returns `true` if the public and private plaintext inputs of a dynamic call match their input IDs,
+    /// given the function ID of the callee.
+    /// Note: This method does **not** perform signature checks.
+    pub fn check_dynamic_input_ids(
+        function_id: &Field<A>,
+        input_ids: &[InputID<A>],
+        inputs: &[Value<A>],
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+    ) -> Boolean<A> {
+        input_ids
+            .iter()
+            .zip_eq(inputs)
+            .enumerate()
+            .map(|(index, (input_id, input))| {
+                // Retrieve the plaintext.
+                let plaintext = match input {
+                    Value::Plaintext(plaintext) => plaintext,
+                    // Ensure the input is a plaintext.
+                    Value::Record(..) => A::halt("Expected a plaintext input in a dynamic call, found a record input"),
+                    Value::Future(..) => A::halt("Expected a plaintext input in a dynamic call, found a future input"),
+                };
+                // Prepare the index as a constant field element.
+                let input_index = Field::constant(console::Field::from_u16(index as u16));
+
+                match input_id {
+                    // A public input is hashed (using `tcm`) to a field element.
+                    InputID::Public(input_hash) => {
+                        // Construct the preimage as `(function ID || input || tcm || index)`.
+                        let mut preimage = Vec::new();
+                        preimage.push(function_id.clone());
+                        preimage.extend(input.to_fields());
+                        preimage.push(tcm.clone());
+                        preimage.push(input_index);
+                        // Ensure the expected hash matches the computed hash.
+                        input_hash.is_equal(&A::hash_psd8(&preimage))
+                    }
+                    // A private input is encrypted (using `tvk`) and hashed to a field element.
+                    InputID::Private(input_hash) => {
+                        // Compute the input view key as `Hash(function ID || tvk || index)`.
+                        let input_view_key = A::hash_psd4(&[function_id.clone(), tvk.clone(), input_index]);
+                        // Compute the ciphertext.
+                        let ciphertext = plaintext.encrypt_symmetric(input_view_key);
+                        // Ensure the expected hash matches the computed hash.
+                        input_hash.is_equal(&A::hash_psd8(&ciphertext.to_fields()))
+                    }
+                    // Ensure the input is public or private.
+                    _ => A::halt("Expected a public or private input in a dynamic call"),
+                }
+            })
+            .fold(Boolean::constant(true), |acc, x| acc & x)
+    }
 }
 
 #[cfg(all(test, console))]
@@ -419,6 +491,79 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sign_and_verify_multisig() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample the shared account and three signers.
+            let private_key = snarkvm_console_account::PrivateKey::new(rng)?;
+            let signer_keys =
+                (0..3).map(|_| snarkvm_console_account::PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+            let signers =
+                signer_keys.iter().map(snarkvm_console_account::ComputeKey::try_from).collect::<Result<_>>()?;
+
+            // Construct a 2-of-3 multisig key and its address.
+            let compute_key = snarkvm_console_account::ComputeKey::try_from(&private_key)?;
+            let multisig_key = snarkvm_console_account::MultisigKey::new(compute_key, 2, signers)?;
+            let address = multisig_key.to_address();
+
+            // Construct a program ID and function name.
+            let program_id = console::ProgramID::from_str("token.aleo")?;
+            let function_name = console::Identifier::from_str("transfer")?;
+
+            // Prepare a record belonging to the multisig address.
+            let record_string =
+                format!("{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 0group.public }}");
+
+            // Construct the inputs and input types.
+            let inputs = [
+                console::Value::<<Circuit as Environment>::Network>::from_str("{ token_amount: 9876543210u128 }")?,
+                console::Value::<<Circuit as Environment>::Network>::from_str(&record_string)?,
+            ];
+            let input_types =
+                vec![console::ValueType::from_str("amount.private")?, console::ValueType::from_str("token.record")?];
+
+            // Compute the signed request, cosigned by a single signer.
+            let mut request = console::Request::sign_multisig(
+                &private_key,
+                &multisig_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                &input_types,
+                None,
+                true,
+                rng,
+            )?;
+            request.cosign(&signer_keys[1], rng)?;
+            assert!(!request.verify(&input_types, true));
+
+            // Ensure the circuit rejects the request without a quorum.
+            let tpk = Group::<Circuit>::new(mode, request.to_tpk());
+            let candidate = Request::<Circuit>::new(mode, request.clone());
+            Circuit::scope(format!("Multisig request {mode} without quorum"), || {
+                let is_valid = candidate.verify(&input_types, &tpk, None, Boolean::new(mode, true));
+                assert!(!is_valid.eject_value());
+            });
+            Circuit::reset();
+
+            // Cosign the request by a second signer.
+            request.cosign(&signer_keys[2], rng)?;
+            assert!(request.verify(&input_types, true));
+
+            // Ensure the circuit accepts the request with a quorum.
+            let tpk = Group::<Circuit>::new(mode, request.to_tpk());
+            let candidate = Request::<Circuit>::new(mode, request.clone());
+            Circuit::scope(format!("Multisig request {mode} with quorum"), || {
+                let is_valid = candidate.verify(&input_types, &tpk, None, Boolean::new(mode, true));
+                assert!(is_valid.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_constant() -> Result<()> {
         // Note: This is correct. At this (high) level of a program, we override the default mode in the `Record` case,
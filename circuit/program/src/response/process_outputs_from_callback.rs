@@ -188,6 +188,96 @@ impl<A: Aleo> Response<A> {
             Err(error) => A::halt(error.to_string()),
         }
     }
+
+    /// Returns the injected circuit outputs of a dynamic call, given the function ID of the callee,
+    /// the number of inputs, tvk, tcm, outputs, output IDs, and the output types of the interface.
+    /// Note: A dynamic call only supports public and private plaintext outputs, and a future output.
+    /// As the callee's future depends on the callee, the caller only receives the output ID of the callee's future,
+    /// in a future of the interface function, which is resolved from the callee's transition in finalize.
+    pub fn process_dynamic_outputs_from_callback(
+        function_id: &Field<A>,
+        num_inputs: usize,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        outputs: Vec<console::Value<A::Network>>,        // Note: Console type
+        output_ids: &[console::OutputID<A::Network>],    // Note: Console type
+        output_types: &[console::ValueType<A::Network>], // Note: Console type
+    ) -> Vec<Value<A>> {
+        match outputs
+            .iter()
+            .zip_eq(output_ids)
+            .zip_eq(output_types)
+            .enumerate()
+            .map(|(index, ((output, output_id), output_types))| {
+                // Prepare the index as a constant field element.
+                let output_index = Field::constant(console::Field::from_u16((num_inputs + index) as u16));
+
+                match output_types {
+                    // For a public output, compute the hash (using `tcm`) of the output.
+                    console::ValueType::Public(..) => {
+                        // Inject the output as `Mode::Private`.
+                        let output = Value::new(Mode::Private, output.clone());
+                        // Ensure the output is a plaintext.
+                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+
+                        // Construct the preimage as `(function ID || output || tcm || index)`.
+                        let mut preimage = Vec::new();
+                        preimage.push(function_id.clone());
+                        preimage.extend(output.to_fields());
+                        preimage.push(tcm.clone());
+                        preimage.push(output_index);
+                        // Return the output ID.
+                        Ok((OutputID::public(A::hash_psd8(&preimage)), output))
+                    }
+                    // For a private output, compute the ciphertext (using `tvk`) and hash the ciphertext.
+                    console::ValueType::Private(..) => {
+                        // Inject the output as `Mode::Private`.
+                        let output = Value::new(Mode::Private, output.clone());
+                        // Compute the output view key as `Hash(function ID || tvk || index)`.
+                        let output_view_key = A::hash_psd4(&[function_id.clone(), tvk.clone(), output_index]);
+                        // Compute the ciphertext.
+                        let ciphertext = match &output {
+                            Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(output_view_key),
+                            // Ensure the output is a plaintext.
+                            Value::Record(..) => A::halt("Expected a plaintext output, found a record output"),
+                            Value::Future(..) => A::halt("Expected a plaintext output, found a future output"),
+                        };
+                        // Return the output ID.
+                        Ok((OutputID::private(A::hash_psd8(&ciphertext.to_fields())), output))
+                    }
+                    // For a future output, inject the output ID of the callee's future.
+                    console::ValueType::Future(locator) => {
+                        // Ensure the output is a future.
+                        ensure!(matches!(output, console::Value::Future(..)), "Expected a future output");
+                        // Inject the output ID as `Mode::Public`.
+                        let future_id = match output_id {
+                            console::OutputID::Future(future_id) => Field::new(Mode::Public, *future_id),
+                            _ => bail!("Expected the output ID of a future output"),
+                        };
+                        // Construct the future of the interface function, which holds the output ID.
+                        let output = Value::Future(Future::new_dynamic(
+                            ProgramID::constant(*locator.program_id()),
+                            Identifier::constant(*locator.resource()),
+                            future_id.clone(),
+                        ));
+                        // Return the output ID.
+                        Ok((OutputID::Future(future_id), output))
+                    }
+                    // Ensure the output is public, private, or a future.
+                    _ => bail!("Expected a public, private, or future output in a dynamic call"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(outputs) => {
+                // Unzip the output IDs from the output values.
+                let (_, outputs): (Vec<OutputID<A>>, _) = outputs.into_iter().unzip();
+                // Return the outputs.
+                outputs
+            }
+            Err(error) => A::halt(error.to_string()),
+        }
+    }
 }
 
 #[cfg(all(test, console))]
@@ -0,0 +1,128 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the byte at the given `index`.
+    ///
+    /// If the index is not a constant, this method costs 3 constraints per byte in the string,
+    /// plus 10 constraints to select the byte.
+    pub fn byte_at(&self, index: &U32<E>) -> U8<E> {
+        // Retrieve the byte at the index, if it exists.
+        let expected = self.bytes.get(*index.eject_value() as usize);
+
+        match index.is_constant() {
+            // If the index is a constant, return the byte directly.
+            true => match expected {
+                Some(byte) => byte.clone(),
+                None => E::halt(format!("Attempted to access byte {index} of a string of size {}", self.bytes.len())),
+            },
+            false => {
+                // Select the byte as the sum of each byte weighted by the indicator `index == i`.
+                let index = index.to_field();
+                let (num_selected, selected) = self.bytes.iter().enumerate().fold(
+                    (Field::zero(), Field::zero()),
+                    |(num_selected, selected), (i, byte)| {
+                        let is_selected =
+                            Field::from_boolean(&index.is_equal(&Field::constant(console::Field::from_u32(i as u32))));
+                        (num_selected + &is_selected, selected + is_selected * byte.to_field())
+                    },
+                );
+                // Ensure the index is within the string, i.e. exactly one byte is selected.
+                E::assert_eq(num_selected, E::one());
+
+                // Witness the selected byte. If the index is out of bounds, witness zero instead, as the circuit is not satisfied.
+                let output =
+                    U8::new(Mode::Private, expected.map(|byte| byte.eject_value()).unwrap_or(console::Integer::new(0)));
+                // Ensure the witnessed byte matches the selected byte.
+                E::assert_eq(&output, selected);
+
+                output
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_byte_at(
+        mode: Mode,
+        index_mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let given = "héllo.aleo";
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(given));
+
+        for (i, expected) in given.as_bytes().iter().enumerate() {
+            let index = U32::<Circuit>::new(index_mode, console::Integer::new(i as u32));
+
+            Circuit::scope(format!("{mode} {index_mode} {i}"), || {
+                let candidate = string.byte_at(&index);
+                assert_eq!(*expected, *candidate.eject_value());
+                assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            });
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_byte_at_constant_index() {
+        check_byte_at(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+        check_byte_at(Mode::Public, Mode::Constant, 0, 0, 0, 0);
+        check_byte_at(Mode::Private, Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_byte_at_constant_string() {
+        check_byte_at(Mode::Constant, Mode::Public, 13, 0, 30, 32);
+        check_byte_at(Mode::Constant, Mode::Private, 13, 0, 30, 32);
+    }
+
+    #[test]
+    fn test_byte_at_public() {
+        check_byte_at(Mode::Public, Mode::Public, 13, 0, 41, 43);
+        check_byte_at(Mode::Public, Mode::Private, 13, 0, 41, 43);
+    }
+
+    #[test]
+    fn test_byte_at_private() {
+        check_byte_at(Mode::Private, Mode::Public, 13, 0, 41, 43);
+        check_byte_at(Mode::Private, Mode::Private, 13, 0, 41, 43);
+    }
+
+    #[test]
+    fn test_byte_at_out_of_bounds() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("aleo"));
+
+        // Ensure a constant index out of bounds halts.
+        let index = U32::<Circuit>::constant(console::Integer::new(4));
+        assert!(std::panic::catch_unwind(|| string.byte_at(&index)).is_err());
+        Circuit::reset();
+
+        // Ensure a private index out of bounds is not satisfied.
+        let index = U32::<Circuit>::new(Mode::Private, console::Integer::new(4));
+        let candidate = string.byte_at(&index);
+        assert_eq!(0, *candidate.eject_value());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenation is within the allowed capacity.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate strings into a string of size {num_bytes}"))
+        }
+
+        Self {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(&other.bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u32 = 32;
+
+    fn check_concat(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two random strings. Take 1/8th to ensure the concatenation fits for all code points.
+            let first = rng.next_string(Circuit::MAX_STRING_BYTES / 8, false);
+            let second = rng.next_string(Circuit::MAX_STRING_BYTES / 8, false);
+            let a = StringType::<Circuit>::new(mode_a, console::StringType::new(&first));
+            let b = StringType::<Circuit>::new(mode_b, console::StringType::new(&second));
+
+            Circuit::scope(format!("{mode_a} {mode_b} {i}"), || {
+                let candidate = a.concat(&b);
+                assert_eq!(format!("{first}{second}"), *candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure the concatenation is equal to the injected string.
+                let expected =
+                    StringType::<Circuit>::new(Mode::Constant, console::StringType::new(&format!("{first}{second}")));
+                assert!(candidate.is_equal(&expected).eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_concat() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_concat(mode_a, mode_b, 0, 0, 0, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_exceeds_capacity() {
+        let string = "a".repeat(Circuit::MAX_STRING_BYTES as usize);
+        let a = StringType::<Circuit>::new(Mode::Private, console::StringType::new(&string));
+        let b = StringType::<Circuit>::new(Mode::Private, console::StringType::new("b"));
+
+        // Ensure the concatenation halts.
+        assert!(std::panic::catch_unwind(|| a.concat(&b)).is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Initializes a string from the given UTF-8 bytes.
+    ///
+    /// The bytes are checked to be well-formed UTF-8 with a fixed number of constraints per byte,
+    /// so the circuit only depends on the number of bytes, and not on their values.
+    pub fn from_bytes(bytes: &[U8<E>]) -> Self {
+        // Ensure the bytes are within the allowed capacity.
+        let num_bytes = bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to initialize a string of size {num_bytes}"))
+        }
+
+        // Ensure the bytes are well-formed UTF-8.
+        // Note: If the bytes are constant, this halts on malformed UTF-8.
+        enforce_utf8(bytes);
+
+        // Inject the number of bytes as a constant.
+        let size_in_bytes = Field::constant(console::Field::from_u32(num_bytes as u32));
+
+        match bytes.eject_mode() {
+            Mode::Constant => Self { mode: Mode::Constant, bytes: bytes.to_vec(), size_in_bytes },
+            _ => {
+                // Witness the bytes of the string. If the bytes are malformed, the circuit is not satisfied,
+                // so zeros are witnessed instead, to ensure the string can always be ejected.
+                let values = bytes.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>();
+                let is_valid = core::str::from_utf8(&values).is_ok();
+                let bytes = values
+                    .iter()
+                    .zip_eq(bytes)
+                    .map(|(value, byte)| {
+                        let candidate =
+                            U8::new(Mode::Private, console::Integer::new(if is_valid { *value } else { 0 }));
+                        // Ensure the witnessed byte matches the given byte.
+                        E::assert_eq(&candidate, byte);
+                        candidate
+                    })
+                    .collect();
+                Self { mode: Mode::Private, bytes, size_in_bytes }
+            }
+        }
+    }
+
+    /// Initializes a string from the first `length` UTF-8 bytes, where the remaining bytes must be zeros.
+    /// This is the inverse of `StringType::to_bytes`.
+    ///
+    /// As the number of bytes in a string is fixed when the circuit is synthesized, the length must be a constant.
+    pub fn from_padded_bytes(bytes: &[U8<E>], length: &U32<E>) -> Self {
+        // Ensure the length is a constant.
+        if !length.is_constant() {
+            E::halt("The length of a string from padded bytes must be a constant")
+        }
+
+        // Ensure the length is within the bytes.
+        let length = *length.eject_value() as usize;
+        if length > bytes.len() {
+            E::halt(format!("Attempted to initialize a string of size {length} from {} bytes", bytes.len()))
+        }
+
+        // Ensure the remaining bytes are zeros.
+        for byte in &bytes[length..] {
+            E::assert_eq(byte, E::zero());
+        }
+        Self::from_bytes(&bytes[..length])
+    }
+}
+
+/// Enforces that the given bytes are well-formed UTF-8, as specified in RFC 3629.
+///
+/// The bytes are processed by a state machine, whose state tracks the number of pending continuation bytes,
+/// and the range of the next continuation byte, which excludes overlong encodings, surrogates,
+/// and code points above `U+10FFFF`.
+fn enforce_utf8<E: Environment>(bytes: &[U8<E>]) {
+    // Initialize the indicators for 1, 2, or 3 pending continuation bytes (at most one is set).
+    let (mut pending_1, mut pending_2, mut pending_3) =
+        (Boolean::constant(false), Boolean::constant(false), Boolean::constant(false));
+    // Initialize the indicators for the range of the next continuation byte.
+    let (mut at_least_a0, mut at_most_9f, mut at_least_90, mut at_most_8f) =
+        (Boolean::constant(false), Boolean::constant(false), Boolean::constant(false), Boolean::constant(false));
+
+    for byte in bytes {
+        let b = byte.to_bits_le();
+
+        // A continuation byte has the form `10xxxxxx`.
+        let is_continuation = &b[7] & !&b[6];
+        // Ensure a continuation byte occurs if and only if one is pending.
+        let num_pending =
+            Field::from_boolean(&pending_1) + Field::from_boolean(&pending_2) + Field::from_boolean(&pending_3);
+        E::assert_eq(&is_continuation, num_pending);
+
+        // Ensure the continuation byte is within the expected range.
+        let is_below_90 = !&b[5] & !&b[4];
+        E::enforce(|| (&at_least_a0, !&b[5], E::zero()));
+        E::enforce(|| (&at_most_9f, &b[5], E::zero()));
+        E::enforce(|| (&at_least_90, &is_below_90, E::zero()));
+        E::enforce(|| (&at_most_8f, !&is_below_90, E::zero()));
+
+        // A leading byte has the form `0xxxxxxx`, `110xxxxx`, `1110xxxx`, or `11110xxx`.
+        let is_11 = &b[7] & &b[6];
+        let is_111 = &is_11 & &b[5];
+        let is_1111 = &is_111 & &b[4];
+        let is_leading_1 = !&b[7];
+        // Note: This excludes the leading bytes `0xC0` and `0xC1`, which only occur in overlong encodings.
+        let is_leading_2 = (&is_11 & !&b[5]) & (&b[4] | &b[3] | &b[2] | &b[1]);
+        let is_leading_3 = &is_111 & !&b[4];
+        // Note: This excludes the leading bytes `0xF5` to `0xF7`, which only occur above `U+10FFFF`.
+        let is_low_2_zero = !&b[1] & !&b[0];
+        let is_leading_4 = (&is_1111 & !&b[3]) & (!&b[2] | &is_low_2_zero);
+        // Ensure the byte is either a leading byte or a continuation byte.
+        E::assert_eq(
+            Field::from_boolean(&is_leading_1)
+                + Field::from_boolean(&is_leading_2)
+                + Field::from_boolean(&is_leading_3)
+                + Field::from_boolean(&is_leading_4)
+                + Field::from_boolean(&is_continuation),
+            E::one(),
+        );
+
+        // Update the range of the next continuation byte.
+        let is_low_3_zero = !&b[2] & &is_low_2_zero;
+        // After `0xE0`, the next byte is at least `0xA0`, to exclude overlong encodings.
+        at_least_a0 = &is_leading_3 & (!&b[3] & &is_low_3_zero);
+        // After `0xED`, the next byte is at most `0x9F`, to exclude surrogates.
+        at_most_9f = &is_leading_3 & (&b[3] & &b[2] & !&b[1] & &b[0]);
+        // After `0xF0`, the next byte is at least `0x90`, to exclude overlong encodings.
+        at_least_90 = &is_leading_4 & &is_low_3_zero;
+        // After `0xF4`, the next byte is at most `0x8F`, to exclude code points above `U+10FFFF`.
+        at_most_8f = &is_leading_4 & (&b[2] & &is_low_2_zero);
+
+        // Update the number of pending continuation bytes.
+        // Note: As a pending continuation byte excludes a leading byte, the indicators remain mutually exclusive.
+        pending_1 = is_leading_2 | &pending_2;
+        pending_2 = is_leading_3 | &pending_3;
+        pending_3 = is_leading_4;
+    }
+
+    // Ensure no continuation bytes are pending.
+    E::assert_eq(
+        Field::from_boolean(&pending_1) + Field::from_boolean(&pending_2) + Field::from_boolean(&pending_3),
+        E::zero(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use rand::Rng;
+
+    /// Returns the given bytes as circuit bytes in the given mode.
+    fn inject_bytes(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::Integer::new(*byte))).collect()
+    }
+
+    fn check_from_bytes(mode: Mode, expected: &str, num_constants: u64, num_private: u64, num_constraints: u64) {
+        let bytes = inject_bytes(mode, expected.as_bytes());
+
+        Circuit::scope(format!("{mode} {expected}"), || {
+            let candidate = StringType::from_bytes(&bytes);
+            assert_eq!(expected, &*candidate.eject_value());
+            assert_scope!(num_constants, 0, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bytes_constant() {
+        for expected in ["", "aleo", "héllo.aleo", "€", "\u{10348}", "\u{D7FF}", "\u{E000}", "\u{10FFFF}"] {
+            check_from_bytes(Mode::Constant, expected, 8, 0, 0);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_public() {
+        check_from_bytes(Mode::Public, "héllo.aleo", 8, 396, 463);
+        check_from_bytes(Mode::Public, "\u{10FFFF}", 8, 144, 169);
+    }
+
+    #[test]
+    fn test_from_bytes_private() {
+        check_from_bytes(Mode::Private, "héllo.aleo", 8, 396, 463);
+        check_from_bytes(Mode::Private, "\u{10FFFF}", 8, 144, 169);
+    }
+
+    #[test]
+    fn test_from_padded_bytes() {
+        for mode in [Mode::Constant, Mode::Private] {
+            let bytes = inject_bytes(mode, b"aleo\0\0");
+
+            // Ensure the padding is removed.
+            Circuit::scope(format!("{mode}"), || {
+                let candidate = StringType::from_padded_bytes(&bytes, &U32::constant(console::Integer::new(4)));
+                assert_eq!("aleo", *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Ensure padding that is not zeros is not satisfied, or halts if it is constant.
+            let length = U32::constant(console::Integer::new(3));
+            match mode {
+                Mode::Constant => {
+                    assert!(std::panic::catch_unwind(|| StringType::from_padded_bytes(&bytes, &length)).is_err())
+                }
+                _ => {
+                    let _candidate = StringType::from_padded_bytes(&bytes, &length);
+                    assert!(!Circuit::is_satisfied());
+                }
+            }
+            Circuit::reset();
+
+            // Ensure a length beyond the bytes, or a length that is not a constant, halts.
+            let length = U32::constant(console::Integer::new(7));
+            assert!(std::panic::catch_unwind(|| StringType::from_padded_bytes(&bytes, &length)).is_err());
+            let length = U32::new(Mode::Private, console::Integer::new(4));
+            assert!(std::panic::catch_unwind(|| StringType::from_padded_bytes(&bytes, &length)).is_err());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_malformed() {
+        let malformed: [&[u8]; 9] = [
+            // An unexpected continuation byte.
+            &[0x80],
+            // A truncated sequence.
+            &[0xC3],
+            // An interrupted sequence.
+            &[0xE2, 0x28, 0xA1],
+            // Overlong encodings.
+            &[0xC0, 0x80],
+            &[0xE0, 0x80, 0x80],
+            &[0xF0, 0x80, 0x80, 0x80],
+            // A surrogate.
+            &[0xED, 0xA0, 0x80],
+            // Code points above `U+10FFFF`.
+            &[0xF4, 0x90, 0x80, 0x80],
+            &[0xF5, 0x80, 0x80, 0x80],
+        ];
+
+        for bytes in malformed {
+            // Ensure constant bytes halt.
+            let constant = inject_bytes(Mode::Constant, bytes);
+            assert!(std::panic::catch_unwind(|| StringType::from_bytes(&constant)).is_err());
+            Circuit::reset();
+
+            // Ensure private bytes are not satisfied.
+            let candidate = StringType::from_bytes(&inject_bytes(Mode::Private, bytes));
+            assert_eq!("\0".repeat(bytes.len()), *candidate.eject_value());
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_matches_console() {
+        let rng = &mut TestRng::default();
+
+        // Sample bytes from the boundaries of the UTF-8 ranges, to cover both well-formed and malformed sequences.
+        let boundaries = [
+            0x00, 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0, 0xC1, 0xC2, 0xDF, 0xE0, 0xE1, 0xEC, 0xED, 0xEE, 0xEF,
+            0xF0, 0xF1, 0xF3, 0xF4, 0xF5, 0xFF,
+        ];
+
+        for i in 0..1000 {
+            let num_bytes = rng.gen_range(1..6);
+            let bytes = (0..num_bytes).map(|_| boundaries[rng.gen_range(0..boundaries.len())]).collect::<Vec<u8>>();
+
+            let _candidate = StringType::from_bytes(&inject_bytes(Mode::Private, &bytes));
+            assert_eq!(
+                core::str::from_utf8(&bytes).is_ok(),
+                Circuit::is_satisfied(),
+                "Mismatch on iteration {i} for {bytes:02x?}"
+            );
+            Circuit::reset();
+        }
+    }
+}
@@ -16,5 +16,7 @@
 use super::*;
 
 pub mod from_bits;
+pub mod from_bytes;
 pub mod to_bits;
+pub mod to_bytes;
 pub mod to_fields;
@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the bytes of the string, padded with zeros to `num_bytes` bytes.
+    pub fn to_bytes(&self, num_bytes: u32) -> Vec<U8<E>> {
+        // Ensure the string fits in the given number of bytes.
+        if self.bytes.len() > num_bytes as usize {
+            E::halt(format!("Attempted to write a string of size {} into {num_bytes} bytes", self.bytes.len()))
+        }
+        let mut bytes = self.bytes.clone();
+        bytes.resize(num_bytes as usize, U8::constant(console::Integer::new(0)));
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u32 = 32;
+
+    fn check_to_bytes(mode: Mode) {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let expected = rng.next_string(Circuit::MAX_STRING_BYTES / 4, false);
+            let candidate = StringType::<Circuit>::new(mode, console::StringType::new(&expected));
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = candidate.to_bytes(Circuit::MAX_STRING_BYTES);
+                assert_eq!(Circuit::MAX_STRING_BYTES as usize, candidate.len());
+
+                // Ensure the bytes match, followed by zeros.
+                let (bytes, padding) = candidate.split_at(expected.len());
+                assert_eq!(expected.as_bytes(), bytes.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>());
+                assert!(padding.iter().all(|byte| *byte.eject_value() == 0 && byte.is_constant()));
+                assert_scope!(8, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bytes_constant() {
+        check_to_bytes(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bytes_public() {
+        check_to_bytes(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bytes_private() {
+        check_to_bytes(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_bytes_halts() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("aleo"));
+        assert!(std::panic::catch_unwind(|| string.to_bytes(3)).is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the number of bytes in the string.
+    ///
+    /// As the number of bytes in a string is fixed when the circuit is synthesized, the length is a constant.
+    pub fn length(&self) -> U32<E> {
+        match u32::try_from(self.bytes.len()) {
+            Ok(num_bytes) => U32::constant(console::Integer::new(num_bytes)),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u32 = 32;
+
+    fn check_length(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let expected = rng.next_string(Circuit::MAX_STRING_BYTES / 4, false);
+            let candidate = StringType::<Circuit>::new(mode, console::StringType::new(&expected));
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = candidate.length();
+                assert_eq!(expected.len(), *candidate.eject_value() as usize);
+                assert!(candidate.is_constant());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_length_constant() {
+        check_length(Mode::Constant, 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_length_public() {
+        check_length(Mode::Public, 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_length_private() {
+        check_length(Mode::Private, 32, 0, 0, 0);
+    }
+}
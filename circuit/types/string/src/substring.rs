@@ -0,0 +1,136 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring between the byte offsets `start` (inclusive) and `end` (exclusive).
+    ///
+    /// As the number of bytes in a string is fixed when the circuit is synthesized, the offsets must be constants.
+    pub fn substring(&self, start: &U32<E>, end: &U32<E>) -> Self {
+        // Ensure the offsets are constants.
+        if !start.is_constant() || !end.is_constant() {
+            E::halt("The offsets of a substring must be constants")
+        }
+
+        // Ensure the range is within the string.
+        let (start, end) = (*start.eject_value() as usize, *end.eject_value() as usize);
+        if start > end || end > self.bytes.len() {
+            E::halt(format!(
+                "Attempted to take the substring [{start}, {end}) of a string of size {}",
+                self.bytes.len()
+            ))
+        }
+
+        // Ensure the offsets lie on character boundaries, i.e. the bytes at the offsets are not continuation bytes.
+        let offsets = match start == end {
+            true => vec![start],
+            false => vec![start, end],
+        };
+        for byte in offsets.into_iter().filter_map(|offset| self.bytes.get(offset)) {
+            let bits = byte.to_bits_le();
+            // Enforce that `bits[7] AND NOT bits[6]` is `false`.
+            E::enforce(|| (&bits[7], !&bits[6], E::zero()));
+        }
+
+        Self {
+            mode: self.mode,
+            bytes: self.bytes[start..end].to_vec(),
+            size_in_bytes: Field::constant(console::Field::from_u32((end - start) as u32)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_substring(
+        mode: Mode,
+        start: u32,
+        end: u32,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let given = "héllo.aleo";
+        let expected = &given[start as usize..end as usize];
+
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(given));
+        let start = U32::constant(console::Integer::new(start));
+        let end = U32::constant(console::Integer::new(end));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.substring(&start, &end);
+            assert_eq!(expected, &*candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_substring_constant() {
+        check_substring(Mode::Constant, 0, 6, 1, 0, 0, 0);
+        check_substring(Mode::Constant, 7, 11, 1, 0, 0, 0);
+        check_substring(Mode::Constant, 3, 3, 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_substring_public() {
+        check_substring(Mode::Public, 0, 6, 1, 0, 0, 2);
+        check_substring(Mode::Public, 7, 11, 1, 0, 0, 1);
+        check_substring(Mode::Public, 3, 3, 1, 0, 0, 1);
+    }
+
+    #[test]
+    fn test_substring_private() {
+        check_substring(Mode::Private, 0, 6, 1, 0, 0, 2);
+        check_substring(Mode::Private, 7, 11, 1, 0, 0, 1);
+        check_substring(Mode::Private, 3, 3, 1, 0, 0, 1);
+    }
+
+    #[test]
+    fn test_substring_halts() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("héllo.aleo"));
+        let offset = |mode, offset| U32::<Circuit>::new(mode, console::Integer::new(offset));
+
+        // Ensure an out-of-range or reversed substring halts.
+        assert!(std::panic::catch_unwind(|| string.substring(&offset(Mode::Constant, 0), &offset(Mode::Constant, 12)))
+            .is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(&offset(Mode::Constant, 4), &offset(Mode::Constant, 2)))
+            .is_err());
+        // Ensure non-constant offsets halt.
+        assert!(std::panic::catch_unwind(|| string.substring(&offset(Mode::Private, 0), &offset(Mode::Constant, 6)))
+            .is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_substring_splits_character() {
+        // Ensure a substring that splits a constant character halts.
+        let string = StringType::<Circuit>::new(Mode::Constant, console::StringType::new("héllo.aleo"));
+        let (start, end) = (U32::constant(console::Integer::new(2)), U32::constant(console::Integer::new(6)));
+        assert!(std::panic::catch_unwind(|| string.substring(&start, &end)).is_err());
+        Circuit::reset();
+
+        // Ensure a substring that splits a private character is not satisfied.
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("héllo.aleo"));
+        let _candidate = string.substring(&start, &end);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
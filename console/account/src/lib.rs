@@ -18,7 +18,7 @@
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
-pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar};
+pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar, U8};
 
 mod address;
 
@@ -32,6 +32,11 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "multisig")]
+pub mod multisig;
+#[cfg(feature = "multisig")]
+pub use multisig::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
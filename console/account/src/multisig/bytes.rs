@@ -0,0 +1,87 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MultisigKey<N> {
+    /// Reads a multisig key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid multisig key version"));
+        }
+        // Read the compute key of the shared account.
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        // Read the threshold.
+        let threshold = u8::read_le(&mut reader)?;
+        // Read the number of signers.
+        let num_signers = u8::read_le(&mut reader)?;
+        // Ensure the number of signers is within bounds.
+        if num_signers as usize > Self::MAX_SIGNERS {
+            return Err(error(format!("Multisig key cannot exceed {} signers", Self::MAX_SIGNERS)));
+        }
+        // Read the signers.
+        let signers = (0..num_signers).map(|_| ComputeKey::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the multisig key.
+        Self::new(compute_key, threshold, signers).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network> ToBytes for MultisigKey<N> {
+    /// Writes a multisig key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the compute key of the shared account.
+        self.compute_key.write_le(&mut writer)?;
+        // Write the threshold.
+        self.threshold.write_le(&mut writer)?;
+        // Write the number of signers.
+        u8::try_from(self.signers.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the signers.
+        self.signers.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multisig::test_helpers::sample_multisig_key;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new multisig key.
+            let num_signers = 1 + usize::try_from(i)? % MultisigKey::<CurrentNetwork>::MAX_SIGNERS;
+            let threshold = 1 + u8::try_from(i % num_signers as u64)?;
+            let (expected, _, _) = sample_multisig_key::<CurrentNetwork>(threshold, num_signers, &mut rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, MultisigKey::read_le(&expected_bytes[..])?);
+            assert!(MultisigKey::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod sign;
+mod to_address;
+mod to_view_key;
+mod verify;
+
+use crate::{ComputeKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Scalar};
+
+/// A `k`-of-`n` multisig account key, which binds the shared account compute key to a threshold and a set of signers.
+///
+/// The shared account key is held by every signer, and is used to decrypt records, derive serial numbers, and sign
+/// requests. A request is only authorized for the multisig address once `threshold` distinct signers cosign it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultisigKey<N: Network> {
+    /// The compute key of the shared account.
+    compute_key: ComputeKey<N>,
+    /// The number of signers required to authorize a request.
+    threshold: u8,
+    /// The compute keys of the signers.
+    signers: Vec<ComputeKey<N>>,
+    /// The PRF secret key `sk_prf` := HashToScalar(pk_sig || pr_sig || threshold || num_signers || signers).
+    sk_prf: Scalar<N>,
+}
+
+impl<N: Network> MultisigKey<N> {
+    /// The maximum number of signers in a multisig account.
+    pub const MAX_SIGNERS: usize = 16;
+
+    /// Initializes a new multisig key from the shared account compute key, the threshold, and the signers.
+    pub fn new(compute_key: ComputeKey<N>, threshold: u8, signers: Vec<ComputeKey<N>>) -> Result<Self> {
+        // Ensure the number of signers is within bounds.
+        ensure!(!signers.is_empty(), "A multisig account must have at least one signer");
+        ensure!(
+            signers.len() <= Self::MAX_SIGNERS,
+            "A multisig account cannot exceed {} signers, found {}",
+            Self::MAX_SIGNERS,
+            signers.len()
+        );
+        // Ensure the threshold is within bounds.
+        ensure!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            "The multisig threshold must be between 1 and {}, found {threshold}",
+            signers.len()
+        );
+        // Ensure the signers are unique.
+        ensure!(!has_duplicates(&signers), "A multisig account cannot contain duplicate signers");
+
+        // Construct the preimage as `(domain || pk_sig || pr_sig || threshold || num_signers || signers)`.
+        // Note: The signers are padded with zeros to `MAX_SIGNERS`, so the preimage has a fixed length.
+        let mut preimage = Vec::with_capacity(5 + 2 * Self::MAX_SIGNERS);
+        preimage.push(Field::new_domain_separator("AleoMultisigKey0"));
+        preimage.push(compute_key.pk_sig().to_x_coordinate());
+        preimage.push(compute_key.pr_sig().to_x_coordinate());
+        preimage.push(Field::from_u8(threshold));
+        preimage.push(Field::from_u8(u8::try_from(signers.len())?));
+        for signer in &signers {
+            preimage.push(signer.pk_sig().to_x_coordinate());
+            preimage.push(signer.pr_sig().to_x_coordinate());
+        }
+        preimage.resize(5 + 2 * Self::MAX_SIGNERS, Field::zero());
+        // Compute `sk_prf` as `HashToScalar(domain || pk_sig || pr_sig || threshold || num_signers || signers)`.
+        let sk_prf = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(Self { compute_key, threshold, signers, sk_prf })
+    }
+}
+
+impl<N: Network> MultisigKey<N> {
+    /// Returns the compute key of the shared account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the number of signers required to authorize a request.
+    pub const fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the compute keys of the signers.
+    pub fn signers(&self) -> &[ComputeKey<N>] {
+        &self.signers
+    }
+
+    /// Returns the PRF secret key.
+    pub const fn sk_prf(&self) -> Scalar<N> {
+        self.sk_prf
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// Samples a `threshold`-of-`num_signers` multisig key, and returns it with the shared and signer private keys.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn sample_multisig_key<N: Network>(
+        threshold: u8,
+        num_signers: usize,
+        rng: &mut TestRng,
+    ) -> Result<(MultisigKey<N>, PrivateKey<N>, Vec<PrivateKey<N>>)> {
+        // Sample the shared account.
+        let private_key = PrivateKey::new(rng)?;
+        // Sample the signers.
+        let signer_keys = (0..num_signers).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let signers = signer_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        // Construct the multisig key.
+        let multisig_key = MultisigKey::new(ComputeKey::try_from(&private_key)?, threshold, signers)?;
+        Ok((multisig_key, private_key, signer_keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the shared account and the signers.
+        let compute_key = ComputeKey::<CurrentNetwork>::try_from(PrivateKey::new(rng)?)?;
+        let signers =
+            (0..3).map(|_| ComputeKey::try_from(PrivateKey::new(rng)?)).collect::<Result<Vec<ComputeKey<_>>>>()?;
+
+        // Ensure valid thresholds succeed.
+        for threshold in 1..=3 {
+            let multisig_key = MultisigKey::new(compute_key, threshold, signers.clone())?;
+            assert_eq!(threshold, multisig_key.threshold());
+            assert_eq!(&signers, multisig_key.signers());
+        }
+
+        // Ensure invalid thresholds fail.
+        assert!(MultisigKey::new(compute_key, 0, signers.clone()).is_err());
+        assert!(MultisigKey::new(compute_key, 4, signers.clone()).is_err());
+        // Ensure an empty set of signers fails.
+        assert!(MultisigKey::new(compute_key, 1, vec![]).is_err());
+        // Ensure duplicate signers fail.
+        assert!(MultisigKey::new(compute_key, 2, vec![signers[0], signers[0]]).is_err());
+        // Ensure too many signers fail.
+        let too_many = vec![signers[0]; MultisigKey::<CurrentNetwork>::MAX_SIGNERS + 1];
+        assert!(MultisigKey::new(compute_key, 1, too_many).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sk_prf_binds_threshold_and_signers() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the shared account and the signers.
+        let compute_key = ComputeKey::<CurrentNetwork>::try_from(PrivateKey::new(rng)?)?;
+        let signers =
+            (0..3).map(|_| ComputeKey::try_from(PrivateKey::new(rng)?)).collect::<Result<Vec<ComputeKey<_>>>>()?;
+
+        // Ensure the PRF secret key changes with the threshold and the signers.
+        let expected = MultisigKey::new(compute_key, 2, signers.clone())?;
+        assert_ne!(expected.sk_prf(), MultisigKey::new(compute_key, 3, signers.clone())?.sk_prf());
+        assert_ne!(expected.sk_prf(), MultisigKey::new(compute_key, 2, signers[..2].to_vec())?.sk_prf());
+        // Ensure the PRF secret key differs from the one of the shared account.
+        assert_ne!(expected.sk_prf(), compute_key.sk_prf());
+        Ok(())
+    }
+}
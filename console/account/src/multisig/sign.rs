@@ -0,0 +1,36 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> MultisigKey<N> {
+    /// Returns the signer index and a signature for the given message, using the private key of a signer.
+    pub fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<(u8, Signature<N>)> {
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Retrieve the index of the signer.
+        let index = match self.signers.iter().position(|signer| *signer == compute_key) {
+            Some(index) => u8::try_from(index)?,
+            None => bail!("The private key does not belong to a signer of the multisig key"),
+        };
+        // Sign the message.
+        Ok((index, Signature::sign(private_key, message, rng)?))
+    }
+}
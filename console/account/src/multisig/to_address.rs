@@ -0,0 +1,55 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> MultisigKey<N> {
+    /// Returns the address corresponding to the multisig key.
+    pub fn to_address(&self) -> Address<N> {
+        // Compute pk_prf := G^sk_prf.
+        let pk_prf = N::g_scalar_multiply(&self.sk_prf);
+        // Compute the address := pk_sig + pr_sig + pk_prf.
+        Address::new(self.compute_key.pk_sig() + self.compute_key.pr_sig() + pk_prf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multisig::test_helpers::sample_multisig_key;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new multisig key.
+            let (multisig_key, private_key, _) = sample_multisig_key::<CurrentNetwork>(2, 3, &mut rng)?;
+            // Derive the view key.
+            let view_key = multisig_key.to_view_key(&private_key)?;
+
+            // Ensure the address matches the view key.
+            assert_eq!(multisig_key.to_address(), view_key.to_address());
+            // Ensure the address differs from the address of the shared account.
+            assert_ne!(multisig_key.to_address(), Address::try_from(&private_key)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> MultisigKey<N> {
+    /// Returns the view key of the multisig account, given the private key of the shared account.
+    pub fn to_view_key(&self, private_key: &PrivateKey<N>) -> Result<ViewKey<N>> {
+        // Ensure the private key belongs to the shared account.
+        ensure!(
+            ComputeKey::try_from(private_key)? == self.compute_key,
+            "The private key does not belong to the shared account of the multisig key"
+        );
+        // Compute view_key := sk_sig + r_sig + sk_prf.
+        Ok(ViewKey::from_scalar(private_key.sk_sig() + private_key.r_sig() + self.sk_prf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multisig::test_helpers::sample_multisig_key;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_to_view_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new multisig key.
+        let (multisig_key, private_key, signer_keys) = sample_multisig_key::<CurrentNetwork>(2, 3, &mut rng)?;
+
+        // Ensure the view key differs from the view key of the shared account.
+        assert_ne!(multisig_key.to_view_key(&private_key)?, ViewKey::try_from(&private_key)?);
+        // Ensure a signer cannot derive the view key with their own private key.
+        assert!(multisig_key.to_view_key(&signer_keys[0]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> MultisigKey<N> {
+    /// Returns `true` if the given signatures are a quorum of valid signatures on the message.
+    ///
+    /// The signatures must be sorted by strictly increasing signer index, and there must be at least `threshold` of them.
+    pub fn verify(&self, message: &[Field<N>], signatures: &[(u8, Signature<N>)]) -> bool {
+        // Ensure the number of signatures meets the threshold.
+        if signatures.len() < self.threshold as usize {
+            eprintln!("Expected at least {} multisig signatures, found {}", self.threshold, signatures.len());
+            return false;
+        }
+        // Ensure the signer indices are strictly increasing, which guarantees each signer is counted at most once.
+        if !signatures.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            eprintln!("Multisig signatures must be sorted by strictly increasing signer index");
+            return false;
+        }
+        // Ensure each signature is valid for its signer.
+        signatures.iter().all(|(index, signature)| match self.signers.get(*index as usize) {
+            Some(signer) => signature.verify(&signer.to_address(), message),
+            None => {
+                eprintln!("Multisig signer index {index} is out of bounds");
+                false
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multisig::test_helpers::sample_multisig_key;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 25;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new multisig key.
+            let (multisig_key, _, signer_keys) = sample_multisig_key::<CurrentNetwork>(2, 3, &mut rng)?;
+
+            // Sample a message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+            // Sign the message with every signer.
+            let signatures = signer_keys
+                .iter()
+                .map(|private_key| multisig_key.sign(private_key, &message, &mut rng))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Ensure any quorum verifies.
+            assert!(multisig_key.verify(&message, &signatures));
+            assert!(multisig_key.verify(&message, &signatures[..2]));
+            assert!(multisig_key.verify(&message, &signatures[1..]));
+            assert!(multisig_key.verify(&message, &[signatures[0], signatures[2]]));
+
+            // Ensure fewer than `threshold` signatures fail.
+            assert!(!multisig_key.verify(&message, &signatures[..1]));
+            // Ensure a repeated signer fails.
+            assert!(!multisig_key.verify(&message, &[signatures[0], signatures[0]]));
+            // Ensure unsorted signatures fail.
+            assert!(!multisig_key.verify(&message, &[signatures[1], signatures[0]]));
+            // Ensure a signature assigned to the wrong signer fails.
+            assert!(!multisig_key.verify(&message, &[signatures[0], (2, signatures[1].1)]));
+            // Ensure a signature on a different message fails.
+            let other = [Uniform::rand(&mut rng)];
+            assert!(!multisig_key.verify(&other, &signatures));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_fails_for_non_signer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new multisig key.
+        let (multisig_key, private_key, _) = sample_multisig_key::<CurrentNetwork>(1, 2, &mut rng)?;
+        // Ensure the shared account is not a signer.
+        assert!(multisig_key.sign(&private_key, &[Field::one()], &mut rng).is_err());
+        Ok(())
+    }
+}
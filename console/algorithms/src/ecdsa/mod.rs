@@ -0,0 +1,323 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+use point::*;
+
+use snarkvm_console_types::environment::prelude::*;
+
+use num_bigint::BigUint;
+use tiny_keccak::{Hasher, Keccak as TinyKeccak};
+
+/// The number of bytes in an Ethereum-encoded ECDSA signature, `r || s || v`.
+pub const ECDSA_SIGNATURE_SIZE_IN_BYTES: usize = 65;
+/// The number of bytes in an EIP-2098 compact ECDSA signature, `r || yParityAndS`.
+pub const ECDSA_COMPACT_SIGNATURE_SIZE_IN_BYTES: usize = 64;
+/// The number of bytes in an Ethereum address.
+pub const ETHEREUM_ADDRESS_SIZE_IN_BYTES: usize = 20;
+
+/// ECDSA signature verification over the secp256k1 curve, using Ethereum encodings.
+///
+/// A signature is encoded as `r || s || v`, where `r` and `s` are 32-byte big-endian integers,
+/// and `v` is the recovery ID, given as `0`, `1`, `27`, or `28`.
+/// A signer is identified by its Ethereum address, which is the last 20 bytes of the
+/// Keccak-256 hash of the uncompressed public key (without the `0x04` prefix).
+///
+/// As with the Ethereum `ecrecover` precompile, signatures with a high `s` are accepted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSASecp256k1;
+
+impl ECDSASecp256k1 {
+    /// Returns `true` if the signature is valid for the Keccak-256 hash of the given message and the given address.
+    pub fn verify_keccak256(
+        message: &[u8],
+        signature: &[u8; ECDSA_SIGNATURE_SIZE_IN_BYTES],
+        address: &[u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES],
+    ) -> bool {
+        Self::verify_digest(&keccak_256(message), signature, address)
+    }
+
+    /// Returns `true` if the signature is valid for the given 32-byte digest and the given address.
+    pub fn verify_digest(
+        digest: &[u8; 32],
+        signature: &[u8; ECDSA_SIGNATURE_SIZE_IN_BYTES],
+        address: &[u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES],
+    ) -> bool {
+        match Self::recover_address(digest, signature) {
+            Ok(candidate) => candidate == *address,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the Ethereum address of the signer of the given 32-byte digest.
+    pub fn recover_address(
+        digest: &[u8; 32],
+        signature: &[u8; ECDSA_SIGNATURE_SIZE_IN_BYTES],
+    ) -> Result<[u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES]> {
+        Ok(Self::ethereum_address(&Self::recover_public_key(digest, signature)?))
+    }
+
+    /// Returns the uncompressed public key (`x || y`, without the `0x04` prefix) of the signer of the given 32-byte digest.
+    pub fn recover_public_key(digest: &[u8; 32], signature: &[u8; ECDSA_SIGNATURE_SIZE_IN_BYTES]) -> Result<[u8; 64]> {
+        let curve = Secp256k1::get();
+
+        // Parse the signature.
+        let r = BigUint::from_bytes_be(&signature[0..32]);
+        let s = BigUint::from_bytes_be(&signature[32..64]);
+        let recovery_id = match signature[64] {
+            0 | 27 => 0u8,
+            1 | 28 => 1u8,
+            v => bail!("Invalid ECDSA recovery ID '{v}'"),
+        };
+
+        // Ensure `r` and `s` are in the range `[1, n)`.
+        ensure!(r != BigUint::default() && r < curve.n, "Invalid ECDSA signature: 'r' is out of range");
+        ensure!(s != BigUint::default() && s < curve.n, "Invalid ECDSA signature: 's' is out of range");
+
+        // Recover the point `R` from its x-coordinate and the parity of its y-coordinate.
+        // Note: As `n < p`, every `r` is a valid x-coordinate candidate.
+        let point_r = match curve.decompress(&r, recovery_id == 1) {
+            Some(point) => point,
+            None => bail!("Invalid ECDSA signature: 'r' is not the x-coordinate of a curve point"),
+        };
+
+        // Compute `Q = r^{-1} * (s * R - e * G)`.
+        let e = BigUint::from_bytes_be(digest) % &curve.n;
+        let r_inverse = curve.scalar_inverse(&r);
+        let u1 = (&curve.n - e) * &r_inverse % &curve.n;
+        let u2 = &s * &r_inverse % &curve.n;
+        let public_key = curve.add(&curve.mul(&curve.generator(), &u1), &curve.mul(&point_r, &u2));
+
+        // Convert the public key to affine coordinates.
+        match curve.to_affine(&public_key) {
+            Some((x, y)) => {
+                let mut output = [0u8; 64];
+                output[0..32].copy_from_slice(&to_bytes_be_32(&x));
+                output[32..64].copy_from_slice(&to_bytes_be_32(&y));
+                Ok(output)
+            }
+            None => bail!("Invalid ECDSA signature: the recovered public key is the point at infinity"),
+        }
+    }
+
+    /// Returns the `r || s || v` signature of the given EIP-2098 compact signature (`r || yParityAndS`),
+    /// where the parity of the y-coordinate of `R` is the most significant bit of `yParityAndS`.
+    /// Note: Only signatures with a low `s` (i.e. `s <= n / 2`) have a compact encoding.
+    pub fn from_compact_signature(
+        compact: &[u8; ECDSA_COMPACT_SIGNATURE_SIZE_IN_BYTES],
+    ) -> [u8; ECDSA_SIGNATURE_SIZE_IN_BYTES] {
+        let mut signature = [0u8; ECDSA_SIGNATURE_SIZE_IN_BYTES];
+        signature[0..64].copy_from_slice(compact);
+        // Move the y-parity bit from `s` into the recovery ID.
+        signature[64] = 27 + (signature[32] >> 7);
+        signature[32] &= 0x7f;
+        signature
+    }
+
+    /// Returns the Ethereum address of the given uncompressed public key (`x || y`, without the `0x04` prefix).
+    pub fn ethereum_address(public_key: &[u8; 64]) -> [u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES] {
+        let hash = keccak_256(public_key);
+        let mut address = [0u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES];
+        address.copy_from_slice(&hash[12..32]);
+        address
+    }
+}
+
+/// Computes the Keccak-256 hash of the given preimage as bytes.
+fn keccak_256(preimage: &[u8]) -> [u8; 32] {
+    let mut keccak = TinyKeccak::v256();
+    keccak.update(preimage);
+
+    let mut hash = [0u8; 32];
+    keccak.finalize(&mut hash);
+    hash
+}
+
+/// Returns the given integer as 32 big-endian bytes.
+fn to_bytes_be_32(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut output = [0u8; 32];
+    output[32 - bytes.len()..].copy_from_slice(&bytes);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    const ITERATIONS: usize = 25;
+
+    /// The web3.js `accounts.sign` example: the private key, the address, the message, and the signature.
+    const PRIVATE_KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+    const MESSAGE: &str = "Some data";
+    const SIGNATURE: &str = "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+
+    /// Returns the Ethereum signed message digest of the given message.
+    fn eth_message_digest(message: &[u8]) -> [u8; 32] {
+        let mut preimage = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+        preimage.extend_from_slice(message);
+        keccak_256(&preimage)
+    }
+
+    /// Returns the Ethereum address of the given private key.
+    fn address_of(private_key: &BigUint) -> [u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES] {
+        let curve = Secp256k1::get();
+        let (x, y) = curve.to_affine(&curve.mul(&curve.generator(), private_key)).unwrap();
+        let mut public_key = [0u8; 64];
+        public_key[0..32].copy_from_slice(&to_bytes_be_32(&x));
+        public_key[32..64].copy_from_slice(&to_bytes_be_32(&y));
+        ECDSASecp256k1::ethereum_address(&public_key)
+    }
+
+    /// Signs the given digest with the given private key and nonce.
+    fn sign(private_key: &BigUint, nonce: &BigUint, digest: &[u8; 32]) -> [u8; ECDSA_SIGNATURE_SIZE_IN_BYTES] {
+        let curve = Secp256k1::get();
+        let (x, y) = curve.to_affine(&curve.mul(&curve.generator(), nonce)).unwrap();
+        let r = &x % &curve.n;
+        let e = BigUint::from_bytes_be(digest) % &curve.n;
+        let s = curve.scalar_inverse(nonce) * (e + &r * private_key) % &curve.n;
+
+        let mut signature = [0u8; ECDSA_SIGNATURE_SIZE_IN_BYTES];
+        signature[0..32].copy_from_slice(&to_bytes_be_32(&r));
+        signature[32..64].copy_from_slice(&to_bytes_be_32(&s));
+        signature[64] = 27 + y.bit(0) as u8;
+        signature
+    }
+
+    /// Samples a random scalar in `[1, n)`.
+    fn sample_scalar(rng: &mut TestRng) -> BigUint {
+        let curve = Secp256k1::get();
+        loop {
+            let scalar = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &curve.n;
+            if scalar != BigUint::default() {
+                return scalar;
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_signature() {
+        let address: [u8; 20] = hex::decode(ADDRESS).unwrap().try_into().unwrap();
+        let signature: [u8; 65] = hex::decode(SIGNATURE).unwrap().try_into().unwrap();
+        let digest = eth_message_digest(MESSAGE.as_bytes());
+
+        // Check the private key corresponds to the address.
+        let private_key = BigUint::from_bytes_be(&hex::decode(PRIVATE_KEY).unwrap());
+        assert_eq!(address_of(&private_key), address);
+
+        // Check the signature is valid for the digest.
+        assert_eq!(ECDSASecp256k1::recover_address(&digest, &signature).unwrap(), address);
+        assert!(ECDSASecp256k1::verify_digest(&digest, &signature, &address));
+
+        // Check the signature is invalid for a different digest.
+        let mut wrong_digest = digest;
+        wrong_digest[0] ^= 1;
+        assert!(!ECDSASecp256k1::verify_digest(&wrong_digest, &signature, &address));
+
+        // Check the signature is invalid for a different address.
+        let mut wrong_address = address;
+        wrong_address[19] ^= 1;
+        assert!(!ECDSASecp256k1::verify_digest(&digest, &signature, &wrong_address));
+    }
+
+    #[test]
+    fn test_compact_signature() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = sample_scalar(rng);
+            let address = address_of(&private_key);
+
+            let message = (0..rng.gen_range(0..256)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let mut signature = sign(&private_key, &sample_scalar(rng), &keccak_256(&message));
+
+            // Normalize the signature to a low `s`, as required by EIP-2098.
+            let curve = Secp256k1::get();
+            let s = BigUint::from_bytes_be(&signature[32..64]);
+            if s > &curve.n >> 1 {
+                signature[32..64].copy_from_slice(&to_bytes_be_32(&(&curve.n - s)));
+                // Negating `s` corresponds to negating `R`, which flips the recovery ID.
+                signature[64] = if signature[64] == 27 { 28 } else { 27 };
+            }
+
+            // Encode the signature as an EIP-2098 compact signature.
+            let mut compact = [0u8; ECDSA_COMPACT_SIGNATURE_SIZE_IN_BYTES];
+            compact.copy_from_slice(&signature[0..64]);
+            compact[32] |= (signature[64] - 27) << 7;
+
+            // Check the compact signature expands to the original signature.
+            assert_eq!(ECDSASecp256k1::from_compact_signature(&compact), signature);
+            assert!(ECDSASecp256k1::verify_keccak256(
+                &message,
+                &ECDSASecp256k1::from_compact_signature(&compact),
+                &address
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = sample_scalar(rng);
+            let address = address_of(&private_key);
+
+            let message = (0..rng.gen_range(0..256)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let signature = sign(&private_key, &sample_scalar(rng), &keccak_256(&message));
+            assert!(ECDSASecp256k1::verify_keccak256(&message, &signature, &address));
+
+            // Check the recovery ID may also be given as `0` or `1`.
+            let mut raw_signature = signature;
+            raw_signature[64] -= 27;
+            assert!(ECDSASecp256k1::verify_keccak256(&message, &raw_signature, &address));
+
+            // Check a flipped recovery ID recovers a different signer.
+            let mut flipped_signature = signature;
+            flipped_signature[64] ^= 1;
+            assert!(!ECDSASecp256k1::verify_keccak256(&message, &flipped_signature, &address));
+        }
+    }
+
+    #[test]
+    fn test_invalid_signatures() {
+        let address: [u8; 20] = hex::decode(ADDRESS).unwrap().try_into().unwrap();
+        let signature: [u8; 65] = hex::decode(SIGNATURE).unwrap().try_into().unwrap();
+        let digest = eth_message_digest(MESSAGE.as_bytes());
+
+        // Check an invalid recovery ID is rejected.
+        let mut candidate = signature;
+        candidate[64] = 2;
+        assert!(ECDSASecp256k1::recover_address(&digest, &candidate).is_err());
+        assert!(!ECDSASecp256k1::verify_digest(&digest, &candidate, &address));
+
+        // Check a zero `r` is rejected.
+        let mut candidate = signature;
+        candidate[0..32].copy_from_slice(&[0u8; 32]);
+        assert!(ECDSASecp256k1::recover_address(&digest, &candidate).is_err());
+
+        // Check a zero `s` is rejected.
+        let mut candidate = signature;
+        candidate[32..64].copy_from_slice(&[0u8; 32]);
+        assert!(ECDSASecp256k1::recover_address(&digest, &candidate).is_err());
+
+        // Check an out-of-range `s` is rejected.
+        let mut candidate = signature;
+        candidate[32..64].copy_from_slice(&[0xffu8; 32]);
+        assert!(ECDSASecp256k1::recover_address(&digest, &candidate).is_err());
+    }
+}
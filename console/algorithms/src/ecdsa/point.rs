@@ -0,0 +1,183 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_bigint::BigUint;
+use std::sync::OnceLock;
+
+/// The base field modulus `p` of secp256k1.
+const P: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+/// The order `n` of the secp256k1 generator.
+const N: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+/// The x-coordinate of the secp256k1 generator.
+const GX: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// The y-coordinate of the secp256k1 generator.
+const GY: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// A secp256k1 point in Jacobian coordinates `(X, Y, Z)`, representing `(X / Z^2, Y / Z^3)`.
+/// The point at infinity is represented with `Z = 0`.
+#[derive(Clone, Debug)]
+pub(super) struct JacobianPoint {
+    x: BigUint,
+    y: BigUint,
+    z: BigUint,
+}
+
+/// The secp256k1 curve `y^2 = x^3 + 7`, defined over the base field of modulus `p`.
+pub(super) struct Secp256k1 {
+    /// The base field modulus.
+    p: BigUint,
+    /// The order of the generator.
+    pub(super) n: BigUint,
+    /// The affine coordinates of the generator.
+    g: (BigUint, BigUint),
+}
+
+impl Secp256k1 {
+    /// Returns the secp256k1 curve parameters.
+    pub(super) fn get() -> &'static Self {
+        static CURVE: OnceLock<Secp256k1> = OnceLock::new();
+        CURVE.get_or_init(|| {
+            let parse = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid secp256k1 constant");
+            Self { p: parse(P), n: parse(N), g: (parse(GX), parse(GY)) }
+        })
+    }
+
+    /// Returns the generator.
+    pub(super) fn generator(&self) -> JacobianPoint {
+        JacobianPoint { x: self.g.0.clone(), y: self.g.1.clone(), z: BigUint::from(1u8) }
+    }
+
+    /// Returns the point at infinity.
+    fn zero(&self) -> JacobianPoint {
+        JacobianPoint { x: BigUint::from(1u8), y: BigUint::from(1u8), z: BigUint::default() }
+    }
+
+    /// Returns the point with the given x-coordinate and y-parity, if it exists.
+    pub(super) fn decompress(&self, x: &BigUint, is_odd: bool) -> Option<JacobianPoint> {
+        if x >= &self.p {
+            return None;
+        }
+        // Compute `y = sqrt(x^3 + 7)`. As `p = 3 mod 4`, the square root is `(x^3 + 7)^((p + 1) / 4)`.
+        let y_squared = (x.modpow(&BigUint::from(3u8), &self.p) + 7u8) % &self.p;
+        let y = y_squared.modpow(&((&self.p + 1u8) >> 2), &self.p);
+        if &y * &y % &self.p != y_squared {
+            return None;
+        }
+        // Select the root with the requested parity.
+        let y = if y.bit(0) == is_odd { y } else { &self.p - y };
+        Some(JacobianPoint { x: x.clone(), y, z: BigUint::from(1u8) })
+    }
+
+    /// Returns the affine coordinates of the given point, or `None` if it is the point at infinity.
+    pub(super) fn to_affine(&self, point: &JacobianPoint) -> Option<(BigUint, BigUint)> {
+        if point.is_zero() {
+            return None;
+        }
+        let z_inverse = self.base_inverse(&point.z);
+        let z_inverse_squared = &z_inverse * &z_inverse % &self.p;
+        let x = &point.x * &z_inverse_squared % &self.p;
+        let y = &point.y * z_inverse_squared * z_inverse % &self.p;
+        Some((x, y))
+    }
+
+    /// Returns the sum of the given points.
+    pub(super) fn add(&self, a: &JacobianPoint, b: &JacobianPoint) -> JacobianPoint {
+        if a.is_zero() {
+            return b.clone();
+        }
+        if b.is_zero() {
+            return a.clone();
+        }
+        let p = &self.p;
+
+        let z1_squared = &a.z * &a.z % p;
+        let z2_squared = &b.z * &b.z % p;
+        let u1 = &a.x * &z2_squared % p;
+        let u2 = &b.x * &z1_squared % p;
+        let s1 = &a.y * z2_squared * &b.z % p;
+        let s2 = &b.y * z1_squared * &a.z % p;
+
+        if u1 == u2 {
+            return match s1 == s2 {
+                true => self.double(a),
+                false => self.zero(),
+            };
+        }
+
+        let h = self.sub(&u2, &u1);
+        let r = self.sub(&s2, &s1);
+        let h_squared = &h * &h % p;
+        let h_cubed = &h_squared * &h % p;
+        let u1_h_squared = u1 * h_squared % p;
+
+        let x3 = self.sub(&self.sub(&(&r * &r % p), &h_cubed), &(&u1_h_squared * 2u8 % p));
+        let y3 = self.sub(&(r * self.sub(&u1_h_squared, &x3) % p), &(s1 * h_cubed % p));
+        let z3 = h * &a.z * &b.z % p;
+        JacobianPoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns the double of the given point.
+    fn double(&self, a: &JacobianPoint) -> JacobianPoint {
+        if a.is_zero() || a.y == BigUint::default() {
+            return self.zero();
+        }
+        let p = &self.p;
+
+        let xx = &a.x * &a.x % p;
+        let yy = &a.y * &a.y % p;
+        let yyyy = &yy * &yy % p;
+        let d = self.sub(&self.sub(&((&a.x + &yy) * (&a.x + &yy) % p), &xx), &yyyy) * 2u8 % p;
+        let e = xx * 3u8 % p;
+
+        let x3 = self.sub(&(&e * &e % p), &(&d * 2u8 % p));
+        let y3 = self.sub(&(e * self.sub(&d, &x3) % p), &(yyyy * 8u8 % p));
+        let z3 = &a.y * &a.z * 2u8 % p;
+        JacobianPoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns the product of the given point and scalar, using double-and-add.
+    pub(super) fn mul(&self, point: &JacobianPoint, scalar: &BigUint) -> JacobianPoint {
+        let mut output = self.zero();
+        for i in (0..scalar.bits()).rev() {
+            output = self.double(&output);
+            if scalar.bit(i) {
+                output = self.add(&output, point);
+            }
+        }
+        output
+    }
+
+    /// Returns the inverse of the given scalar, modulo the generator order.
+    pub(super) fn scalar_inverse(&self, scalar: &BigUint) -> BigUint {
+        scalar.modpow(&(&self.n - 2u8), &self.n)
+    }
+
+    /// Returns the inverse of the given base field element.
+    fn base_inverse(&self, element: &BigUint) -> BigUint {
+        element.modpow(&(&self.p - 2u8), &self.p)
+    }
+
+    /// Returns `a - b` in the base field, for reduced `a` and `b`.
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - b) % &self.p
+    }
+}
+
+impl JacobianPoint {
+    /// Returns `true` if the point is the point at infinity.
+    fn is_zero(&self) -> bool {
+        self.z == BigUint::default()
+    }
+}
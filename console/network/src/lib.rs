@@ -188,6 +188,8 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum number of events emitted in finalize.
+    const MAX_EVENTS: u16 = 16;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
@@ -22,7 +22,8 @@ impl<N: Network> FromBytes for Access<N> {
         match variant {
             0 => Ok(Self::Member(Identifier::read_le(&mut reader)?)),
             1 => Ok(Self::Index(U32::read_le(&mut reader)?)),
-            2.. => Err(error(format!("Failed to deserialize access variant {variant}"))),
+            2 => Ok(Self::Register(read_variable_length_integer(&mut reader)?)),
+            3.. => Err(error(format!("Failed to deserialize access variant {variant}"))),
         }
     }
 }
@@ -39,6 +40,10 @@ impl<N: Network> ToBytes for Access<N> {
                 1u8.write_le(&mut writer)?;
                 index.write_le(&mut writer)
             }
+            Access::Register(locator) => {
+                2u8.write_le(&mut writer)?;
+                variable_length_integer(locator).write_le(&mut writer)
+            }
         }
     }
 }
@@ -72,6 +77,10 @@ mod tests {
             // Index
             let index = U32::<CurrentNetwork>::rand(rng);
             check_bytes(Access::Index(index))?;
+
+            // Register
+            let locator = rng.gen_range(0..u16::MAX as u64);
+            check_bytes(Access::Register(locator))?;
         }
         Ok(())
     }
@@ -27,6 +27,8 @@ pub enum Access<N: Network> {
     Member(Identifier<N>),
     /// Access an element of an array.
     Index(U32<N>),
+    /// Access an element of an array, at the index held by the loop-index register of a `repeat` block.
+    Register(u64),
 }
 
 impl<N: Network> From<Identifier<N>> for Access<N> {
@@ -19,11 +19,17 @@ impl<N: Network> Parser for Access<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         alt((
             map(pair(tag("["), pair(U32::parse, tag("]"))), |(_, (index, _))| Self::Index(index)),
+            map(pair(tag("[r"), pair(parse_locator, tag("]"))), |(_, (locator, _))| Self::Register(locator)),
             map(pair(tag("."), Identifier::parse), |(_, identifier)| Self::Member(identifier)),
         ))(string)
     }
 }
 
+/// Parses a register locator from the string, i.e. the `3` in `r3`.
+fn parse_locator(string: &str) -> ParserResult<u64> {
+    map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)
+}
+
 impl<N: Network> FromStr for Access<N> {
     type Err = Error;
 
@@ -57,6 +63,8 @@ impl<N: Network> Display for Access<N> {
             Self::Member(identifier) => write!(f, ".{}", identifier),
             // Prints the access index, i.e. `[0u32]`
             Self::Index(index) => write!(f, "[{}]", index),
+            // Prints the access register, i.e. `[r0]`
+            Self::Register(locator) => write!(f, "[r{}]", locator),
         }
     }
 }
@@ -72,6 +80,7 @@ mod tests {
     fn test_parse() -> Result<()> {
         assert_eq!(Access::parse(".data"), Ok(("", Access::<CurrentNetwork>::Member(Identifier::from_str("data")?))));
         assert_eq!(Access::parse("[0u32]"), Ok(("", Access::<CurrentNetwork>::Index(U32::new(0)))));
+        assert_eq!(Access::parse("[r3]"), Ok(("", Access::<CurrentNetwork>::Register(3))));
         Ok(())
     }
 
@@ -87,6 +96,8 @@ mod tests {
         assert!(Access::<CurrentNetwork>::parse("[index]").is_err());
         assert!(Access::<CurrentNetwork>::parse("[0.0]").is_err());
         assert!(Access::<CurrentNetwork>::parse("[999999999999]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r0.foo]").is_err());
 
         // Must fit within the data capacity of a base field element.
         let access =
@@ -100,6 +111,7 @@ mod tests {
     fn test_display() -> Result<()> {
         assert_eq!(Access::<CurrentNetwork>::Member(Identifier::from_str("foo")?).to_string(), ".foo");
         assert_eq!(Access::<CurrentNetwork>::Index(U32::new(0)).to_string(), "[0u32]");
+        assert_eq!(Access::<CurrentNetwork>::Register(3).to_string(), "[r3]");
         Ok(())
     }
 }
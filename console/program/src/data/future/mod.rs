@@ -24,7 +24,7 @@ mod serialize;
 mod to_bits;
 mod to_fields;
 
-use crate::{Access, Identifier, Plaintext, ProgramID, Value};
+use crate::{Access, Identifier, Literal, Plaintext, ProgramID, Value};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -48,6 +48,14 @@ impl<N: Network> Future<N> {
         Self { program_id, function_name, arguments }
     }
 
+    /// Initializes the future of a dynamic call, as seen by the caller, given the interface function
+    /// and the output ID of the callee's future.
+    /// Note: The callee's future is resolved from the callee's transition when the caller's future is finalized.
+    #[inline]
+    pub fn new_dynamic(program_id: ProgramID<N>, function_name: Identifier<N>, future_id: Field<N>) -> Self {
+        Self::new(program_id, function_name, vec![Argument::Plaintext(Plaintext::from(Literal::Field(future_id)))])
+    }
+
     /// Returns the program ID.
     #[inline]
     pub const fn program_id(&self) -> &ProgramID<N> {
@@ -65,4 +73,13 @@ impl<N: Network> Future<N> {
     pub fn arguments(&self) -> &[Argument<N>] {
         &self.arguments
     }
+
+    /// Returns the output ID of the callee's future, if this may be the future of a dynamic call.
+    #[inline]
+    pub fn dynamic_future_id(&self) -> Option<&Field<N>> {
+        match self.arguments.as_slice() {
+            [Argument::Plaintext(Plaintext::Literal(Literal::Field(future_id), _))] => Some(future_id),
+            _ => None,
+        }
+    }
 }
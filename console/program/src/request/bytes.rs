@@ -21,7 +21,7 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid request version"));
         }
 
@@ -52,6 +52,29 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the signer commitment.
         let scm = FromBytes::read_le(&mut reader)?;
 
+        // Read the multisig key and cosignatures.
+        let (multisig_key, cosignatures) = match version {
+            // A version 1 request is signed by a single account.
+            1 => (None, Vec::new()),
+            // A version 2 request is signed by a multisig account.
+            _ => {
+                // Read the multisig key.
+                let multisig_key = MultisigKey::read_le(&mut reader)?;
+                // Read the number of cosignatures.
+                let num_cosignatures = u8::read_le(&mut reader)?;
+                // Ensure the number of cosignatures is within bounds.
+                if num_cosignatures as usize > MultisigKey::<N>::MAX_SIGNERS {
+                    return Err(error("Invalid request: too many cosignatures"));
+                }
+                // Read the cosignatures.
+                let mut cosignatures = Vec::with_capacity(num_cosignatures as usize);
+                for _ in 0..num_cosignatures {
+                    cosignatures.push((u8::read_le(&mut reader)?, Signature::read_le(&mut reader)?));
+                }
+                (Some(multisig_key), cosignatures)
+            }
+        };
+
         Ok(Self::from((
             signer,
             network_id,
@@ -64,15 +87,19 @@ impl<N: Network> FromBytes for Request<N> {
             tvk,
             tcm,
             scm,
-        )))
+        ))
+        .with_multisig(multisig_key, cosignatures))
     }
 }
 
 impl<N: Network> ToBytes for Request<N> {
     /// Writes the request to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Write the version, which is `2` if the request is signed by a multisig account.
+        match self.multisig_key {
+            Some(_) => 2u8.write_le(&mut writer)?,
+            None => 1u8.write_le(&mut writer)?,
+        }
 
         // Write the signer.
         self.signer.write_le(&mut writer)?;
@@ -110,7 +137,27 @@ impl<N: Network> ToBytes for Request<N> {
         // Write the transition commitment.
         self.tcm.write_le(&mut writer)?;
         // Write the signer commitment.
-        self.scm.write_le(&mut writer)
+        self.scm.write_le(&mut writer)?;
+
+        // Write the multisig key and cosignatures.
+        match &self.multisig_key {
+            Some(multisig_key) => {
+                // Write the multisig key.
+                multisig_key.write_le(&mut writer)?;
+                // Write the number of cosignatures.
+                u8::try_from(self.cosignatures.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the cosignatures.
+                for (index, signature) in &self.cosignatures {
+                    index.write_le(&mut writer)?;
+                    signature.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
+            None => match self.cosignatures.is_empty() {
+                true => Ok(()),
+                false => Err(error("Invalid request: cosignatures require a multisig key")),
+            },
+        }
     }
 }
 
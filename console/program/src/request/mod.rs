@@ -23,7 +23,7 @@ mod string;
 mod verify;
 
 use crate::{compute_function_id, Identifier, Plaintext, ProgramID, Record, Value, ValueType};
-use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_account::{Address, ComputeKey, GraphKey, MultisigKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -51,6 +51,10 @@ pub struct Request<N: Network> {
     tcm: Field<N>,
     /// The signer commitment.
     scm: Field<N>,
+    /// The multisig key, if the signer is a multisig account.
+    multisig_key: Option<MultisigKey<N>>,
+    /// The signer index and signature of each cosigner, if the signer is a multisig account.
+    cosignatures: Vec<(u8, Signature<N>)>,
 }
 
 impl<N: Network>
@@ -88,7 +92,21 @@ impl<N: Network>
         if *network_id != N::ID {
             N::halt(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id))
         } else {
-            Self { signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm, scm }
+            Self {
+                signer,
+                network_id,
+                program_id,
+                function_name,
+                input_ids,
+                inputs,
+                signature,
+                sk_tag,
+                tvk,
+                tcm,
+                scm,
+                multisig_key: None,
+                cosignatures: Vec::new(),
+            }
         }
     }
 }
@@ -160,6 +178,34 @@ impl<N: Network> Request<N> {
     pub const fn scm(&self) -> &Field<N> {
         &self.scm
     }
+
+    /// Returns the multisig key, if the signer is a multisig account.
+    pub const fn multisig_key(&self) -> Option<&MultisigKey<N>> {
+        self.multisig_key.as_ref()
+    }
+
+    /// Returns the signer index and signature of each cosigner.
+    pub fn cosignatures(&self) -> &[(u8, Signature<N>)] {
+        &self.cosignatures
+    }
+
+    /// Returns the request with the given multisig key and cosignatures.
+    /// Note: See `Request::sign_multisig` to create a multisig request. This method is used to eject from a circuit.
+    pub fn with_multisig(
+        mut self,
+        multisig_key: Option<MultisigKey<N>>,
+        cosignatures: Vec<(u8, Signature<N>)>,
+    ) -> Self {
+        self.multisig_key = multisig_key;
+        self.cosignatures = cosignatures;
+        self
+    }
+
+    /// Returns the message that each cosigner signs, as `[domain, challenge]`.
+    /// Note: The challenge binds the cosignatures to the signer, the transition, and the inputs of the request.
+    pub fn to_multisig_message(&self) -> Result<Vec<Field<N>>> {
+        Ok(vec![Field::new_domain_separator("AleoMultisigRequest0"), self.signature.challenge().to_field()?])
+    }
 }
 
 #[cfg(test)]
@@ -174,9 +220,23 @@ mod test_helpers {
     pub(super) fn sample_requests(rng: &mut TestRng) -> Vec<Request<CurrentNetwork>> {
         (0..ITERATIONS)
             .map(|i| {
-                // Sample a random private key and address.
+                // Sample a random private key.
                 let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-                let address = Address::try_from(&private_key).unwrap();
+                // On every other iteration, sample a 2-of-3 multisig key for the private key.
+                let multisig = match i % 2 == 1 {
+                    true => {
+                        let signer_keys = (0..3).map(|_| PrivateKey::new(rng).unwrap()).collect::<Vec<_>>();
+                        let signers = signer_keys.iter().map(|key| ComputeKey::try_from(key).unwrap()).collect();
+                        let compute_key = ComputeKey::try_from(&private_key).unwrap();
+                        Some((MultisigKey::new(compute_key, 2, signers).unwrap(), signer_keys))
+                    }
+                    false => None,
+                };
+                // Derive the address.
+                let address = match &multisig {
+                    Some((multisig_key, _)) => multisig_key.to_address(),
+                    None => Address::try_from(&private_key).unwrap(),
+                };
 
                 // Construct a program ID and function name.
                 let program_id = ProgramID::from_str("token.aleo").unwrap();
@@ -210,8 +270,17 @@ mod test_helpers {
                 let root_tvk = None;
 
                 // Compute the signed request.
-                let request =
-                    Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, root_tvk, is_root, rng).unwrap();
+                let request = match multisig {
+                    Some((multisig_key, signer_keys)) => {
+                        let mut request = Request::sign_multisig(&private_key, &multisig_key, program_id, function_name, inputs.into_iter(), &input_types, root_tvk, is_root, rng).unwrap();
+                        // Cosign the request with a quorum of signers.
+                        for signer_key in &signer_keys[1..] {
+                            request.cosign(signer_key, rng).unwrap();
+                        }
+                        request
+                    }
+                    None => Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, root_tvk, is_root, rng).unwrap(),
+                };
                 assert!(request.verify(&input_types, is_root));
                 request
             })
@@ -22,7 +22,8 @@ impl<N: Network> Serialize for Request<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Request", 11)?;
+                let mut transition =
+                    serializer.serialize_struct("Request", 11 + 2 * self.multisig_key.is_some() as usize)?;
                 transition.serialize_field("signer", &self.signer)?;
                 transition.serialize_field("network", &self.network_id)?;
                 transition.serialize_field("program", &self.program_id)?;
@@ -34,6 +35,10 @@ impl<N: Network> Serialize for Request<N> {
                 transition.serialize_field("tvk", &self.tvk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
                 transition.serialize_field("scm", &self.scm)?;
+                if let Some(multisig_key) = &self.multisig_key {
+                    transition.serialize_field("multisig_key", multisig_key)?;
+                    transition.serialize_field("cosignatures", &self.cosignatures)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -49,7 +54,7 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
                 // Parse the request from a string into a value.
                 let mut request = serde_json::Value::deserialize(deserializer)?;
                 // Recover the request.
-                Ok(Self::from((
+                let request_without_multisig = Self::from((
                     // Retrieve the signer.
                     DeserializeExt::take_from_value::<D>(&mut request, "signer")?,
                     // Retrieve the network ID.
@@ -72,7 +77,19 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
                     DeserializeExt::take_from_value::<D>(&mut request, "tcm")?,
                     // Retrieve the `scm`.
                     DeserializeExt::take_from_value::<D>(&mut request, "scm")?,
-                )))
+                ));
+                // Retrieve the multisig key, if it exists.
+                let multisig_key: Option<_> = serde_json::from_value(
+                    request.get_mut("multisig_key").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Retrieve the cosignatures, if they exist.
+                let cosignatures: Option<_> = serde_json::from_value(
+                    request.get_mut("cosignatures").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Return the request.
+                Ok(request_without_multisig.with_multisig(multisig_key, cosignatures.unwrap_or_default()))
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request"),
         }
@@ -28,6 +28,65 @@ impl<N: Network> Request<N> {
         root_tvk: Option<Field<N>>,
         is_root: bool,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_internal(private_key, None, program_id, function_name, inputs, input_types, root_tvk, is_root, rng)
+    }
+
+    /// Returns the request for a given multisig key, the private key of its shared account, program ID,
+    /// function name, inputs, input types, and RNG. The request must be cosigned by a quorum of signers
+    /// using `Request::cosign` before it is valid.
+    pub fn sign_multisig<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        multisig_key: &MultisigKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_internal(
+            private_key,
+            Some(multisig_key),
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            root_tvk,
+            is_root,
+            rng,
+        )
+    }
+
+    /// Cosigns the request, using the private key of a signer of the multisig key.
+    pub fn cosign<R: Rng + CryptoRng>(&mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<()> {
+        // Retrieve the multisig key.
+        let multisig_key = match &self.multisig_key {
+            Some(multisig_key) => multisig_key,
+            None => bail!("Cannot cosign a request that is not signed by a multisig account"),
+        };
+        // Sign the multisig message.
+        let (index, signature) = multisig_key.sign(private_key, &self.to_multisig_message()?, rng)?;
+        // Insert the cosignature, keeping the cosignatures sorted by signer index.
+        match self.cosignatures.binary_search_by_key(&index, |(index, _)| *index) {
+            Ok(_) => bail!("The request is already cosigned by signer {index}"),
+            Err(position) => self.cosignatures.insert(position, (index, signature)),
+        }
+        Ok(())
+    }
+
+    /// Returns the request, signed by the given private key on its own behalf or on behalf of a multisig key.
+    fn sign_internal<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        multisig_key: Option<&MultisigKey<N>>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -48,8 +107,11 @@ impl<N: Network> Request<N> {
         // Retrieve `pr_sig`.
         let pr_sig = compute_key.pr_sig();
 
-        // Derive the view key.
-        let view_key = ViewKey::try_from((private_key, &compute_key))?;
+        // Derive the view key, which for a multisig account is bound to its threshold and signers.
+        let view_key = match multisig_key {
+            Some(multisig_key) => multisig_key.to_view_key(private_key)?,
+            None => ViewKey::try_from((private_key, &compute_key))?,
+        };
         // Derive `sk_tag` from the graph key.
         let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
 
@@ -60,8 +122,11 @@ impl<N: Network> Request<N> {
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
         let g_r = N::g_scalar_multiply(&r);
 
-        // Derive the signer from the compute key.
-        let signer = Address::try_from(compute_key)?;
+        // Derive the signer from the multisig key or the compute key.
+        let signer = match multisig_key {
+            Some(multisig_key) => multisig_key.to_address(),
+            None => Address::try_from(compute_key)?,
+        };
         // Compute the transition view key `tvk` as `r * signer`.
         let tvk = (*signer * r).to_x_coordinate();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
@@ -240,6 +305,8 @@ impl<N: Network> Request<N> {
             tvk,
             tcm,
             scm,
+            multisig_key: multisig_key.cloned(),
+            cosignatures: Vec::new(),
         })
     }
 }
@@ -21,6 +21,20 @@ impl<N: Network> Request<N> {
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
+        self.verify_internal(input_types, is_root, true)
+    }
+
+    /// Returns `true` if the request is valid, without checking the cosignatures of a multisig request.
+    ///
+    /// This is used to check a multisig request before it is cosigned. For a single-signer request, it is
+    /// equivalent to `Request::verify`.
+    pub fn verify_without_quorum(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
+        self.verify_internal(input_types, is_root, false)
+    }
+
+    /// Returns `true` if the request is valid, and `false` otherwise.
+    /// If `check_quorum` is `false`, the cosignatures of a multisig request are not checked.
+    fn verify_internal(&self, input_types: &[ValueType<N>], is_root: bool, check_quorum: bool) -> bool {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
             // Compute the transition commitment `tcm` as `Hash(tvk)`.
@@ -204,7 +218,70 @@ impl<N: Network> Request<N> {
         }
 
         // Verify the signature.
-        self.signature.verify(&self.signer, &message)
+        match &self.multisig_key {
+            Some(multisig_key) => self.verify_multisig(multisig_key, &message, check_quorum),
+            None => match self.cosignatures.is_empty() {
+                true => self.signature.verify(&self.signer, &message),
+                false => {
+                    eprintln!("Request has cosignatures, but is not signed by a multisig account");
+                    false
+                }
+            },
+        }
+    }
+
+    /// Returns `true` if the signature is valid for the multisig address and, if `check_quorum` is `true`,
+    /// the cosignatures form a quorum.
+    ///
+    /// Verifies (challenge == challenge') && (signer == address') && (cosignatures are a quorum) where:
+    ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, message)
+    ///     address' := MultisigKey::to_address()
+    fn verify_multisig(&self, multisig_key: &MultisigKey<N>, message: &[Field<N>], check_quorum: bool) -> bool {
+        // Ensure the signature is from the shared account of the multisig key.
+        if self.signature.compute_key() != *multisig_key.compute_key() {
+            eprintln!("Request signature is not from the shared account of the multisig key");
+            return false;
+        }
+        // Ensure the signer is the multisig address.
+        if self.signer != multisig_key.to_address() {
+            eprintln!("Request signer does not match the multisig address");
+            return false;
+        }
+
+        // Retrieve pk_sig.
+        let pk_sig = self.signature.compute_key().pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = self.signature.compute_key().pr_sig();
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, signer, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([self.to_tpk(), pk_sig, pr_sig, *self.signer].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the candidate verifier challenge.
+        match N::hash_to_scalar_psd8(&preimage) {
+            // Ensure the candidate challenge matches.
+            Ok(candidate_challenge) if candidate_challenge == self.signature.challenge() => (),
+            Ok(_) => return false,
+            Err(error) => {
+                eprintln!("Failed to compute the challenge in request verification: {error}");
+                return false;
+            }
+        }
+
+        // If the quorum is not checked, the request is valid.
+        if !check_quorum {
+            return true;
+        }
+
+        // Verify the cosignatures.
+        match self.to_multisig_message() {
+            Ok(multisig_message) => multisig_key.verify(&multisig_message, &self.cosignatures),
+            Err(error) => {
+                eprintln!("Failed to construct the multisig message in request verification: {error}");
+                false
+            }
+        }
     }
 }
 
@@ -273,4 +350,80 @@ mod tests {
             assert!(request.verify(&input_types, is_root));
         }
     }
+
+    #[test]
+    fn test_sign_and_verify_multisig() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS / 10 {
+            // Sample the shared account and three signers.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let signer_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+            let signers = signer_keys.iter().map(|key| ComputeKey::try_from(key).unwrap()).collect();
+
+            // Construct a 2-of-3 multisig key and its address.
+            let multisig_key = MultisigKey::new(ComputeKey::try_from(&private_key).unwrap(), 2, signers).unwrap();
+            let address = multisig_key.to_address();
+
+            // Construct a program ID and function name.
+            let program_id = ProgramID::from_str("token.aleo").unwrap();
+            let function_name = Identifier::from_str("transfer").unwrap();
+
+            // Prepare a record belonging to the multisig address.
+            let record_string = format!(
+                "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+            );
+
+            // Construct the inputs and input types.
+            let inputs = [
+                Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+                Value::from_str(&record_string).unwrap(),
+            ];
+            let input_types =
+                vec![ValueType::from_str("amount.private").unwrap(), ValueType::from_str("token.record").unwrap()];
+
+            // Sample 'is_root'.
+            let is_root = Uniform::rand(rng);
+
+            // Compute the signed request.
+            let mut request = Request::sign_multisig(
+                &private_key,
+                &multisig_key,
+                program_id,
+                function_name,
+                inputs.into_iter(),
+                &input_types,
+                None,
+                is_root,
+                rng,
+            )
+            .unwrap();
+            assert_eq!(address, *request.signer());
+
+            // Ensure the request is invalid without a quorum.
+            assert!(!request.verify(&input_types, is_root));
+            // Ensure the request is otherwise valid.
+            assert!(request.verify_without_quorum(&input_types, is_root));
+            request.cosign(&signer_keys[2], rng).unwrap();
+            assert!(!request.verify(&input_types, is_root));
+            // Ensure a signer cannot cosign twice.
+            assert!(request.cosign(&signer_keys[2], rng).is_err());
+            // Ensure the shared account cannot cosign.
+            assert!(request.cosign(&private_key, rng).is_err());
+
+            // Ensure the request is valid with a quorum.
+            request.cosign(&signer_keys[0], rng).unwrap();
+            assert!(request.verify(&input_types, is_root));
+            assert_eq!(vec![0, 2], request.cosignatures().iter().map(|(index, _)| *index).collect::<Vec<_>>());
+
+            // Ensure the request is invalid without the multisig key.
+            let candidate = request.clone().with_multisig(None, request.cosignatures().to_vec());
+            assert!(!candidate.verify(&input_types, is_root));
+            // Ensure the request is invalid with a different multisig key.
+            let signers = multisig_key.signers().to_vec();
+            let other_key = MultisigKey::new(*multisig_key.compute_key(), 1, signers).unwrap();
+            let candidate = request.clone().with_multisig(Some(other_key), request.cosignatures().to_vec());
+            assert!(!candidate.verify(&input_types, is_root));
+        }
+    }
 }
@@ -18,6 +18,7 @@
 
 mod bitwise;
 mod bytes;
+mod operations;
 mod parse;
 mod random;
 mod serialize;
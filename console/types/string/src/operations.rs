@@ -0,0 +1,163 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types_integers::{U32, U8};
+
+impl<E: Environment> StringType<E> {
+    /// Returns the number of bytes in the string.
+    pub fn length(&self) -> U32<E> {
+        match u32::try_from(self.string.len()) {
+            Ok(num_bytes) => U32::new(num_bytes),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Note: `StringType::new` halts if the concatenation exceeds `E::MAX_STRING_BYTES`.
+        Self::new(&format!("{}{}", self.string, other.string))
+    }
+
+    /// Returns the substring between the byte offsets `start` (inclusive) and `end` (exclusive).
+    pub fn substring(&self, start: &U32<E>, end: &U32<E>) -> Self {
+        let (start, end) = (**start as usize, **end as usize);
+        // Ensure the range is within the string, and lies on character boundaries.
+        match self.string.get(start..end) {
+            Some(substring) => Self::new(substring),
+            None => E::halt(format!("Attempted to take an invalid substring [{start}, {end}) of '{}'", self.string)),
+        }
+    }
+
+    /// Returns the byte at the given `index`.
+    pub fn byte_at(&self, index: &U32<E>) -> U8<E> {
+        match self.string.as_bytes().get(**index as usize) {
+            Some(byte) => U8::new(*byte),
+            None => E::halt(format!("Attempted to access byte {index} of a string of {} bytes", self.string.len())),
+        }
+    }
+
+    /// Returns the bytes of the string, padded with zeros to `num_bytes` bytes.
+    pub fn to_bytes(&self, num_bytes: u32) -> Vec<U8<E>> {
+        // Ensure the string fits in the given number of bytes.
+        if self.string.len() > num_bytes as usize {
+            E::halt(format!("Attempted to write a string of {} bytes into {num_bytes} bytes", self.string.len()))
+        }
+        let mut bytes: Vec<_> = self.string.as_bytes().iter().map(|byte| U8::new(*byte)).collect();
+        bytes.resize(num_bytes as usize, U8::new(0));
+        bytes
+    }
+
+    /// Initializes a string from the given UTF-8 bytes.
+    pub fn from_bytes(bytes: &[U8<E>]) -> Self {
+        match String::from_utf8(bytes.iter().map(|byte| **byte).collect()) {
+            // Note: `StringType::new` halts if the string exceeds `E::MAX_STRING_BYTES`.
+            Ok(string) => Self::new(&string),
+            Err(error) => E::halt(format!("Attempted to initialize a string from invalid UTF-8: {error}")),
+        }
+    }
+
+    /// Initializes a string from the first `length` UTF-8 bytes, where the remaining bytes must be zeros.
+    /// This is the inverse of `StringType::to_bytes`.
+    pub fn from_padded_bytes(bytes: &[U8<E>], length: &U32<E>) -> Self {
+        let length = **length as usize;
+        // Ensure the length is within the bytes, and the remaining bytes are zeros.
+        match bytes.get(length..) {
+            Some(padding) if padding.iter().all(|byte| **byte == 0) => Self::from_bytes(&bytes[..length]),
+            _ => E::halt(format!("Attempted to initialize a string of {length} bytes from invalid padded bytes")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_length_and_concat() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let candidate = a.concat(&b);
+            assert_eq!(&*candidate, &format!("{}{}", &*a, &*b));
+            assert_eq!(*candidate.length(), *a.length() + *b.length());
+        }
+
+        // Ensure the concatenation halts if it exceeds the maximum number of bytes.
+        let a = StringType::<CurrentEnvironment>::new(&"a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize));
+        assert!(std::panic::catch_unwind(|| a.concat(&StringType::new("b"))).is_err());
+    }
+
+    #[test]
+    fn test_substring_and_byte_at() {
+        let string = StringType::<CurrentEnvironment>::new("héllo.aleo");
+
+        assert_eq!(&*string.substring(&U32::new(0), &U32::new(6)), "héllo");
+        assert_eq!(&*string.substring(&U32::new(7), &U32::new(11)), "aleo");
+        assert_eq!(&*string.substring(&U32::new(3), &U32::new(3)), "");
+        assert_eq!(*string.byte_at(&U32::new(6)), b'.');
+        assert_eq!(*string.byte_at(&U32::new(1)), 0xC3);
+
+        // Ensure an out-of-range or reversed substring halts.
+        assert!(std::panic::catch_unwind(|| string.substring(&U32::new(0), &U32::new(12))).is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(&U32::new(4), &U32::new(2))).is_err());
+        // Ensure a substring that splits a character halts.
+        assert!(std::panic::catch_unwind(|| string.substring(&U32::new(2), &U32::new(6))).is_err());
+        // Ensure an out-of-range byte halts.
+        assert!(std::panic::catch_unwind(|| string.byte_at(&U32::new(11))).is_err());
+    }
+
+    #[test]
+    fn test_to_bytes_and_from_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Check the round trip, without padding.
+            let bytes = expected.to_bytes(u32::try_from(expected.len()).unwrap());
+            assert_eq!(bytes.iter().map(|byte| **byte).collect::<Vec<_>>(), expected.as_bytes());
+            assert_eq!(StringType::from_bytes(&bytes), expected);
+
+            // Check the padding.
+            let bytes = expected.to_bytes(CurrentEnvironment::MAX_STRING_BYTES);
+            assert_eq!(bytes.len(), CurrentEnvironment::MAX_STRING_BYTES as usize);
+            assert!(bytes[expected.len()..].iter().all(|byte| **byte == 0));
+
+            // Check the round trip, with padding.
+            assert_eq!(StringType::from_padded_bytes(&bytes, &expected.length()), expected);
+        }
+
+        // Ensure a string that does not fit halts.
+        let string = StringType::<CurrentEnvironment>::new("aleo");
+        assert!(std::panic::catch_unwind(|| string.to_bytes(3)).is_err());
+        // Ensure invalid UTF-8 halts.
+        let bytes = [0xE2u8, 0x28, 0xA1].map(U8::<CurrentEnvironment>::new);
+        assert!(std::panic::catch_unwind(|| StringType::<CurrentEnvironment>::from_bytes(&bytes)).is_err());
+        // Ensure padding that is not zeros, or a length beyond the bytes, halts.
+        let bytes = string.to_bytes(6);
+        assert!(std::panic::catch_unwind(|| StringType::from_padded_bytes(&bytes, &U32::new(3))).is_err());
+        assert!(std::panic::catch_unwind(|| StringType::from_padded_bytes(&bytes, &U32::new(7))).is_err());
+        assert_eq!(StringType::from_padded_bytes(&bytes, &U32::new(5)), StringType::new("aleo\0"));
+    }
+}
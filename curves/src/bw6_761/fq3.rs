@@ -0,0 +1,162 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+use snarkvm_fields::{field, Fp3, Fp3Parameters};
+use snarkvm_utilities::biginteger::BigInteger768 as BigInteger;
+
+use crate::bw6_761::Fq;
+
+pub type Fq3 = Fp3<Fq3Parameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Fq3Parameters;
+
+impl Fp3Parameters for Fq3Parameters {
+    type Fp = Fq;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP3_C1: [Fq; 3] = [
+        // NONRESIDUE^((q^0 - 1) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x202ffffffff85d5,
+                0x5a5826358fff8ce7,
+                0x9e996e43827faade,
+                0xda6aff320ee47df4,
+                0xece9cb3e1d94b80b,
+                0xc0e667a25248240b,
+                0xa74da5bfdcad3905,
+                0x2352e7fe462f2103,
+                0x7b56588008b1c87c,
+                0x45848a63e711022f,
+                0xd7a81ebb9f65a9df,
+                0x51f77ef127e87d,
+            ])
+        ),
+        // NONRESIDUE^((q^1 - 1) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x7f96b51bd840c549,
+                0xd59782096496171f,
+                0x49b046fd9ce14bbc,
+                0x4b6163bba7527a56,
+                0xef6c92fb771d59f1,
+                0x425bedbac1dfdc7,
+                0xd3ac39de759c0ffd,
+                0x9f43ed0e063a81d0,
+                0x5bd7d20b4f9a3ce2,
+                0x411f03c36cf5c3c,
+                0x2d658fd49661c472,
+                0x1100249ae760b93,
+            ])
+        ),
+        // NONRESIDUE^((q^2 - 1) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x67a04ae427bfb5f8,
+                0x9d32d491eb6a5cff,
+                0x43d03c1cb68051d4,
+                0xb75ca96f69859a5,
+                0x763497f5325ec60,
+                0x48076b5c278dd94d,
+                0x8ca3965ff91efd06,
+                0x1e6077657ea02f5d,
+                0xcdd6c153a8c37724,
+                0x28b5b634e5c22ea4,
+                0x9e01e3efd42e902c,
+                0xe3d6815769a804,
+            ])
+        ),
+    ];
+    const FROBENIUS_COEFF_FP3_C2: [Fq; 3] = [
+        // NONRESIDUE^((2 * q^0 - 2) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x202ffffffff85d5,
+                0x5a5826358fff8ce7,
+                0x9e996e43827faade,
+                0xda6aff320ee47df4,
+                0xece9cb3e1d94b80b,
+                0xc0e667a25248240b,
+                0xa74da5bfdcad3905,
+                0x2352e7fe462f2103,
+                0x7b56588008b1c87c,
+                0x45848a63e711022f,
+                0xd7a81ebb9f65a9df,
+                0x51f77ef127e87d,
+            ])
+        ),
+        // NONRESIDUE^((2 * q^1 - 2) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x67a04ae427bfb5f8,
+                0x9d32d491eb6a5cff,
+                0x43d03c1cb68051d4,
+                0xb75ca96f69859a5,
+                0x763497f5325ec60,
+                0x48076b5c278dd94d,
+                0x8ca3965ff91efd06,
+                0x1e6077657ea02f5d,
+                0xcdd6c153a8c37724,
+                0x28b5b634e5c22ea4,
+                0x9e01e3efd42e902c,
+                0xe3d6815769a804,
+            ])
+        ),
+        // NONRESIDUE^((2 * q^2 - 2) / 3)
+        field!(
+            Fq,
+            BigInteger([
+                0x7f96b51bd840c549,
+                0xd59782096496171f,
+                0x49b046fd9ce14bbc,
+                0x4b6163bba7527a56,
+                0xef6c92fb771d59f1,
+                0x425bedbac1dfdc7,
+                0xd3ac39de759c0ffd,
+                0x9f43ed0e063a81d0,
+                0x5bd7d20b4f9a3ce2,
+                0x411f03c36cf5c3c,
+                0x2d658fd49661c472,
+                0x1100249ae760b93,
+            ])
+        ),
+    ];
+    /// NONRESIDUE = -4
+    const NONRESIDUE: Fq = field!(
+        Fq,
+        BigInteger([
+            0xe12e00000001e9c2,
+            0x63c1e3faa001cd69,
+            0xb1b4384fcbe29cf6,
+            0xc79630bc713d5a1d,
+            0x30127ac071851e2d,
+            0x979f350dcd36af1,
+            0x6a66defed8b361f2,
+            0x53abac78b24d4e23,
+            0xb7ab89dede485a92,
+            0x5c3a0745675e8452,
+            0x446f17918c5f5700,
+            0xfdf24e3267fa1e,
+        ])
+    );
+}
@@ -0,0 +1,193 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, Field, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger384, BigInteger768},
+    BitIteratorBE,
+};
+
+use crate::{
+    bw6_761::{Fq, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bw6_761G1Parameters;
+
+impl ModelParameters for Bw6_761G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bw6_761G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// B1 is unused, as scalar multiplication does not use the GLV decomposition.
+    const B1: Fr = field!(Fr, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// B2 is unused, as scalar multiplication does not use the GLV decomposition.
+    const B2: Fr = field!(Fr, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// COFACTOR =
+    /// 26642435879335816683987677701488073867751118270052650655942102502312977592501693353047140953112195348280268661194876
+    const COFACTOR: &'static [u64] = &[
+        0x3de580000000007c,
+        0x832ba4061000003b,
+        0xc61c554757551c0c,
+        0xc856a0853c9db94c,
+        0x2c77d5ac34cb12ef,
+        0xad1972339049ce76,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 91141326767669940707819291241958318717982251277713150053234367522357946997763584490607453720072232540829942217804
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x6cbc6719306b799,
+            0x35e466ec4fc64c14,
+            0x174a5cff3b221c74,
+            0x512b12e900a45d2e,
+            0x62eba10945bdbdf0,
+            0xfe1019f053865a
+        ])
+    );
+    /// PHI = a primitive cube root of unity in Fq
+    const PHI: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x67a04ae427bfb5f8,
+            0x9d32d491eb6a5cff,
+            0x43d03c1cb68051d4,
+            0xb75ca96f69859a5,
+            0x763497f5325ec60,
+            0x48076b5c278dd94d,
+            0x8ca3965ff91efd06,
+            0x1e6077657ea02f5d,
+            0xcdd6c153a8c37724,
+            0x28b5b634e5c22ea4,
+            0x9e01e3efd42e902c,
+            0xe3d6815769a804,
+        ])
+    );
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x756769f442c231aa,
+            0x6e32813404448615,
+            0xb2b9914a353a4acb,
+            0xec54c9b5c69584ca,
+            0x6899ce22b746d7da,
+            0xa178088025a774
+        ])
+    );
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger768([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = -1
+    const WEIERSTRASS_B: Fq = field!(
+        Fq,
+        BigInteger768([
+            0xf29a000000007ab6,
+            0x8c391832e000739b,
+            0x77738a6b6870f959,
+            0xbe36179047832b03,
+            0x84f3089e56574722,
+            0xc5a3614ac0b1d984,
+            0x5c81153f4906e9fe,
+            0x4d28be3a9f55c815,
+            0xd72c1d6f77d5f5c5,
+            0x73a18e069ac04458,
+            0xf9dfaa846595555f,
+            0xd0f0a60a5be58c,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        let mut output = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            output.double_in_place();
+            if bit {
+                output += &p;
+            }
+        }
+        output
+    }
+}
+
+///
+/// G1_GENERATOR_X =
+/// 5121082781502305659180673370797932802982723165543122975335209418297923345190975559367024470794567213188867624617939698818627134048004915564891369096213721202693630090148097888923493708182442885908076346607300083583163982163789167
+///
+pub const G1_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger768([
+        0x851a1c1166ae2603,
+        0xeea72e25492571be,
+        0xa6f20c75f7feab02,
+        0xeeea8a1b066c47a4,
+        0x6469c9e9402afc4f,
+        0x4cd9755dfe95e5ba,
+        0x3a1be33544ef7b02,
+        0x1e7fe0555536b119,
+        0xfd947c7fee73da05,
+        0xcffde31744f837a0,
+        0xa7b583036935c864,
+        0x9918c73dcc124a,
+    ])
+);
+
+///
+/// G1_GENERATOR_Y =
+/// 3936891438836912583780051937508657570812555345221986954460153541952284123191322673907692687918062549220672207508016306900511821136915723707181225276750353973961376292016478674329987421847947883311993206004548631860683957877121757
+///
+pub const G1_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger768([
+        0xa3c13889f58ed694,
+        0xee5cb8acda0ea650,
+        0xcea7c1807dfc6e02,
+        0xdc672db2da00df5d,
+        0xcde53070a22b574d,
+        0x4f3dc271f97fe60b,
+        0xd5a2d25bb5f4cc8c,
+        0xd68088f33580a980,
+        0x760b0d826b393143,
+        0xe54149ee3970878e,
+        0xc62f69887a0e0ed1,
+        0x30ceaa8cbab39d,
+    ])
+);
@@ -0,0 +1,193 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, Field, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger384, BigInteger768},
+    BitIteratorBE,
+};
+
+use crate::{
+    bw6_761::{Fq, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bw6_761G2Parameters;
+
+impl ModelParameters for Bw6_761G2Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bw6_761G2Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+    /// B1 is unused, as scalar multiplication does not use the GLV decomposition.
+    const B1: Fr = field!(Fr, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// B2 is unused, as scalar multiplication does not use the GLV decomposition.
+    const B2: Fr = field!(Fr, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// COFACTOR =
+    /// 26642435879335816683987677701488073867751118270052650655942102502312977592501693353047140953112195348280268661194869
+    const COFACTOR: &'static [u64] = &[
+        0x3de5800000000075,
+        0x832ba4061000003b,
+        0xc61c554757551c0c,
+        0xc856a0853c9db94c,
+        0x2c77d5ac34cb12ef,
+        0xad1972339049ce76,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 214911522365886453591244899095480747723790054550866810551297776298664428889000553861210287833206024638187939842124
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger384([
+            0xc789f8066b9af9ee,
+            0xca6c6dc67d6d42ce,
+            0x87d7a8a9da8db962,
+            0xaf9ef54b967af20c,
+            0xd2be337d7d38f0a5,
+            0x5ef152cf9ffea4
+        ])
+    );
+    /// PHI = a primitive cube root of unity in Fq
+    const PHI: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x67a04ae427bfb5f8,
+            0x9d32d491eb6a5cff,
+            0x43d03c1cb68051d4,
+            0xb75ca96f69859a5,
+            0x763497f5325ec60,
+            0x48076b5c278dd94d,
+            0x8ca3965ff91efd06,
+            0x1e6077657ea02f5d,
+            0xcdd6c153a8c37724,
+            0x28b5b634e5c22ea4,
+            0x9e01e3efd42e902c,
+            0xe3d6815769a804,
+        ])
+    );
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x756769f442c231aa,
+            0x6e32813404448615,
+            0xb2b9914a353a4acb,
+            0xec54c9b5c69584ca,
+            0x6899ce22b746d7da,
+            0xa178088025a774
+        ])
+    );
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger768([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 4
+    const WEIERSTRASS_B: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x136efffffffe16c9,
+            0x82cf5a6dcffe3319,
+            0x6458c05f1f0e0741,
+            0xd10ae605e52a4eda,
+            0x41ca591c0266e100,
+            0x7d0fd59c3626929f,
+            0x9967dc004d00c112,
+            0x1ccff9c033379af5,
+            0x9ad6ec10a23f63af,
+            0x5cec11251a72c235,
+            0x8d18b1ae789ba83e,
+            0x24f5d6c91bd3ec,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        let mut output = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            output.double_in_place();
+            if bit {
+                output += &p;
+            }
+        }
+        output
+    }
+}
+
+///
+/// G2_GENERATOR_X =
+/// 4299495046121976197967899669570453663523609133273326781652351086315825538613647196868186765217397067654540962955975840176693419657193486845273767814170238104346390446211356851381676719718477415944688400098442139983341339355564981
+///
+pub const G2_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger768([
+        0x22aab15d6e6db785,
+        0x2d4ff25bb7779d47,
+        0xd6e307f08fd0c1a9,
+        0xe1f9a0520c1f952d,
+        0xa4fb4fd53ad518d4,
+        0xf19a04a7a47da06d,
+        0xb40146bfc4efa321,
+        0x42eaf4df92bc1fcf,
+        0x941a4672957418ab,
+        0xce598708852a92dc,
+        0xc5676a44843ae7bb,
+        0x2fc541f69a8dbe,
+    ])
+);
+
+///
+/// G2_GENERATOR_Y =
+/// 5390068907621955088169558704058566111636841949933812064258391788701969884935470780815806205108266591280868872340793447961301446566465351736303970166703756567269434202182284388197563290670801062112376259336491499915806321277334517
+///
+pub const G2_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger768([
+        0x797e6c45cf5a4db,
+        0xf2af166031457596,
+        0x40a34fe935a0bd36,
+        0xb5b1538e72692756,
+        0x8b2071df96488d9b,
+        0x41914b18661e79e2,
+        0xc5ddedd06903d6f2,
+        0x6dda5abf0c1df1aa,
+        0xd561192fc0f03d13,
+        0xe45ff24a1d016a19,
+        0x14d2af10a474f091,
+        0xa11703f4f8d9f4,
+    ])
+);
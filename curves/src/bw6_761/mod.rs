@@ -0,0 +1,45 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod fr;
+#[doc(inline)]
+pub use fr::*;
+
+pub mod fq;
+#[doc(inline)]
+pub use fq::*;
+
+pub mod fq3;
+#[doc(inline)]
+pub use fq3::*;
+
+pub mod fq6;
+#[doc(inline)]
+pub use fq6::*;
+
+pub mod g1;
+#[doc(inline)]
+pub use g1::*;
+
+pub mod g2;
+#[doc(inline)]
+pub use g2::*;
+
+pub mod parameters;
+#[doc(inline)]
+pub use parameters::*;
+
+#[cfg(test)]
+mod tests;
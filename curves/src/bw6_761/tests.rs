@@ -0,0 +1,228 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    bw6_761::{
+        g1::Bw6_761G1Parameters,
+        g2::Bw6_761G2Parameters,
+        Bw6_761,
+        Fq,
+        Fq3,
+        Fq6,
+        Fr,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingEngine,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    BitIteratorBE,
+};
+
+use rand::Rng;
+use std::ops::{Mul, MulAssign};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bw6_761_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bw6_761_fq3() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rng.gen();
+        let b: Fq3 = rng.gen();
+        field_test(a, b, &mut rng);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq3>(&mut rng);
+}
+
+#[test]
+fn test_bw6_761_fq6() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rng.gen();
+        let h: Fq6 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq6>(&mut rng);
+}
+
+#[test]
+fn test_fq3_mul_by_01() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..100 {
+        let c0 = Fq::rand(&mut rng);
+        let c1 = Fq::rand(&mut rng);
+        let mut a = Fq3::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_01(&c0, &c1);
+        b.mul_assign(&Fq3::new(c0, c1, Fq::zero()));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_014() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..100 {
+        let c0 = Fq::rand(&mut rng);
+        let c1 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_014(&c0, &c1, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, c1, Fq::zero()), Fq3::new(Fq::zero(), c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_034() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..100 {
+        let c0 = Fq::rand(&mut rng);
+        let c3 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, Fq::zero(), Fq::zero()), Fq3::new(c3, c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_g1_projective_mul() {
+    let mut rng = TestRng::default();
+
+    let point = G1Projective::rand(&mut rng);
+    let scalar = Fr::rand(&mut rng);
+    let affine = point.to_affine();
+    assert_eq!(point.mul(scalar), affine.mul(scalar));
+    assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G1Projective>(&mut rng);
+    sw_tests::<Bw6_761G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G2Projective>(&mut rng);
+    sw_tests::<Bw6_761G2Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bw6_761::pairing(sa, b);
+    let ans2 = Bw6_761::pairing(a, sb);
+    let ans3 = Bw6_761::pairing(a, b).pow(s.to_bigint());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq6::one());
+    assert_ne!(ans2, Fq6::one());
+    assert_ne!(ans3, Fq6::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
+}
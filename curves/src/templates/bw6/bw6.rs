@@ -0,0 +1,206 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+        short_weierstrass_jacobian,
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+    AffineCurve,
+};
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+use serde::{Deserialize, Serialize};
+
+pub trait Bw6Parameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Hash + Send + Sync + Sized {
+    /// The bits of the first Miller loop count, `x + 1`.
+    const ATE_LOOP_COUNT_1: &'static [u64];
+    /// The NAF digits of the second Miller loop count, `x^3 - x^2 - x`, least significant first.
+    const ATE_LOOP_COUNT_2: &'static [i8];
+    const TWIST_TYPE: TwistType;
+    /// The exponent of the hard part of the final exponentiation, `(p^2 - p + 1) / r`.
+    const FINAL_EXPONENT_LAST_CHUNK: &'static [u64];
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp3Params = Self::Fp3Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+            BaseField = Self::Fp,
+            ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+        >;
+
+    fn g1_is_in_correct_subgroup(p: &short_weierstrass_jacobian::Affine<Self::G1Parameters>) -> bool {
+        p.mul_bits(BitIteratorBE::new(<Self::G1Parameters as ModelParameters>::ScalarField::characteristic())).is_zero()
+    }
+
+    fn g2_is_in_correct_subgroup(p: &short_weierstrass_jacobian::Affine<Self::G2Parameters>) -> bool {
+        p.mul_bits(BitIteratorBE::new(<Self::G1Parameters as ModelParameters>::ScalarField::characteristic())).is_zero()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Bw6<P: Bw6Parameters>(PhantomData<P>);
+
+type CoeffTriplet<T> = (T, T, T);
+
+impl<P: Bw6Parameters> Bw6<P> {
+    /// Evaluate the line function at point p.
+    fn ell(f: &mut Fp6<P::Fp6Params>, coeffs: &CoeffTriplet<P::Fp>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+}
+
+impl<P: Bw6Parameters> PairingEngine for Bw6<P>
+where
+    G1Affine<P>: PairingCurve<
+            BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+            ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+            Projective = G1Projective<P>,
+            PairWith = G2Affine<P>,
+            Prepared = G1Prepared<P>,
+            PairingResult = Fp6<P::Fp6Params>,
+        >,
+    G2Affine<P>: PairingCurve<
+            BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+            ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+            Projective = G2Projective<P>,
+            PairWith = G1Affine<P>,
+            Prepared = G2Prepared<P>,
+            PairingResult = Fp6<P::Fp6Params>,
+        >,
+{
+    type Fq = P::Fp;
+    type Fqe = P::Fp;
+    type Fqk = Fp6<P::Fp6Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        // Computing the optimal ate pairing following
+        // https://eprint.iacr.org/2020/351.pdf, Algorithm 5,
+        // as f_{x+1,Q}(P) * frobenius(f_{x^3-x^2-x,Q}(P)).
+        let mut pairs_1 = vec![];
+        let mut pairs_2 = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs_1.push((p, q.ell_coeffs_1.iter()));
+                pairs_2.push((p, q.ell_coeffs_2.iter()));
+            }
+        }
+
+        // f_1 = f_{x+1,Q}(P)
+        let mut f_1 = Self::Fqk::one();
+
+        for i in BitIteratorBE::new(P::ATE_LOOP_COUNT_1).skip(1) {
+            f_1.square_in_place();
+
+            for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                    Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        // f_2 = f_{x^3-x^2-x,Q}(P)
+        let mut f_2 = Self::Fqk::one();
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            if i != P::ATE_LOOP_COUNT_2.len() - 1 {
+                f_2.square_in_place();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if P::ATE_LOOP_COUNT_2[i - 1] != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                    Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        f_2.frobenius_map(1);
+
+        f_1 * f_2
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // Easy part: f^((p^3 - 1)(p + 1)).
+
+        // f1 = f.conjugate() = f^(p^3)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        match f.inverse() {
+            Some(f2) => {
+                // r = f^(p^3 - 1)
+                let mut r = f1 * f2;
+
+                // f2 = f^(p^3 - 1)
+                let f2 = r;
+                // r = f^((p^3 - 1)(p + 1))
+                r.frobenius_map(1);
+                r *= &f2;
+
+                // Hard part: r^((p^2 - p + 1) / r).
+                Some(r.pow(P::FINAL_EXPONENT_LAST_CHUNK))
+            }
+            None => None,
+        }
+    }
+}
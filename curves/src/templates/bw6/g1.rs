@@ -0,0 +1,60 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::{
+        bw6::Bw6Parameters,
+        short_weierstrass_jacobian::{Affine, Projective},
+    },
+    traits::AffineCurve,
+};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{serialize::*, FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+pub type G1Affine<P> = Affine<<P as Bw6Parameters>::G1Parameters>;
+pub type G1Projective<P> = Projective<<P as Bw6Parameters>::G1Parameters>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct G1Prepared<P: Bw6Parameters>(pub G1Affine<P>);
+
+impl<P: Bw6Parameters> G1Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn from_affine(p: G1Affine<P>) -> Self {
+        G1Prepared(p)
+    }
+}
+
+impl<P: Bw6Parameters> Default for G1Prepared<P> {
+    fn default() -> Self {
+        G1Prepared(G1Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Bw6Parameters> ToBytes for G1Prepared<P> {
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl<P: Bw6Parameters> FromBytes for G1Prepared<P> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(G1Affine::<P>::read_le(reader)?))
+    }
+}
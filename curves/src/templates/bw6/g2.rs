@@ -0,0 +1,185 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::Bw6Parameters,
+        short_weierstrass_jacobian::{Affine, Projective},
+    },
+    traits::{AffineCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::{bititerator::BitIteratorBE, serialize::*, ToBytes};
+
+use std::io::{Result as IoResult, Write};
+
+pub type G2Affine<P> = Affine<<P as Bw6Parameters>::G2Parameters>;
+pub type G2Projective<P> = Projective<<P as Bw6Parameters>::G2Parameters>;
+type CoeffTriplet<T> = (T, T, T);
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct G2Prepared<P: Bw6Parameters> {
+    // Stores the coefficients of the line evaluations for both Miller loops,
+    // as calculated in https://eprint.iacr.org/2020/351.pdf
+    pub ell_coeffs_1: Vec<CoeffTriplet<P::Fp>>,
+    pub ell_coeffs_2: Vec<CoeffTriplet<P::Fp>>,
+    pub infinity: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct G2HomProjective<P: Bw6Parameters> {
+    x: P::Fp,
+    y: P::Fp,
+    z: P::Fp,
+}
+
+impl<P: Bw6Parameters> Default for G2Prepared<P> {
+    fn default() -> Self {
+        Self::from_affine(G2Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Bw6Parameters> ToBytes for G2Prepared<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for ell_coeffs in [&self.ell_coeffs_1, &self.ell_coeffs_2] {
+            (ell_coeffs.len() as u32).write_le(&mut writer)?;
+            for coeff in ell_coeffs {
+                coeff.0.write_le(&mut writer)?;
+                coeff.1.write_le(&mut writer)?;
+                coeff.2.write_le(&mut writer)?;
+            }
+        }
+        self.infinity.write_le(writer)
+    }
+}
+
+impl<P: Bw6Parameters> FromBytes for G2Prepared<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut read_coeffs = || -> IoResult<Vec<CoeffTriplet<P::Fp>>> {
+            let ell_coeffs_len: u32 = FromBytes::read_le(&mut reader)?;
+            let mut ell_coeffs = Vec::new();
+            for _ in 0..ell_coeffs_len {
+                let coeff_1: P::Fp = FromBytes::read_le(&mut reader)?;
+                let coeff_2: P::Fp = FromBytes::read_le(&mut reader)?;
+                let coeff_3: P::Fp = FromBytes::read_le(&mut reader)?;
+                ell_coeffs.push((coeff_1, coeff_2, coeff_3));
+            }
+            Ok(ell_coeffs)
+        };
+        let ell_coeffs_1 = read_coeffs()?;
+        let ell_coeffs_2 = read_coeffs()?;
+
+        let infinity: bool = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { ell_coeffs_1, ell_coeffs_2, infinity })
+    }
+}
+
+impl<P: Bw6Parameters> G2Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine<P>) -> Self {
+        if q.is_zero() {
+            return Self { ell_coeffs_1: vec![], ell_coeffs_2: vec![], infinity: true };
+        }
+
+        // `one_half` = 1/2 in the field.
+        let one_half = P::Fp::half();
+
+        // The first Miller loop runs over the bits of `ATE_LOOP_COUNT_1`.
+        let mut r = G2HomProjective { x: q.x, y: q.y, z: P::Fp::one() };
+        let bit_iterator = BitIteratorBE::new(P::ATE_LOOP_COUNT_1);
+        let mut ell_coeffs_1 = Vec::with_capacity(bit_iterator.len() * 3 / 2);
+
+        for i in bit_iterator.skip(1) {
+            ell_coeffs_1.push(doubling_step::<P>(&mut r, &one_half));
+
+            if i {
+                ell_coeffs_1.push(addition_step::<P>(&mut r, &q));
+            }
+        }
+
+        // The second Miller loop runs over the NAF digits of `ATE_LOOP_COUNT_2`.
+        let mut r = G2HomProjective { x: q.x, y: q.y, z: P::Fp::one() };
+        let neg_q = -q;
+        let mut ell_coeffs_2 = Vec::with_capacity(P::ATE_LOOP_COUNT_2.len() * 3 / 2);
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            ell_coeffs_2.push(doubling_step::<P>(&mut r, &one_half));
+
+            match P::ATE_LOOP_COUNT_2[i - 1] {
+                1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &q)),
+                -1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &neg_q)),
+                _ => continue,
+            }
+        }
+
+        Self { ell_coeffs_1, ell_coeffs_2, infinity: false }
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn doubling_step<B: Bw6Parameters>(r: &mut G2HomProjective<B>, two_inv: &B::Fp) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+
+    let mut a = r.x * r.y;
+    a *= two_inv;
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = B::G2Parameters::WEIERSTRASS_B * (c.double() + c);
+    let f = e.double() + e;
+    let mut g = b + f;
+    g *= two_inv;
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_square = e.square();
+
+    r.x = a * (b - f);
+    r.y = g.square() - (e_square.double() + e_square);
+    r.z = b * h;
+    match B::TWIST_TYPE {
+        TwistType::M => (i, j.double() + j, -h),
+        TwistType::D => (-h, j.double() + j, i),
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn addition_step<B: Bw6Parameters>(r: &mut G2HomProjective<B>, q: &G2Affine<B>) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+    let theta = r.y - (q.y * r.z);
+    let lambda = r.x - (q.x * r.z);
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+    r.x = lambda * h;
+    r.y = theta * (g - h) - (e * r.y);
+    r.z *= &e;
+    let j = theta * q.x - (lambda * q.y);
+
+    match B::TWIST_TYPE {
+        TwistType::M => (j, -theta, lambda),
+        TwistType::D => (lambda, -theta, j),
+    }
+}
@@ -0,0 +1,466 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Field, Fp3, Fp3Parameters, One, Zero};
+use snarkvm_utilities::{rand::Uniform, serialize::*, FromBytes, ToBits, ToBytes};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::Hash,
+    io::{Read, Result as IoResult, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+pub trait Fp6Parameters: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + Hash + Send + Sync {
+    type Fp3Params: Fp3Parameters;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP6_C1: [Fp<Self>; 6];
+}
+
+type Fp<P> = <<P as Fp6Parameters>::Fp3Params as Fp3Parameters>::Fp;
+
+/// An element of Fp6, represented by c0 + c1 * v, where v^2 = u.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Fp6<P: Fp6Parameters> {
+    pub c0: Fp3<P::Fp3Params>,
+    pub c1: Fp3<P::Fp3Params>,
+}
+
+impl<P: Fp6Parameters> Fp6<P> {
+    /// Initializes an element of `Fp6` from two `Fp3` elements.
+    pub const fn new(c0: Fp3<P::Fp3Params>, c1: Fp3<P::Fp3Params>) -> Self {
+        Self { c0, c1 }
+    }
+}
+
+impl<P: Fp6Parameters> Fp6<P> {
+    pub fn conjugate(&mut self) {
+        self.c1 = self.c1.neg();
+    }
+
+    pub fn mul_by_fp(&mut self, element: &Fp<P>) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+
+    /// Multiply by quadratic nonresidue u.
+    #[inline(always)]
+    pub(crate) fn mul_fp3_by_nonresidue(fe: &Fp3<P::Fp3Params>) -> Fp3<P::Fp3Params> {
+        let new_c0 = P::Fp3Params::mul_fp_by_nonresidue(&fe.c2);
+        let new_c1 = fe.c0;
+        let new_c2 = fe.c1;
+        Fp3::new(new_c0, new_c1, new_c2)
+    }
+
+    pub fn mul_by_034(&mut self, c0: &Fp<P>, c3: &Fp<P>, c4: &Fp<P>) {
+        let mut a = self.c0;
+        a.mul_by_fp(c0);
+        let mut b = self.c1;
+        b.mul_by_01(c3, c4);
+
+        let c0 = *c0 + c3;
+        let c1 = c4;
+        let mut e = self.c0 + self.c1;
+        e.mul_by_01(&c0, c1);
+        self.c1 = e - (a + b);
+        self.c0 = a + Self::mul_fp3_by_nonresidue(&b);
+    }
+
+    pub fn mul_by_014(&mut self, c0: &Fp<P>, c1: &Fp<P>, c4: &Fp<P>) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        self.c1.add_assign(self.c0);
+        self.c1.mul_by_01(c0, &o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = bb;
+        self.c0 = Self::mul_fp3_by_nonresidue(&self.c0);
+        self.c0.add_assign(aa);
+    }
+}
+
+impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "Fp6_2over3({} + {} * v)", self.c0, self.c1)
+    }
+}
+
+impl<P: Fp6Parameters> Distribution<Fp6<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp6<P> {
+        Fp6::new(Uniform::rand(rng), Uniform::rand(rng))
+    }
+}
+
+impl<P: Fp6Parameters> Zero for Fp6<P> {
+    fn zero() -> Self {
+        Self::new(Fp3::zero(), Fp3::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl<P: Fp6Parameters> One for Fp6<P> {
+    fn one() -> Self {
+        Self::new(Fp3::one(), Fp3::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero()
+    }
+}
+
+impl<P: Fp6Parameters> Field for Fp6<P> {
+    type BasePrimeField = <Fp3<P::Fp3Params> as Field>::BasePrimeField;
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        Self::new(Fp3::from_base_prime_field(other), Fp3::zero())
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        Fp3::<P::Fp3Params>::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut copy = *self;
+        copy.double_in_place();
+        copy
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let split_at = bytes.len() / 2;
+        if let Some(c0) = Fp3::<P::Fp3Params>::from_random_bytes(&bytes[..split_at]) {
+            if let Some((c1, flags)) = Fp3::<P::Fp3Params>::from_random_bytes_with_flags::<F>(&bytes[split_at..]) {
+                return Some((Fp6::new(c0, c1), flags));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+
+        self.c1.mul_by_fp(&P::FROBENIUS_COEFF_FP6_C1[power % 6]);
+    }
+
+    fn square(&self) -> Self {
+        let mut copy = *self;
+        copy.square_in_place();
+        copy
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        let mut ab = self.c0;
+        ab.mul_assign(&self.c1);
+        let mut c0c1 = self.c0;
+        c0c1.add_assign(self.c1);
+        let mut c0 = self.c1;
+        c0 = Self::mul_fp3_by_nonresidue(&c0);
+        c0.add_assign(self.c0);
+        c0.mul_assign(&c0c1);
+        c0.sub_assign(&ab);
+        self.c1 = ab;
+        self.c1.add_assign(ab);
+        ab = Self::mul_fp3_by_nonresidue(&ab);
+        c0.sub_assign(&ab);
+        self.c0 = c0;
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            let mut c0s = self.c0;
+            c0s.square_in_place();
+            let mut c1s = self.c1;
+            c1s.square_in_place();
+            c1s = Self::mul_fp3_by_nonresidue(&c1s);
+            c0s.sub_assign(&c1s);
+
+            c0s.inverse().map(|t| {
+                let mut tmp = Fp6::new(t, t);
+                tmp.c0.mul_assign(&self.c0);
+                tmp.c1.mul_assign(&self.c1);
+                tmp.c1 = -tmp.c1;
+
+                tmp
+            })
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        match self.inverse() {
+            Some(inv) => {
+                *self = inv;
+                Some(self)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<P: Fp6Parameters> Neg for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        let mut copy = Self::zero();
+        copy.c0 = self.c0.neg();
+        copy.c1 = self.c1.neg();
+        copy
+    }
+}
+
+impl_add_sub_from_field_ref!(Fp6, Fp6Parameters);
+impl_mul_div_from_field_ref!(Fp6, Fp6Parameters);
+
+impl<'a, P: Fp6Parameters> Add<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Sub<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Mul<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Div<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> AddAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> SubAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> MulAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, other: &Self) {
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        self.c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        self.c0 = v0 + Self::mul_fp3_by_nonresidue(&v1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> DivAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: Fp6Parameters> Ord for Fp6<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let c1_cmp = self.c1.cmp(&other.c1);
+        if c1_cmp == Ordering::Equal { self.c0.cmp(&other.c0) } else { c1_cmp }
+    }
+}
+
+impl<P: Fp6Parameters> PartialOrd for Fp6<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp6Parameters> From<u128> for Fp6<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u64> for Fp6<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u32> for Fp6<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u16> for Fp6<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u8> for Fp6<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> ToBits for Fp6<P> {
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.c0.write_bits_le(vec);
+        self.c1.write_bits_le(vec);
+    }
+
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.c0.write_bits_be(vec);
+        self.c1.write_bits_be(vec);
+    }
+}
+
+impl<P: Fp6Parameters> ToBytes for Fp6<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write_le(&mut writer)?;
+        self.c1.write_le(&mut writer)
+    }
+}
+
+impl<P: Fp6Parameters> FromBytes for Fp6<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = Fp3::read_le(&mut reader)?;
+        let c1 = Fp3::read_le(&mut reader)?;
+        Ok(Fp6::new(c0, c1))
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalSerializeWithFlags for Fp6<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, mut writer: W, flags: F) -> Result<(), SerializationError> {
+        self.c0.serialize_uncompressed(&mut writer)?;
+        self.c1.serialize_with_flags(&mut writer, flags)?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.c0.uncompressed_size() + self.c1.serialized_size_with_flags::<F>()
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalSerialize for Fp6<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: W, _compress: Compress) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.c0.serialized_size(compress) + self.c1.serialized_size(compress)
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalDeserializeWithFlags for Fp6<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(mut reader: R) -> Result<(Self, F), SerializationError> {
+        let c0 = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let (c1, flags) = Fp3::deserialize_with_flags(&mut reader)?;
+        Ok((Self::new(c0, c1), flags))
+    }
+}
+
+impl<P: Fp6Parameters> Valid for Fp6<P> {
+    fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
+        Ok(())
+    }
+
+    fn batch_check<'a>(_batch: impl Iterator<Item = &'a Self>) -> Result<(), snarkvm_utilities::SerializationError>
+    where
+        Self: 'a,
+    {
+        Ok(())
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalDeserialize for Fp6<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Fp6::new(c0, c1))
+    }
+}
@@ -0,0 +1,557 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    impl_add_sub_from_field_ref,
+    impl_mul_div_from_field_ref,
+    FftField,
+    Field,
+    FieldError,
+    FieldParameters,
+    LegendreSymbol,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger768 as BigInteger},
+    serialize::CanonicalDeserialize,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use zeroize::Zeroize;
+
+pub trait Fp768Parameters: FieldParameters<BigInteger = BigInteger> {}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
+pub struct Fp768<P: Fp768Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
+
+impl<P: Fp768Parameters> Fp768<P> {
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline]
+    fn reduce(&mut self) {
+        if !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+}
+
+impl<P: Fp768Parameters> Zero for Fp768<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self(BigInteger::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp768Parameters> One for Fp768<P> {
+    #[inline]
+    fn one() -> Self {
+        Self(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: Fp768Parameters> Field for Fp768<P> {
+    type BasePrimeField = Self;
+
+    // 768/64 = 12 limbs.
+    impl_field_from_random_bytes_with_flags!(12);
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        other
+    }
+
+    fn half() -> Self {
+        // Compute 1/2 `(p+1)/2` as `1/2`.
+        // This is cheaper than `Self::one().double().inverse()`
+        let mut two_inv = P::MODULUS;
+        two_inv.add_nocarry(&1u64.into());
+        two_inv.div2();
+        Self::from_bigint(two_inv).unwrap() // Guaranteed to be valid.
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        // This cannot exceed the backing capacity.
+        self.0.mul2();
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let other = *self;
+        self.mul_assign(&other);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInteger::from(1);
+
+            let mut u = self.0;
+            let mut v = P::MODULUS;
+            let mut b = Self(P::R2, PhantomData); // Avoids unnecessary reduction step.
+            let mut c = Self::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        b.0.add_nocarry(&P::MODULUS);
+                        b.0.div2();
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        c.0.add_nocarry(&P::MODULUS);
+                        c.0.div2();
+                    }
+                }
+
+                if v < u {
+                    u.sub_noborrow(&v);
+                    b.sub_assign(&c);
+                } else {
+                    v.sub_noborrow(&u);
+                    c.sub_assign(&b);
+                }
+            }
+
+            if u == one { Some(b) } else { Some(c) }
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp768Parameters> PrimeField for Fp768<P> {
+    type BigInteger = BigInteger;
+    type Parameters = P;
+
+    #[inline]
+    fn from_bigint(r: BigInteger) -> Option<Self> {
+        let mut r = Fp768(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp768(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_bigint(&self) -> BigInteger {
+        let mut tmp = self.0;
+        let mut r = tmp.0;
+        // Montgomery Reduction
+        for i in 0..12 {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+            fa::mac_with_carry(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..12 {
+                r[(j + i) % 12] = fa::mac_with_carry(r[(j + i) % 12], k, P::MODULUS.0[j], &mut carry);
+            }
+            r[i] = carry;
+        }
+
+        tmp.0 = r;
+        tmp
+    }
+
+    #[inline]
+    fn decompose(
+        &self,
+        _q1: &[u64; 4],
+        _q2: &[u64; 4],
+        _b1: Self,
+        _b2: Self,
+        _r128: Self,
+        _half_r: &[u64; 8],
+    ) -> (Self, Self, bool, bool) {
+        unimplemented!()
+    }
+}
+
+impl<P: Fp768Parameters> FftField for Fp768<P> {
+    type FftParameters = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: Fp768Parameters> SquareRootField for Fp768<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        // With a two-adicity of one, the modulus is 3 mod 4 and the square root is simply
+        // `self^((MODULUS + 1) / 4) = self^(T_MINUS_ONE_DIV_TWO) * self`.
+        if P::TWO_ADICITY == 1 {
+            return match self.legendre() {
+                LegendreSymbol::QuadraticNonResidue => None,
+                _ => Some(*self * self.pow(P::T_MINUS_ONE_DIV_TWO)),
+            };
+        }
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+/// `Fp` elements are ordered lexicographically.
+impl<P: Fp768Parameters> Ord for Fp768<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bigint().cmp(&other.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> PartialOrd for Fp768<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp768Parameters + PoseidonDefaultParameters> PoseidonDefaultField for Fp768<P> {}
+
+impl_primefield_from_int!(Fp768, u128, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u64, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u32, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u16, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u8, Fp768Parameters);
+
+impl_primefield_standard_sample!(Fp768, Fp768Parameters);
+
+impl_add_sub_from_field_ref!(Fp768, Fp768Parameters);
+impl_mul_div_from_field_ref!(Fp768, Fp768Parameters);
+
+impl<P: Fp768Parameters> ToBits for Fp768<P> {
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.to_bigint().write_bits_le(vec);
+        vec.truncate(initial_len + P::MODULUS_BITS as usize);
+    }
+
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.write_bits_le(vec);
+        vec[initial_len..].reverse();
+    }
+
+    fn num_bits() -> Option<usize> {
+        Some(768)
+    }
+}
+
+impl<P: Fp768Parameters> ToBytes for Fp768<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.to_bigint().write_le(writer)
+    }
+}
+
+impl<P: Fp768Parameters> FromBytes for Fp768<P> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger::read_le(reader).and_then(|b| match Self::from_bigint(b) {
+            Some(f) => Ok(f),
+            None => Err(FieldError::InvalidFieldElement.into()),
+        })
+    }
+}
+
+impl<P: Fp768Parameters> FromStr for Fp768<P> {
+    type Err = FieldError;
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(FieldError::ParsingEmptyString);
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten =
+            Self::from_bigint(<Self as PrimeField>::BigInteger::from(10)).ok_or(FieldError::InvalidFieldElement)?;
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(FieldError::InvalidString);
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    res.add_assign(
+                        &Self::from_bigint(<Self as PrimeField>::BigInteger::from(u64::from(c)))
+                            .ok_or(FieldError::InvalidFieldElement)?,
+                    );
+                }
+                None => return Err(FieldError::ParsingNonDigitCharacter),
+            }
+        }
+
+        if !res.is_valid() { Err(FieldError::InvalidFieldElement) } else { Ok(res) }
+    }
+}
+
+impl<P: Fp768Parameters> Debug for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> Display for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> Neg for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = P::MODULUS;
+            tmp.sub_noborrow(&self.0);
+            Self(tmp, PhantomData)
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, P: Fp768Parameters> Add<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Sub<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Mul<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Div<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> AddAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // This cannot exceed the backing capacity.
+        self.0.add_nocarry(&other.0);
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp768Parameters> SubAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, add the modulus to self first.
+        if other.0 > self.0 {
+            self.0.add_nocarry(&P::MODULUS);
+        }
+
+        self.0.sub_noborrow(&other.0);
+    }
+}
+
+impl<'a, P: Fp768Parameters> MulAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        // The limb count makes the unrolled form used for the smaller fields impractical,
+        // so this iterates the same no-carry CIOS multiplication over the limbs instead.
+        let mut r = [0u64; 12];
+
+        for i in 0..12 {
+            let mut carry1 = 0u64;
+            let mut carry2 = 0u64;
+
+            r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[i], &mut carry1);
+            let k = r[0].wrapping_mul(P::INV);
+            fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+
+            for j in 1..12 {
+                r[j] = fa::mac_with_carry(r[j], (self.0).0[j], (other.0).0[i], &mut carry1);
+                r[j - 1] = fa::mac_with_carry(r[j], k, P::MODULUS.0[j], &mut carry2);
+            }
+            r[11] = carry1 + carry2;
+        }
+
+        (self.0).0 = r;
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp768Parameters> DivAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
@@ -677,7 +677,7 @@ pub mod test_helpers {
         // Construct the transaction.
         let transaction = Transaction::from_execution(execution, None).unwrap();
         // Prepare the confirmed transaction.
-        let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![]).unwrap();
+        let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![], vec![]).unwrap();
         // Prepare the transactions.
         let transactions = Transactions::from_iter([confirmed]);
 
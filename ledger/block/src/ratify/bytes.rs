@@ -73,7 +73,32 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::PuzzleReward(amount)
             }
-            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            3 => {
+                // Read the activation height.
+                let height: u32 = FromBytes::read_le(&mut reader)?;
+                // Read the number of bytes in the restrictions list.
+                let num_bytes: u32 = FromBytes::read_le(&mut reader)?;
+                // Ensure the restrictions list does not exceed the maximum size.
+                if num_bytes as usize > Self::MAX_RESTRICTIONS_SIZE {
+                    return Err(error("Failed to decode ratify object - the restrictions list is too large"));
+                }
+                // Read the restrictions list.
+                let mut bytes = vec![0u8; num_bytes as usize];
+                reader.read_exact(&mut bytes)?;
+                let restrictions = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+                // Read the number of signatures.
+                let num_signatures: u16 = FromBytes::read_le(&mut reader)?;
+                // Ensure the number of signatures does not exceed the maximum committee size.
+                if num_signatures > Committee::<N>::MAX_COMMITTEE_SIZE {
+                    return Err(error("Failed to decode ratify object - too many restrictions signatures"));
+                }
+                // Read the signatures.
+                let signatures =
+                    (0..num_signatures).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<_>>()?;
+                // Return the ratify object.
+                Self::Restrictions(height, Box::new(restrictions), Box::new(signatures))
+            }
+            4.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(ratify)
     }
@@ -111,6 +136,22 @@ impl<N: Network> ToBytes for Ratify<N> {
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::Restrictions(height, restrictions, signatures) => {
+                // Ensure the restrictions list does not exceed the maximum size.
+                if restrictions.len() > Self::MAX_RESTRICTIONS_SIZE {
+                    return Err(error("Failed to encode ratify object - the restrictions list is too large"));
+                }
+                // Ensure the number of signatures does not exceed the maximum committee size.
+                if signatures.len() > Committee::<N>::MAX_COMMITTEE_SIZE as usize {
+                    return Err(error("Failed to encode ratify object - too many restrictions signatures"));
+                }
+                (3 as Variant).write_le(&mut writer)?;
+                height.write_le(&mut writer)?;
+                u32::try_from(restrictions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                restrictions.as_bytes().write_le(&mut writer)?;
+                u16::try_from(signatures.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                signatures.write_le(&mut writer)
+            }
         }
     }
 }
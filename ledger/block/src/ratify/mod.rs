@@ -17,10 +17,15 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{
+    account::Signature,
+    network::prelude::*,
+    types::{Address, Field},
+};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
+use std::collections::HashSet;
 
 type Variant = u8;
 /// A helper type to represent the public balances.
@@ -38,19 +43,75 @@ pub enum Ratify<N: Network> {
     BlockReward(u64),
     /// The puzzle reward.
     PuzzleReward(u64),
+    /// The restrictions list, as a JSON-string, that takes effect from the given block height,
+    /// along with the signatures of the committee members that authorize it.
+    Restrictions(u32, Box<String>, Box<Vec<Signature<N>>>),
 }
 
 impl<N: Network> Ratify<N> {
+    /// The maximum number of bytes allowed in a restrictions list.
+    pub const MAX_RESTRICTIONS_SIZE: usize = 1 << 20; // 1 MB
+
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
         Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
     }
+
+    /// Returns the message that committee members sign to authorize the given restrictions list,
+    /// which takes effect from the given activation height, and replaces the previously ratified list.
+    ///
+    /// The message binds the network ID and the ID of the previously ratified list (or zero, if none),
+    /// so that the signatures cannot be replayed on another network, or after another list is ratified.
+    pub fn restrictions_message(
+        previous_restrictions_id: Option<Field<N>>,
+        activation_height: u32,
+        restrictions: &str,
+    ) -> Result<Vec<u8>> {
+        let previous_restrictions_id = previous_restrictions_id.unwrap_or_else(Field::zero).to_bytes_le()?;
+        Ok([
+            b"restrictions".as_slice(),
+            &N::ID.to_le_bytes(),
+            &previous_restrictions_id,
+            &activation_height.to_le_bytes(),
+            restrictions.as_bytes(),
+        ]
+        .concat())
+    }
+
+    /// Ensures the given restrictions list is authorized by the signatures of a quorum of the given committee.
+    pub fn verify_restrictions(
+        previous_restrictions_id: Option<Field<N>>,
+        activation_height: u32,
+        restrictions: &str,
+        signatures: &[Signature<N>],
+        committee: &Committee<N>,
+    ) -> Result<()> {
+        // Construct the signed message.
+        let message = Self::restrictions_message(previous_restrictions_id, activation_height, restrictions)?;
+        // Ensure each signature is from a distinct committee member, and is valid.
+        let mut signers = HashSet::with_capacity(signatures.len());
+        for signature in signatures {
+            let signer = signature.to_address();
+            ensure!(
+                committee.is_committee_member(signer),
+                "The restrictions signer '{signer}' is not in the committee"
+            );
+            ensure!(signers.insert(signer), "The restrictions signer '{signer}' is duplicated");
+            ensure!(signature.verify_bytes(&signer, &message), "The restrictions signature from '{signer}' is invalid");
+        }
+        // Ensure the signers reach the quorum threshold.
+        ensure!(
+            committee.is_quorum_threshold_reached(&signers),
+            "The restrictions list is not authorized by a quorum of the committee"
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use console::network::MainnetV0;
+    use console::{account::PrivateKey, network::MainnetV0};
 
     type CurrentNetwork = MainnetV0;
 
@@ -65,11 +126,18 @@ pub(crate) mod test_helpers {
             .iter()
             .map(|(address, (amount, _, _))| (*address, (*address, *address, *amount)))
             .collect();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let signature = Signature::sign_bytes(&private_key, &[0u8], rng).unwrap();
 
         vec![
             Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances)),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
+            Ratify::Restrictions(
+                rng.gen(),
+                Box::new(r#"{"restrictions_id":"0field","programs":{},"functions":{},"arguments":{}}"#.to_string()),
+                Box::new(vec![signature]),
+            ),
         ]
     }
 }
@@ -77,9 +145,49 @@ pub(crate) mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::account::PrivateKey;
 
     #[test]
     fn check_ratify_size() {
         assert_eq!(std::mem::size_of::<Ratify<console::network::MainnetV0>>(), 32);
     }
+
+    #[test]
+    fn test_verify_restrictions() {
+        type CurrentNetwork = console::network::MainnetV0;
+
+        let rng = &mut TestRng::default();
+
+        // Sample a committee of four members with equal stake.
+        let private_keys: Vec<_> = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect();
+        let members = private_keys.iter().map(|private_key| Address::try_from(private_key).unwrap()).collect();
+        let committee = ledger_committee::test_helpers::sample_committee_for_round_and_members(1, members, rng);
+        let outsider = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Signs the restrictions list with the given private keys.
+        let list = r#"{"restrictions_id":"0field","programs":{},"functions":{},"arguments":{}}"#;
+        let previous_id = Some(Field::from_u64(1));
+        let message = Ratify::<CurrentNetwork>::restrictions_message(previous_id, 10, list).unwrap();
+        let mut sign = |private_keys: &[PrivateKey<CurrentNetwork>]| -> Vec<_> {
+            private_keys.iter().map(|private_key| Signature::sign_bytes(private_key, &message, rng).unwrap()).collect()
+        };
+        let verify = |signatures: &[Signature<CurrentNetwork>]| {
+            Ratify::verify_restrictions(previous_id, 10, list, signatures, &committee).is_ok()
+        };
+
+        // Ensure a quorum of signatures is accepted.
+        assert!(verify(&sign(&private_keys[..3])));
+        // Ensure signatures below the quorum are rejected.
+        assert!(!verify(&sign(&private_keys[..2])));
+        // Ensure duplicate signatures are rejected.
+        assert!(!verify(&sign(&[private_keys[0], private_keys[1], private_keys[1]])));
+        // Ensure signatures from outside of the committee are rejected.
+        assert!(!verify(&sign(&[private_keys[0], private_keys[1], outsider])));
+        // Ensure signatures over a different activation height are rejected.
+        let signatures = sign(&private_keys);
+        assert!(Ratify::verify_restrictions(previous_id, 11, list, &signatures, &committee).is_err());
+        // Ensure signatures over a different previous restrictions list are rejected.
+        assert!(Ratify::verify_restrictions(None, 10, list, &signatures, &committee).is_err());
+        assert!(Ratify::verify_restrictions(Some(Field::from_u64(2)), 10, list, &signatures, &committee).is_err());
+    }
 }
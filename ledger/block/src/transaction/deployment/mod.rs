@@ -57,10 +57,11 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is valid.
+        // Note: Editions after the initial edition are upgrades of a deployed program.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
@@ -44,7 +44,7 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 let finalize =
                     (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                 // Return the confirmed transaction.
-                Self::accepted_execute(index, transaction, finalize).map_err(error)
+                Self::accepted_execute(index, transaction, finalize, vec![]).map_err(error)
             }
             2 => {
                 // Read the index.
@@ -76,7 +76,28 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::rejected_execute(index, transaction, rejected, finalize).map_err(error)
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            4 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the transaction.
+                let transaction = Transaction::<N>::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Read the number of events.
+                let num_events = NumFinalizeSize::read_le(&mut reader)?;
+                // Ensure there is at least one event.
+                if num_events == 0 {
+                    return Err(error("Confirmed transaction variant 4 must contain at least one event"));
+                }
+                // Read the events.
+                let events = (0..num_events).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the confirmed transaction.
+                Self::accepted_execute(index, transaction, finalize, events).map_err(error)
+            }
+            5.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -97,9 +118,13 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
-            Self::AcceptedExecute(index, transaction, finalize) => {
+            Self::AcceptedExecute(index, transaction, finalize, events) => {
                 // Write the variant.
-                1u8.write_le(&mut writer)?;
+                // Note: An accepted execute without events is written as variant 1, for backwards compatibility.
+                match events.is_empty() {
+                    true => 1u8.write_le(&mut writer)?,
+                    false => 4u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
@@ -107,7 +132,15 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the number of finalize operations.
                 NumFinalizeSize::try_from(finalize.len()).map_err(error)?.write_le(&mut writer)?;
                 // Write the finalize operations.
-                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))?;
+                // If there are no events, return early.
+                if events.is_empty() {
+                    return Ok(());
+                }
+                // Write the number of events.
+                NumFinalizeSize::try_from(events.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the events.
+                events.iter().try_for_each(|event| event.write_le(&mut writer))
             }
             Self::RejectedDeploy(index, transaction, rejected, finalize) => {
                 // Write the variant.
@@ -19,7 +19,7 @@ mod string;
 
 use crate::{rejected::Rejected, Transaction};
 use console::{network::prelude::*, program::FINALIZE_ID_DEPTH, types::Field};
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 pub type NumFinalizeSize = u16;
 
@@ -28,8 +28,8 @@ pub type NumFinalizeSize = u16;
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
-    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
+    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations, events)`.
+    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>),
     /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, finalize_operations)`.
     RejectedDeploy(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
     /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize_operations)`.
@@ -98,6 +98,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         index: u32,
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
+        events: Vec<Event<N>>,
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
@@ -113,9 +114,27 @@ impl<N: Network> ConfirmedTransaction<N> {
                 }
             }
         }
+        // Ensure the number of events is within the size of the Merkle tree of the finalize ID.
+        let max_events = Transaction::<N>::MAX_TRANSITIONS * N::MAX_EVENTS as usize;
+        ensure!(
+            events.len() <= max_events,
+            "Transaction '{}' (execute) emits {} events, exceeding the maximum of {max_events}",
+            transaction.id(),
+            events.len()
+        );
+        // Ensure the events were emitted by programs in the transaction.
+        for event in events.iter() {
+            if !transaction.transitions().any(|transition| transition.program_id() == event.program_id()) {
+                bail!(
+                    "Transaction '{}' (execute) contains an event from '{}', which is not in the transaction",
+                    transaction.id(),
+                    event.program_id()
+                )
+            }
+        }
         // Ensure the transaction is an execute transaction.
         match transaction.is_execute() {
-            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations)),
+            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations, events)),
             false => bail!("Transaction '{}' is not an execute transaction", transaction.id()),
         }
     }
@@ -226,7 +245,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn transaction(&self) -> &Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -236,7 +255,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn into_transaction(self) -> Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -246,7 +265,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn num_finalize(&self) -> usize {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize.len(),
-            Self::AcceptedExecute(_, _, finalize) => finalize.len(),
+            Self::AcceptedExecute(_, _, finalize, _) => finalize.len(),
             Self::RejectedDeploy(_, _, _, finalize) => finalize.len(),
             Self::RejectedExecute(_, _, _, finalize) => finalize.len(),
         }
@@ -256,20 +275,43 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn finalize_operations(&self) -> &Vec<FinalizeOperation<N>> {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize,
-            Self::AcceptedExecute(_, _, finalize) => finalize,
+            Self::AcceptedExecute(_, _, finalize, _) => finalize,
             Self::RejectedDeploy(_, _, _, finalize) => finalize,
             Self::RejectedExecute(_, _, _, finalize) => finalize,
         }
     }
 
+    /// Returns the events emitted in finalize, which are only present in an accepted execute transaction.
+    pub fn events(&self) -> &[Event<N>] {
+        match self {
+            Self::AcceptedExecute(_, _, _, events) => events,
+            Self::AcceptedDeploy(..) | Self::RejectedDeploy(..) | Self::RejectedExecute(..) => &[],
+        }
+    }
+
     /// Returns the finalize ID, by computing the root of a (small) Merkle tree comprised of
     /// the ordered finalize operations for the transaction.
+    /// If the transaction emitted events, the finalize ID is the hash of this root and the root of
+    /// a (small) Merkle tree comprised of the ordered events.
     pub fn to_finalize_id(&self) -> Result<Field<N>> {
         // Prepare the leaves.
         let leaves = self.finalize_operations().iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
-        // Compute the finalize ID.
+        // Compute the finalize root.
         // Note: This call will ensure the number of finalize operations is within the size of the Merkle tree.
-        Ok(*N::merkle_tree_bhp::<FINALIZE_ID_DEPTH>(&leaves)?.root())
+        let finalize_root = *N::merkle_tree_bhp::<FINALIZE_ID_DEPTH>(&leaves)?.root();
+
+        // If there are no events, return the finalize root.
+        if self.events().is_empty() {
+            return Ok(finalize_root);
+        }
+
+        // Prepare the event leaves.
+        let leaves = self.events().iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        // Compute the events root.
+        // Note: This call will ensure the number of events is within the size of the Merkle tree.
+        let events_root = *N::merkle_tree_bhp::<FINALIZE_ID_DEPTH>(&leaves)?.root();
+        // Compute the finalize ID.
+        N::hash_bhp512(&[finalize_root.to_bits_le(), events_root.to_bits_le()].concat())
     }
 
     /// Returns the rejected ID, if the confirmed transaction is rejected.
@@ -296,7 +338,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction_id(&self) -> Result<N::TransactionID> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.id()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.id()),
+            Self::AcceptedExecute(_, transaction, ..) => Ok(transaction.id()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _)
             | Self::RejectedExecute(_, fee_transaction, rejected, _) => {
                 Ok(rejected.to_unconfirmed_id(&fee_transaction.fee_transition())?.into())
@@ -310,7 +352,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction(&self) -> Result<Transaction<N>> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.clone()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.clone()),
+            Self::AcceptedExecute(_, transaction, ..) => Ok(transaction.clone()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _) => Transaction::from_deployment(
                 rejected
                     .program_owner()
@@ -374,7 +416,7 @@ pub mod test_helpers {
         // Sample an execute transaction.
         let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng);
         // Return the confirmed transaction.
-        ConfirmedTransaction::accepted_execute(index, tx, vec![]).unwrap()
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], vec![]).unwrap()
     }
 
     /// Samples a rejected deploy transaction at the given index.
@@ -460,20 +502,68 @@ mod test {
             FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
         ];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone()).unwrap();
+        let confirmed =
+            ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone(), vec![]).unwrap();
 
         assert_eq!(confirmed.index(), index);
         assert_eq!(confirmed.transaction(), &tx);
         assert_eq!(confirmed.num_finalize(), finalize_operations.len());
         assert_eq!(confirmed.finalize_operations(), &finalize_operations);
+        assert!(confirmed.events().is_empty());
 
         // Attempt to create an `AcceptedExecution` with invalid `FinalizeOperation`s.
         let finalize_operations = vec![FinalizeOperation::InitializeMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations, vec![]);
         assert!(confirmed.is_err());
 
         let finalize_operations = vec![FinalizeOperation::RemoveMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, finalize_operations, vec![]);
+        assert!(confirmed.is_err());
+    }
+
+    #[test]
+    fn test_accepted_execute_with_events() {
+        let rng = &mut TestRng::default();
+
+        let index = Uniform::rand(rng);
+        let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let program_id = *tx.transitions().next().unwrap().program_id();
+
+        // Create an `AcceptedExecution` with an event from a program in the transaction.
+        let event = Event::new(
+            program_id,
+            FromStr::from_str("transfer").unwrap(),
+            FromStr::from_str("{ amount: 10u64, memo: 5field }").unwrap(),
+        );
+        let events = vec![event.clone(), event];
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), vec![], events.clone()).unwrap();
+        assert_eq!(confirmed.events(), &events[..]);
+
+        // Ensure the events are committed to in the finalize ID.
+        let without_events = ConfirmedTransaction::accepted_execute(index, tx.clone(), vec![], vec![]).unwrap();
+        assert_ne!(confirmed.to_finalize_id().unwrap(), without_events.to_finalize_id().unwrap());
+
+        // Ensure the events round-trip through bytes and JSON.
+        let bytes = confirmed.to_bytes_le().unwrap();
+        assert_eq!(confirmed, ConfirmedTransaction::read_le(&bytes[..]).unwrap());
+        let json = serde_json::to_string(&confirmed).unwrap();
+        assert_eq!(confirmed, serde_json::from_str(&json).unwrap());
+
+        // Attempt to create an `AcceptedExecution` with an event from a program outside of the transaction.
+        let event = Event::new(
+            FromStr::from_str("unrelated.aleo").unwrap(),
+            FromStr::from_str("transfer").unwrap(),
+            FromStr::from_str("10u64").unwrap(),
+        );
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), vec![], vec![event]);
+        assert!(confirmed.is_err());
+
+        // Ensure the maximum number of events fits in the Merkle tree of the finalize ID.
+        let max_events = Transaction::<CurrentNetwork>::MAX_TRANSITIONS * CurrentNetwork::MAX_EVENTS as usize;
+        assert!(max_events <= 1 << FINALIZE_ID_DEPTH);
+        // Attempt to create an `AcceptedExecution` with more than the maximum number of events.
+        let events = vec![events[0].clone(); max_events + 1];
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, vec![], events);
         assert!(confirmed.is_err());
     }
 
@@ -29,13 +29,17 @@ impl<N: Network> Serialize for ConfirmedTransaction<N> {
                     object.serialize_field("finalize", finalize_operations)?;
                     object.end()
                 }
-                Self::AcceptedExecute(index, transaction, finalize_operations) => {
-                    let mut object = serializer.serialize_struct("ConfirmedTransaction", 5)?;
+                Self::AcceptedExecute(index, transaction, finalize_operations, events) => {
+                    let mut object =
+                        serializer.serialize_struct("ConfirmedTransaction", 5 + !events.is_empty() as usize)?;
                     object.serialize_field("status", "accepted")?;
                     object.serialize_field("type", "execute")?;
                     object.serialize_field("index", index)?;
                     object.serialize_field("transaction", transaction)?;
                     object.serialize_field("finalize", finalize_operations)?;
+                    if !events.is_empty() {
+                        object.serialize_field("events", events)?;
+                    }
                     object.end()
                 }
                 Self::RejectedDeploy(index, transaction, rejected_deployment, finalize_operations) => {
@@ -92,8 +96,14 @@ impl<'de, N: Network> Deserialize<'de> for ConfirmedTransaction<N> {
                     (Some("accepted"), Some("execute")) => {
                         // Parse the finalize operations.
                         let finalize: Vec<_> = DeserializeExt::take_from_value::<D>(&mut object, "finalize")?;
+                        // Parse the events, if they exist.
+                        let events: Option<Vec<_>> = serde_json::from_value(
+                            object.get_mut("events").unwrap_or(&mut serde_json::Value::Null).take(),
+                        )
+                        .map_err(de::Error::custom)?;
                         // Return the accepted execute transaction.
-                        Self::accepted_execute(index, transaction, finalize).map_err(de::Error::custom)
+                        Self::accepted_execute(index, transaction, finalize, events.unwrap_or_default())
+                            .map_err(de::Error::custom)
                     }
                     (Some("rejected"), Some("deploy")) => {
                         // Parse the rejected deployment.
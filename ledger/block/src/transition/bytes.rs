@@ -21,9 +21,12 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transition version"));
-        }
+        // Note: Version 2 denotes a transition that is signed by a multisig account.
+        let is_multisig = match version {
+            1 => false,
+            2 => true,
+            _ => return Err(error("Invalid transition version")),
+        };
 
         // Read the transition ID.
         let transition_id = N::TransitionID::read_le(&mut reader)?;
@@ -59,7 +62,8 @@ impl<N: Network> FromBytes for Transition<N> {
 
         // Construct the candidate transition.
         let transition =
-            Self::new(program_id, function_name, inputs, outputs, tpk, tcm, scm).map_err(|e| error(e.to_string()))?;
+            Self::new_with_multisig(program_id, function_name, inputs, outputs, tpk, tcm, scm, is_multisig)
+                .map_err(|e| error(e.to_string()))?;
         // Ensure the transition ID matches the expected ID.
         match transition_id == *transition.id() {
             true => Ok(transition),
@@ -72,7 +76,10 @@ impl<N: Network> ToBytes for Transition<N> {
     /// Writes the literal to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        match self.is_multisig {
+            true => 2u8.write_le(&mut writer)?,
+            false => 1u8.write_le(&mut writer)?,
+        }
 
         // Write the transition ID.
         self.id.write_le(&mut writer)?;
@@ -115,6 +122,29 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
 
+        // Construct the same transition, signed by a multisig account.
+        let multisig = Transition::new_with_multisig(
+            *expected.program_id(),
+            *expected.function_name(),
+            expected.inputs().to_vec(),
+            expected.outputs().to_vec(),
+            *expected.tpk(),
+            *expected.tcm(),
+            *expected.scm(),
+            true,
+        )?;
+        // Ensure the multisig flag is bound to the transition ID.
+        assert_ne!(expected.id(), multisig.id());
+
+        // Check the byte representation.
+        let multisig_bytes = multisig.to_bytes_le()?;
+        let candidate = Transition::read_le(&multisig_bytes[..])?;
+        assert_eq!(multisig, candidate);
+        assert!(candidate.is_multisig());
+
+        // Check the JSON representation.
+        assert_eq!(multisig, serde_json::from_str(&serde_json::to_string(&multisig)?)?);
+
         Ok(())
     }
 }
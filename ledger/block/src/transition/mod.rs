@@ -65,6 +65,8 @@ pub struct Transition<N: Network> {
     tcm: Field<N>,
     /// The transition signer commitment.
     scm: Field<N>,
+    /// Whether the transition is signed by a multisig account.
+    is_multisig: bool,
 }
 
 impl<N: Network> Transition<N> {
@@ -78,12 +80,35 @@ impl<N: Network> Transition<N> {
         tpk: Group<N>,
         tcm: Field<N>,
         scm: Field<N>,
+    ) -> Result<Self> {
+        Self::new_with_multisig(program_id, function_name, inputs, outputs, tpk, tcm, scm, false)
+    }
+
+    /// Initializes a new transition, where `is_multisig` indicates the transition is signed by a multisig account.
+    ///
+    /// A multisig transition is proven with the multisig circuit of its function, so the flag is bound to
+    /// the transition ID.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_multisig(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Input<N>>,
+        outputs: Vec<Output<N>>,
+        tpk: Group<N>,
+        tcm: Field<N>,
+        scm: Field<N>,
+        is_multisig: bool,
     ) -> Result<Self> {
         // Compute the transition ID.
         let function_tree = Self::function_tree(&inputs, &outputs)?;
-        let id = N::hash_bhp512(&(*function_tree.root(), tcm).to_bits_le())?;
+        let mut preimage = (*function_tree.root(), tcm).to_bits_le();
+        // Note: The flag is only appended for a multisig transition, so the ID of any other transition is unchanged.
+        if is_multisig {
+            preimage.push(true);
+        }
+        let id = N::hash_bhp512(&preimage)?;
         // Return the transition.
-        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm, scm })
+        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm, scm, is_multisig })
     }
 
     /// Initializes a new transition from a request and response.
@@ -261,8 +286,54 @@ impl<N: Network> Transition<N> {
         let tcm = *request.tcm();
         // Retrieve the `scm`.
         let scm = *request.scm();
+        // Determine whether the request is signed by a multisig account.
+        let is_multisig = request.multisig_key().is_some();
         // Return the transition.
-        Self::new(program_id, function_name, inputs, outputs, tpk, tcm, scm)
+        Self::new_with_multisig(program_id, function_name, inputs, outputs, tpk, tcm, scm, is_multisig)
+    }
+
+    /// Returns the transition without the plaintext, ciphertext, and future payloads of its inputs and outputs.
+    /// The pruned transition retains its ID, serial numbers, tags, commitments, and output records.
+    pub fn to_pruned(&self) -> Result<Self> {
+        // Prune the inputs.
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| match input {
+                Input::Constant(id, _) => Input::Constant(*id, None),
+                Input::Public(id, _) => Input::Public(*id, None),
+                Input::Private(id, _) => Input::Private(*id, None),
+                Input::Record(serial_number, tag) => Input::Record(*serial_number, *tag),
+                Input::ExternalRecord(id) => Input::ExternalRecord(*id),
+            })
+            .collect();
+        // Prune the outputs.
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| match output {
+                Output::Constant(id, _) => Output::Constant(*id, None),
+                Output::Public(id, _) => Output::Public(*id, None),
+                Output::Private(id, _) => Output::Private(*id, None),
+                Output::Record(commitment, checksum, record) => Output::Record(*commitment, *checksum, record.clone()),
+                Output::ExternalRecord(id) => Output::ExternalRecord(*id),
+                Output::Future(id, _) => Output::Future(*id, None),
+            })
+            .collect();
+        // Construct the pruned transition.
+        let transition = Self::new_with_multisig(
+            self.program_id,
+            self.function_name,
+            inputs,
+            outputs,
+            self.tpk,
+            self.tcm,
+            self.scm,
+            self.is_multisig,
+        )?;
+        // Ensure the transition ID is unchanged.
+        ensure!(transition.id == self.id, "Failed to prune transition '{}'", self.id);
+        Ok(transition)
     }
 }
 
@@ -306,6 +377,11 @@ impl<N: Network> Transition<N> {
     pub const fn scm(&self) -> &Field<N> {
         &self.scm
     }
+
+    /// Returns `true` if the transition is signed by a multisig account.
+    pub const fn is_multisig(&self) -> bool {
+        self.is_multisig
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -504,3 +580,51 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_pruned() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition.
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+
+        // Prune the transition.
+        let pruned = transition.to_pruned()?;
+        assert_eq!(pruned.id(), transition.id());
+        assert_eq!(pruned.serial_numbers().collect::<Vec<_>>(), transition.serial_numbers().collect::<Vec<_>>());
+        assert_eq!(pruned.tags().collect::<Vec<_>>(), transition.tags().collect::<Vec<_>>());
+        assert_eq!(pruned.commitments().collect::<Vec<_>>(), transition.commitments().collect::<Vec<_>>());
+        assert_eq!(pruned.nonces().collect::<Vec<_>>(), transition.nonces().collect::<Vec<_>>());
+
+        // Ensure the payloads are removed.
+        for input in pruned.inputs() {
+            assert!(matches!(
+                input,
+                Input::Constant(_, None)
+                    | Input::Public(_, None)
+                    | Input::Private(_, None)
+                    | Input::Record(..)
+                    | Input::ExternalRecord(..)
+            ));
+        }
+        for output in pruned.outputs() {
+            assert!(matches!(
+                output,
+                Output::Constant(_, None)
+                    | Output::Public(_, None)
+                    | Output::Private(_, None)
+                    | Output::Record(..)
+                    | Output::ExternalRecord(..)
+                    | Output::Future(_, None)
+            ));
+        }
+
+        // Ensure the pruned transition round-trips through its byte representation.
+        assert_eq!(pruned, Transition::read_le(&pruned.to_bytes_le()?[..])?);
+        Ok(())
+    }
+}
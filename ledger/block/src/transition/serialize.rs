@@ -20,7 +20,7 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 8)?;
+                let mut transition = serializer.serialize_struct("Transition", 8 + self.is_multisig as usize)?;
                 transition.serialize_field("id", &self.id)?;
                 transition.serialize_field("program", &self.program_id)?;
                 transition.serialize_field("function", &self.function_name)?;
@@ -29,6 +29,9 @@ impl<N: Network> Serialize for Transition<N> {
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
                 transition.serialize_field("scm", &self.scm)?;
+                if self.is_multisig {
+                    transition.serialize_field("multisig", &self.is_multisig)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -45,9 +48,14 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                 let mut transition = serde_json::Value::deserialize(deserializer)?;
                 // Retrieve the ID.
                 let id: N::TransitionID = DeserializeExt::take_from_value::<D>(&mut transition, "id")?;
+                // Retrieve the multisig flag, if it exists.
+                let is_multisig: Option<bool> = serde_json::from_value(
+                    transition.get_mut("multisig").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
 
                 // Recover the transition.
-                let transition = Self::new(
+                let transition = Self::new_with_multisig(
                     // Retrieve the program ID.
                     DeserializeExt::take_from_value::<D>(&mut transition, "program")?,
                     // Retrieve the function name.
@@ -62,6 +70,8 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                     DeserializeExt::take_from_value::<D>(&mut transition, "tcm")?,
                     // Retrieve the `scm`.
                     DeserializeExt::take_from_value::<D>(&mut transition, "scm")?,
+                    // Retrieve the multisig flag.
+                    is_multisig.unwrap_or_default(),
                 )
                 .map_err(de::Error::custom)?;
 
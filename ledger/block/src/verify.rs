@@ -33,6 +33,8 @@ impl<N: Network> Block<N> {
         current_state_root: N::StateRoot,
         previous_committee_lookback: &Committee<N>,
         current_committee_lookback: &Committee<N>,
+        current_committee: &Committee<N>,
+        previous_restrictions_id: Option<Field<N>>,
         current_puzzle: &Puzzle<N>,
         current_epoch_hash: N::BlockHash,
         current_timestamp: i64,
@@ -68,7 +70,12 @@ impl<N: Network> Block<N> {
         ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_hash)?;
 
         // Ensure the block ratifications are correct.
-        self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
+        self.verify_ratifications(
+            expected_block_reward,
+            expected_puzzle_reward,
+            current_committee,
+            previous_restrictions_id,
+        )?;
 
         // Ensure the block transactions are correct.
         self.verify_transactions()?;
@@ -277,7 +284,16 @@ impl<N: Network> Block<N> {
     }
 
     /// Ensures the block ratifications are correct.
-    fn verify_ratifications(&self, expected_block_reward: u64, expected_puzzle_reward: u64) -> Result<()> {
+    ///
+    /// A restrictions list may only be ratified once per block, after the rewards,
+    /// and must be authorized by a quorum of the `current committee`, over the `previous restrictions ID`.
+    fn verify_ratifications(
+        &self,
+        expected_block_reward: u64,
+        expected_puzzle_reward: u64,
+        current_committee: &Committee<N>,
+        previous_restrictions_id: Option<Field<N>>,
+    ) -> Result<()> {
         let height = self.height();
 
         // Ensure there are sufficient ratifications.
@@ -307,6 +323,23 @@ impl<N: Network> Block<N> {
             puzzle_reward == expected_puzzle_reward,
             "Block {height} has an invalid puzzle reward (found '{puzzle_reward}', expected '{expected_puzzle_reward}')",
         );
+
+        // Ensure the remaining ratifications are at most one authorized restrictions list.
+        for (index, ratify) in ratifications_iter.enumerate() {
+            match ratify {
+                Ratify::Restrictions(activation_height, restrictions, signatures) if index == 0 => {
+                    Ratify::verify_restrictions(
+                        previous_restrictions_id,
+                        *activation_height,
+                        restrictions,
+                        signatures,
+                        current_committee,
+                    )
+                    .map_err(|e| anyhow!("Block {height} has an unauthorized restrictions list - {e}"))?;
+                }
+                _ => bail!("Block {height} is invalid - ratification {} is not allowed", index + 2),
+            }
+        }
         Ok(())
     }
 
@@ -33,7 +33,8 @@ impl<N: Network> EpochProgram<N> {
         ensure!(num_inputs == console_inputs.len(), "Expected {num_inputs} inputs, found {}", console_inputs.len());
 
         // Initialize the registers.
-        let call_stack = CallStack::PackageRun(vec![], PrivateKey::new(&mut rand::thread_rng())?, Default::default());
+        let call_stack =
+            CallStack::PackageRun(vec![], PrivateKey::new(&mut rand::thread_rng())?, Default::default(), 0);
         let mut registers = Registers::<N, A>::new(call_stack, register_types);
         lap!(timer, "Initialize the registers");
 
@@ -24,5 +24,8 @@ mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+mod snapshot;
+pub use snapshot::*;
+
 mod traits;
 pub use traits::*;
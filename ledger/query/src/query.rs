@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{QuerySnapshot, QueryTrait};
 use console::{
     network::prelude::*,
     program::{ProgramID, StatePath},
@@ -28,6 +28,8 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The offline snapshot of the ledger state.
+    Snapshot(QuerySnapshot<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -60,6 +62,12 @@ impl<N: Network, B: BlockStorage<N>> From<&str> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<QuerySnapshot<N>> for Query<N, B> {
+    fn from(snapshot: QuerySnapshot<N>) -> Self {
+        Self::Snapshot(snapshot)
+    }
+}
+
 #[cfg_attr(feature = "async", async_trait(?Send))]
 impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     /// Returns the current state root.
@@ -78,6 +86,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.current_state_root(),
         }
     }
 
@@ -98,6 +107,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.current_state_root(),
         }
     }
 
@@ -117,6 +127,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -137,6 +148,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.get_state_path_for_commitment(commitment),
         }
     }
 }
@@ -160,6 +172,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(_) => bail!("Program {program_id} is not available in a query snapshot"),
         }
     }
 
@@ -182,6 +195,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(_) => bail!("Program {program_id} is not available in a query snapshot"),
         }
     }
 
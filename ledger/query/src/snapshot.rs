@@ -0,0 +1,129 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+use std::path::Path;
+
+/// An offline snapshot of the ledger state, consisting of a global state root
+/// and the state paths for a selected set of commitments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuerySnapshot<N: Network> {
+    /// The global state root.
+    state_root: N::StateRoot,
+    /// The state paths for the selected commitments.
+    state_paths: Vec<StatePath<N>>,
+}
+
+impl<N: Network> QuerySnapshot<N> {
+    /// Initializes a new snapshot from the given global state root and state paths.
+    pub fn new(state_root: N::StateRoot, state_paths: Vec<StatePath<N>>) -> Result<Self> {
+        // Ensure the state paths are for the global state root.
+        for state_path in &state_paths {
+            ensure!(
+                state_path.global_state_root() == state_root,
+                "The state path for commitment '{}' is not for the global state root '{state_root}'",
+                state_path.transition_leaf().id()
+            );
+        }
+        Ok(Self { state_root, state_paths })
+    }
+
+    /// Reads the snapshot from the file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_bytes_le(&std::fs::read(path)?)
+    }
+
+    /// Writes the snapshot to the file at the given path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(std::fs::write(path, self.to_bytes_le()?)?)
+    }
+
+    /// Returns the global state root.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the state paths for the selected commitments.
+    pub fn state_paths(&self) -> &[StatePath<N>] {
+        &self.state_paths
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for QuerySnapshot<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        match self.state_paths.iter().find(|state_path| state_path.transition_leaf().id() == *commitment) {
+            Some(state_path) => Ok(state_path.clone()),
+            None => bail!("Commitment '{commitment}' is not in the query snapshot"),
+        }
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+impl<N: Network> FromBytes for QuerySnapshot<N> {
+    /// Reads the snapshot from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid query snapshot version"));
+        }
+
+        // Read the global state root.
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the number of state paths.
+        let num_state_paths = u32::read_le(&mut reader)?;
+        // Read the state paths.
+        let state_paths = (0..num_state_paths).map(|_| StatePath::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        // Construct the snapshot.
+        Self::new(state_root, state_paths).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for QuerySnapshot<N> {
+    /// Writes the snapshot to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the global state root.
+        self.state_root.write_le(&mut writer)?;
+        // Write the number of state paths.
+        u32::try_from(self.state_paths.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the state paths.
+        self.state_paths.iter().try_for_each(|state_path| state_path.write_le(&mut writer))
+    }
+}
@@ -28,8 +28,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Decouple the transmissions into ratifications, solutions, and transactions.
         let (ratifications, solutions, transactions) = decouple_transmissions(transmissions.into_iter())?;
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Ensure the ratifications from the memory pool are supported.
+        ensure_candidate_ratifications(&ratifications)?;
         // Construct the block template.
         let (header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids) =
             self.construct_block_template(&previous_block, Some(&subdag), ratifications, solutions, transactions, rng)?;
@@ -56,8 +56,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
     ) -> Result<Block<N>> {
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(candidate_ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Ensure the ratifications from the memory pool are supported.
+        ensure_candidate_ratifications(&candidate_ratifications)?;
 
         // Retrieve the latest block as the previous block (for the next block).
         let previous_block = self.latest_block();
@@ -98,6 +98,24 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Drop the write lock on the current block.
         drop(current_block);
 
+        // Record the transaction counts and fees of the block.
+        #[cfg(feature = "metrics")]
+        {
+            let num_accepted = block.transactions().num_accepted();
+            let num_rejected = block.transactions().num_rejected();
+            let num_aborted = block.aborted_transaction_ids().len();
+            let fees =
+                block.transactions().transaction_fee_amounts().filter_map(|fee| fee.ok().map(|fee| *fee)).sum::<u64>();
+            metrics::histogram(metrics::ledger::BLOCK_ACCEPTED_TRANSACTIONS, num_accepted as f64);
+            metrics::histogram(metrics::ledger::BLOCK_REJECTED_TRANSACTIONS, num_rejected as f64);
+            metrics::histogram(metrics::ledger::BLOCK_ABORTED_TRANSACTIONS, num_aborted as f64);
+            metrics::histogram(metrics::ledger::BLOCK_TRANSACTION_FEES, fees as f64);
+            metrics::increment_counter_by(metrics::ledger::ACCEPTED_TRANSACTIONS, num_accepted as u64);
+            metrics::increment_counter_by(metrics::ledger::REJECTED_TRANSACTIONS, num_rejected as u64);
+            metrics::increment_counter_by(metrics::ledger::ABORTED_TRANSACTIONS, num_aborted as u64);
+            metrics::increment_counter_by(metrics::ledger::TRANSACTION_FEES, fees);
+        }
+
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
             *self.current_committee.write() = Some(current_committee);
@@ -119,6 +137,41 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Rolls back the ledger to the given block height, removing every block above it.
+    pub fn rollback_to(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Ensure the block height is not above the latest block height.
+        ensure!(
+            height <= current_block.height(),
+            "Cannot roll back to block {height}, as it is above the latest block"
+        );
+        // Update the VM.
+        self.vm.remove_blocks_after(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = self.vm.finalize_store().committee_store().current_committee().ok();
+        // Update the current epoch hash.
+        *self.current_epoch_hash.write() = self.get_epoch_hash(height).ok();
+
+        Ok(())
+    }
+}
+
+/// Ensures the candidate ratifications are supported, which is currently at most one restrictions list.
+/// Note: The restrictions list must be authorized by a quorum of the committee, which is checked during speculation.
+fn ensure_candidate_ratifications<N: Network>(candidate_ratifications: &[Ratify<N>]) -> Result<()> {
+    ensure!(candidate_ratifications.len() <= 1, "At most one ratification is supported from the memory pool");
+    ensure!(
+        candidate_ratifications.iter().all(|ratify| matches!(ratify, Ratify::Restrictions(..))),
+        "Only restrictions lists are supported as ratifications from the memory pool"
+    );
+    Ok(())
 }
 
 /// Splits candidate solutions into a collection of accepted ones and aborted ones.
@@ -302,6 +355,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
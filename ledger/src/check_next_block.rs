@@ -64,6 +64,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -112,6 +113,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.latest_state_root(),
             &previous_committee_lookback,
             &committee_lookback,
+            &self.latest_committee()?,
+            self.vm.finalize_store().get_latest_restrictions_id_confirmed()?,
             self.puzzle(),
             self.latest_epoch_hash()?,
             OffsetDateTime::now_utc().unix_timestamp(),
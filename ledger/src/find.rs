@@ -49,6 +49,78 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().find_transition_id(id)
     }
 
+    /// Returns the transitions within the given range of block heights in which the given address appears publicly,
+    /// as `(block height, transition)` pairs in ascending order of block height.
+    ///
+    /// Note: The address index is only maintained if the `address-index` feature is enabled.
+    pub fn find_transitions_for_address(
+        &self,
+        address: &Address<N>,
+        heights: Range<u32>,
+        filter: TransitionsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (u32, Transition<N>)>> {
+        let transition_store = self.vm.transition_store();
+        let credits = ProgramID::<N>::from_str("credits.aleo")?;
+
+        // Retrieve the indexed transitions within the range of block heights, in ascending order of block height.
+        let mut transition_ids = Vec::new();
+        for (height, transition_id) in transition_store.find_transition_ids_for_address(address, heights)? {
+            // Determine whether to include this transition (or not), based on the filter.
+            let (program_id, function_name) = match (
+                transition_store.get_program_id(&transition_id)?,
+                transition_store.get_function_name(&transition_id)?,
+            ) {
+                (Some(program_id), Some(function_name)) => (program_id, function_name.to_string()),
+                _ => bail!("Missing the locator for transition '{transition_id}'"),
+            };
+            let is_included = match filter {
+                TransitionsFilter::All => true,
+                TransitionsFilter::TransferPublic => {
+                    program_id == credits && function_name.starts_with("transfer_public")
+                }
+                TransitionsFilter::Bond => program_id == credits && function_name.starts_with("bond_"),
+                TransitionsFilter::Program(filter_program_id) => program_id == filter_program_id,
+            };
+            if is_included {
+                transition_ids.push((height, transition_id));
+            }
+        }
+
+        Ok(transition_ids.into_iter().flat_map(move |(height, transition_id)| {
+            match transition_store.get_transition(&transition_id) {
+                Ok(Some(transition)) => Some((height, transition)),
+                Ok(None) => None,
+                Err(e) => {
+                    warn!("Failed to process 'find_transitions_for_address({:?})': {e}", filter);
+                    None
+                }
+            }
+        }))
+    }
+
+    /// Returns the events with the given `program ID` and `event name` that were emitted within the given range
+    /// of block heights, as `(block height, transaction ID, event)` tuples in ascending order of block height.
+    #[allow(clippy::type_complexity)]
+    pub fn find_events(
+        &self,
+        program_id: &ProgramID<N>,
+        event_name: &Identifier<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, N::TransactionID, Event<N>)>> {
+        let block_store = self.vm.block_store();
+
+        // Retrieve the matching events, in the order they were emitted.
+        let mut events = Vec::new();
+        for (height, transaction_id) in block_store.find_transaction_ids_for_event(program_id, event_name, heights)? {
+            for event in block_store.get_events(&transaction_id)? {
+                if event.program_id() == program_id && event.name() == event_name {
+                    events.push((height, transaction_id, event));
+                }
+            }
+        }
+        Ok(events)
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
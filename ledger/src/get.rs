@@ -52,6 +52,23 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns a query snapshot of the latest state root, with the state paths for the given record commitments.
+    /// The snapshot can be used to prepare executions offline, without access to a node or a block store.
+    pub fn export_query_snapshot<'a>(
+        &self,
+        commitments: impl IntoIterator<Item = &'a Field<N>>,
+    ) -> Result<QuerySnapshot<N>> {
+        // Retrieve the latest state root.
+        let state_root = self.latest_state_root();
+        // Retrieve the state path for each commitment.
+        let state_paths = commitments
+            .into_iter()
+            .map(|commitment| self.get_state_path_for_commitment(commitment))
+            .collect::<Result<_>>()?;
+        // Note: If a new block is added concurrently, the state paths will not match the state root, and this will fail.
+        QuerySnapshot::new(state_root, state_paths)
+    }
+
     /// Returns the epoch hash for the given block height.
     pub fn get_epoch_hash(&self, block_height: u32) -> Result<N::BlockHash> {
         // Compute the epoch number from the current block height.
@@ -259,6 +276,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
 
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    #[cfg(feature = "history")]
+    pub fn get_mapping_value_at(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the block height is not in the future.
+        let latest_height = self.latest_height();
+        ensure!(
+            block_height <= latest_height,
+            "Block {block_height} is beyond the latest block height {latest_height}"
+        );
+        // Retrieve the value as of the block height.
+        self.vm.finalize_store().get_mapping_value_at(program_id, mapping_name, key, block_height)
+    }
+
     /// Returns the delegators for the given validator.
     pub fn get_delegators_for_validator(&self, validator: &Address<N>) -> Result<Vec<Address<N>>> {
         // Construct the credits.aleo program ID.
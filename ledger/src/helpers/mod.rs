@@ -18,3 +18,6 @@ pub use bft::*;
 
 mod supply;
 pub use supply::*;
+
+mod snapshot;
+pub use snapshot::*;
@@ -0,0 +1,510 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{BlockTree, Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+use ledger_block::{Block, Header, Ratify, Transaction, Transition};
+use ledger_committee::Committee;
+use ledger_store::{to_key_id, to_mapping_id, to_value_id};
+use synthesizer::program::FinalizeOperation;
+
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The magic bytes at the start of a ledger snapshot file.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"SVMLEDGR";
+/// The number of bytes in the checksum at the end of a ledger snapshot file.
+const CHECKSUM_SIZE: usize = 32;
+
+/// The entries of every mapping, as `(program ID, mapping name, entries)`.
+pub type SnapshotMappings<N> = Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>;
+
+/// A portable snapshot of the ledger at a given block height, used to initialize a ledger without replaying it.
+///
+/// The snapshot contains the block hashes and headers of the prior blocks, the block at the snapshot height,
+/// the committees and finalize state as of the snapshot height, and the deployments of the prior blocks.
+/// The transitions of the prior blocks are included without their input and output payloads.
+///
+/// Note: The block headers are verified against the state root in the block at the snapshot height.
+/// The finalize state is verified against the finalize operations of the block at the snapshot height,
+/// which are committed to by its finalize root. As the finalize root does not commit to the entries
+/// that are left unmodified by the block, nor to the entries of a mapping replaced by a ratification,
+/// those entries are only covered by the checksum of the snapshot file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LedgerSnapshot<N: Network> {
+    /// The block hashes and headers of the blocks prior to the snapshot height.
+    headers: Vec<(N::BlockHash, Header<N>)>,
+    /// The block at the snapshot height.
+    block: Block<N>,
+    /// The committees, as `(block height, committee)`, up to and including the snapshot height.
+    committees: Vec<(u32, Committee<N>)>,
+    /// The entries of every mapping, as of the snapshot height.
+    mappings: SnapshotMappings<N>,
+    /// The ratified finalize operations of the block at the snapshot height.
+    ratified_operations: Vec<FinalizeOperation<N>>,
+    /// The ratified restrictions lists, as `(block height, activation height, restrictions ID, restrictions list)`.
+    restrictions: Vec<(u32, u32, Field<N>, String)>,
+    /// The accepted deployment transactions of the prior blocks.
+    deployments: Vec<Transaction<N>>,
+    /// The pruned transitions of the prior blocks, excluding those of the accepted deployment transactions.
+    transitions: Vec<Transition<N>>,
+}
+
+impl<N: Network> LedgerSnapshot<N> {
+    /// Initializes a new ledger snapshot.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        headers: Vec<(N::BlockHash, Header<N>)>,
+        block: Block<N>,
+        committees: Vec<(u32, Committee<N>)>,
+        mappings: SnapshotMappings<N>,
+        ratified_operations: Vec<FinalizeOperation<N>>,
+        restrictions: Vec<(u32, u32, Field<N>, String)>,
+        deployments: Vec<Transaction<N>>,
+        transitions: Vec<Transition<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of prior blocks matches the snapshot height.
+        ensure!(headers.len() == block.height() as usize, "The snapshot must contain the headers of all prior blocks");
+        // Ensure the committee at the snapshot height is included.
+        ensure!(
+            committees.last().map(|(height, _)| *height) == Some(block.height()),
+            "The snapshot must contain the committee at block {}",
+            block.height()
+        );
+        // Ensure the committees are in ascending order of block height.
+        ensure!(
+            committees.windows(2).all(|pair| pair[0].0 + 1 == pair[1].0),
+            "The snapshot committees must be for sequential block heights"
+        );
+        // Ensure the restrictions lists were ratified at or before the snapshot height.
+        ensure!(
+            restrictions.iter().all(|(height, ..)| *height <= block.height()),
+            "The snapshot contains a restrictions list above block {}",
+            block.height()
+        );
+        // Ensure the deployments are all deployment transactions.
+        ensure!(
+            deployments.iter().all(|transaction| transaction.is_deploy()),
+            "The snapshot deployments must be deployment transactions"
+        );
+        Ok(Self { headers, block, committees, mappings, ratified_operations, restrictions, deployments, transitions })
+    }
+
+    /// Reads the snapshot from the file at the given path, and ensures its checksum is correct.
+    ///
+    /// The file is streamed, so that the snapshot is decoded without first reading the file into memory.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut file = BufReader::new(File::open(path)?);
+        let file_size = file.get_ref().metadata()?.len();
+        // Ensure the file is large enough to contain the magic bytes and checksum.
+        let overhead = (SNAPSHOT_MAGIC.len() + CHECKSUM_SIZE) as u64;
+        ensure!(file_size >= overhead, "The ledger snapshot file is truncated");
+        // Ensure the magic bytes are correct.
+        let mut magic = [0u8; SNAPSHOT_MAGIC.len()];
+        file.read_exact(&mut magic)?;
+        ensure!(&magic == SNAPSHOT_MAGIC, "The file is not a ledger snapshot");
+
+        // Read the snapshot from the payload, while computing the checksum of the payload.
+        let mut payload = Checksummed::new((&mut file).take(file_size - overhead));
+        let snapshot = Self::read_le(&mut payload)?;
+        // Ensure the payload is read in full.
+        ensure!(payload.inner.limit() == 0, "The ledger snapshot contains trailing bytes");
+        let candidate_checksum = payload.hasher.finalize();
+
+        // Ensure the checksum is correct.
+        let mut checksum = [0u8; CHECKSUM_SIZE];
+        file.read_exact(&mut checksum)?;
+        ensure!(candidate_checksum.as_slice() == checksum, "The ledger snapshot checksum is incorrect");
+        Ok(snapshot)
+    }
+
+    /// Writes the snapshot to the file at the given path, as the magic bytes, the payload, and the checksum of the payload.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        // Write the magic bytes.
+        file.write_all(SNAPSHOT_MAGIC)?;
+        // Write the payload, while computing the checksum of the payload.
+        let mut payload = Checksummed::new(&mut file);
+        self.write_le(&mut payload)?;
+        let checksum = payload.hasher.finalize();
+        // Write the checksum.
+        file.write_all(&checksum)?;
+        Ok(file.flush()?)
+    }
+
+    /// Verifies the block hashes and headers of the snapshot against the given genesis block hash,
+    /// and against the previous state root of the block at the snapshot height.
+    pub fn verify(&self, genesis_hash: N::BlockHash) -> Result<()> {
+        // Ensure the first block is the genesis block.
+        let first_hash = self.headers.first().map(|(block_hash, _)| *block_hash).unwrap_or(self.block.hash());
+        ensure!(first_hash == genesis_hash, "The ledger snapshot is for a different genesis block");
+
+        // Ensure each block hash is computed from the previous block hash and the block header.
+        cfg_iter!(self.headers).enumerate().try_for_each(|(height, (block_hash, header))| {
+            ensure!(header.height() as usize == height, "Mismatching header height for block {height}");
+            let previous_hash = match height.checked_sub(1) {
+                Some(previous_height) => self.headers[previous_height].0,
+                None => N::BlockHash::default(),
+            };
+            let candidate_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+            ensure!(*block_hash == candidate_hash.into(), "Incorrect block hash for block {height}");
+            Ok(())
+        })?;
+        // Ensure the block at the snapshot height extends the prior blocks.
+        let previous_hash = self.headers.last().map(|(block_hash, _)| *block_hash).unwrap_or_default();
+        ensure!(self.block.previous_hash() == previous_hash, "The snapshot block does not extend the prior blocks");
+
+        // Ensure the prior blocks match the previous state root of the block at the snapshot height.
+        if !self.headers.is_empty() {
+            let leaves = cfg_iter!(self.headers).map(|(block_hash, _)| block_hash.to_bits_le()).collect::<Vec<_>>();
+            let tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+            ensure!(
+                N::StateRoot::from(*tree.root()) == self.block.previous_state_root(),
+                "The prior blocks do not match the state root in block {}",
+                self.block.height()
+            );
+        }
+
+        // Ensure the finalize state matches the finalize root of the block at the snapshot height.
+        self.verify_mappings()
+    }
+
+    /// Verifies the finalize state of the snapshot against the finalize operations of the block at the
+    /// snapshot height, which are committed to by its finalize root.
+    fn verify_mappings(&self) -> Result<()> {
+        // Ensure the ratified finalize operations complete the finalize root of the block.
+        let finalize_root = self.block.transactions().to_finalize_root(self.ratified_operations.clone())?;
+        ensure!(
+            finalize_root == self.block.finalize_root(),
+            "The ratified finalize operations do not match the finalize root in block {}",
+            self.block.height()
+        );
+
+        // Compute the value ID of each entry in the snapshot, indexed by mapping ID and key ID.
+        let mut mappings = HashMap::with_capacity(self.mappings.len());
+        for (program_id, mapping_name, entries) in &self.mappings {
+            let value_ids = cfg_iter!(entries)
+                .map(|(key, value)| {
+                    let key_id = to_key_id(program_id, mapping_name, key)?;
+                    Ok((key_id, to_value_id(&key_id, value)?))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            ensure!(
+                value_ids.len() == entries.len(),
+                "The ledger snapshot contains a duplicate key in the mapping '{program_id}/{mapping_name}'"
+            );
+            if mappings.insert(to_mapping_id(program_id, mapping_name)?, value_ids).is_some() {
+                bail!("The ledger snapshot contains the mapping '{program_id}/{mapping_name}' twice")
+            }
+        }
+
+        // Order the finalize operations of the block as they were applied.
+        // Note: The ratifications are applied before the transactions in the genesis block, and after them otherwise.
+        let transaction_operations =
+            self.block.transactions().iter().flat_map(|confirmed| confirmed.finalize_operations().iter());
+        let operations: Vec<_> = match self.block.height() {
+            0 => self.ratified_operations.iter().chain(transaction_operations).collect(),
+            _ => transaction_operations.chain(self.ratified_operations.iter()).collect(),
+        };
+
+        // Determine the mappings and entries that are committed to by the finalize operations.
+        // Note: A mapping is tracked as `(exists, is_complete)`, where a mapping initialized in the block
+        // is complete, as all of its entries are committed to by the subsequent finalize operations.
+        let mut expected_mappings = IndexMap::new();
+        let mut expected_entries = IndexMap::new();
+        for operation in operations {
+            match *operation {
+                FinalizeOperation::InitializeMapping(mapping_id) => {
+                    expected_mappings.insert(mapping_id, (true, true));
+                    expected_entries.retain(|(id, _), _| *id != mapping_id);
+                }
+                FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id)
+                | FinalizeOperation::UpdateKeyValue(mapping_id, key_id, value_id) => {
+                    expected_entries.insert((mapping_id, key_id), Some(value_id));
+                }
+                FinalizeOperation::RemoveKeyValue(mapping_id, key_id) => {
+                    expected_entries.insert((mapping_id, key_id), None);
+                }
+                // Note: The entries of a replaced mapping are not committed to by the finalize operation.
+                FinalizeOperation::ReplaceMapping(mapping_id) => {
+                    expected_mappings.insert(mapping_id, (true, false));
+                    expected_entries.retain(|(id, _), _| *id != mapping_id);
+                }
+                FinalizeOperation::RemoveMapping(mapping_id) => {
+                    expected_mappings.insert(mapping_id, (false, false));
+                    expected_entries.retain(|(id, _), _| *id != mapping_id);
+                }
+            }
+        }
+
+        // Ensure the mappings match the finalize operations.
+        for (mapping_id, (exists, is_complete)) in expected_mappings {
+            match mappings.get(&mapping_id) {
+                Some(entries) => {
+                    ensure!(exists, "The ledger snapshot contains the removed mapping '{mapping_id}'");
+                    if is_complete {
+                        let num_entries = expected_entries
+                            .iter()
+                            .filter(|((id, _), value_id)| *id == mapping_id && value_id.is_some())
+                            .count();
+                        ensure!(
+                            entries.len() == num_entries,
+                            "The ledger snapshot contains extra entries in the mapping '{mapping_id}'"
+                        );
+                    }
+                }
+                None => ensure!(!exists, "The ledger snapshot is missing the mapping '{mapping_id}'"),
+            }
+        }
+        // Ensure the entries match the finalize operations.
+        for ((mapping_id, key_id), value_id) in expected_entries {
+            let candidate = mappings.get(&mapping_id).and_then(|entries| entries.get(&key_id));
+            ensure!(
+                candidate == value_id.as_ref(),
+                "The ledger snapshot entry '{key_id}' in the mapping '{mapping_id}' does not match block {}",
+                self.block.height()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the snapshot height.
+    pub fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the block hashes and headers of the blocks prior to the snapshot height.
+    pub fn headers(&self) -> &[(N::BlockHash, Header<N>)] {
+        &self.headers
+    }
+
+    /// Returns the block at the snapshot height.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the committees, as `(block height, committee)`, up to and including the snapshot height.
+    pub fn committees(&self) -> &[(u32, Committee<N>)] {
+        &self.committees
+    }
+
+    /// Returns the entries of every mapping, as of the snapshot height.
+    pub fn mappings(&self) -> &SnapshotMappings<N> {
+        &self.mappings
+    }
+
+    /// Returns the ratified finalize operations of the block at the snapshot height.
+    pub fn ratified_operations(&self) -> &[FinalizeOperation<N>] {
+        &self.ratified_operations
+    }
+
+    /// Returns the ratified restrictions lists, as `(block height, activation height, restrictions ID, restrictions list)`.
+    pub fn restrictions(&self) -> &[(u32, u32, Field<N>, String)] {
+        &self.restrictions
+    }
+
+    /// Returns the accepted deployment transactions of the prior blocks.
+    pub fn deployments(&self) -> &[Transaction<N>] {
+        &self.deployments
+    }
+
+    /// Returns the pruned transitions of the prior blocks.
+    pub fn transitions(&self) -> &[Transition<N>] {
+        &self.transitions
+    }
+}
+
+/// Reads `num_elements` elements from the given reader.
+fn read_elements<T, R: Read>(
+    mut reader: R,
+    num_elements: u32,
+    read_element: impl Fn(&mut R) -> IoResult<T>,
+) -> IoResult<Vec<T>> {
+    (0..num_elements).map(|_| read_element(&mut reader)).collect()
+}
+
+/// Writes the number of elements as a `u32`, followed by the elements, to the given writer.
+fn write_elements<T, W: Write>(
+    mut writer: W,
+    elements: &[T],
+    write_element: impl Fn(&mut W, &T) -> IoResult<()>,
+) -> IoResult<()> {
+    u32::try_from(elements.len()).map_err(error)?.write_le(&mut writer)?;
+    elements.iter().try_for_each(|element| write_element(&mut writer, element))
+}
+
+impl<N: Network> FromBytes for LedgerSnapshot<N> {
+    /// Reads the ledger snapshot from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid ledger snapshot version"));
+        }
+        // Read the network ID.
+        let network_id = u16::read_le(&mut reader)?;
+        // Ensure the network ID is correct.
+        if network_id != N::ID {
+            return Err(error(format!("The ledger snapshot is for network {network_id}, expected {}", N::ID)));
+        }
+
+        // Read the block headers.
+        let num_headers = u32::read_le(&mut reader)?;
+        let headers = read_elements(&mut reader, num_headers, |reader| {
+            Ok((N::BlockHash::read_le(&mut *reader)?, Header::read_le(&mut *reader)?))
+        })?;
+        // Read the block.
+        let block = Block::read_le(&mut reader)?;
+        // Read the committees.
+        let num_committees = u32::read_le(&mut reader)?;
+        let committees = read_elements(&mut reader, num_committees, |reader| {
+            Ok((u32::read_le(&mut *reader)?, Committee::read_le(&mut *reader)?))
+        })?;
+        // Read the mappings.
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mappings = read_elements(&mut reader, num_mappings, |reader| {
+            let program_id = ProgramID::read_le(&mut *reader)?;
+            let mapping_name = Identifier::read_le(&mut *reader)?;
+            let num_entries = u32::read_le(&mut *reader)?;
+            let entries = read_elements(&mut *reader, num_entries, |reader| {
+                Ok((Plaintext::read_le(&mut *reader)?, Value::read_le(&mut *reader)?))
+            })?;
+            Ok((program_id, mapping_name, entries))
+        })?;
+        // Read the ratified finalize operations.
+        let num_operations = u32::read_le(&mut reader)?;
+        let ratified_operations =
+            read_elements(&mut reader, num_operations, |reader| FinalizeOperation::read_le(&mut *reader))?;
+        // Read the restrictions lists.
+        let num_restrictions = u32::read_le(&mut reader)?;
+        let restrictions = read_elements(&mut reader, num_restrictions, |reader| {
+            let block_height = u32::read_le(&mut *reader)?;
+            let activation_height = u32::read_le(&mut *reader)?;
+            let restrictions_id = Field::read_le(&mut *reader)?;
+            let num_bytes = u32::read_le(&mut *reader)?;
+            if num_bytes as usize > Ratify::<N>::MAX_RESTRICTIONS_SIZE {
+                return Err(error("The restrictions list in the ledger snapshot is too large"));
+            }
+            let mut bytes = vec![0u8; num_bytes as usize];
+            reader.read_exact(&mut bytes)?;
+            let restrictions = String::from_utf8(bytes).map_err(error)?;
+            Ok((block_height, activation_height, restrictions_id, restrictions))
+        })?;
+        // Read the deployments.
+        let num_deployments = u32::read_le(&mut reader)?;
+        let deployments = read_elements(&mut reader, num_deployments, |reader| Transaction::read_le(&mut *reader))?;
+        // Read the transitions.
+        let num_transitions = u32::read_le(&mut reader)?;
+        let transitions = read_elements(&mut reader, num_transitions, |reader| Transition::read_le(&mut *reader))?;
+
+        // Construct the ledger snapshot.
+        Self::new(headers, block, committees, mappings, ratified_operations, restrictions, deployments, transitions)
+            .map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for LedgerSnapshot<N> {
+    /// Writes the ledger snapshot to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the network ID.
+        N::ID.write_le(&mut writer)?;
+
+        // Write the block headers.
+        write_elements(&mut writer, &self.headers, |writer, (block_hash, header)| {
+            block_hash.write_le(&mut *writer)?;
+            header.write_le(&mut *writer)
+        })?;
+        // Write the block.
+        self.block.write_le(&mut writer)?;
+        // Write the committees.
+        write_elements(&mut writer, &self.committees, |writer, (block_height, committee)| {
+            block_height.write_le(&mut *writer)?;
+            committee.write_le(&mut *writer)
+        })?;
+        // Write the mappings.
+        write_elements(&mut writer, &self.mappings, |writer, (program_id, mapping_name, entries)| {
+            program_id.write_le(&mut *writer)?;
+            mapping_name.write_le(&mut *writer)?;
+            write_elements(&mut *writer, entries, |writer, (key, value)| {
+                key.write_le(&mut *writer)?;
+                value.write_le(&mut *writer)
+            })
+        })?;
+        // Write the ratified finalize operations.
+        write_elements(&mut writer, &self.ratified_operations, |writer, operation| operation.write_le(&mut *writer))?;
+        // Write the restrictions lists.
+        write_elements(&mut writer, &self.restrictions, |writer, (block_height, activation_height, id, list)| {
+            block_height.write_le(&mut *writer)?;
+            activation_height.write_le(&mut *writer)?;
+            id.write_le(&mut *writer)?;
+            u32::try_from(list.len()).map_err(error)?.write_le(&mut *writer)?;
+            list.as_bytes().write_le(&mut *writer)
+        })?;
+        // Write the deployments.
+        write_elements(&mut writer, &self.deployments, |writer, transaction| transaction.write_le(&mut *writer))?;
+        // Write the transitions.
+        write_elements(&mut writer, &self.transitions, |writer, transition| transition.write_le(&mut *writer))
+    }
+}
+
+/// A reader or writer that computes the checksum of the bytes that pass through it.
+struct Checksummed<T> {
+    /// The inner reader or writer.
+    inner: T,
+    /// The hasher of the bytes read or written.
+    hasher: Sha256,
+}
+
+impl<T> Checksummed<T> {
+    /// Initializes a new checksummed reader or writer.
+    fn new(inner: T) -> Self {
+        Self { inner, hasher: Sha256::new() }
+    }
+}
+
+impl<R: Read> Read for Checksummed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_bytes = self.inner.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+impl<W: Write> Write for Checksummed<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let num_bytes = self.inner.write(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
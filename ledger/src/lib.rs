@@ -42,6 +42,7 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -56,10 +57,10 @@ use ledger_authority::Authority;
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_puzzle::{Puzzle, PuzzleSolutions, Solution, SolutionID};
-use ledger_query::Query;
+use ledger_query::{Query, QuerySnapshot};
 use ledger_store::{ConsensusStorage, ConsensusStore};
 use synthesizer::{
-    program::{FinalizeGlobalState, Program},
+    program::{Event, FinalizeGlobalState, Program},
     vm::VM,
 };
 
@@ -94,6 +95,18 @@ pub enum RecordsFilter<N: Network> {
     SlowUnspent(PrivateKey<N>),
 }
 
+#[derive(Copy, Clone, Debug)]
+pub enum TransitionsFilter<N: Network> {
+    /// Returns all transitions associated with the address.
+    All,
+    /// Returns only `credits.aleo/transfer_public*` transitions associated with the address.
+    TransferPublic,
+    /// Returns only `credits.aleo/bond_*` transitions associated with the address.
+    Bond,
+    /// Returns only transitions of the given program associated with the address.
+    Program(ProgramID<N>),
+}
+
 #[derive(Clone)]
 pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     /// The VM state.
@@ -111,12 +124,26 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Loads the ledger from storage.
     pub fn load(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+        Self::load_with_pruning_depth(genesis_block, storage_mode, None)
+    }
+
+    /// Loads the ledger from storage, retaining the transactions of the given number of most recent blocks in full,
+    /// or of all blocks if the `pruning depth` is `None`. As new blocks are added, the proofs and transition
+    /// payloads of older blocks are pruned, and retrieving their transactions returns an error.
+    /// The block headers, serial numbers, tags, commitments, and records are retained.
+    ///
+    /// The pruning depth is persisted in storage, and a pruned ledger cannot be reloaded as an archival ledger.
+    pub fn load_with_pruning_depth(
+        genesis_block: Block<N>,
+        storage_mode: StorageMode,
+        pruning_depth: Option<u32>,
+    ) -> Result<Self> {
         let timer = timer!("Ledger::load");
 
         // Retrieve the genesis hash.
         let genesis_hash = genesis_block.hash();
         // Initialize the ledger.
-        let ledger = Self::load_unchecked(genesis_block, storage_mode)?;
+        let ledger = Self::load_unchecked_with_pruning_depth(genesis_block, storage_mode, pruning_depth)?;
 
         // Ensure the ledger contains the correct genesis block.
         if !ledger.contains_block_hash(&genesis_hash)? {
@@ -132,7 +159,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let block_heights: Vec<u32> =
             (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
+            // Note: If the ledger was loaded from a snapshot, only the headers of the prior blocks are stored,
+            // and the transactions of pruned blocks are no longer retrievable.
+            let block_hash = ledger.get_hash(height)?;
+            let block_store = ledger.vm.block_store();
+            match block_store.get_block_authority(&block_hash)?.is_some() && !block_store.is_pruned(&block_hash)? {
+                true => ledger.get_block(height).map(|_| ())?,
+                false => ledger.get_header(height).map(|_| ())?,
+            };
             Ok::<_, Error>(())
         })?;
         lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
@@ -143,16 +177,36 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Loads the ledger from storage, without performing integrity checks.
     pub fn load_unchecked(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+        Self::load_unchecked_with_pruning_depth(genesis_block, storage_mode, None)
+    }
+
+    /// Loads the ledger from storage with the given pruning depth, without performing integrity checks.
+    pub fn load_unchecked_with_pruning_depth(
+        genesis_block: Block<N>,
+        storage_mode: StorageMode,
+        pruning_depth: Option<u32>,
+    ) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
 
         info!("Loading the ledger from storage...");
         // Initialize the consensus store.
-        let store = match ConsensusStore::<N, C>::open(storage_mode) {
+        let store = match ConsensusStore::<N, C>::open_with_pruning_depth(storage_mode, pruning_depth) {
             Ok(store) => store,
             Err(e) => bail!("Failed to load ledger (run 'snarkos clean' and try again)\n\n{e}\n"),
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, adding the genesis block if the store is empty.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
@@ -191,7 +245,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current epoch hash.
         ledger.current_epoch_hash = Arc::new(RwLock::new(Some(ledger.get_epoch_hash(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
+        finish!(timer, "Set the latest block");
         Ok(ledger)
     }
 
@@ -0,0 +1,169 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_store::atomic_batch_scope;
+
+use std::path::Path;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports a snapshot of the ledger at the given block height to the file at the given path.
+    pub fn export_snapshot<P: AsRef<Path>>(&self, path: P, height: u32) -> Result<()> {
+        self.get_snapshot(height)?.save(path)
+    }
+
+    /// Returns a snapshot of the ledger at the given block height.
+    pub fn get_snapshot(&self, height: u32) -> Result<LedgerSnapshot<N>> {
+        // Acquire the read lock on the current block, to prevent the ledger from advancing during the export.
+        let current_block = self.current_block.read();
+        // Ensure the block height exists.
+        ensure!(height <= current_block.height(), "Cannot export a snapshot above the latest block");
+
+        // Retrieve the block at the snapshot height.
+        let block = self.get_block(height)?;
+        // Retrieve the block hashes and headers of the prior blocks.
+        let headers = cfg_into_iter!(0..height)
+            .map(|height| Ok((self.get_hash(height)?, self.get_header(height)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the committees for the rounds that may be looked back on from the subsequent blocks.
+        // Note: Twice the lookback range is kept, to also cover the certificates within the garbage collection range.
+        let committee_store = self.vm.finalize_store().committee_store();
+        let starting_round = block.round().saturating_sub(2 * Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        let starting_height = committee_store
+            .get_height_for_round(starting_round)?
+            .ok_or_else(|| anyhow!("Missing the block height for round {starting_round}"))?;
+        let committees = (starting_height..=height)
+            .map(|height| match committee_store.get_committee(height)? {
+                Some(committee) => Ok((height, committee)),
+                None => bail!("Missing the committee for block {height}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the mappings as of the snapshot height.
+        let mappings = self.vm.finalize_store().get_mappings_at(height)?;
+        // Retrieve the ratified finalize operations of the block at the snapshot height.
+        let ratified_operations = self
+            .vm
+            .finalize_store()
+            .get_ratified_operations_confirmed(height)?
+            .ok_or_else(|| anyhow!("Missing the ratified finalize operations for block {height}"))?;
+        // Retrieve the restrictions lists ratified up to the snapshot height.
+        let mut restrictions = self.vm.finalize_store().get_restrictions_confirmed()?;
+        restrictions.retain(|(block_height, ..)| *block_height <= height);
+
+        // Retrieve the deployments and the pruned transitions of the prior blocks.
+        let contents = cfg_into_iter!(0..height)
+            .map(|height| {
+                let mut deployments = Vec::new();
+                let mut transitions = Vec::new();
+                for confirmed in self.get_transactions(height)?.iter() {
+                    match confirmed {
+                        // Note: Deployments are kept in full, as they are required to load the programs.
+                        ConfirmedTransaction::AcceptedDeploy(_, transaction, _) => {
+                            deployments.push(transaction.clone())
+                        }
+                        _ => {
+                            for transition in confirmed.transaction().transitions() {
+                                transitions.push(transition.to_pruned()?);
+                            }
+                        }
+                    }
+                }
+                Ok((deployments, transitions))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let (deployments, transitions): (Vec<_>, Vec<_>) = contents.into_iter().unzip();
+
+        LedgerSnapshot::new(
+            headers,
+            block,
+            committees,
+            mappings,
+            ratified_operations,
+            restrictions,
+            deployments.into_iter().flatten().collect(),
+            transitions.into_iter().flatten().collect(),
+        )
+    }
+
+    /// Loads the ledger from the snapshot file at the given path, into empty storage.
+    ///
+    /// The snapshot is written to storage in a single atomic batch, so that a failed load leaves the storage empty.
+    pub fn load_from_snapshot<P: AsRef<Path>>(
+        genesis_block: Block<N>,
+        path: P,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_snapshot");
+
+        info!("Loading the ledger from a snapshot...");
+        // Read the snapshot.
+        let snapshot = LedgerSnapshot::<N>::open(path)?;
+        // Verify the snapshot against the genesis block.
+        snapshot.verify(genesis_block.hash())?;
+        lap!(timer, "Read and verify the snapshot");
+
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage_mode)?;
+        // Ensure the consensus store is empty.
+        if store.block_store().heights().max().is_some() {
+            bail!("Cannot load a snapshot into non-empty storage (run 'snarkos clean' and try again)")
+        }
+
+        atomic_batch_scope!(store, {
+            // Store the committees.
+            store.finalize_store().committee_store().initialize(snapshot.committees().to_vec())?;
+            // Store the mappings.
+            for (program_id, mapping_name, entries) in snapshot.mappings() {
+                store.finalize_store().initialize_mapping(*program_id, *mapping_name)?;
+                store.finalize_store().replace_mapping(*program_id, *mapping_name, entries.clone())?;
+            }
+            // Store the ratified finalize operations of the block at the snapshot height.
+            store
+                .finalize_store()
+                .insert_ratified_operations(snapshot.height(), snapshot.ratified_operations().to_vec())?;
+            // Store the restrictions lists.
+            for (block_height, activation_height, restrictions_id, restrictions) in snapshot.restrictions() {
+                store.finalize_store().insert_restrictions(
+                    *block_height,
+                    *activation_height,
+                    *restrictions_id,
+                    restrictions.clone(),
+                )?;
+            }
+
+            // Store the deployments.
+            for transaction in snapshot.deployments() {
+                store.transaction_store().insert(transaction)?;
+            }
+            // Store the pruned transitions.
+            for transition in snapshot.transitions() {
+                store.transition_store().insert(transition)?;
+            }
+            // Store the block headers and the block at the snapshot height.
+            store.block_store().initialize(snapshot.headers(), snapshot.block())
+        })?;
+        lap!(timer, "Store the snapshot");
+
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+        // Ensure the ledger is at the snapshot height.
+        ensure!(ledger.latest_height() == snapshot.height(), "Failed to load the ledger at the snapshot height");
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+}
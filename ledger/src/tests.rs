@@ -17,20 +17,25 @@ use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentAleo, CurrentLedger, CurrentNetwork},
     Ledger,
+    LedgerSnapshot,
     RecordsFilter,
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, Signature, ViewKey},
     network::{prelude::*, MainnetV0},
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
-    types::U16,
+    types::{Field, U16},
 };
 use ledger_authority::Authority;
 use ledger_block::{Block, ConfirmedTransaction, Execution, Ratify, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID};
-use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use ledger_query::{Query, QuerySnapshot, QueryTrait};
+use ledger_store::{
+    helpers::memory::{BlockMemory, ConsensusMemory},
+    ConsensusStore,
+};
 use snarkvm_utilities::try_vm_runtime;
 use synthesizer::{program::Program, vm::VM, Stack};
 
@@ -230,6 +235,163 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_export_query_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let block = ledger.get_block(0).unwrap();
+    // Retrieve the commitments.
+    let commitments = block.transactions().commitments().take(2).collect::<Vec<_>>();
+
+    // Export the query snapshot.
+    let snapshot = ledger.export_query_snapshot(commitments.iter().copied()).unwrap();
+    assert_eq!(snapshot.state_root(), ledger.latest_state_root());
+    assert_eq!(snapshot.state_paths().len(), commitments.len());
+
+    // Ensure the snapshot round-trips through its byte representation.
+    let snapshot = QuerySnapshot::<CurrentNetwork>::from_bytes_le(&snapshot.to_bytes_le().unwrap()).unwrap();
+
+    // Ensure the snapshot query matches the ledger.
+    let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(snapshot);
+    assert_eq!(query.current_state_root().unwrap(), ledger.latest_state_root());
+    for commitment in commitments {
+        assert_eq!(
+            query.get_state_path_for_commitment(commitment).unwrap(),
+            ledger.get_state_path_for_commitment(commitment).unwrap()
+        );
+    }
+    // Ensure a commitment that is not in the snapshot is rejected.
+    assert!(query.get_state_path_for_commitment(&Field::from_u64(1)).is_err());
+}
+
+#[test]
+fn test_export_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counts:
+    key as address.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo self.caller r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as address.public;
+    input r1 as u8.public;
+    set r1 into counts[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+
+    // Execute the program.
+    let inputs = [Value::<CurrentNetwork>::from_str("7u8").unwrap()];
+    let execution = ledger.vm.execute(&private_key, (program_id, "foo"), inputs.iter(), None, 0, None, rng).unwrap();
+    let block_2 = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![execution.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+    let checksum_2 = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+
+    // Transfer public credits.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transfer = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block_3 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer], rng).unwrap();
+    ledger.advance_to_next_block(&block_3).unwrap();
+
+    // Export the snapshot at block 2.
+    let path = std::env::temp_dir().join(format!("snarkvm-ledger-snapshot-{}", rng.gen::<u64>()));
+    ledger.export_snapshot(&path, 2).unwrap();
+    // Ensure a snapshot above the latest block cannot be exported.
+    assert!(ledger.export_snapshot(&path, 4).is_err());
+
+    // Load a new ledger from the snapshot.
+    let loaded =
+        CurrentLedger::load_from_snapshot(ledger.genesis_block.clone(), &path, StorageMode::Production).unwrap();
+    assert_eq!(loaded.latest_height(), 2);
+    assert_eq!(loaded.latest_block(), block_2);
+    assert_eq!(loaded.latest_state_root(), ledger.vm().block_store().get_state_root(2).unwrap().unwrap());
+    assert_eq!(loaded.latest_committee().unwrap(), ledger.get_committee(2).unwrap().unwrap());
+    assert_eq!(loaded.vm().finalize_store().get_checksum_confirmed().unwrap(), checksum_2);
+    assert_eq!(loaded.get_header(1).unwrap(), *block_1.header());
+    assert!(loaded.vm().contains_program(&program_id));
+    // Ensure the transitions and records of the prior blocks are retained.
+    for transition_id in execution.transition_ids() {
+        assert!(loaded.contains_transition_id(transition_id).unwrap());
+    }
+    for commitment in ledger.genesis_block.transactions().commitments() {
+        assert!(loaded.contains_commitment(commitment).unwrap());
+    }
+
+    // Ensure the loaded ledger can be extended.
+    loaded.check_next_block(&block_3, rng).unwrap();
+    loaded.advance_to_next_block(&block_3).unwrap();
+    assert_eq!(loaded.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(
+        loaded.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+
+    // Ensure a snapshot whose finalize state does not match the finalize root of the snapshot block is rejected.
+    let snapshot = ledger.get_snapshot(2).unwrap();
+    snapshot.verify(ledger.genesis_block.hash()).unwrap();
+    let counts = Identifier::from_str("counts").unwrap();
+    let tamper = |mappings, ratified_operations| {
+        LedgerSnapshot::new(
+            snapshot.headers().to_vec(),
+            snapshot.block().clone(),
+            snapshot.committees().to_vec(),
+            mappings,
+            ratified_operations,
+            snapshot.restrictions().to_vec(),
+            snapshot.deployments().to_vec(),
+            snapshot.transitions().to_vec(),
+        )
+        .unwrap()
+    };
+    // Tamper with the entry that is updated in the snapshot block.
+    let mut mappings = snapshot.mappings().clone();
+    let (_, _, entries) = mappings.iter_mut().find(|(id, name, _)| *id == program_id && *name == counts).unwrap();
+    entries[0].1 = Value::from_str("8u8").unwrap();
+    let tampered = tamper(mappings, snapshot.ratified_operations().to_vec());
+    assert!(tampered.verify(ledger.genesis_block.hash()).is_err());
+    // Tamper with the ratified finalize operations.
+    let tampered = tamper(snapshot.mappings().clone(), vec![]);
+    assert!(tampered.verify(ledger.genesis_block.hash()).is_err());
+    // Remove the mapping that is updated in the snapshot block.
+    let mut mappings = snapshot.mappings().clone();
+    mappings.retain(|(id, name, _)| *id != program_id || *name != counts);
+    let tampered = tamper(mappings, snapshot.ratified_operations().to_vec());
+    tampered.save(&path).unwrap();
+    assert!(CurrentLedger::load_from_snapshot(ledger.genesis_block.clone(), &path, StorageMode::Production).is_err());
+
+    // Ensure a corrupted snapshot is rejected.
+    ledger.export_snapshot(&path, 2).unwrap();
+    let mut bytes = std::fs::read(&path).unwrap();
+    let index = bytes.len() / 2;
+    bytes[index] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+    assert!(CurrentLedger::load_from_snapshot(ledger.genesis_block.clone(), &path, StorageMode::Production).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -633,6 +795,345 @@ finalize foo:
     assert_eq!(program, ledger.get_program(program_id).unwrap())
 }
 
+#[test]
+fn test_rollback_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the finalize state of the genesis block.
+    let genesis_checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+    let genesis_committee = ledger.latest_committee().unwrap();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counts:
+    key as address.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo self.caller r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as address.public;
+    input r1 as u8.public;
+    set r1 into counts[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+    let checksum_1 = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+
+    // Execute the program and transfer public credits.
+    let inputs = [Value::<CurrentNetwork>::from_str("7u8").unwrap()];
+    let execution = ledger.vm.execute(&private_key, (program_id, "foo"), inputs.iter(), None, 0, None, rng).unwrap();
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transfer = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block_2 = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![execution, transfer], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Ensure the mapping value is set.
+    let counts = Identifier::from_str("counts").unwrap();
+    let key = Plaintext::from_str(&address.to_string()).unwrap();
+    assert!(ledger.vm().finalize_store().contains_key_confirmed(program_id, counts, &key).unwrap());
+
+    // Roll back to block 1.
+    ledger.rollback_to(1).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.latest_hash(), block_1.hash());
+    assert_eq!(ledger.latest_state_root(), ledger.vm().block_store().get_state_root(1).unwrap().unwrap());
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), checksum_1);
+    assert!(!ledger.vm().finalize_store().contains_key_confirmed(program_id, counts, &key).unwrap());
+    assert!(!ledger.contains_block_hash(&block_2.hash()).unwrap());
+    for transaction in block_2.transactions().iter() {
+        assert!(!ledger.contains_transaction_id(&transaction.id()).unwrap());
+    }
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Roll back to the genesis block.
+    ledger.rollback_to(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), genesis_checksum);
+    assert_eq!(ledger.latest_committee().unwrap(), genesis_committee);
+    assert!(!ledger.vm().finalize_store().contains_program_confirmed(&program_id).unwrap());
+    assert!(!ledger.vm().contains_program(&program_id));
+
+    // Ensure the ledger cannot roll back to a future block.
+    assert!(ledger.rollback_to(1).is_err());
+
+    // Ensure the removed blocks can be re-added.
+    ledger.advance_to_next_block(&block_1).unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert!(ledger.vm().finalize_store().contains_key_confirmed(program_id, counts, &key).unwrap());
+}
+
+#[test]
+fn test_pruning_depth() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    let genesis_block = ledger.genesis_block.clone();
+
+    // Ensure the pruning depth must be non-zero.
+    assert!(CurrentLedger::load_with_pruning_depth(genesis_block.clone(), StorageMode::Production, Some(0)).is_err());
+    // Load a ledger that retains the transactions of the latest block only.
+    let ledger = CurrentLedger::load_with_pruning_depth(genesis_block, StorageMode::Production, Some(1)).unwrap();
+    assert_eq!(ledger.vm().block_store().pruning_depth().unwrap(), Some(1));
+
+    // Transfer public credits in block 1.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transfer = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer.clone()], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+
+    // Ensure the genesis block is pruned, and block 1 is not.
+    let error = ledger.get_block(0).unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+    assert!(ledger.get_transactions(0).is_err());
+    assert_eq!(ledger.get_block(1).unwrap(), block_1);
+    assert_eq!(ledger.get_transaction(transfer.id()).unwrap(), transfer);
+
+    // Advance to block 2.
+    let block_2 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+
+    // Ensure block 1 is pruned.
+    assert!(ledger.get_block(1).is_err());
+    let error = ledger.get_transaction(transfer.id()).unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+    let error = ledger.vm().block_store().get_events(&transfer.id()).unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+    // Ensure the headers, transition IDs, and records are retained.
+    assert_eq!(ledger.get_header(1).unwrap(), *block_1.header());
+    assert!(ledger.contains_transaction_id(&transfer.id()).unwrap());
+    for transition_id in transfer.transition_ids() {
+        assert!(ledger.contains_transition_id(transition_id).unwrap());
+    }
+    for commitment in ledger.genesis_block.transactions().commitments() {
+        assert!(ledger.contains_commitment(commitment).unwrap());
+    }
+    // Ensure the records of the pruned blocks can still be found.
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    assert!(ledger.find_records(&view_key, RecordsFilter::All).unwrap().count() > 0);
+
+    // Ensure the ledger can still advance.
+    let block_3 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_3).unwrap();
+    assert_eq!(ledger.latest_height(), 3);
+}
+
+#[cfg(feature = "address-index")]
+#[test]
+fn test_find_transitions_for_address() {
+    use crate::TransitionsFilter;
+
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient.
+    let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Transfer public credits to the recipient in block 1.
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transfer = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer.clone()], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+
+    // Advance to block 2.
+    let block_2 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+
+    // Ensure the transfer is found for the recipient.
+    let transfer_transition = transfer.transitions().next().unwrap();
+    let candidates =
+        ledger.find_transitions_for_address(&recipient, 0..3, TransitionsFilter::All).unwrap().collect::<Vec<_>>();
+    assert_eq!(candidates, vec![(1, transfer_transition.clone())]);
+    let candidates =
+        ledger.find_transitions_for_address(&recipient, 0..3, TransitionsFilter::TransferPublic).unwrap().count();
+    assert_eq!(candidates, 1);
+
+    // Ensure the transfer is excluded by the filter and the block range.
+    assert_eq!(ledger.find_transitions_for_address(&recipient, 0..3, TransitionsFilter::Bond).unwrap().count(), 0);
+    assert_eq!(ledger.find_transitions_for_address(&recipient, 2..3, TransitionsFilter::All).unwrap().count(), 0);
+
+    // Ensure the sender finds the transfer and the fee transition of block 1.
+    let candidates = ledger
+        .find_transitions_for_address(&address, 1..2, TransitionsFilter::All)
+        .unwrap()
+        .map(|(_, transition)| *transition.id())
+        .collect::<Vec<_>>();
+    assert!(candidates.contains(transfer_transition.id()));
+    assert!(candidates.contains(transfer.fee_transition().unwrap().id()));
+}
+
+#[test]
+fn test_find_events() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("events_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.public;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;
+    emit doubled r1;
+    emit done true;",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Execute the program in blocks 2 and 3.
+    let mut transaction_ids = Vec::new();
+    for input in ["1u8", "2u8"] {
+        let inputs = [Value::<CurrentNetwork>::from_str(input).unwrap()];
+        let transaction =
+            ledger.vm.execute(&private_key, (program_id, "foo"), inputs.iter(), None, 0, None, rng).unwrap();
+        transaction_ids.push(transaction.id());
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        // Ensure the transaction was accepted with its events.
+        let confirmed = block.transactions().iter().next().unwrap();
+        assert!(confirmed.is_accepted());
+        assert_eq!(confirmed.events().len(), 2);
+    }
+    assert_eq!(ledger.latest_height(), 3);
+
+    // Ensure the events are found in ascending order of block height.
+    let doubled = Identifier::from_str("doubled").unwrap();
+    let candidates = ledger.find_events(&program_id, &doubled, 0..4).unwrap();
+    assert_eq!(candidates.len(), 2);
+    assert_eq!((candidates[0].0, candidates[0].1), (2, transaction_ids[0]));
+    assert_eq!(candidates[0].2.payload(), &Plaintext::from_str("2u8").unwrap());
+    assert_eq!((candidates[1].0, candidates[1].1), (3, transaction_ids[1]));
+    assert_eq!(candidates[1].2.payload(), &Plaintext::from_str("4u8").unwrap());
+
+    // Ensure the events are filtered by the block range and the event name.
+    assert_eq!(ledger.find_events(&program_id, &doubled, 3..4).unwrap().len(), 1);
+    assert_eq!(ledger.find_events(&program_id, &doubled, 0..3).unwrap()[0].0, 2);
+    assert!(ledger.find_events(&program_id, &doubled, 4..10).unwrap().is_empty());
+    let done = Identifier::from_str("done").unwrap();
+    assert_eq!(ledger.find_events(&program_id, &done, 0..4).unwrap().len(), 2);
+    let missing = Identifier::from_str("missing").unwrap();
+    assert!(ledger.find_events(&program_id, &missing, 0..4).unwrap().is_empty());
+
+    // Ensure the events are retrievable from the confirmed transaction in storage.
+    let confirmed = ledger.get_confirmed_transaction(transaction_ids[0]).unwrap();
+    assert_eq!(confirmed.events().len(), 2);
+}
+
+#[test]
+fn test_restrictions_ratification_requires_quorum() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the validators, with equal stake.
+    let private_keys: Vec<_> = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect();
+    let addresses: Vec<_> = private_keys.iter().map(|private_key| Address::try_from(private_key).unwrap()).collect();
+    let committee_map = addresses.iter().map(|address| (*address, (MIN_VALIDATOR_STAKE, true, 0u8))).collect();
+    let remaining_supply = <CurrentNetwork as Network>::STARTING_SUPPLY - MIN_VALIDATOR_STAKE * 4;
+    let public_balances = addresses.iter().map(|address| (*address, remaining_supply / 4)).collect();
+    let bonded_balances =
+        addresses.iter().map(|address| (*address, (*address, *address, MIN_VALIDATOR_STAKE))).collect();
+
+    // Initialize a ledger with the validators as the genesis committee.
+    let genesis_block = sample_vm()
+        .genesis_quorum(
+            &private_keys[0],
+            Committee::new_genesis(committee_map).unwrap(),
+            public_balances,
+            bonded_balances,
+            rng,
+        )
+        .unwrap();
+    let ledger = CurrentLedger::load(genesis_block, StorageMode::Production).unwrap();
+
+    // Construct a restrictions list, and the ratification signed by the given validators.
+    let activation_height = 10;
+    let list = synthesizer::Restrictions::<CurrentNetwork>::new_blank().unwrap().to_string();
+    let message = Ratify::<CurrentNetwork>::restrictions_message(None, activation_height, &list).unwrap();
+    let ratify = |private_keys: &[PrivateKey<CurrentNetwork>], rng: &mut TestRng| {
+        let signatures = private_keys
+            .iter()
+            .map(|private_key| Signature::sign_bytes(private_key, &message, rng).unwrap())
+            .collect::<Vec<_>>();
+        Ratify::Restrictions(activation_height, Box::new(list.clone()), Box::new(signatures))
+    };
+
+    // Ensure a restrictions list without signatures is rejected.
+    let unauthorized = ratify(&[], rng);
+    assert!(
+        ledger
+            .prepare_advance_to_next_beacon_block(&private_keys[0], vec![unauthorized], vec![], vec![], rng)
+            .is_err()
+    );
+    // Ensure a restrictions list signed by less than a quorum of the committee is rejected.
+    let unauthorized = ratify(&private_keys[..2], rng);
+    assert!(
+        ledger
+            .prepare_advance_to_next_beacon_block(&private_keys[0], vec![unauthorized], vec![], vec![], rng)
+            .is_err()
+    );
+    // Ensure a restrictions list signed by a non-committee member is rejected.
+    let outsider = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let unauthorized = ratify(&[private_keys[0], private_keys[1], outsider], rng);
+    assert!(
+        ledger
+            .prepare_advance_to_next_beacon_block(&private_keys[0], vec![unauthorized], vec![], vec![], rng)
+            .is_err()
+    );
+    assert!(ledger.vm().finalize_store().get_restrictions_confirmed().unwrap().is_empty());
+
+    // Ensure a restrictions list signed by a quorum of the committee is ratified.
+    let authorized = ratify(&private_keys[..3], rng);
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_keys[0], vec![authorized], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.vm().finalize_store().get_restrictions_confirmed().unwrap().len(), 1);
+
+    // Ensure the signatures cannot be replayed, once the restrictions list has been ratified.
+    let replayed = ratify(&private_keys[..3], rng);
+    assert!(
+        ledger.prepare_advance_to_next_beacon_block(&private_keys[0], vec![replayed], vec![], vec![], rng).is_err()
+    );
+}
+
 #[test]
 fn test_bond_and_unbond_validator() {
     let rng = &mut TestRng::default();
@@ -20,7 +20,7 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
     TransactionStorage,
     TransactionStore,
     TransitionStorage,
@@ -28,7 +28,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, HeaderLeaf, Identifier, ProgramID, StatePath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -44,37 +44,40 @@ use ledger_block::{
 };
 use ledger_narwhal_batch_certificate::BatchCertificate;
 use ledger_puzzle::{Solution, SolutionID};
-use synthesizer_program::{FinalizeOperation, Program};
+use synthesizer_program::{Event, FinalizeOperation, Program};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, ops::Range, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The key of the pruning depth of the block storage.
+const PRUNING_DEPTH_KEY: u8 = 0;
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
     confirmed: ConfirmedTransaction<N>,
-) -> Result<(ConfirmedTxType<N>, Transaction<N>, Vec<FinalizeOperation<N>>)> {
+) -> Result<(ConfirmedTxType<N>, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
     match confirmed {
         ConfirmedTransaction::AcceptedDeploy(index, tx, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::AcceptedDeploy(index), tx, finalize_operations))
+            Ok((ConfirmedTxType::AcceptedDeploy(index), tx, finalize_operations, vec![]))
         }
-        ConfirmedTransaction::AcceptedExecute(index, tx, finalize_operations) => {
+        ConfirmedTransaction::AcceptedExecute(index, tx, finalize_operations, events) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::AcceptedExecute(index), tx, finalize_operations))
+            Ok((ConfirmedTxType::AcceptedExecute(index), tx, finalize_operations, events))
         }
         ConfirmedTransaction::RejectedDeploy(index, tx, rejected, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedDeploy(index, rejected), tx, finalize_operations))
+            Ok((ConfirmedTxType::RejectedDeploy(index, rejected), tx, finalize_operations, vec![]))
         }
         ConfirmedTransaction::RejectedExecute(index, tx, rejected, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedExecute(index, rejected), tx, finalize_operations))
+            Ok((ConfirmedTxType::RejectedExecute(index, rejected), tx, finalize_operations, vec![]))
         }
     }
 }
@@ -83,6 +86,7 @@ fn to_confirmed_transaction<N: Network>(
     confirmed_type: ConfirmedTxType<N>,
     transaction: Transaction<N>,
     finalize_operations: Vec<FinalizeOperation<N>>,
+    events: Vec<Event<N>>,
 ) -> Result<ConfirmedTransaction<N>> {
     match confirmed_type {
         ConfirmedTxType::AcceptedDeploy(index) => {
@@ -91,7 +95,7 @@ fn to_confirmed_transaction<N: Network>(
         }
         ConfirmedTxType::AcceptedExecute(index) => {
             // Return the confirmed transaction.
-            ConfirmedTransaction::accepted_execute(index, transaction, finalize_operations)
+            ConfirmedTransaction::accepted_execute(index, transaction, finalize_operations, events)
         }
         ConfirmedTxType::RejectedDeploy(index, rejected) => {
             // Return the confirmed transaction.
@@ -137,9 +141,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of rejected or aborted `transaction ID` to `block hash`.
     type RejectedOrAbortedTransactionIDMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
     /// The mapping of `transaction ID` to `(block hash, confirmed tx type, finalize operations)`.
-    type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
+    type ConfirmedTransactionsMap: for<'a> Map<
+        'a,
+        N::TransactionID,
+        (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>),
+    >;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `block hash` to `()`, for the blocks whose transactions have been pruned.
+    type PrunedMap: for<'a> Map<'a, N::BlockHash, ()>;
+    /// The mapping of `PRUNING_DEPTH_KEY` to the pruning depth, if the storage is pruned.
+    type PruningDepthMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `transaction ID` to `[event]`, for the transactions that emitted events.
+    type EventsMap: for<'a> Map<'a, N::TransactionID, Vec<Event<N>>>;
+    /// The mapping of `(program ID, event name)` to `[(block height, transaction ID)]`.
+    /// Note: The block height is stored in big-endian, so that the transactions are ordered by block height.
+    type EventIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), ([u8; 4], N::TransactionID), ()>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -182,6 +199,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap;
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap;
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap;
+    /// Returns the event index map.
+    fn event_index_map(&self) -> &Self::EventIndexMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -214,6 +239,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.pruned_map().start_atomic();
+        self.pruning_depth_map().start_atomic();
+        self.events_map().start_atomic();
+        self.event_index_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -236,6 +265,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.pruned_map().is_atomic_in_progress()
+            || self.pruning_depth_map().is_atomic_in_progress()
+            || self.events_map().is_atomic_in_progress()
+            || self.event_index_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -258,6 +291,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.pruned_map().atomic_checkpoint();
+        self.pruning_depth_map().atomic_checkpoint();
+        self.events_map().atomic_checkpoint();
+        self.event_index_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -280,6 +317,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.pruned_map().clear_latest_checkpoint();
+        self.pruning_depth_map().clear_latest_checkpoint();
+        self.events_map().clear_latest_checkpoint();
+        self.event_index_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -302,6 +343,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.pruned_map().atomic_rewind();
+        self.pruning_depth_map().atomic_rewind();
+        self.events_map().atomic_rewind();
+        self.event_index_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -324,6 +369,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.pruned_map().abort_atomic();
+        self.pruning_depth_map().abort_atomic();
+        self.events_map().abort_atomic();
+        self.event_index_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -346,6 +395,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.pruned_map().finish_atomic()?;
+        self.pruning_depth_map().finish_atomic()?;
+        self.events_map().finish_atomic()?;
+        self.event_index_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -444,10 +497,21 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             }
 
             // Store the confirmed transactions.
-            for (confirmed_type, transaction, finalize_operations) in confirmed {
+            for (confirmed_type, transaction, finalize_operations, events) in confirmed {
                 // Store the block hash and confirmed transaction data.
                 self.confirmed_transactions_map()
                     .insert(transaction.id(), (block.hash(), confirmed_type.clone(), finalize_operations))?;
+                // Store the events.
+                if !events.is_empty() {
+                    for event in events.iter() {
+                        self.event_index_map().insert(
+                            (*event.program_id(), *event.name()),
+                            (block.height().to_be_bytes(), transaction.id()),
+                            (),
+                        )?;
+                    }
+                    self.events_map().insert(transaction.id(), events)?;
+                }
                 // Store the rejected deployment or execution.
                 if let ConfirmedTxType::RejectedDeploy(_, rejected) | ConfirmedTxType::RejectedExecute(_, rejected) =
                     confirmed_type
@@ -456,6 +520,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
                 // Store the transaction.
                 self.transaction_store().insert(&transaction)?;
+                // Index the transitions by the addresses that appear publicly in them.
+                #[cfg(feature = "address-index")]
+                for transition in transaction.transitions() {
+                    self.transaction_store().transition_store().insert_address_index(transition, block.height())?;
+                }
             }
 
             Ok(())
@@ -509,6 +578,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Retrieve the events of the block transactions.
+        let events = transaction_ids
+            .iter()
+            .map(|transaction_id| Ok((*transaction_id, self.get_events(transaction_id)?)))
+            .collect::<Result<Vec<_>>>()?;
+
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
             Some(authority) => match authority {
@@ -587,6 +662,40 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the events.
+            for (transaction_id, events) in events {
+                for event in events.iter() {
+                    self.event_index_map().remove_key(
+                        &(*event.program_id(), *event.name()),
+                        &(block_height.to_be_bytes(), transaction_id),
+                    )?;
+                }
+                self.events_map().remove(&transaction_id)?;
+            }
+
+            // Remove the pruned marker.
+            self.pruned_map().remove(block_hash)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the proofs and the transition payloads of the transactions in the block for the given `block hash`.
+    fn prune(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+            None => bail!("Failed to prune block: missing transactions for block '{block_hash}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the block transactions.
+            for transaction_id in transaction_ids.iter() {
+                self.transaction_store().prune(transaction_id)?;
+            }
+            // Mark the block as pruned.
+            self.pruned_map().insert(*block_hash, ())?;
+
             Ok(())
         })
     }
@@ -639,6 +748,31 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the transaction IDs that emitted an event with the given `program ID` and `event name`
+    /// within the given range of block heights, as `(block height, transaction ID)` pairs in ascending order of block height.
+    fn find_transaction_ids_for_event(
+        &self,
+        program_id: &ProgramID<N>,
+        event_name: &Identifier<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, N::TransactionID)>> {
+        let map = (*program_id, *event_name);
+        // Seek to the start height.
+        // Note: The default transaction ID is zero, which precedes every transaction ID at the same block height.
+        let mut key = (heights.start.to_be_bytes(), N::TransactionID::default());
+        // Scan the index until the end height.
+        let mut transaction_ids = Vec::new();
+        while let Some(((height, transaction_id), _)) = self.event_index_map().get_next_confirmed(&map, Some(&key))? {
+            let block_height = u32::from_be_bytes(height);
+            if block_height >= heights.end {
+                break;
+            }
+            transaction_ids.push((block_height, transaction_id));
+            key = (height, transaction_id);
+        }
+        Ok(transaction_ids)
+    }
+
     /// Returns the state root that contains the given `block height`.
     fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         match self.state_root_map().get_confirmed(&block_height)? {
@@ -879,6 +1013,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    fn get_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        match self.events_map().get_confirmed(transaction_id)? {
+            Some(events) => Ok(cow_to_cloned!(events)),
+            None => Ok(Vec::new()),
+        }
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         // Check if the transaction was rejected or aborted.
@@ -919,8 +1061,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 Some(confirmed_attributes) => cow_to_cloned!(confirmed_attributes),
                 None => bail!("Missing confirmed transaction '{transaction_id}' in block storage"),
             };
+        // Retrieve the events.
+        let events = self.get_events(&transaction.id())?;
         // Construct the confirmed transaction.
-        to_confirmed_transaction(confirmed_type, transaction, finalize_operations).map(Some)
+        to_confirmed_transaction(confirmed_type, transaction, finalize_operations, events).map(Some)
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
@@ -1045,8 +1189,77 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Prune the block that is no longer within the pruning depth.
+            if let Some(depth) = self.pruning_depth()? {
+                if let Some(height) = block.height().checked_sub(depth) {
+                    self.prune_unchecked(height)?;
+                }
+            }
+            Ok(())
+        })?;
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
+    /// Stores the given `(block hash, header)` pairs of the prior blocks, followed by the given block, into empty storage.
+    /// For the prior blocks, only the block hashes, headers, and state roots are stored.
+    ///
+    /// Note: This method is used to initialize storage from a ledger snapshot, which omits the contents of prior blocks.
+    pub fn initialize(&self, headers: &[(N::BlockHash, Header<N>)], block: &Block<N>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the storage is empty.
+        ensure!(tree.number_of_leaves() == 0, "Block storage must be empty to be initialized");
+        // Ensure the block height is correct.
+        ensure!(
+            block.height() as usize == headers.len(),
+            "Attempted to initialize storage with an incorrect block height"
+        );
+        // Ensure the heights of the prior blocks are correct.
+        for (height, (_, header)) in headers.iter().enumerate() {
+            ensure!(header.height() as usize == height, "Mismatching header height for block {height}");
+        }
+
+        // Compute the block tree of the prior blocks.
+        let leaves = cfg_iter!(headers).map(|(block_hash, _)| block_hash.to_bits_le()).collect::<Vec<_>>();
+        let prior_tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+        // Ensure the prior blocks match the previous state root of the block.
+        if !headers.is_empty() && N::StateRoot::from(*prior_tree.root()) != block.previous_state_root() {
+            bail!("The prior blocks do not match the previous state root of block {}", block.height())
+        }
+        // Ensure the prior blocks match the previous block hash of the block.
+        if headers.last().map(|(block_hash, _)| *block_hash).unwrap_or_default() != block.previous_hash() {
+            bail!("The prior blocks do not match the previous block hash of block {}", block.height())
+        }
+        // Prepare an updated Merkle tree containing the block hash.
+        let updated_tree = prior_tree.prepare_append(&[block.hash().to_bits_le()])?;
+
+        atomic_batch_scope!(self, {
+            // Store the prior blocks.
+            // Note: The state root of each prior block is the previous state root of the subsequent block.
+            let next_headers = headers.iter().skip(1).map(|(_, header)| header).chain([block.header()]);
+            for ((block_hash, header), next_header) in headers.iter().zip(next_headers) {
+                let state_root = next_header.previous_state_root();
+                // Store the (block height, state root) pair.
+                self.storage.state_root_map().insert(header.height(), state_root)?;
+                // Store the (state root, block height) pair.
+                self.storage.reverse_state_root_map().insert(state_root, header.height())?;
+                // Store the block hash.
+                self.storage.id_map().insert(header.height(), *block_hash)?;
+                // Store the block height.
+                self.storage.reverse_id_map().insert(*block_hash, header.height())?;
+                // Store the block header.
+                self.storage.header_map().insert(*block_hash, *header)?;
+            }
+            // Store the block.
+            self.storage.insert((*updated_tree.root()).into(), block)
+        })?;
+
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1116,6 +1329,77 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Configures the number of most recent blocks whose transactions are retained in full,
+    /// or `None` to retain the transactions of all blocks, when the storage is opened.
+    /// When set, inserting a block prunes the block that falls outside of the pruning depth.
+    ///
+    /// The pruning depth is persisted, and storage that is pruned cannot be reopened as archival storage.
+    ///
+    /// Note: Blocks that are already outside of the pruning depth are not pruned, and can be pruned with `prune`.
+    pub(crate) fn configure_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        ensure!(depth != Some(0), "The pruning depth must be at least 1");
+        match (self.pruning_depth()?, depth) {
+            (Some(previous), None) => {
+                bail!("The block storage is pruned to a depth of {previous} blocks, and cannot be opened as archival")
+            }
+            (previous, Some(depth)) if previous != Some(depth) => {
+                self.storage.pruning_depth_map().insert(PRUNING_DEPTH_KEY, depth)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of most recent blocks whose transactions are retained in full, if the storage is pruned.
+    pub fn pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(self.storage.pruning_depth_map().get_confirmed(&PRUNING_DEPTH_KEY)?.map(|depth| cow_to_copied!(depth)))
+    }
+
+    /// Prunes the proofs and the transition payloads of the transactions in the block at the given `block height`.
+    /// The block header, and the serial numbers, tags, commitments, and records of the transitions are retained.
+    pub fn prune(&self, block_height: u32) -> Result<()> {
+        // Ensure the storage is pruned.
+        ensure!(self.pruning_depth()?.is_some(), "Cannot prune archival block storage");
+        // Ensure the block is not the latest block.
+        ensure!(block_height < self.current_block_height(), "Cannot prune the latest block");
+        // Prune the block.
+        self.prune_unchecked(block_height)
+    }
+
+    /// Prunes the block at the given `block height`, if it has not been pruned yet.
+    fn prune_unchecked(&self, block_height: u32) -> Result<()> {
+        // Retrieve the block hash.
+        let Some(block_hash) = self.storage.get_block_hash(block_height)? else {
+            bail!("Failed to prune block {block_height}: missing block hash");
+        };
+        // Note: The transactions of blocks prior to a ledger snapshot are not stored.
+        if self.is_pruned(&block_hash)? || !self.storage.transactions_map().contains_key_confirmed(&block_hash)? {
+            return Ok(());
+        }
+        // Prune the block.
+        self.storage.prune(&block_hash)
+    }
+
+    /// Returns `true` if the transactions in the block for the given `block hash` have been pruned.
+    pub fn is_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        self.storage.pruned_map().contains_key_confirmed(block_hash)
+    }
+
+    /// Ensures the transactions in the block for the given `block hash` have not been pruned.
+    fn ensure_not_pruned(&self, block_hash: &N::BlockHash) -> Result<()> {
+        match self.is_pruned(block_hash)? {
+            true => bail!("The transactions in block '{block_hash}' have been pruned"),
+            false => Ok(()),
+        }
+    }
+
+    /// Ensures the block containing the given `transaction ID` has not been pruned.
+    fn ensure_transaction_not_pruned(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        match self.storage.find_block_hash(transaction_id)? {
+            Some(block_hash) => self.ensure_not_pruned(&block_hash),
+            None => Ok(()),
+        }
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1192,6 +1476,17 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn find_block_height_from_solution_id(&self, solution_id: &SolutionID<N>) -> Result<Option<u32>> {
         self.storage.find_block_height_from_solution_id(solution_id)
     }
+
+    /// Returns the transaction IDs that emitted an event with the given `program ID` and `event name`
+    /// within the given range of block heights, as `(block height, transaction ID)` pairs in ascending order of block height.
+    pub fn find_transaction_ids_for_event(
+        &self,
+        program_id: &ProgramID<N>,
+        event_name: &Identifier<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, N::TransactionID)>> {
+        self.storage.find_transaction_ids_for_event(program_id, event_name, heights)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1257,6 +1552,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
+        self.ensure_not_pruned(block_hash)?;
         self.storage.get_block_transactions(block_hash)
     }
 
@@ -1268,8 +1564,15 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_aborted_transaction_ids(block_hash)
     }
 
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    pub fn get_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
+        self.storage.get_events(transaction_id)
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_transaction(transaction_id)
     }
 
@@ -1278,16 +1581,19 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         &self,
         transaction_id: &N::TransactionID,
     ) -> Result<Option<ConfirmedTransaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_confirmed_transaction(*transaction_id)
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_unconfirmed_transaction(transaction_id)
     }
 
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
+        self.ensure_not_pruned(block_hash)?;
         self.storage.get_block(block_hash)
     }
 
@@ -1409,6 +1715,56 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_configure_pruning_depth() {
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure archival storage can be configured as archival, and cannot be pruned.
+        block_store.configure_pruning_depth(None).unwrap();
+        assert_eq!(block_store.pruning_depth().unwrap(), None);
+        assert!(block_store.prune(0).is_err());
+
+        // Ensure the pruning depth must be non-zero.
+        assert!(block_store.configure_pruning_depth(Some(0)).is_err());
+        // Ensure archival storage can be pruned, and the pruning depth is persisted.
+        block_store.configure_pruning_depth(Some(2)).unwrap();
+        assert_eq!(block_store.pruning_depth().unwrap(), Some(2));
+        let stored_depth = block_store.storage.pruning_depth_map().get_confirmed(&PRUNING_DEPTH_KEY).unwrap();
+        assert_eq!(stored_depth.as_deref(), Some(&2));
+
+        // Ensure the pruning depth can be changed, but the pruned storage cannot be configured as archival.
+        block_store.configure_pruning_depth(Some(5)).unwrap();
+        assert_eq!(block_store.pruning_depth().unwrap(), Some(5));
+        assert!(block_store.configure_pruning_depth(None).is_err());
+        assert_eq!(block_store.pruning_depth().unwrap(), Some(5));
+    }
+
+    #[test]
+    fn test_initialize() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store, and insert the block.
+        let expected = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        expected.insert(&block).unwrap();
+
+        // Initialize a new block store from the block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the prior blocks must match the block height.
+        assert!(block_store.initialize(&[(block.hash(), *block.header())], &block).is_err());
+        block_store.initialize(&[], &block).unwrap();
+
+        // Ensure the block store matches.
+        assert_eq!(block_store.current_state_root(), expected.current_state_root());
+        assert_eq!(block_store.current_block_height(), 0);
+        assert_eq!(block_store.get_block(&block.hash()).unwrap(), Some(block.clone()));
+        // Ensure the block store cannot be initialized twice.
+        assert!(block_store.initialize(&[], &block).is_err());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
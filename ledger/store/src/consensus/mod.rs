@@ -23,7 +23,7 @@ use crate::{
     TransitionStorage,
     TransitionStore,
 };
-use console::network::prelude::*;
+use console::{network::prelude::*, types::Field};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
@@ -114,10 +114,23 @@ pub struct ConsensusStore<N: Network, C: ConsensusStorage<N>> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
-    /// Initializes the consensus store.
+    /// Initializes the consensus store, which retains the transactions of all blocks.
+    ///
+    /// Note: Storage that is pruned cannot be opened with this method; use `open_with_pruning_depth` instead.
     pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_with_pruning_depth(storage, None)
+    }
+
+    /// Initializes the consensus store, which retains the transactions of the given number of most recent blocks,
+    /// or of all blocks if the `pruning depth` is `None`. The pruning depth is persisted in storage.
+    pub fn open_with_pruning_depth<S: Clone + Into<StorageMode>>(
+        storage: S,
+        pruning_depth: Option<u32>,
+    ) -> Result<Self> {
         // Initialize the consensus storage.
         let storage = C::open(storage.clone())?;
+        // Configure the pruning depth of the block storage.
+        storage.block_store().configure_pruning_depth(pruning_depth)?;
         // Return the consensus store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -147,6 +160,11 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.transition_store()
     }
 
+    /// Returns the ID of the ratified restrictions list in effect at the given `block height`, if one exists.
+    pub fn get_restrictions_id(&self, block_height: u32) -> Result<Option<Field<N>>> {
+        self.finalize_store().get_restrictions_id_confirmed(block_height)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
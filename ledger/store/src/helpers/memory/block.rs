@@ -14,17 +14,21 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransactionMemory, TransitionMemory},
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 use aleo_std_storage::StorageMode;
 
@@ -67,6 +71,14 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The pruned map.
+    pruned_map: MemoryMap<N::BlockHash, ()>,
+    /// The pruning depth map.
+    pruning_depth_map: MemoryMap<u8, u32>,
+    /// The events map.
+    events_map: MemoryMap<N::TransactionID, Vec<Event<N>>>,
+    /// The event index map.
+    event_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), ([u8; 4], N::TransactionID), ()>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -90,6 +102,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type PrunedMap = MemoryMap<N::BlockHash, ()>;
+    type PruningDepthMap = MemoryMap<u8, u32>;
+    type EventsMap = MemoryMap<N::TransactionID, Vec<Event<N>>>;
+    type EventIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), ([u8; 4], N::TransactionID), ()>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -118,6 +134,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            pruned_map: MemoryMap::default(),
+            pruning_depth_map: MemoryMap::default(),
+            events_map: MemoryMap::default(),
+            event_index_map: NestedMemoryMap::default(),
             transaction_store,
         })
     }
@@ -207,6 +227,26 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the event index map.
+    fn event_index_map(&self) -> &Self::EventIndexMap {
+        &self.event_index_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
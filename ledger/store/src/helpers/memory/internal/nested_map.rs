@@ -15,7 +15,7 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{NestedMap, NestedMapRead, PendingIndex};
 use console::network::prelude::*;
 
 use core::hash::Hash;
@@ -23,6 +23,7 @@ use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
     collections::{btree_map, BTreeMap, BTreeSet},
+    ops::Bound,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -42,6 +43,7 @@ pub struct NestedMemoryMap<
     map_inner: Arc<RwLock<BTreeMap<Vec<u8>, V>>>,           // map-key -> value
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
+    pending_index: Arc<Mutex<PendingIndex<M, K, V>>>,
     checkpoint: Arc<Mutex<Vec<usize>>>,
 }
 
@@ -57,6 +59,7 @@ impl<
             map_inner: Default::default(),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            pending_index: Default::default(),
             checkpoint: Default::default(),
         }
     }
@@ -85,6 +88,7 @@ impl<
             map_inner: Arc::new(RwLock::new(map_inner)),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            pending_index: Default::default(),
             checkpoint: Default::default(),
         }
     }
@@ -104,7 +108,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-key-value pair to the batch.
-            true => self.atomic_batch.lock().push((map, Some(key), Some(value))),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(map, Some(&key), Some(&value))?;
+                atomic_batch.push((map, Some(key), Some(value)));
+            }
             // Otherwise, insert the key-value pair directly into the map.
             false => insert(&mut self.map.write(), &mut self.map_inner.write(), &map, &key, value),
         }
@@ -118,7 +126,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-None pair to the batch.
-            true => self.atomic_batch.lock().push((*map, None, None)),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(*map, None, None)?;
+                atomic_batch.push((*map, None, None));
+            }
             // Otherwise, remove the map directly from the map.
             false => remove_map(&mut self.map.write(), &mut self.map_inner.write(), map),
         }
@@ -132,7 +144,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-None pair to the batch.
-            true => self.atomic_batch.lock().push((*map, Some(key.clone()), None)),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(*map, Some(key), None)?;
+                atomic_batch.push((*map, Some(key.clone()), None));
+            }
             // Otherwise, remove the key-value pair directly from the map.
             false => remove_key(&mut self.map.write(), &mut self.map_inner.write(), map, key),
         }
@@ -189,6 +205,7 @@ impl<
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
+        self.pending_index.lock().truncate(checkpoint);
     }
 
     ///
@@ -197,6 +214,7 @@ impl<
     fn abort_atomic(&self) {
         // Clear the atomic batch.
         *self.atomic_batch.lock() = Default::default();
+        self.pending_index.lock().clear();
         // Clear the checkpoint stack.
         *self.checkpoint.lock() = Default::default();
         // Set the atomic batch flag to `false`.
@@ -209,6 +227,7 @@ impl<
     fn finish_atomic(&self) -> Result<()> {
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        self.pending_index.lock().clear();
 
         if !operations.is_empty() {
             // Acquire a write lock on the map.
@@ -438,6 +457,61 @@ impl<
         }
     }
 
+    ///
+    /// Returns the confirmed key-value pair that immediately follows the given key in the map.
+    /// If no key is given, returns the first confirmed key-value pair in the map.
+    ///
+    fn get_next_confirmed(&'a self, map: &M, key: Option<&K>) -> Result<Option<(K, V)>> {
+        // Serialize 'm'.
+        let m = bincode::serialize(map)?;
+        // Retrieve the next serialized key for the serialized map.
+        let next = {
+            // Acquire the read lock on 'map'.
+            let maps = self.map.read();
+            let Some(keys) = maps.get(&m) else {
+                return Ok(None);
+            };
+            match key {
+                Some(key) => keys.range((Bound::Excluded(bincode::serialize(key)?), Bound::Unbounded)).next().cloned(),
+                None => keys.iter().next().cloned(),
+            }
+        };
+
+        match next {
+            Some(k) => {
+                // Concatenate 'm' and 'k' with a 0-byte separator.
+                let mk = to_map_key(&m, &k);
+                // Retrieve the value.
+                let value = match self.map_inner.read().get(&mk) {
+                    Some(value) => value.clone(),
+                    None => bail!("Missing value for a key in the nested memory map"),
+                };
+                // Return the key-value pair.
+                Ok(Some((bincode::deserialize(&k)?, value)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the latest operation in the atomic batch on the key that immediately follows the given key
+    /// in the map, as the key and its pending value, where a `None` value removes the key.
+    /// If no key is given, returns the latest operation on the first key of the map in the atomic batch.
+    ///
+    fn get_next_pending(&self, map: &M, key: Option<&K>) -> Result<Option<(K, Option<V>)>> {
+        // Serialize the key, if one is given.
+        let key = key.map(bincode::serialize).transpose()?;
+        // Return the next operation in the atomic batch.
+        Ok(self.pending_index.lock().get_next(map, key.as_deref()))
+    }
+
+    ///
+    /// Returns `true` if the map is scheduled to be removed in the atomic batch.
+    ///
+    fn is_map_removed_pending(&self, map: &M) -> bool {
+        self.pending_index.lock().is_map_removed(map)
+    }
+
     ///
     /// Returns an iterator visiting each map-key-value pair in the atomic batch.
     ///
@@ -648,6 +722,14 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
+    #[test]
+    fn test_get_next() {
+        // Initialize a map.
+        let map: NestedMemoryMap<usize, usize, String> = Default::default();
+
+        crate::helpers::test_helpers::nested_map::check_get_next(map);
+    }
+
     #[test]
     fn test_check_iterators_match() {
         // Initialize a map.
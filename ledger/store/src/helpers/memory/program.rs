@@ -20,12 +20,15 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    RollbackOperation,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -39,6 +42,18 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_length_map: MemoryMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The rollback map.
+    rollback_map: MemoryMap<u32, Vec<RollbackOperation<N>>>,
+    /// The history height map.
+    history_height_map: MemoryMap<u8, u32>,
+    /// The history map.
+    history_map: NestedMemoryMap<Field<N>, u32, Option<Value<N>>>,
+    /// The restrictions map.
+    restrictions_map: MemoryMap<u32, (u32, Field<N>, String)>,
+    /// The ratified map.
+    ratified_map: MemoryMap<u32, Vec<FinalizeOperation<N>>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -48,6 +63,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLengthMap = MemoryMap<(ProgramID<N>, Identifier<N>), u64>;
+    type RollbackMap = MemoryMap<u32, Vec<RollbackOperation<N>>>;
+    type HistoryHeightMap = MemoryMap<u8, u32>;
+    type HistoryMap = NestedMemoryMap<Field<N>, u32, Option<Value<N>>>;
+    type RestrictionsMap = MemoryMap<u32, (u32, Field<N>, String)>;
+    type RatifiedMap = MemoryMap<u32, Vec<FinalizeOperation<N>>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -58,6 +79,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            mapping_length_map: MemoryMap::default(),
+            rollback_map: MemoryMap::default(),
+            history_height_map: MemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            restrictions_map: MemoryMap::default(),
+            ratified_map: MemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -83,6 +110,36 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap {
+        &self.history_height_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap {
+        &self.restrictions_map
+    }
+
+    /// Returns the ratified map.
+    fn ratified_map(&self) -> &Self::RatifiedMap {
+        &self.ratified_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
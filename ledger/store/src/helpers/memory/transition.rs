@@ -13,11 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -41,6 +48,12 @@ pub struct TransitionMemory<N: Network> {
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: MemoryMap<N::TransitionID, Field<N>>,
+    /// The transitions that are signed by a multisig account.
+    multisig_map: MemoryMap<N::TransitionID, bool>,
+    /// The address map.
+    address_map: NestedMemoryMap<Address<N>, N::TransitionID, u32>,
+    /// The reverse address map.
+    reverse_address_map: MemoryMap<N::TransitionID, Vec<Address<N>>>,
 }
 
 #[rustfmt::skip]
@@ -53,6 +66,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
     type SCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type MultisigMap = MemoryMap<N::TransitionID, bool>;
+    type AddressMap = NestedMemoryMap<Address<N>, N::TransitionID, u32>;
+    type ReverseAddressMap = MemoryMap<N::TransitionID, Vec<Address<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -65,6 +81,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
             scm_map: MemoryMap::default(),
+            multisig_map: MemoryMap::default(),
+            address_map: NestedMemoryMap::default(),
+            reverse_address_map: MemoryMap::default(),
         })
     }
 
@@ -107,6 +126,21 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the multisig transitions.
+    fn multisig_map(&self) -> &Self::MultisigMap {
+        &self.multisig_map
+    }
+
+    /// Returns the address map.
+    fn address_map(&self) -> &Self::AddressMap {
+        &self.address_map
+    }
+
+    /// Returns the reverse address map.
+    fn reverse_address_map(&self) -> &Self::ReverseAddressMap {
+        &self.reverse_address_map
+    }
 }
 
 /// An in-memory transition input storage.
@@ -20,6 +20,9 @@ pub mod rocksdb;
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
+mod pending_index;
+pub(crate) use pending_index::PendingIndex;
+
 mod traits;
 pub use traits::*;
 
@@ -0,0 +1,168 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::{Result, Serialize};
+
+use core::hash::Hash;
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Bound,
+};
+
+/// The pending operations on a single map.
+struct PendingMap<K, V> {
+    /// Whether the map is removed in the atomic batch, before the operations on its keys.
+    is_removed: bool,
+    /// The latest operation on each key, by serialized key, where a `None` value removes the key.
+    keys: BTreeMap<Vec<u8>, (K, Option<V>)>,
+}
+
+impl<K, V> Default for PendingMap<K, V> {
+    fn default() -> Self {
+        Self { is_removed: false, keys: Default::default() }
+    }
+}
+
+/// The state of the index before an operation of the atomic batch, which is restored to undo the operation.
+enum Undo<M, K, V> {
+    /// The previous operation on a serialized key of a map.
+    Key(M, Vec<u8>, Option<(K, Option<V>)>),
+    /// The previous operations on a map.
+    Map(M, Option<PendingMap<K, V>>),
+}
+
+/// An index of the atomic batch of a nested map, which holds the latest operation on each key of each map,
+/// ordered by serialized key, so that the batch can be searched in key order without folding the batch.
+///
+/// The index mirrors the atomic batch: each operation pushed to the batch is pushed to the index,
+/// and the index is truncated and cleared along with the batch.
+pub(crate) struct PendingIndex<M, K, V> {
+    /// The pending operations on each map.
+    maps: HashMap<M, PendingMap<K, V>>,
+    /// The undo entries, one for each operation in the atomic batch.
+    undo: Vec<Undo<M, K, V>>,
+}
+
+impl<M, K, V> Default for PendingIndex<M, K, V> {
+    fn default() -> Self {
+        Self { maps: Default::default(), undo: Default::default() }
+    }
+}
+
+impl<M: Copy + Eq + Hash, K: Clone + Serialize, V: Clone> PendingIndex<M, K, V> {
+    /// Adds an operation of the atomic batch to the index.
+    /// A `None` key removes the map, and a `None` value removes the key.
+    pub(crate) fn push(&mut self, map: M, key: Option<&K>, value: Option<&V>) -> Result<()> {
+        match key {
+            Some(key) => {
+                let bytes = bincode::serialize(key)?;
+                let keys = &mut self.maps.entry(map).or_default().keys;
+                let previous = keys.insert(bytes.clone(), (key.clone(), value.cloned()));
+                self.undo.push(Undo::Key(map, bytes, previous));
+            }
+            None => {
+                let previous = self.maps.insert(map, PendingMap { is_removed: true, keys: Default::default() });
+                self.undo.push(Undo::Map(map, previous));
+            }
+        }
+        Ok(())
+    }
+
+    /// Undoes the operations after the first `num_operations` operations of the atomic batch.
+    pub(crate) fn truncate(&mut self, num_operations: usize) {
+        while self.undo.len() > num_operations {
+            match self.undo.pop() {
+                Some(Undo::Key(map, bytes, previous)) => {
+                    if let Some(pending) = self.maps.get_mut(&map) {
+                        match previous {
+                            Some(previous) => pending.keys.insert(bytes, previous),
+                            None => pending.keys.remove(&bytes),
+                        };
+                    }
+                }
+                Some(Undo::Map(map, previous)) => {
+                    match previous {
+                        Some(previous) => self.maps.insert(map, previous),
+                        None => self.maps.remove(&map),
+                    };
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Removes all operations from the index.
+    pub(crate) fn clear(&mut self) {
+        self.maps.clear();
+        self.undo.clear();
+    }
+
+    /// Returns `true` if the given map is removed in the atomic batch.
+    pub(crate) fn is_map_removed(&self, map: &M) -> bool {
+        self.maps.get(map).map_or(false, |pending| pending.is_removed)
+    }
+
+    /// Returns the latest operation on the key that immediately follows the given serialized key in the map,
+    /// or on the first key of the map if no key is given, as the key and its pending value.
+    pub(crate) fn get_next(&self, map: &M, key: Option<&[u8]>) -> Option<(K, Option<V>)> {
+        let pending = self.maps.get(map)?;
+        let lower = match key {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Unbounded,
+        };
+        pending.keys.range::<[u8], _>((lower, Bound::Unbounded)).next().map(|(_, operation)| operation.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_restores_operations() {
+        let mut index = PendingIndex::<usize, usize, String>::default();
+        let next = |index: &PendingIndex<_, _, _>, key: Option<usize>| {
+            index.get_next(&0, key.map(|key| bincode::serialize(&key).unwrap()).as_deref())
+        };
+
+        index.push(0, Some(&1), Some(&"one".to_string())).unwrap();
+        index.push(0, Some(&3), None).unwrap();
+        assert_eq!(next(&index, None), Some((1, Some("one".to_string()))));
+        assert_eq!(next(&index, Some(1)), Some((3, None)));
+        assert_eq!(next(&index, Some(3)), None);
+
+        // Override a key, remove the map, and insert a key into the removed map.
+        index.push(0, Some(&1), Some(&"uno".to_string())).unwrap();
+        index.push(0, None, None).unwrap();
+        index.push(0, Some(&2), Some(&"two".to_string())).unwrap();
+        assert!(index.is_map_removed(&0));
+        assert_eq!(next(&index, None), Some((2, Some("two".to_string()))));
+        assert_eq!(next(&index, Some(2)), None);
+
+        // Undo the removal of the map.
+        index.truncate(3);
+        assert!(!index.is_map_removed(&0));
+        assert_eq!(next(&index, None), Some((1, Some("uno".to_string()))));
+        assert_eq!(next(&index, Some(1)), Some((3, None)));
+
+        // Undo the override.
+        index.truncate(2);
+        assert_eq!(next(&index, None), Some((1, Some("one".to_string()))));
+
+        // Clear the index.
+        index.clear();
+        assert_eq!(next(&index, None), None);
+    }
+}
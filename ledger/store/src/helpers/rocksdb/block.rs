@@ -15,7 +15,7 @@
 
 use crate::{
     helpers::rocksdb::{
-        internal::{self, DataMap, Database},
+        internal::{self, DataMap, Database, NestedDataMap},
         BlockMap,
         MapID,
         TransactionDB,
@@ -26,11 +26,15 @@ use crate::{
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 use aleo_std_storage::StorageMode;
 
@@ -73,6 +77,14 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The pruned map.
+    pruned_map: DataMap<N::BlockHash, ()>,
+    /// The pruning depth map.
+    pruning_depth_map: DataMap<u8, u32>,
+    /// The events map.
+    events_map: DataMap<N::TransactionID, Vec<Event<N>>>,
+    /// The event index map.
+    event_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), ([u8; 4], N::TransactionID), ()>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -96,6 +108,10 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type PrunedMap = DataMap<N::BlockHash, ()>;
+    type PruningDepthMap = DataMap<u8, u32>;
+    type EventsMap = DataMap<N::TransactionID, Vec<Event<N>>>;
+    type EventIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), ([u8; 4], N::TransactionID), ()>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -123,7 +139,11 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            pruned_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Pruned))?,
+            pruning_depth_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PruningDepth))?,
+            events_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Events))?,
+            event_index_map: internal::RocksDB::open_nested_map(N::ID, storage, MapID::Block(BlockMap::EventIndex))?,
             transaction_store,
         })
     }
@@ -213,6 +233,26 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the event index map.
+    fn event_index_map(&self) -> &Self::EventIndexMap {
+        &self.event_index_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -86,6 +86,10 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Pruned = DataID::BlockPrunedMap as u16,
+    PruningDepth = DataID::BlockPruningDepthMap as u16,
+    Events = DataID::BlockEventsMap as u16,
+    EventIndex = DataID::BlockEventIndexMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -189,6 +193,9 @@ pub enum TransitionMap {
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
     SCM = DataID::TransitionSCMMap as u16,
+    Address = DataID::TransitionAddressMap as u16,
+    ReverseAddress = DataID::TransitionReverseAddressMap as u16,
+    Multisig = DataID::TransitionMultisigMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -199,6 +206,12 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    Rollback = DataID::RollbackMap as u16,
+    HistoryHeight = DataID::HistoryHeightMap as u16,
+    History = DataID::HistoryMap as u16,
+    Restrictions = DataID::RestrictionsMap as u16,
+    Ratified = DataID::RatifiedMap as u16,
+    MappingLength = DataID::MappingLengthMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -293,6 +306,26 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    RollbackMap,
+    HistoryHeightMap,
+    HistoryMap,
+    RestrictionsMap,
+    // Block
+    BlockPrunedMap,
+    // Transition
+    TransitionAddressMap,
+    TransitionReverseAddressMap,
+    // Block
+    BlockEventsMap,
+    BlockEventIndexMap,
+    // Program
+    RatifiedMap,
+    // Block
+    BlockPruningDepthMap,
+    // Program
+    MappingLengthMap,
+    // Transition
+    TransitionMultisigMap,
 
     // Testing
     #[cfg(test)]
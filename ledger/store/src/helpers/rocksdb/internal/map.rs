@@ -227,6 +227,9 @@ impl<
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            // Record the size of the write batch.
+            #[cfg(feature = "metrics")]
+            metrics::histogram(metrics::store::ROCKSDB_BATCH_WRITE_SIZE, batch.size_in_bytes() as f64);
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
             // Ensure that the database atomic batch is empty.
@@ -217,6 +217,7 @@ impl Database for RocksDB {
             context,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            pending_index: Default::default(),
             checkpoints: Default::default(),
         })
     }
@@ -259,6 +260,8 @@ impl RocksDB {
         // writes have been paused becomes executed as a single atomic batch.
         let batch = mem::take(&mut *self.atomic_batch.lock());
         if !DISCARD_BATCH {
+            #[cfg(feature = "metrics")]
+            metrics::histogram(metrics::store::ROCKSDB_BATCH_WRITE_SIZE, batch.size_in_bytes() as f64);
             self.rocksdb.write(batch)?;
         }
 
@@ -386,6 +389,7 @@ impl RocksDB {
             context,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            pending_index: Default::default(),
             checkpoints: Default::default(),
         })
     }
@@ -16,7 +16,7 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{NestedMap, NestedMapRead, PendingIndex};
 use console::prelude::{anyhow, cfg_into_iter, FromBytes};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
@@ -40,6 +40,8 @@ pub struct NestedDataMap<
     pub(super) batch_in_progress: Arc<AtomicBool>,
     /// The database transaction.
     pub(super) atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
+    /// The index of the database transaction, ordered by key.
+    pub(super) pending_index: Arc<Mutex<PendingIndex<M, K, V>>>,
     /// The checkpoint stack for the batched operations within the map.
     pub(super) checkpoints: Arc<Mutex<Vec<usize>>>,
 }
@@ -115,7 +117,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-key-value pair to the batch.
-            true => self.atomic_batch.lock().push((map, Some(key), Some(value))),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(map, Some(&key), Some(&value))?;
+                atomic_batch.push((map, Some(key), Some(value)));
+            }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
                 // Prepare the prefixed map-key and serialized value.
@@ -134,7 +140,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-None pair to the batch.
-            true => self.atomic_batch.lock().push((*map, None, None)),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(*map, None, None)?;
+                atomic_batch.push((*map, None, None));
+            }
             // Otherwise, remove the map directly from the map.
             false => {
                 // Serialize the map.
@@ -179,7 +189,11 @@ impl<
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key to the batch.
-            true => self.atomic_batch.lock().push((*map, Some(key.clone()), None)),
+            true => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                self.pending_index.lock().push(*map, Some(key), None)?;
+                atomic_batch.push((*map, Some(key.clone()), None));
+            }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
                 // Prepare the prefixed map-key.
@@ -248,6 +262,7 @@ impl<
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
+        self.pending_index.lock().truncate(checkpoint);
     }
 
     ///
@@ -256,6 +271,7 @@ impl<
     fn abort_atomic(&self) {
         // Clear the atomic batch.
         self.atomic_batch.lock().clear();
+        self.pending_index.lock().clear();
         // Clear the checkpoint stack.
         self.checkpoints.lock().clear();
         // Set the atomic batch flag to `false`.
@@ -272,6 +288,7 @@ impl<
     fn finish_atomic(&self) -> Result<()> {
         // Retrieve the atomic batch belonging to the map.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        self.pending_index.lock().clear();
 
         if !operations.is_empty() {
             // Enqueue all the operations from the map in the database-wide batch.
@@ -332,6 +349,9 @@ impl<
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            // Record the size of the write batch.
+            #[cfg(feature = "metrics")]
+            metrics::histogram(metrics::store::ROCKSDB_BATCH_WRITE_SIZE, batch.size_in_bytes() as f64);
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
             // Ensure that the database atomic batch is empty.
@@ -556,6 +576,67 @@ impl<
         }
     }
 
+    ///
+    /// Returns the confirmed key-value pair that immediately follows the given key in the map.
+    /// If no key is given, returns the first confirmed key-value pair in the map.
+    ///
+    fn get_next_confirmed(&'a self, map: &M, key: Option<&K>) -> Result<Option<(K, V)>> {
+        // Serialize the map.
+        let serialized_map = bincode::serialize(map)?;
+
+        // A raw iterator doesn't allocate.
+        let mut iter = self.database.raw_iterator();
+        match key {
+            Some(key) => {
+                // Find the first record at or after the given key.
+                let raw_map_key = self.create_prefixed_map_key(map, key)?;
+                iter.seek(&raw_map_key);
+                // Skip the given key itself, if it exists.
+                if iter.key() == Some(raw_map_key.as_slice()) {
+                    iter.next();
+                }
+            }
+            // Find the first record with the nested map prefix.
+            None => iter.seek(self.create_prefixed_map(map)?),
+        }
+
+        // Ensure the record belongs to the nested map.
+        let (Some(map_key), Some(value)) = (iter.key(), iter.value()) else {
+            return Ok(None);
+        };
+        if !map_key.starts_with(&self.context) {
+            return Ok(None);
+        }
+        let Ok((entry_map, entry_key)) = get_map_and_key(map_key) else {
+            return Ok(None);
+        };
+        if entry_map != serialized_map {
+            return Ok(None);
+        }
+
+        // Deserialize the key-value pair.
+        Ok(Some((bincode::deserialize(entry_key)?, bincode::deserialize(value)?)))
+    }
+
+    ///
+    /// Returns the latest operation in the atomic batch on the key that immediately follows the given key
+    /// in the map, as the key and its pending value, where a `None` value removes the key.
+    /// If no key is given, returns the latest operation on the first key of the map in the atomic batch.
+    ///
+    fn get_next_pending(&self, map: &M, key: Option<&K>) -> Result<Option<(K, Option<V>)>> {
+        // Serialize the key, if one is given.
+        let key = key.map(bincode::serialize).transpose()?;
+        // Return the next operation in the atomic batch.
+        Ok(self.pending_index.lock().get_next(map, key.as_deref()))
+    }
+
+    ///
+    /// Returns `true` if the map is scheduled to be removed in the atomic batch.
+    ///
+    fn is_map_removed_pending(&self, map: &M) -> bool {
+        self.pending_index.lock().is_map_removed(map)
+    }
+
     ///
     /// Returns an iterator visiting each key-value pair in the atomic batch.
     ///
@@ -798,6 +879,7 @@ mod tests {
             database,
             context,
             atomic_batch: Default::default(),
+            pending_index: Default::default(),
             batch_in_progress: Default::default(),
             checkpoints: Default::default(),
         }
@@ -1040,6 +1122,18 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_get_next() {
+        // Initialize a map.
+        let map: NestedDataMap<usize, usize, String> =
+            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+                .expect("Failed to open data map");
+
+        crate::helpers::test_helpers::nested_map::check_get_next(map);
+    }
+
     #[test]
     #[serial]
     #[traced_test]
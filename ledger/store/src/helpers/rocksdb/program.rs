@@ -20,12 +20,15 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    RollbackOperation,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -39,6 +42,18 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_length_map: DataMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The rollback map.
+    rollback_map: DataMap<u32, Vec<RollbackOperation<N>>>,
+    /// The history height map.
+    history_height_map: DataMap<u8, u32>,
+    /// The history map.
+    history_map: NestedDataMap<Field<N>, u32, Option<Value<N>>>,
+    /// The restrictions map.
+    restrictions_map: DataMap<u32, (u32, Field<N>, String)>,
+    /// The ratified map.
+    ratified_map: DataMap<u32, Vec<FinalizeOperation<N>>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -48,6 +63,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLengthMap = DataMap<(ProgramID<N>, Identifier<N>), u64>;
+    type RollbackMap = DataMap<u32, Vec<RollbackOperation<N>>>;
+    type HistoryHeightMap = DataMap<u8, u32>;
+    type HistoryMap = NestedDataMap<Field<N>, u32, Option<Value<N>>>;
+    type RestrictionsMap = DataMap<u32, (u32, Field<N>, String)>;
+    type RatifiedMap = DataMap<u32, Vec<FinalizeOperation<N>>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -58,6 +79,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_length_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::MappingLength))?,
+            rollback_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Rollback))?,
+            history_height_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryHeight))?,
+            history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            restrictions_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Restrictions))?,
+            ratified_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Ratified))?,
             storage_mode: storage.into(),
         })
     }
@@ -71,7 +98,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_length_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::MappingLength))?,
+            rollback_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Rollback))?,
+            history_height_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryHeight))?,
+            history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            restrictions_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Restrictions))?,
+            ratified_map: rocksdb::RocksDB::open_map_testing(temp_dir, dev, MapID::Program(ProgramMap::Ratified))?,
             storage_mode: dev.into(),
         })
     }
@@ -91,6 +124,36 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap {
+        &self.history_height_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap {
+        &self.restrictions_map
+    }
+
+    /// Returns the ratified map.
+    fn ratified_map(&self) -> &Self::RatifiedMap {
+        &self.ratified_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -14,7 +14,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::rocksdb::{
+        self,
+        DataMap,
+        Database,
+        MapID,
+        NestedDataMap,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
@@ -24,7 +33,7 @@ use crate::{
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -48,6 +57,12 @@ pub struct TransitionDB<N: Network> {
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: DataMap<N::TransitionID, Field<N>>,
+    /// The transitions that are signed by a multisig account.
+    multisig_map: DataMap<N::TransitionID, bool>,
+    /// The address map.
+    address_map: NestedDataMap<Address<N>, N::TransitionID, u32>,
+    /// The reverse address map.
+    reverse_address_map: DataMap<N::TransitionID, Vec<Address<N>>>,
 }
 
 #[rustfmt::skip]
@@ -60,6 +75,9 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
     type SCMMap = DataMap<N::TransitionID, Field<N>>;
+    type MultisigMap = DataMap<N::TransitionID, bool>;
+    type AddressMap = NestedDataMap<Address<N>, N::TransitionID, u32>;
+    type ReverseAddressMap = DataMap<N::TransitionID, Vec<Address<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -72,6 +90,9 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(),  MapID::Transition(TransitionMap::ReverseTCM))?,
             scm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::SCM))?,
+            multisig_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::Multisig))?,
+            address_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::Address))?,
+            reverse_address_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::ReverseAddress))?,
         })
     }
 
@@ -114,6 +135,21 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the multisig transitions.
+    fn multisig_map(&self) -> &Self::MultisigMap {
+        &self.multisig_map
+    }
+
+    /// Returns the address map.
+    fn address_map(&self) -> &Self::AddressMap {
+        &self.address_map
+    }
+
+    /// Returns the reverse address map.
+    fn reverse_address_map(&self) -> &Self::ReverseAddressMap {
+        &self.reverse_address_map
+    }
 }
 
 /// An database transition input storage.
@@ -0,0 +1,116 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::NestedMap;
+
+const MAP: usize = 0;
+const NUM_ITEMS: usize = 10;
+
+/// Walks the given map from its first entry using `get_next_confirmed`.
+fn walk_confirmed(map: &impl for<'a> NestedMap<'a, usize, usize, String>) -> Vec<(usize, String)> {
+    let mut entries = Vec::new();
+    let mut cursor = None;
+    while let Some((key, value)) = map.get_next_confirmed(&MAP, cursor.as_ref()).unwrap() {
+        cursor = Some(key);
+        entries.push((key, value));
+    }
+    entries
+}
+
+/// Walks the given map from its first entry using `get_next_speculative`.
+fn walk_speculative(map: &impl for<'a> NestedMap<'a, usize, usize, String>) -> Vec<(usize, String)> {
+    let mut entries = Vec::new();
+    let mut cursor = None;
+    while let Some((key, value)) = map.get_next_speculative(&MAP, cursor.as_ref()).unwrap() {
+        cursor = Some(key);
+        entries.push((key, value));
+    }
+    entries
+}
+
+pub fn check_get_next(map: impl for<'a> NestedMap<'a, usize, usize, String>) {
+    ensure_map_is_empty(&map);
+
+    assert_eq!(map.get_next_confirmed(&MAP, None).unwrap(), None);
+    assert_eq!(map.get_next_speculative(&MAP, None).unwrap(), None);
+    assert_eq!(map.len_map_speculative(&MAP).unwrap(), 0);
+
+    // Insert the even keys, and a key in a different map.
+    for i in (0..NUM_ITEMS).step_by(2) {
+        map.insert(MAP, i, i.to_string()).unwrap();
+    }
+    map.insert(MAP + 1, 1, 1.to_string()).unwrap();
+
+    let confirmed = (0..NUM_ITEMS).step_by(2).map(|i| (i, i.to_string())).collect::<Vec<_>>();
+    assert_eq!(walk_confirmed(&map), confirmed);
+    assert_eq!(walk_speculative(&map), confirmed);
+    assert_eq!(map.len_map_speculative(&MAP).unwrap(), confirmed.len());
+
+    // Ensure the cursor does not need to exist in the map.
+    assert_eq!(map.get_next_confirmed(&MAP, Some(&3)).unwrap(), Some((4, 4.to_string())));
+    assert_eq!(map.get_next_confirmed(&MAP, Some(&(NUM_ITEMS - 2))).unwrap(), None);
+
+    /* test atomic operations */
+
+    {
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert the odd keys, update a key, and remove a key.
+        for i in (1..NUM_ITEMS).step_by(2) {
+            map.insert(MAP, i, i.to_string()).unwrap();
+        }
+        map.insert(MAP, 2, "two".to_string()).unwrap();
+        map.remove_key(&MAP, &4).unwrap();
+
+        let speculative = (0..NUM_ITEMS)
+            .filter(|i| *i != 4)
+            .map(|i| (i, if i == 2 { "two".to_string() } else { i.to_string() }))
+            .collect::<Vec<_>>();
+        assert_eq!(walk_confirmed(&map), confirmed);
+        assert_eq!(walk_speculative(&map), speculative);
+        assert_eq!(map.len_map_confirmed(&MAP).unwrap(), confirmed.len());
+        assert_eq!(map.len_map_speculative(&MAP).unwrap(), speculative.len());
+
+        // Remove consecutive keys after a checkpoint, and ensure the rewind restores them.
+        map.atomic_checkpoint();
+        map.remove_key(&MAP, &6).unwrap();
+        map.remove_key(&MAP, &7).unwrap();
+        assert_eq!(map.get_next_speculative(&MAP, Some(&5)).unwrap(), Some((8, 8.to_string())));
+        map.atomic_rewind();
+        assert_eq!(walk_speculative(&map), speculative);
+
+        // Clear the map, and insert a single key.
+        map.remove_map(&MAP).unwrap();
+        map.insert(MAP, 7, 7.to_string()).unwrap();
+
+        assert_eq!(walk_confirmed(&map), confirmed);
+        assert_eq!(walk_speculative(&map), vec![(7, 7.to_string())]);
+        assert_eq!(map.len_map_speculative(&MAP).unwrap(), 1);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        assert_eq!(walk_confirmed(&map), vec![(7, 7.to_string())]);
+        assert_eq!(walk_speculative(&map), vec![(7, 7.to_string())]);
+        assert_eq!(map.len_map_speculative(&MAP).unwrap(), 1);
+    }
+
+    map.remove_map(&MAP).unwrap();
+    map.remove_map(&(MAP + 1)).unwrap();
+
+    ensure_map_is_empty(&map);
+}
@@ -16,7 +16,7 @@
 use console::network::prelude::{Deserialize, Result, Serialize};
 
 use core::hash::Hash;
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 /// A trait representing 'nested map'-like storage operations with read-write capabilities.
 pub trait NestedMap<
@@ -100,6 +100,39 @@ pub trait NestedMapRead<
     ///
     fn len_map_confirmed(&self, map: &M) -> Result<usize>;
 
+    ///
+    /// Returns the number of speculative entries in the map.
+    /// This method applies the atomic batch on top of the confirmed entries.
+    ///
+    fn len_map_speculative(&'a self, map: &M) -> Result<usize> {
+        // Collect the distinct keys touched by the atomic batch for the given map.
+        let mut pending_keys = BTreeMap::new();
+        for (m, k, _) in self.iter_pending() {
+            // If the map does not match the given map, then continue.
+            if &*m != map {
+                continue;
+            }
+            match k {
+                Some(k) => {
+                    pending_keys.insert(bincode::serialize(&*k)?, k.into_owned());
+                }
+                // If the map is scheduled to be cleared, fall back to the full speculative map.
+                None => return Ok(self.get_map_speculative(map)?.len()),
+            }
+        }
+
+        // Adjust the confirmed length by the keys that were inserted or removed in the batch.
+        let mut length = self.len_map_confirmed(map)?;
+        for key in pending_keys.values() {
+            match (self.contains_key_confirmed(map, key)?, self.contains_key_speculative(map, key)?) {
+                (false, true) => length = length.saturating_add(1),
+                (true, false) => length = length.saturating_sub(1),
+                _ => (),
+            }
+        }
+        Ok(length)
+    }
+
     ///
     /// Checks whether there are any confirmed entries in the map.
     ///
@@ -156,6 +189,66 @@ pub trait NestedMapRead<
         }
     }
 
+    ///
+    /// Returns the confirmed key-value pair that immediately follows the given key in the map.
+    /// If no key is given, returns the first confirmed key-value pair in the map.
+    ///
+    /// Keys are ordered by their serialized bytes, which is the same order on every backend.
+    ///
+    fn get_next_confirmed(&'a self, map: &M, key: Option<&K>) -> Result<Option<(K, V)>>;
+
+    ///
+    /// Returns the latest operation in the atomic batch on the key that immediately follows the given key
+    /// in the map, as the key and its pending value, where a `None` value removes the key.
+    /// If no key is given, returns the latest operation on the first key of the map in the atomic batch.
+    ///
+    fn get_next_pending(&self, map: &M, key: Option<&K>) -> Result<Option<(K, Option<V>)>>;
+
+    ///
+    /// Returns `true` if the map is scheduled to be removed in the atomic batch.
+    /// Note: Keys that are inserted after the removal are returned by `get_next_pending`.
+    ///
+    fn is_map_removed_pending(&self, map: &M) -> bool;
+
+    ///
+    /// Returns the speculative key-value pair that immediately follows the given key in the map.
+    /// If no key is given, returns the first speculative key-value pair in the map.
+    /// This method applies the atomic batch on top of the confirmed entries.
+    ///
+    /// Note: Each step seeks the next operation in the atomic batch and the next confirmed entry,
+    /// and a step is only repeated for a key that is removed in the atomic batch.
+    ///
+    fn get_next_speculative(&'a self, map: &M, key: Option<&K>) -> Result<Option<(K, V)>> {
+        // Determine if the confirmed entries of the map are removed in the atomic batch.
+        let is_removed = self.is_map_removed_pending(map);
+
+        let mut cursor = key.cloned();
+        loop {
+            // Retrieve the next operation in the atomic batch.
+            let Some((pending_key, pending_value)) = self.get_next_pending(map, cursor.as_ref())? else {
+                // If there is none, return the next confirmed key-value pair.
+                return match is_removed {
+                    true => Ok(None),
+                    false => self.get_next_confirmed(map, cursor.as_ref()),
+                };
+            };
+            // If the next confirmed key precedes the pending key, it is not overridden by the atomic batch.
+            if !is_removed {
+                if let Some((key, value)) = self.get_next_confirmed(map, cursor.as_ref())? {
+                    if bincode::serialize(&key)? < bincode::serialize(&pending_key)? {
+                        return Ok(Some((key, value)));
+                    }
+                }
+            }
+            match pending_value {
+                // If the pending key is inserted, return it.
+                Some(value) => return Ok(Some((pending_key, value))),
+                // If the pending key is removed, continue from the pending key.
+                None => cursor = Some(pending_key),
+            }
+        }
+    }
+
     ///
     /// Returns an iterator visiting each map-key-value pair in the atomic batch.
     ///
@@ -164,6 +164,51 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `(height, committee)` pairs into empty storage, and indexes storage up to the latest round.
+    /// Unlike `insert`, the heights must be sequential, but need not start from block height 0.
+    ///
+    /// Note: This method is used to initialize storage from a ledger snapshot, which only contains the latest committees.
+    fn initialize(&self, committees: Vec<(u32, Committee<N>)>) -> Result<()> {
+        // Ensure the storage is empty.
+        ensure!(self.current_round().is_err(), "Committee storage must be empty to be initialized");
+        // Ensure there is at least one committee.
+        let Some((latest_height, latest_committee)) = committees.last() else {
+            bail!("There must be at least one committee to initialize committee storage")
+        };
+        // Ensure the heights are sequential and the rounds are increasing.
+        for window in committees.windows(2) {
+            let ((height, committee), (next_height, next_committee)) = (&window[0], &window[1]);
+            ensure!(*next_height == height + 1, "Next height must be sequential");
+            ensure!(
+                next_committee.starting_round() > committee.starting_round(),
+                "Next round {} must be greater than current round {}",
+                next_committee.starting_round(),
+                committee.starting_round()
+            );
+        }
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Store the latest round.
+            self.current_round_map().insert(ROUND_KEY, latest_committee.starting_round())?;
+            // Store the round to height mappings, up to the next committee.
+            for window in committees.windows(2) {
+                let ((height, committee), (_, next_committee)) = (&window[0], &window[1]);
+                for round in committee.starting_round()..next_committee.starting_round() {
+                    self.round_to_height_map().insert(round, *height)?;
+                }
+            }
+            // Store the latest round's height.
+            self.round_to_height_map().insert(latest_committee.starting_round(), *latest_height)?;
+
+            // Store the committees.
+            for (height, committee) in committees.iter() {
+                self.committee_map().insert(*height, committee.clone())?;
+            }
+            Ok(())
+        })
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     fn remove(&self, height: u32) -> Result<()> {
@@ -370,6 +415,12 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
         self.storage.insert(next_height, committee)
     }
 
+    /// Stores the given `(height, committee)` pairs into empty storage, and indexes storage up to the latest round.
+    /// Unlike `insert`, the heights must be sequential, but need not start from block height 0.
+    pub fn initialize(&self, committees: Vec<(u32, Committee<N>)>) -> Result<()> {
+        self.storage.initialize(committees)
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     pub fn remove(&self, height: u32) -> Result<()> {
@@ -615,4 +666,46 @@ mod tests {
         assert_eq!(store.get_committee_for_round(4).unwrap(), None);
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
+
+    #[test]
+    fn test_initialize() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_4 = ledger_committee::test_helpers::sample_committee_for_round(10, rng);
+        let committee_5 = ledger_committee::test_helpers::sample_committee_for_round(13, rng);
+        let committee_6 = ledger_committee::test_helpers::sample_committee_for_round(14, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        // Ensure the committees must be sequential.
+        assert!(store.initialize(vec![]).is_err());
+        assert!(store.initialize(vec![(4, committee_4.clone()), (6, committee_6.clone())]).is_err());
+        assert!(store.initialize(vec![(4, committee_5.clone()), (5, committee_4.clone())]).is_err());
+
+        // Initialize the committees.
+        store.initialize(vec![(4, committee_4.clone()), (5, committee_5.clone()), (6, committee_6.clone())]).unwrap();
+        assert_eq!(store.current_round().unwrap(), 14);
+        assert_eq!(store.current_height().unwrap(), 6);
+        assert_eq!(store.current_committee().unwrap(), committee_6);
+
+        assert_eq!(store.get_height_for_round(9).unwrap(), None);
+        assert_eq!(store.get_height_for_round(10).unwrap().unwrap(), 4);
+        assert_eq!(store.get_height_for_round(12).unwrap().unwrap(), 4);
+        assert_eq!(store.get_height_for_round(13).unwrap().unwrap(), 5);
+        assert_eq!(store.get_height_for_round(14).unwrap().unwrap(), 6);
+        assert_eq!(store.get_height_for_round(15).unwrap(), None);
+        assert_eq!(store.get_committee(3).unwrap(), None);
+        assert_eq!(store.get_committee_for_round(11).unwrap().unwrap(), committee_4);
+
+        // Ensure the storage cannot be initialized twice.
+        assert!(store.initialize(vec![(7, committee_6.clone())]).is_err());
+
+        // Insert the next committee.
+        let committee_7 = ledger_committee::test_helpers::sample_committee_for_round(16, rng);
+        store.insert(7, committee_7.clone()).unwrap();
+        assert_eq!(store.current_height().unwrap(), 7);
+        assert_eq!(store.get_height_for_round(15).unwrap().unwrap(), 6);
+        assert_eq!(store.get_committee_for_round(16).unwrap().unwrap(), committee_7);
+    }
 }
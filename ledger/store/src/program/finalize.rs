@@ -30,11 +30,11 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
-fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+pub fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
     // Construct the preimage.
     let mut preimage = Vec::new();
     program_id.write_bits_le(&mut preimage);
@@ -45,7 +45,7 @@ fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifie
 }
 
 /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
-fn to_key_id<N: Network>(
+pub fn to_key_id<N: Network>(
     program_id: &ProgramID<N>,
     mapping_name: &Identifier<N>,
     key: &Plaintext<N>,
@@ -61,6 +61,41 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the value ID for the given `key ID` and `value`.
+pub fn to_value_id<N: Network>(key_id: &Field<N>, value: &Value<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())
+}
+
+/// Returns the speculative number of entries in the given `(program ID, mapping name)`.
+fn get_mapping_length<N: Network, P: FinalizeStorage<N>>(
+    storage: &P,
+    mapping: &(ProgramID<N>, Identifier<N>),
+) -> Result<u64> {
+    match storage.mapping_length_map().get_speculative(mapping)? {
+        Some(length) => Ok(cow_to_copied!(length)),
+        None => bail!("Illegal operation: the number of entries in '{}/{}' is not initialized.", mapping.0, mapping.1),
+    }
+}
+
+/// The key of the block height from which the mapping history is recorded.
+#[cfg(feature = "history")]
+const HISTORY_KEY: u8 = 0;
+
+/// The maximum number of blocks that can be rolled back, beyond which the rollback operations are pruned.
+pub const MAX_ROLLBACK_DEPTH: u32 = 100;
+
+/// An operation that restores a piece of finalize storage to its state prior to a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum RollbackOperation<N: Network> {
+    /// Restores the mapping names of the `program ID`, or removes the `program ID` if `None`.
+    ProgramID(ProgramID<N>, Option<IndexSet<Identifier<N>>>),
+    /// Restores the value of the `key` in the `(program ID, mapping name)`, or removes the `key` if `None`.
+    KeyValue((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>),
+    /// Restores the number of entries in the `(program ID, mapping name)`, or removes it if `None`.
+    MappingLength((ProgramID<N>, Identifier<N>), Option<u64>),
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -78,6 +113,18 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(program ID, mapping name)` to the number of entries in the mapping.
+    type MappingLengthMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u64>;
+    /// The mapping of `block height` to `[rollback operation]`.
+    type RollbackMap: for<'a> Map<'a, u32, Vec<RollbackOperation<N>>>;
+    /// The mapping of `HISTORY_KEY` to the block height from which the history is recorded.
+    type HistoryHeightMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `key ID` to `[(block height, value)]`, where `None` indicates the key was removed.
+    type HistoryMap: for<'a> NestedMap<'a, Field<N>, u32, Option<Value<N>>>;
+    /// The mapping of `block height` to `(activation height, restrictions ID, restrictions list)`.
+    type RestrictionsMap: for<'a> Map<'a, u32, (u32, Field<N>, String)>;
+    /// The mapping of `block height` to `[ratified finalize operation]`.
+    type RatifiedMap: for<'a> Map<'a, u32, Vec<FinalizeOperation<N>>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -92,6 +139,18 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap;
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap;
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap;
+    /// Returns the ratified map.
+    fn ratified_map(&self) -> &Self::RatifiedMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -101,6 +160,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.mapping_length_map().start_atomic();
+        self.rollback_map().start_atomic();
+        self.history_height_map().start_atomic();
+        self.history_map().start_atomic();
+        self.restrictions_map().start_atomic();
+        self.ratified_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -108,6 +173,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.mapping_length_map().is_atomic_in_progress()
+            || self.rollback_map().is_atomic_in_progress()
+            || self.history_height_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.restrictions_map().is_atomic_in_progress()
+            || self.ratified_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -115,6 +186,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.mapping_length_map().atomic_checkpoint();
+        self.rollback_map().atomic_checkpoint();
+        self.history_height_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.restrictions_map().atomic_checkpoint();
+        self.ratified_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -122,6 +199,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.mapping_length_map().clear_latest_checkpoint();
+        self.rollback_map().clear_latest_checkpoint();
+        self.history_height_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.restrictions_map().clear_latest_checkpoint();
+        self.ratified_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -129,6 +212,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.mapping_length_map().atomic_rewind();
+        self.rollback_map().atomic_rewind();
+        self.history_height_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.restrictions_map().atomic_rewind();
+        self.ratified_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -136,13 +225,25 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.mapping_length_map().abort_atomic();
+        self.rollback_map().abort_atomic();
+        self.history_height_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.restrictions_map().abort_atomic();
+        self.ratified_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.mapping_length_map().finish_atomic()?;
+        self.rollback_map().finish_atomic()?;
+        self.history_height_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.restrictions_map().finish_atomic()?;
+        self.ratified_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -171,6 +272,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the program ID map with the new mapping name.
             self.program_id_map().insert(program_id, mapping_names)?;
+            // Initialize the number of entries in the mapping.
+            self.mapping_length_map().insert((program_id, mapping_name), 0)?;
 
             Ok(())
         })?;
@@ -200,14 +303,19 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             );
         }
 
+        // Retrieve the number of entries in the mapping.
+        let length = get_mapping_length(self, &(program_id, mapping_name))?;
+
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // Increment the number of entries in the mapping.
+            self.mapping_length_map().insert((program_id, mapping_name), length.saturating_add(1))?;
 
             Ok(())
         })?;
@@ -231,15 +339,24 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot update key-value.")
         }
+        // Determine the number of entries in the mapping after the update, if the key is new.
+        let new_length = match self.contains_key_speculative(program_id, mapping_name, &key)? {
+            true => None,
+            false => Some(get_mapping_length(self, &(program_id, mapping_name))?.saturating_add(1)),
+        };
 
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // Increment the number of entries in the mapping, if the key is new.
+            if let Some(new_length) = new_length {
+                self.mapping_length_map().insert((program_id, mapping_name), new_length)?;
+            }
 
             Ok(())
         })?;
@@ -265,12 +382,17 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             return Ok(None);
         }
 
+        // Retrieve the number of entries in the mapping.
+        let length = get_mapping_length(self, &(program_id, mapping_name))?;
+
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, key)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key.
             self.key_value_map().remove_key(&(program_id, mapping_name), key)?;
+            // Decrement the number of entries in the mapping.
+            self.mapping_length_map().insert((program_id, mapping_name), length.saturating_sub(1))?;
 
             Ok(())
         })?;
@@ -291,6 +413,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot replace mapping.")
         }
+        // Determine the number of distinct keys in the new entries.
+        // Note: As `Plaintext` is not hashable, the keys are deduplicated by their byte representation.
+        let length = entries.iter().map(|(key, _)| key.to_bytes_le()).collect::<Result<IndexSet<_>>>()?.len();
 
         atomic_batch_scope!(self, {
             // Remove the existing key-value entries.
@@ -301,6 +426,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Insert the key-value entry.
                 self.key_value_map().insert((program_id, mapping_name), key, value)?;
             }
+            // Update the number of entries in the mapping.
+            self.mapping_length_map().insert((program_id, mapping_name), u64::try_from(length)?)?;
 
             Ok(())
         })?;
@@ -327,6 +454,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.program_id_map().insert(program_id, mapping_names)?;
             // Remove the mapping.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the number of entries in the mapping.
+            self.mapping_length_map().remove(&(program_id, mapping_name))?;
 
             Ok(())
         })?;
@@ -351,11 +480,246 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             for mapping_name in mapping_names.iter() {
                 // Remove the mapping.
                 self.key_value_map().remove_map(&(*program_id, *mapping_name))?;
+                // Remove the number of entries in the mapping.
+                self.mapping_length_map().remove(&(*program_id, *mapping_name))?;
             }
             Ok(())
         })
     }
 
+    /// Stores the rollback operations for the given `block height`, which undo the pending
+    /// operations in the current atomic batch when applied by `rollback`.
+    ///
+    /// The rollback operations of the block at `MAX_ROLLBACK_DEPTH` below the given `block height` are pruned.
+    ///
+    /// Note: This method must be called within the atomic batch that finalizes the block.
+    fn store_rollback_operations(&self, block_height: u32) -> Result<()> {
+        // Ensure the rollback operations for the block height do not already exist.
+        if self.rollback_map().contains_key_speculative(&block_height)? {
+            bail!("Illegal operation: rollback operations for block {block_height} already exist in storage.")
+        }
+
+        // Initialize a list for the rollback operations.
+        let mut operations = Vec::new();
+
+        // Restore the mapping names of each program ID that is modified in the batch.
+        let program_ids: IndexSet<_> = self.program_id_map().iter_pending().map(|(k, _)| cow_to_copied!(k)).collect();
+        for program_id in program_ids {
+            let mapping_names = self.program_id_map().get_confirmed(&program_id)?.map(|names| cow_to_cloned!(names));
+            operations.push(RollbackOperation::ProgramID(program_id, mapping_names));
+        }
+
+        // Retrieve the pending key-value operations.
+        let pending: Vec<_> = self
+            .key_value_map()
+            .iter_pending()
+            .map(|(m, k, _)| (cow_to_copied!(m), k.map(|k| cow_to_cloned!(k))))
+            .collect();
+        // Retrieve each mapping that is removed or replaced in the batch.
+        let mappings: IndexSet<_> = pending.iter().filter(|(_, k)| k.is_none()).map(|(m, _)| *m).collect();
+        // Retrieve each key that is modified in the batch, including the keys of a removed or replaced mapping
+        // both prior to and after the batch.
+        // Note: As `Plaintext` is not hashable, the keys are deduplicated by their byte representation.
+        let mut keys = IndexMap::new();
+        for (mapping, key) in pending {
+            if let Some(key) = key {
+                keys.entry((mapping, key.to_bytes_le()?)).or_insert(key);
+            }
+        }
+        for mapping in mappings {
+            let confirmed = self.key_value_map().get_map_confirmed(&mapping)?;
+            let speculative = self.key_value_map().get_map_speculative(&mapping)?;
+            for (key, _) in confirmed.into_iter().chain(speculative) {
+                keys.entry((mapping, key.to_bytes_le()?)).or_insert(key);
+            }
+        }
+        // Restore the value of each key whose value is changed by the batch.
+        for ((mapping, _), key) in keys {
+            let value = self.key_value_map().get_value_confirmed(&mapping, &key)?.map(|value| cow_to_cloned!(value));
+            let new_value =
+                self.key_value_map().get_value_speculative(&mapping, &key)?.map(|value| cow_to_cloned!(value));
+            if value != new_value {
+                operations.push(RollbackOperation::KeyValue(mapping, key, value));
+            }
+        }
+
+        // Restore the number of entries of each mapping whose number of entries is changed by the batch.
+        let mappings: IndexSet<_> =
+            self.mapping_length_map().iter_pending().map(|(mapping, _)| cow_to_copied!(mapping)).collect();
+        for mapping in mappings {
+            let length = self.mapping_length_map().get_confirmed(&mapping)?.map(|length| cow_to_copied!(length));
+            let new_length = self.mapping_length_map().get_speculative(&mapping)?.map(|length| cow_to_copied!(length));
+            if length != new_length {
+                operations.push(RollbackOperation::MappingLength(mapping, length));
+            }
+        }
+
+        // Store the history of each key that is modified in the batch.
+        #[cfg(feature = "history")]
+        self.store_history(block_height, &operations)?;
+
+        // Store the rollback operations.
+        self.rollback_map().insert(block_height, operations)?;
+        // Prune the rollback operations that are beyond the maximum rollback depth.
+        if let Some(pruned_height) = block_height.checked_sub(MAX_ROLLBACK_DEPTH) {
+            self.rollback_map().remove(&pruned_height)?;
+        }
+        Ok(())
+    }
+
+    /// Stores the value of each key that is modified by the given rollback operations, as of the given `block height`.
+    ///
+    /// Note: This method must be called within the atomic batch that finalizes the block.
+    #[cfg(feature = "history")]
+    fn store_history(&self, block_height: u32, operations: &[RollbackOperation<N>]) -> Result<()> {
+        // Record the block height from which the history is recorded, if it is not yet recorded.
+        if !self.history_height_map().contains_key_speculative(&HISTORY_KEY)? {
+            self.history_height_map().insert(HISTORY_KEY, block_height)?;
+        }
+        for (key_id, (mapping, key)) in self.get_modified_keys(operations)? {
+            // Ensure the history of the key is not already recorded for the block height.
+            if self.history_map().contains_key_speculative(&key_id, &block_height)? {
+                bail!("Illegal operation: the history of key '{key}' already exists for block {block_height}.")
+            }
+            // Retrieve the value of the key after the block.
+            let value = self.key_value_map().get_value_speculative(&mapping, &key)?.map(|value| cow_to_cloned!(value));
+            // Store the history of the key.
+            self.history_map().insert(key_id, block_height, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the keys that are modified by the given rollback operations, indexed by their key ID.
+    #[cfg(feature = "history")]
+    #[allow(clippy::type_complexity)]
+    fn get_modified_keys(
+        &self,
+        operations: &[RollbackOperation<N>],
+    ) -> Result<IndexMap<Field<N>, ((ProgramID<N>, Identifier<N>), Plaintext<N>)>> {
+        let mut keys = IndexMap::new();
+        for operation in operations {
+            match operation {
+                RollbackOperation::ProgramID(..) | RollbackOperation::MappingLength(..) => (),
+                RollbackOperation::KeyValue(mapping, key, _) => {
+                    keys.insert(to_key_id(&mapping.0, &mapping.1, key)?, (*mapping, key.clone()));
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Stores the restrictions list ratified in the given `block height`, which takes effect from the `activation height`.
+    ///
+    /// Note: This method must be called within the atomic batch that finalizes the block.
+    fn insert_restrictions(
+        &self,
+        block_height: u32,
+        activation_height: u32,
+        restrictions_id: Field<N>,
+        restrictions: String,
+    ) -> Result<()> {
+        // Ensure the restrictions list takes effect after the block height.
+        if activation_height <= block_height {
+            bail!("Illegal operation: restrictions ratified in block {block_height} must take effect after the block.")
+        }
+        // Ensure the restrictions for the block height do not already exist.
+        if self.restrictions_map().contains_key_speculative(&block_height)? {
+            bail!("Illegal operation: restrictions for block {block_height} already exist in storage.")
+        }
+        // Store the restrictions.
+        self.restrictions_map().insert(block_height, (activation_height, restrictions_id, restrictions))
+    }
+
+    /// Stores the ratified finalize operations of the given `block height`, which are committed to by its finalize root.
+    ///
+    /// The ratified finalize operations of the block at `MAX_ROLLBACK_DEPTH` below the given `block height` are pruned.
+    ///
+    /// Note: This method must be called within the atomic batch that finalizes the block.
+    fn insert_ratified_operations(&self, block_height: u32, operations: Vec<FinalizeOperation<N>>) -> Result<()> {
+        // Ensure the ratified finalize operations for the block height do not already exist.
+        if self.ratified_map().contains_key_speculative(&block_height)? {
+            bail!("Illegal operation: ratified finalize operations for block {block_height} already exist in storage.")
+        }
+        // Store the ratified finalize operations.
+        self.ratified_map().insert(block_height, operations)?;
+        // Prune the ratified finalize operations that are beyond the maximum rollback depth.
+        if let Some(pruned_height) = block_height.checked_sub(MAX_ROLLBACK_DEPTH) {
+            self.ratified_map().remove(&pruned_height)?;
+        }
+        Ok(())
+    }
+
+    /// Reverts the finalize storage to its state prior to the given `block height`, by applying the stored
+    /// rollback operations and removing the committee, restrictions, and ratified operations for the block.
+    ///
+    /// Note: Blocks must be rolled back one at a time, in descending order of block height.
+    fn rollback(&self, block_height: u32) -> Result<()> {
+        // Retrieve the rollback operations.
+        let Some(operations) = self.rollback_map().get_confirmed(&block_height)? else {
+            bail!("Illegal operation: rollback operations for block {block_height} are not found in storage.")
+        };
+        // Determine if a committee exists for the block height.
+        let has_committee = self.committee_store().get_committee(block_height)?.is_some();
+        // Determine if restrictions were ratified in the block height.
+        let has_restrictions = self.restrictions_map().contains_key_confirmed(&block_height)?;
+        // Determine if ratified finalize operations are stored for the block height.
+        let has_ratified = self.ratified_map().contains_key_confirmed(&block_height)?;
+        // Determine the key IDs whose history is recorded for the block height.
+        #[cfg(feature = "history")]
+        let key_ids: Vec<_> = self.get_modified_keys(&operations)?.into_keys().collect();
+        // Retrieve the block height from which the history is recorded.
+        #[cfg(feature = "history")]
+        let history_height =
+            self.history_height_map().get_confirmed(&HISTORY_KEY)?.map(|height| cow_to_copied!(height));
+
+        atomic_batch_scope!(self, {
+            // Apply the rollback operations.
+            for operation in operations.iter() {
+                match operation {
+                    RollbackOperation::ProgramID(program_id, Some(mapping_names)) => {
+                        self.program_id_map().insert(*program_id, mapping_names.clone())?
+                    }
+                    RollbackOperation::ProgramID(program_id, None) => self.program_id_map().remove(program_id)?,
+                    RollbackOperation::KeyValue(mapping, key, Some(value)) => {
+                        self.key_value_map().insert(*mapping, key.clone(), value.clone())?
+                    }
+                    RollbackOperation::KeyValue(mapping, key, None) => self.key_value_map().remove_key(mapping, key)?,
+                    RollbackOperation::MappingLength(mapping, Some(length)) => {
+                        self.mapping_length_map().insert(*mapping, *length)?
+                    }
+                    RollbackOperation::MappingLength(mapping, None) => self.mapping_length_map().remove(mapping)?,
+                }
+            }
+            // Remove the committee for the block height.
+            if has_committee {
+                self.committee_store().remove(block_height)?;
+            }
+            // Remove the restrictions for the block height.
+            if has_restrictions {
+                self.restrictions_map().remove(&block_height)?;
+            }
+            // Remove the ratified finalize operations for the block height.
+            if has_ratified {
+                self.ratified_map().remove(&block_height)?;
+            }
+            // Remove the history of each key for the block height.
+            #[cfg(feature = "history")]
+            {
+                for key_id in key_ids.iter() {
+                    self.history_map().remove_key(key_id, &block_height)?;
+                }
+                // If the history is recorded from the block height, remove the recorded block height.
+                if history_height == Some(block_height) {
+                    self.history_height_map().remove(&HISTORY_KEY)?;
+                }
+            }
+            // Remove the rollback operations.
+            self.rollback_map().remove(&block_height)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -429,6 +793,32 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get length (S).")
+        }
+        // Retrieve the number of entries for the mapping.
+        Ok(usize::try_from(get_mapping_length(self, &(program_id, mapping_name))?)?)
+    }
+
+    /// Returns the speculative entry that follows the given `key` for the given `program ID` and `mapping name`.
+    /// If no `key` is given, returns the first speculative entry of the mapping.
+    fn get_next_key_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Option<&Plaintext<N>>,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get next entry (S).")
+        }
+        // Retrieve the next entry for the mapping.
+        self.key_value_map().get_next_speculative(&(program_id, mapping_name), key)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -455,6 +845,165 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    ///
+    /// Returns an error if the given `block height` precedes the block from which the history is recorded.
+    #[cfg(feature = "history")]
+    fn get_value_at(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the block height from which the history is recorded.
+        let Some(history_height) = self.history_height_map().get_confirmed(&HISTORY_KEY)? else {
+            bail!("The mapping history is not recorded in storage.")
+        };
+        let history_height = cow_to_copied!(history_height);
+        // Ensure the block height is not before the history is recorded.
+        ensure!(
+            block_height >= history_height,
+            "The mapping history is only recorded from block {history_height}, which is after block {block_height}"
+        );
+
+        // Compute the key ID.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        // Retrieve the block heights in which the key was modified, along with the resulting values.
+        let history = self.history_map().get_map_confirmed(&key_id)?;
+        // If the key was never modified since the history is recorded, it retains its confirmed value.
+        if history.is_empty() {
+            return self.get_value_confirmed(program_id, mapping_name, key);
+        }
+        // Retrieve the value from the latest modification at or before the given block height.
+        match history.into_iter().filter(|(height, _)| *height <= block_height).max_by_key(|(height, _)| *height) {
+            Some((_, value)) => Ok(value),
+            // If the history of the key is recorded from genesis, the key did not exist at the given block height.
+            None if history_height == 0 => Ok(None),
+            // Otherwise, the value of the key prior to its first recorded modification is unknown.
+            None => bail!("The value of key '{key}' at block {block_height} predates the recorded mapping history"),
+        }
+    }
+
+    /// Returns the entries of every mapping, as `(program ID, mapping name, entries)`, as of the given `block height`.
+    /// The entries are computed by applying the rollback operations of the subsequent blocks to the confirmed state.
+    #[allow(clippy::type_complexity)]
+    fn get_mappings_at(
+        &self,
+        block_height: u32,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>> {
+        // Retrieve the confirmed mapping names of each program.
+        let mut programs: IndexMap<_, _> =
+            self.program_id_map().iter_confirmed().map(|(k, v)| (cow_to_copied!(k), cow_to_cloned!(v))).collect();
+        // Retrieve the confirmed entries of each mapping.
+        // Note: As `Plaintext` is not hashable, the keys are indexed by their byte representation.
+        let mut mappings = IndexMap::new();
+        for (program_id, mapping_names) in programs.iter() {
+            for mapping_name in mapping_names.iter() {
+                let mapping = (*program_id, *mapping_name);
+                let entries = self.key_value_map().get_map_confirmed(&mapping)?;
+                let entries = entries
+                    .into_iter()
+                    .map(|(key, value)| Ok((key.to_bytes_le()?, (key, value))))
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                mappings.insert(mapping, entries);
+            }
+        }
+
+        // Retrieve the heights of the subsequent blocks, in descending order.
+        let mut heights: Vec<_> = self
+            .rollback_map()
+            .keys_confirmed()
+            .map(|height| cow_to_copied!(height))
+            .filter(|h| *h > block_height)
+            .collect();
+        heights.sort_unstable_by(|a, b| b.cmp(a));
+        // Ensure the rollback operations of every subsequent block exist.
+        if let Some(latest_height) = heights.first() {
+            if heights.len() != (latest_height - block_height) as usize {
+                bail!("The rollback operations after block {block_height} are incomplete in storage.")
+            }
+        }
+
+        // Apply the rollback operations of each subsequent block.
+        for height in heights {
+            let Some(operations) = self.rollback_map().get_confirmed(&height)? else {
+                bail!("The rollback operations for block {height} are not found in storage.")
+            };
+            for operation in operations.iter() {
+                match operation {
+                    RollbackOperation::ProgramID(program_id, Some(mapping_names)) => {
+                        programs.insert(*program_id, mapping_names.clone());
+                    }
+                    RollbackOperation::ProgramID(program_id, None) => {
+                        programs.shift_remove(program_id);
+                    }
+                    RollbackOperation::KeyValue(mapping, key, Some(value)) => {
+                        mappings.entry(*mapping).or_default().insert(key.to_bytes_le()?, (key.clone(), value.clone()));
+                    }
+                    RollbackOperation::KeyValue(mapping, key, None) => {
+                        if let Some(entries) = mappings.get_mut(mapping) {
+                            entries.shift_remove(&key.to_bytes_le()?);
+                        }
+                    }
+                    // Counters are rebuilt when the snapshot is loaded by `initialize_mapping` and `replace_mapping`.
+                    RollbackOperation::MappingLength(..) => {}
+                }
+            }
+        }
+
+        // Return the entries of each mapping.
+        let mut output = Vec::new();
+        for (program_id, mapping_names) in programs {
+            for mapping_name in mapping_names {
+                let entries = mappings.swap_remove(&(program_id, mapping_name)).unwrap_or_default();
+                output.push((program_id, mapping_name, entries.into_values().collect()));
+            }
+        }
+        Ok(output)
+    }
+
+    /// Returns the confirmed restrictions lists, in ascending order of the block height in which they were ratified,
+    /// as `(block height, activation height, restrictions ID, restrictions list)`.
+    #[allow(clippy::type_complexity)]
+    fn get_restrictions_confirmed(&self) -> Result<Vec<(u32, u32, Field<N>, String)>> {
+        let mut restrictions: Vec<_> = self
+            .restrictions_map()
+            .iter_confirmed()
+            .map(|(k, v)| {
+                let (activation_height, restrictions_id, restrictions) = cow_to_cloned!(v);
+                (cow_to_copied!(k), activation_height, restrictions_id, restrictions)
+            })
+            .collect();
+        restrictions.sort_unstable_by_key(|(block_height, ..)| *block_height);
+        Ok(restrictions)
+    }
+
+    /// Returns the confirmed ratified finalize operations of the given `block height`, if they are stored.
+    fn get_ratified_operations_confirmed(&self, block_height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        Ok(self.ratified_map().get_confirmed(&block_height)?.map(|operations| cow_to_cloned!(operations)))
+    }
+
+    /// Returns the ID of the ratified restrictions list in effect at the given `block height`, if one exists.
+    fn get_restrictions_id_confirmed(&self, block_height: u32) -> Result<Option<Field<N>>> {
+        // Note: If several lists share an activation height, the most recently ratified list takes effect.
+        Ok(self
+            .get_restrictions_confirmed()?
+            .into_iter()
+            .filter(|(_, activation_height, _, _)| *activation_height <= block_height)
+            .max_by_key(|(_, activation_height, _, _)| *activation_height)
+            .map(|(_, _, restrictions_id, _)| restrictions_id))
+    }
+
+    /// Returns the ID of the most recently ratified restrictions list, if one exists.
+    fn get_latest_restrictions_id_confirmed(&self) -> Result<Option<Field<N>>> {
+        Ok(self
+            .restrictions_map()
+            .iter_confirmed()
+            .max_by_key(|(block_height, _)| **block_height)
+            .map(|(_, restrictions)| restrictions.1))
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -547,6 +1096,27 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Determine the mappings whose number of entries is not yet stored.
+        // Note: This only occurs for storage that was populated prior to the mapping length map.
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in storage.program_id_map().iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                let mapping = (cow_to_copied!(program_id), *mapping_name);
+                if !storage.mapping_length_map().contains_key_confirmed(&mapping)? {
+                    mappings.push(mapping);
+                }
+            }
+        }
+        // Store the number of entries of each of these mappings.
+        if !mappings.is_empty() {
+            atomic_batch_scope!(storage, {
+                for mapping in mappings {
+                    let length = storage.key_value_map().len_map_confirmed(&mapping)?;
+                    storage.mapping_length_map().insert(mapping, u64::try_from(length)?)?;
+                }
+                Ok(())
+            })?;
+        }
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -625,6 +1195,22 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        self.storage.get_mapping_length_speculative(program_id, mapping_name)
+    }
+
+    /// Returns the speculative entry that follows the given `key` for the given `program ID` and `mapping name`.
+    /// If no `key` is given, returns the first speculative entry of the mapping.
+    fn get_next_key_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Option<&Plaintext<N>>,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_next_key_value_speculative(program_id, mapping_name, key)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -700,6 +1286,33 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
         self.storage.remove_program(program_id)
     }
+
+    /// Stores the rollback operations for the given `block height`, which undo the pending
+    /// operations in the current atomic batch when applied by `rollback`.
+    pub fn store_rollback_operations(&self, block_height: u32) -> Result<()> {
+        self.storage.store_rollback_operations(block_height)
+    }
+
+    /// Stores the restrictions list ratified in the given `block height`, which takes effect from the `activation height`.
+    pub fn insert_restrictions(
+        &self,
+        block_height: u32,
+        activation_height: u32,
+        restrictions_id: Field<N>,
+        restrictions: String,
+    ) -> Result<()> {
+        self.storage.insert_restrictions(block_height, activation_height, restrictions_id, restrictions)
+    }
+
+    /// Stores the ratified finalize operations of the given `block height`, which are committed to by its finalize root.
+    pub fn insert_ratified_operations(&self, block_height: u32, operations: Vec<FinalizeOperation<N>>) -> Result<()> {
+        self.storage.insert_ratified_operations(block_height, operations)
+    }
+
+    /// Reverts the finalize storage to its state prior to the given `block height`.
+    pub fn rollback(&self, block_height: u32) -> Result<()> {
+        self.storage.rollback(block_height)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -708,6 +1321,15 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.contains_program_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` and `mapping name` exist.
+    pub fn contains_mapping_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<bool> {
+        self.storage.contains_mapping_speculative(program_id, mapping_name)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     pub fn contains_key_confirmed(
         &self,
@@ -763,6 +1385,49 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    #[cfg(feature = "history")]
+    pub fn get_mapping_value_at(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns the entries of every mapping, as `(program ID, mapping name, entries)`, as of the given `block height`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_mappings_at(
+        &self,
+        block_height: u32,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>> {
+        self.storage.get_mappings_at(block_height)
+    }
+
+    /// Returns the confirmed restrictions lists, in ascending order of the block height in which they were ratified,
+    /// as `(block height, activation height, restrictions ID, restrictions list)`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_restrictions_confirmed(&self) -> Result<Vec<(u32, u32, Field<N>, String)>> {
+        self.storage.get_restrictions_confirmed()
+    }
+
+    /// Returns the confirmed ratified finalize operations of the given `block height`, if they are stored.
+    pub fn get_ratified_operations_confirmed(&self, block_height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        self.storage.get_ratified_operations_confirmed(block_height)
+    }
+
+    /// Returns the ID of the ratified restrictions list in effect at the given `block height`, if one exists.
+    pub fn get_restrictions_id_confirmed(&self, block_height: u32) -> Result<Option<Field<N>>> {
+        self.storage.get_restrictions_id_confirmed(block_height)
+    }
+
+    /// Returns the ID of the most recently ratified restrictions list, if one exists.
+    pub fn get_latest_restrictions_id_confirmed(&self) -> Result<Option<Field<N>>> {
+        self.storage.get_latest_restrictions_id_confirmed()
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
@@ -1209,6 +1874,497 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rollback() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let replaced_name = Identifier::from_str("replaced").unwrap();
+        let new_program_id = ProgramID::<CurrentNetwork>::from_str("world.aleo").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Initialize the mappings, and insert the list of keys and values.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.initialize_mapping(program_id, replaced_name).unwrap();
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, replaced_name, key, value).unwrap();
+        }
+        // Retrieve the checksum prior to the block.
+        let expected_checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Modify the finalize store in an atomic batch, and store the rollback operations.
+        finalize_store.start_atomic();
+        let key = Plaintext::from_str("0field").unwrap();
+        finalize_store
+            .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("100u64").unwrap())
+            .unwrap();
+        finalize_store
+            .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("200u64").unwrap())
+            .unwrap();
+        finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("1field").unwrap()).unwrap();
+        finalize_store
+            .insert_key_value(
+                program_id,
+                mapping_name,
+                Plaintext::from_str("10field").unwrap(),
+                Value::from_str("10u64").unwrap(),
+            )
+            .unwrap();
+        finalize_store
+            .replace_mapping(program_id, replaced_name, vec![(key.clone(), Value::from_str("300u64").unwrap())])
+            .unwrap();
+        finalize_store.initialize_mapping(new_program_id, mapping_name).unwrap();
+        finalize_store
+            .insert_key_value(new_program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+            .unwrap();
+        finalize_store.store_rollback_operations(1).unwrap();
+        finalize_store.finish_atomic().unwrap();
+
+        // Ensure the rollback operations cannot be stored twice.
+        assert!(finalize_store.store_rollback_operations(1).is_err());
+        // Ensure the modifications were applied.
+        assert_ne!(finalize_store.get_checksum_confirmed().unwrap(), expected_checksum);
+        assert_eq!(finalize_store.get_mapping_confirmed(program_id, replaced_name).unwrap().len(), 1);
+        assert!(finalize_store.contains_program_confirmed(&new_program_id).unwrap());
+
+        // Roll back the block.
+        finalize_store.rollback(1).unwrap();
+        // Ensure the finalize store is restored.
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), expected_checksum);
+        assert_eq!(finalize_store.get_mapping_confirmed(program_id, replaced_name).unwrap().len(), 10);
+        assert_eq!(
+            finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(),
+            Some(Value::from_str("0u64").unwrap())
+        );
+        assert!(!finalize_store.contains_program_confirmed(&new_program_id).unwrap());
+        assert!(!finalize_store.contains_key_confirmed(new_program_id, mapping_name, &key).unwrap());
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, replaced_name).unwrap(), 10);
+        let mapping = (new_program_id, mapping_name);
+        assert!(!finalize_store.storage.mapping_length_map().contains_key_confirmed(&mapping).unwrap());
+        // Ensure the block cannot be rolled back twice.
+        assert!(finalize_store.rollback(1).is_err());
+    }
+
+    #[test]
+    fn test_mapping_length() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory.clone()).unwrap();
+
+        // Ensure the length of an uninitialized mapping cannot be retrieved.
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        // Ensure the length of a new mapping is 0.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+
+        // Ensure inserting and updating keys increments the length only for new keys.
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+        finalize_store
+            .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+        let new_key = Plaintext::from_str("10field").unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, new_key, Value::from_str("1u64").unwrap()).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 11);
+
+        // Ensure removing keys decrements the length only for existing keys.
+        assert!(finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap().is_some());
+        assert!(finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap().is_none());
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+
+        // Ensure the length is speculative within an atomic batch, and is discarded if the batch is aborted.
+        finalize_store.start_atomic();
+        finalize_store
+            .insert_key_value(program_id, mapping_name, key.clone(), Value::from_str("0u64").unwrap())
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 11);
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+
+        // Ensure replacing the mapping sets the length to the number of distinct keys.
+        let entries = vec![
+            (key.clone(), Value::from_str("0u64").unwrap()),
+            (key.clone(), Value::from_str("1u64").unwrap()),
+            (Plaintext::from_str("1field").unwrap(), Value::from_str("1u64").unwrap()),
+        ];
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 2);
+        assert_eq!(finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap().len(), 2);
+
+        // Ensure a missing length is restored when the finalize store is opened.
+        program_memory.mapping_length_map().remove(&(program_id, mapping_name)).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 2);
+
+        // Ensure removing the mapping removes its length.
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        let mapping = (program_id, mapping_name);
+        assert!(!finalize_store.storage.mapping_length_map().contains_key_confirmed(&mapping).unwrap());
+    }
+
+    #[test]
+    fn test_rollback_replace_mapping() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Initialize the mapping, and insert the list of keys and values.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        let mut entries = Vec::new();
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+            entries.push((key, value));
+        }
+        // Retrieve the checksum prior to the block.
+        let expected_checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Replace the mapping, updating one key, removing one key, and inserting one key.
+        entries[0].1 = Value::from_str("100u64").unwrap();
+        entries.pop();
+        entries.push((Plaintext::from_str("10field").unwrap(), Value::from_str("10u64").unwrap()));
+        finalize_store.start_atomic();
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        finalize_store.store_rollback_operations(1).unwrap();
+        finalize_store.finish_atomic().unwrap();
+
+        // Ensure only the modified keys are stored in the rollback operations.
+        let operations = finalize_store.storage.rollback_map().get_confirmed(&1).unwrap().unwrap();
+        assert_eq!(operations.len(), 3);
+        assert!(operations.iter().all(|operation| matches!(operation, RollbackOperation::KeyValue(..))));
+
+        // Roll back the block, and ensure the finalize store is restored.
+        finalize_store.rollback(1).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), expected_checksum);
+    }
+
+    #[test]
+    fn test_rollback_pruning() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Finalize blocks beyond the maximum rollback depth.
+        let latest_height = MAX_ROLLBACK_DEPTH + 5;
+        for block_height in 1..=latest_height {
+            finalize_store.start_atomic();
+            finalize_store
+                .update_key_value(
+                    program_id,
+                    mapping_name,
+                    key.clone(),
+                    Value::from_str(&format!("{block_height}u64")).unwrap(),
+                )
+                .unwrap();
+            finalize_store.store_rollback_operations(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        }
+
+        // Ensure only the rollback operations within the maximum rollback depth are retained.
+        let rollback_map = finalize_store.storage.rollback_map();
+        assert_eq!(rollback_map.keys_confirmed().count(), MAX_ROLLBACK_DEPTH as usize);
+        assert!(!rollback_map.contains_key_confirmed(&(latest_height - MAX_ROLLBACK_DEPTH)).unwrap());
+        assert!(rollback_map.contains_key_confirmed(&(latest_height - MAX_ROLLBACK_DEPTH + 1)).unwrap());
+        // Ensure the mappings can only be retrieved within the maximum rollback depth.
+        assert!(finalize_store.get_mappings_at(latest_height - MAX_ROLLBACK_DEPTH).is_ok());
+        assert!(finalize_store.get_mappings_at(latest_height - MAX_ROLLBACK_DEPTH - 1).is_err());
+    }
+
+    #[test]
+    fn test_insert_restrictions() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Initialize the restrictions IDs.
+        let first_id = Field::<CurrentNetwork>::from_u64(1);
+        let second_id = Field::<CurrentNetwork>::from_u64(2);
+
+        // Ensure restrictions must take effect after the block in which they are ratified.
+        assert!(finalize_store.insert_restrictions(5, 5, first_id, "first".to_string()).is_err());
+
+        // Finalize two blocks which ratify restrictions.
+        for (block_height, activation_height, restrictions_id) in [(1, 10, first_id), (2, 20, second_id)] {
+            finalize_store.start_atomic();
+            finalize_store
+                .insert_restrictions(block_height, activation_height, restrictions_id, "list".to_string())
+                .unwrap();
+            finalize_store.store_rollback_operations(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        }
+
+        // Ensure the restrictions cannot be stored twice for the same block.
+        assert!(finalize_store.insert_restrictions(2, 30, second_id, "list".to_string()).is_err());
+        // Ensure the restrictions ID is in effect from the activation height.
+        assert_eq!(finalize_store.get_restrictions_confirmed().unwrap().len(), 2);
+        assert_eq!(finalize_store.get_restrictions_id_confirmed(9).unwrap(), None);
+        assert_eq!(finalize_store.get_restrictions_id_confirmed(10).unwrap(), Some(first_id));
+        assert_eq!(finalize_store.get_restrictions_id_confirmed(19).unwrap(), Some(first_id));
+        assert_eq!(finalize_store.get_restrictions_id_confirmed(20).unwrap(), Some(second_id));
+        assert_eq!(finalize_store.get_latest_restrictions_id_confirmed().unwrap(), Some(second_id));
+
+        // Roll back the latest block.
+        finalize_store.rollback(2).unwrap();
+        // Ensure the restrictions ratified in the block are removed.
+        assert_eq!(finalize_store.get_restrictions_confirmed().unwrap().len(), 1);
+        assert_eq!(finalize_store.get_restrictions_id_confirmed(20).unwrap(), Some(first_id));
+        assert_eq!(finalize_store.get_latest_restrictions_id_confirmed().unwrap(), Some(first_id));
+    }
+
+    #[test]
+    fn test_insert_ratified_operations() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Finalize the blocks up to the maximum rollback depth.
+        for block_height in 0..=MAX_ROLLBACK_DEPTH {
+            let operations = vec![FinalizeOperation::ReplaceMapping(Field::<CurrentNetwork>::from_u32(block_height))];
+            finalize_store.start_atomic();
+            finalize_store.insert_ratified_operations(block_height, operations).unwrap();
+            finalize_store.store_rollback_operations(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        }
+
+        // Ensure the ratified finalize operations cannot be stored twice for the same block.
+        assert!(finalize_store.insert_ratified_operations(MAX_ROLLBACK_DEPTH, vec![]).is_err());
+        // Ensure the ratified finalize operations beyond the maximum rollback depth are pruned.
+        assert_eq!(finalize_store.get_ratified_operations_confirmed(0).unwrap(), None);
+        assert_eq!(
+            finalize_store.get_ratified_operations_confirmed(1).unwrap(),
+            Some(vec![FinalizeOperation::ReplaceMapping(Field::from_u32(1))])
+        );
+
+        // Roll back the latest block.
+        finalize_store.rollback(MAX_ROLLBACK_DEPTH).unwrap();
+        // Ensure the ratified finalize operations of the block are removed.
+        assert_eq!(finalize_store.get_ratified_operations_confirmed(MAX_ROLLBACK_DEPTH).unwrap(), None);
+        assert!(finalize_store.get_ratified_operations_confirmed(MAX_ROLLBACK_DEPTH - 1).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_get_mappings_at() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_name = Identifier::from_str("other").unwrap();
+        let new_program_id = ProgramID::<CurrentNetwork>::from_str("world.aleo").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Returns the mappings as of the given block height, sorted by their string representation.
+        let mappings_at = |block_height: u32| {
+            let mut mappings: Vec<_> = finalize_store
+                .get_mappings_at(block_height)
+                .unwrap()
+                .into_iter()
+                .map(|(program_id, mapping_name, entries)| {
+                    let mut entries: Vec<_> = entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+                    entries.sort();
+                    (program_id.to_string(), mapping_name.to_string(), entries)
+                })
+                .collect();
+            mappings.sort();
+            mappings
+        };
+        // Finalizes a block with the given modifications.
+        let finalize_block = |block_height: u32, modify: &dyn Fn()| {
+            finalize_store.start_atomic();
+            modify();
+            finalize_store.store_rollback_operations(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        };
+
+        // Block 0: Initialize the mappings.
+        finalize_block(0, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.initialize_mapping(program_id, other_name).unwrap();
+        });
+        let mut expected = vec![mappings_at(0)];
+        // Block 1: Insert keys.
+        finalize_block(1, &|| {
+            for item in 0..5 {
+                let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+                let value = Value::from_str(&format!("{item}u64")).unwrap();
+                finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+                finalize_store.insert_key_value(program_id, other_name, key, value).unwrap();
+            }
+        });
+        expected.push(mappings_at(1));
+        // Block 2: Update and remove keys, and replace a mapping.
+        finalize_block(2, &|| {
+            finalize_store
+                .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("9u64").unwrap())
+                .unwrap();
+            finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("1field").unwrap()).unwrap();
+            finalize_store
+                .replace_mapping(program_id, other_name, vec![(key.clone(), Value::from_str("7u64").unwrap())])
+                .unwrap();
+        });
+        expected.push(mappings_at(2));
+        // Block 3: Initialize a new program.
+        finalize_block(3, &|| {
+            finalize_store.initialize_mapping(new_program_id, mapping_name).unwrap();
+            finalize_store
+                .insert_key_value(new_program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+                .unwrap();
+        });
+        expected.push(mappings_at(3));
+
+        // Ensure the mappings at each block height are correct.
+        assert_eq!(expected[1][0].2.len(), 5);
+        assert_eq!(expected[3].len(), 3);
+        for (block_height, expected) in (0u32..).zip(expected) {
+            assert_eq!(mappings_at(block_height), expected);
+        }
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn test_get_mapping_value_at() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Finalizes a block with the given modifications.
+        let finalize_block = |block_height: u32, modify: &dyn Fn()| {
+            finalize_store.start_atomic();
+            modify();
+            finalize_store.store_rollback_operations(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        };
+        // Block 0: Initialize the mapping.
+        finalize_block(0, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        });
+        // Block 1: Insert the key.
+        finalize_block(1, &|| {
+            finalize_store
+                .insert_key_value(program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+                .unwrap();
+        });
+        // Block 2: Modify a different key.
+        finalize_block(2, &|| {
+            finalize_store
+                .insert_key_value(program_id, mapping_name, other_key.clone(), Value::from_str("2u64").unwrap())
+                .unwrap();
+        });
+        // Block 3: Update the key.
+        finalize_block(3, &|| {
+            finalize_store
+                .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("3u64").unwrap())
+                .unwrap();
+        });
+        // Block 4: Remove the key.
+        finalize_block(4, &|| {
+            finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        });
+        // Block 5: Replace the mapping.
+        finalize_block(5, &|| {
+            finalize_store
+                .replace_mapping(program_id, mapping_name, vec![(key.clone(), Value::from_str("5u64").unwrap())])
+                .unwrap();
+        });
+
+        // Ensure the historical values are correct.
+        let value_at = |key: &Plaintext<CurrentNetwork>, block_height: u32| {
+            finalize_store.get_mapping_value_at(program_id, mapping_name, key, block_height).unwrap()
+        };
+        let expected = [None, Some("1u64"), Some("1u64"), Some("3u64"), None, Some("5u64"), Some("5u64")];
+        for (block_height, expected) in (0u32..).zip(expected) {
+            assert_eq!(value_at(&key, block_height), expected.map(|value| Value::from_str(value).unwrap()));
+        }
+        let expected = [None, None, Some("2u64"), Some("2u64"), Some("2u64"), None, None];
+        for (block_height, expected) in (0u32..).zip(expected) {
+            assert_eq!(value_at(&other_key, block_height), expected.map(|value| Value::from_str(value).unwrap()));
+        }
+
+        // Roll back block 5, and ensure its history is removed.
+        finalize_store.rollback(5).unwrap();
+        assert_eq!(value_at(&key, 5), None);
+        assert_eq!(value_at(&other_key, 5), Some(Value::from_str("2u64").unwrap()));
+        assert_eq!(value_at(&key, 3), Some(Value::from_str("3u64").unwrap()));
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn test_get_mapping_value_before_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+
+        // Initialize a new finalize store, with keys that are inserted before the history is recorded.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store
+            .insert_key_value(program_id, mapping_name, key.clone(), Value::from_str("1u64").unwrap())
+            .unwrap();
+        finalize_store
+            .insert_key_value(program_id, mapping_name, other_key.clone(), Value::from_str("1u64").unwrap())
+            .unwrap();
+        let value_at = |key: &Plaintext<CurrentNetwork>, block_height: u32| {
+            finalize_store.get_mapping_value_at(program_id, mapping_name, key, block_height)
+        };
+        // Ensure the history cannot be retrieved before it is recorded.
+        assert!(value_at(&key, 0).is_err());
+
+        // Block 5: Update a key, which starts recording the history.
+        finalize_store.start_atomic();
+        finalize_store
+            .update_key_value(program_id, mapping_name, other_key.clone(), Value::from_str("5u64").unwrap())
+            .unwrap();
+        finalize_store.store_rollback_operations(5).unwrap();
+        finalize_store.finish_atomic().unwrap();
+
+        // Ensure heights before the history is recorded are rejected.
+        assert!(value_at(&key, 4).is_err());
+        // Ensure a key that is unmodified since the history is recorded retains its value.
+        assert_eq!(value_at(&key, 5).unwrap(), Some(Value::from_str("1u64").unwrap()));
+        // Ensure the modified key has its recorded value.
+        assert_eq!(value_at(&other_key, 5).unwrap(), Some(Value::from_str("5u64").unwrap()));
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
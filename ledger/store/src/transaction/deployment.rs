@@ -183,6 +183,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // If the program exists, ensure the deployment is the next edition of the program.
+        if let Some(latest_edition) = self.edition_map().get_speculative(&program_id)? {
+            let latest_edition = cow_to_copied!(latest_edition);
+            if latest_edition.checked_add(1) != Some(edition) {
+                bail!("Failed to insert edition {edition} of program '{program_id}' (latest edition {latest_edition})")
+            }
+        }
+
         atomic_batch_scope!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
@@ -223,6 +231,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the transaction deployed the latest edition of the program.
+        if self.get_transaction_edition(transaction_id)? != Some(edition) {
+            bail!("Failed to remove transaction '{transaction_id}', as it is not the latest edition of '{program_id}'")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -232,8 +244,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition, or remove the edition if this is the initial edition.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -310,6 +325,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Find the edition, starting from the latest edition.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(id) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(id) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -396,7 +434,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_transaction_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -434,7 +472,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().get_fee(transaction_id)
     }
 
-    /// Returns the owner for the given `program ID`.
+    /// Returns the owner of the latest edition for the given `program ID`.
     fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
@@ -443,8 +481,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
+        // Retrieve the latest edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
             None => return Ok(None),
@@ -470,9 +507,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of the deployed edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -576,6 +613,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    pub fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_transaction_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -604,6 +646,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_certificate(program_id, function_name)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
+
     /// Returns the fee for the given `transaction ID`.
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
@@ -195,6 +195,37 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proofs and the transition payloads of the execution transaction for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee boolean.
+        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+        // Retrieve the global state root.
+        let global_state_root = match self.inclusion_map().get_confirmed(transaction_id)? {
+            Some(inclusion) => cow_to_cloned!(inclusion).0,
+            None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transitions.
+            for transition_id in &transition_ids {
+                self.transition_store().prune(transition_id)?;
+            }
+
+            // Remove the proof.
+            self.inclusion_map().insert(*transaction_id, (global_state_root, None))?;
+
+            // Prune the fee.
+            if has_fee {
+                self.fee_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -320,6 +351,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and the transition payloads of the transaction for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -138,6 +138,25 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proof and the fee transition payloads for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the fee transition ID and global state root.
+        let (transition_id, global_state_root, _) = match self.fee_map().get_confirmed(transaction_id)? {
+            Some(fee) => cow_to_cloned!(fee),
+            None => bail!("Failed to locate the fee transition ID for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the proof.
+            self.fee_map().insert(*transaction_id, (transition_id, global_state_root, None))?;
+
+            // Prune the fee transition.
+            self.transition_store().prune(&transition_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -197,6 +216,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proof and the fee transition payloads for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, F::TransitionStorage> {
         self.storage.transition_store()
@@ -195,6 +195,25 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proofs and the transition payloads of the transaction for the given `transaction ID`.
+    /// Note: The deployment of a deployment transaction is retained, as it is required to load the program.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+
+        match transaction_type {
+            // Prune the fee of the deployment transaction.
+            TransactionType::Deploy => self.fee_store().prune(transaction_id),
+            // Prune the execution transaction.
+            TransactionType::Execute => self.execution_store().prune(transaction_id),
+            // Prune the fee transaction.
+            TransactionType::Fee => self.fee_store().prune(transaction_id),
+        }
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -263,6 +282,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and the transition payloads of the transaction for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -366,11 +390,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Return the edition deployed by the transaction.
+                match self.storage.deployment_store().get_transaction_edition(transaction_id)? {
+                    Some(edition) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -23,17 +23,21 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
 };
 use console::{
     network::prelude::*,
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 use ledger_block::{Input, Output, Transition};
 
+#[cfg(feature = "address-index")]
+use console::program::{Argument, Future, Literal};
+
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use core::ops::Range;
 use std::borrow::Cow;
 
 /// A trait for transition storage.
@@ -54,6 +58,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
     /// The signer commitments.
     type SCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
+    /// The transitions that are signed by a multisig account.
+    type MultisigMap: for<'a> Map<'a, N::TransitionID, bool>;
+    /// The mapping of `address` to the `transition ID`s in which it appears publicly, and their block heights.
+    type AddressMap: for<'a> NestedMap<'a, Address<N>, N::TransitionID, u32>;
+    /// The mapping of `transition ID` to the addresses that appear publicly in it.
+    type ReverseAddressMap: for<'a> Map<'a, N::TransitionID, Vec<Address<N>>>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -74,6 +84,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
     /// Returns the signer commitments map.
     fn scm_map(&self) -> &Self::SCMMap;
+    /// Returns the multisig transitions map.
+    fn multisig_map(&self) -> &Self::MultisigMap;
+    /// Returns the address map.
+    fn address_map(&self) -> &Self::AddressMap;
+    /// Returns the reverse address map.
+    fn reverse_address_map(&self) -> &Self::ReverseAddressMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -91,6 +107,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
         self.scm_map().start_atomic();
+        self.multisig_map().start_atomic();
+        self.address_map().start_atomic();
+        self.reverse_address_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -103,6 +122,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
             || self.scm_map().is_atomic_in_progress()
+            || self.multisig_map().is_atomic_in_progress()
+            || self.address_map().is_atomic_in_progress()
+            || self.reverse_address_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -115,6 +137,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
         self.scm_map().atomic_checkpoint();
+        self.multisig_map().atomic_checkpoint();
+        self.address_map().atomic_checkpoint();
+        self.reverse_address_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -127,6 +152,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
         self.scm_map().clear_latest_checkpoint();
+        self.multisig_map().clear_latest_checkpoint();
+        self.address_map().clear_latest_checkpoint();
+        self.reverse_address_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -139,6 +167,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
         self.scm_map().atomic_rewind();
+        self.multisig_map().atomic_rewind();
+        self.address_map().atomic_rewind();
+        self.reverse_address_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -151,6 +182,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
         self.scm_map().abort_atomic();
+        self.multisig_map().abort_atomic();
+        self.address_map().abort_atomic();
+        self.reverse_address_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -162,7 +196,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
         self.reverse_tcm_map().finish_atomic()?;
-        self.scm_map().finish_atomic()
+        self.scm_map().finish_atomic()?;
+        self.multisig_map().finish_atomic()?;
+        self.address_map().finish_atomic()?;
+        self.reverse_address_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -186,6 +223,28 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
             // Store `scm`.
             self.scm_map().insert(transition_id, *transition.scm())?;
+            // Store the multisig flag, if the transition is signed by a multisig account.
+            if transition.is_multisig() {
+                self.multisig_map().insert(transition_id, true)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Indexes the given `transition` of the block at the given height by the addresses that appear publicly in it.
+    #[cfg(feature = "address-index")]
+    fn insert_address_index(&self, transition: &Transition<N>, block_height: u32) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Retrieve the transition ID.
+            let transition_id = *transition.id();
+            // Store the address index entries.
+            let addresses = public_addresses(transition);
+            for address in addresses.iter() {
+                self.address_map().insert(*address, transition_id, block_height)?;
+            }
+            // Store the reverse address index entry.
+            self.reverse_address_map().insert(transition_id, addresses)?;
 
             Ok(())
         })
@@ -203,6 +262,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             Some(tcm) => cow_to_copied!(tcm),
             None => return Ok(()),
         };
+        // Retrieve the indexed addresses.
+        let addresses = match self.reverse_address_map().get_confirmed(transition_id)? {
+            Some(addresses) => cow_to_cloned!(addresses),
+            None => Vec::new(),
+        };
 
         atomic_batch_scope!(self, {
             // Remove the program ID and function name.
@@ -221,6 +285,32 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().remove(&tcm)?;
             // Remove `scm`.
             self.scm_map().remove(transition_id)?;
+            // Remove the multisig flag.
+            self.multisig_map().remove(transition_id)?;
+            // Remove the address index entries.
+            for address in addresses.iter() {
+                self.address_map().remove_key(address, transition_id)?;
+            }
+            self.reverse_address_map().remove(transition_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the input and output payloads of the given `transition ID` from storage.
+    /// The input and output IDs, serial numbers, tags, commitments, and records are retained.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the pruned transition.
+        let transition = match self.get(transition_id)? {
+            Some(transition) => transition.to_pruned()?,
+            None => bail!("Failed to prune transition '{transition_id}': missing transition"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Overwrite the inputs with their pruned form.
+            self.input_store().insert(*transition_id, transition.inputs())?;
+            // Overwrite the outputs with their pruned form.
+            self.output_store().insert(*transition_id, transition.outputs())?;
 
             Ok(())
         })
@@ -243,11 +333,13 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let tcm = self.tcm_map().get_confirmed(transition_id)?;
         // Retrieve `scm`.
         let scm = self.scm_map().get_confirmed(transition_id)?;
+        // Retrieve the multisig flag.
+        let is_multisig = self.multisig_map().contains_key_confirmed(transition_id)?;
 
         match (tpk, tcm, scm) {
             (Some(tpk), Some(tcm), Some(scm)) => {
                 // Construct the transition.
-                let transition = Transition::new(
+                let transition = Transition::new_with_multisig(
                     program_id,
                     function_name,
                     inputs,
@@ -255,6 +347,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
                     cow_to_cloned!(tpk),
                     cow_to_cloned!(tcm),
                     cow_to_cloned!(scm),
+                    is_multisig,
                 )?;
                 // Ensure the transition ID matches.
                 match transition.id() == transition_id {
@@ -267,6 +360,54 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     }
 }
 
+/// Returns the addresses that appear in the public inputs and outputs of the given transition, without duplicates.
+/// This includes the addresses within constant and public plaintexts, and within the arguments of future outputs.
+#[cfg(feature = "address-index")]
+fn public_addresses<N: Network>(transition: &Transition<N>) -> Vec<Address<N>> {
+    let mut addresses = Vec::new();
+    for input in transition.inputs() {
+        if let Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) = input {
+            plaintext_addresses(plaintext, &mut addresses);
+        }
+    }
+    for output in transition.outputs() {
+        match output {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                plaintext_addresses(plaintext, &mut addresses)
+            }
+            Output::Future(_, Some(future)) => future_addresses(future, &mut addresses),
+            _ => (),
+        }
+    }
+    addresses
+}
+
+/// Appends the addresses within the given plaintext to the given list.
+#[cfg(feature = "address-index")]
+fn plaintext_addresses<N: Network>(plaintext: &Plaintext<N>, addresses: &mut Vec<Address<N>>) {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), _) => {
+            if !addresses.contains(address) {
+                addresses.push(*address);
+            }
+        }
+        Plaintext::Literal(..) => (),
+        Plaintext::Struct(members, _) => members.values().for_each(|member| plaintext_addresses(member, addresses)),
+        Plaintext::Array(elements, _) => elements.iter().for_each(|element| plaintext_addresses(element, addresses)),
+    }
+}
+
+/// Appends the addresses within the arguments of the given future to the given list.
+#[cfg(feature = "address-index")]
+fn future_addresses<N: Network>(future: &Future<N>, addresses: &mut Vec<Address<N>>) {
+    for argument in future.arguments() {
+        match argument {
+            Argument::Plaintext(plaintext) => plaintext_addresses(plaintext, addresses),
+            Argument::Future(future) => future_addresses(future, addresses),
+        }
+    }
+}
+
 /// The transition store.
 #[derive(Clone)]
 pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
@@ -286,6 +427,8 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     reverse_tcm: T::ReverseTCMMap,
     /// The map of signer commitments.
     scm: T::SCMMap,
+    /// The address map.
+    address: T::AddressMap,
     /// The transition storage.
     storage: T,
 }
@@ -305,6 +448,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            address: storage.address_map().clone(),
             storage,
         })
     }
@@ -320,6 +464,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            address: storage.address_map().clone(),
             storage,
         }
     }
@@ -334,6 +479,17 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Indexes the given `transition` of the block at the given height by the addresses that appear publicly in it.
+    #[cfg(feature = "address-index")]
+    pub fn insert_address_index(&self, transition: &Transition<N>, block_height: u32) -> Result<()> {
+        self.storage.insert_address_index(transition, block_height)
+    }
+
+    /// Prunes the input and output payloads of the given `transition ID` from storage.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -389,6 +545,27 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the IDs of the transitions within the given range of block heights in which the given `address`
+    /// appears publicly, as `(block height, transition ID)` pairs in ascending order of block height.
+    ///
+    /// Note: The address index is only maintained if the `address-index` feature is enabled.
+    pub fn find_transition_ids_for_address(
+        &self,
+        address: &Address<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, N::TransitionID)>> {
+        let mut transition_ids = self
+            .address
+            .get_map_confirmed(address)?
+            .into_iter()
+            .filter(|(_, height)| heights.contains(height))
+            .map(|(transition_id, height)| (height, transition_id))
+            .collect::<Vec<_>>();
+        // Sort the transitions by block height.
+        transition_ids.sort_by_key(|(height, _)| *height);
+        Ok(transition_ids)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -734,4 +911,135 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_insert_get_remove_multisig() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition, and construct the same transition signed by a multisig account.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transition = transaction.transitions().next().unwrap();
+        let transition = Transition::new_with_multisig(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+            true,
+        )
+        .unwrap();
+        let transition_id = *transition.id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionMemory::open(None).unwrap();
+
+        // Insert the transition.
+        transition_store.insert(&transition).unwrap();
+        assert!(transition_store.multisig_map().contains_key_confirmed(&transition_id).unwrap());
+
+        // Ensure the transition is retrieved with its multisig flag.
+        let candidate = transition_store.get(&transition_id).unwrap().unwrap();
+        assert!(candidate.is_multisig());
+        assert_eq!(transition, candidate);
+
+        // Remove the transition.
+        transition_store.remove(&transition_id).unwrap();
+        assert!(!transition_store.multisig_map().contains_key_confirmed(&transition_id).unwrap());
+        assert_eq!(None, transition_store.get(&transition_id).unwrap());
+    }
+
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionMemory::open(None).unwrap();
+
+        for transition in transaction.transitions() {
+            // Retrieve the transition ID.
+            let transition_id = *transition.id();
+
+            // Insert the transition.
+            transition_store.insert(transition).unwrap();
+
+            // Prune the transition.
+            transition_store.prune(&transition_id).unwrap();
+
+            // Ensure the transition is pruned.
+            let candidate = transition_store.get(&transition_id).unwrap().unwrap();
+            assert_eq!(candidate, transition.to_pruned().unwrap());
+            // Ensure the serial numbers, tags, commitments, and nonces are retained.
+            for serial_number in transition.serial_numbers() {
+                assert!(transition_store.input_store().contains_serial_number(serial_number).unwrap());
+            }
+            for tag in transition.tags() {
+                assert!(transition_store.input_store().contains_tag(tag).unwrap());
+            }
+            for commitment in transition.commitments() {
+                assert!(transition_store.output_store().contains_commitment(commitment).unwrap());
+            }
+            for nonce in transition.nonces() {
+                assert!(transition_store.output_store().contains_nonce(nonce).unwrap());
+            }
+
+            // Ensure pruning is idempotent.
+            transition_store.prune(&transition_id).unwrap();
+            assert_eq!(transition_store.get(&transition_id).unwrap().unwrap(), candidate);
+
+            // Remove the transition.
+            transition_store.remove(&transition_id).unwrap();
+            assert_eq!(None, transition_store.get(&transition_id).unwrap());
+        }
+    }
+
+    #[cfg(feature = "address-index")]
+    #[test]
+    fn test_address_index() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction, which has a public fee.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee_transition = transaction.fee_transition().unwrap();
+
+        // Ensure the fee payer is found in the public fee transition.
+        let addresses = public_addresses(fee_transition.transition());
+        assert!(addresses.contains(&fee_transition.payer().unwrap()));
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+
+        // Insert the transitions, and index them at block height 5.
+        for transition in transaction.transitions() {
+            transition_store.insert(transition).unwrap();
+            transition_store.insert_address_index(transition, 5).unwrap();
+        }
+        // Ensure each address is indexed to the fee transition at block height 5.
+        for address in addresses.iter() {
+            let candidates = transition_store.find_transition_ids_for_address(address, 0..10).unwrap();
+            assert!(candidates.contains(&(5, *fee_transition.id())));
+            // Ensure the block heights outside of the range are excluded.
+            assert!(transition_store.find_transition_ids_for_address(address, 0..5).unwrap().is_empty());
+            assert!(transition_store.find_transition_ids_for_address(address, 6..10).unwrap().is_empty());
+        }
+
+        // Prune the fee transition, and ensure the index is retained.
+        transition_store.prune(fee_transition.id()).unwrap();
+        for address in addresses.iter() {
+            let candidates = transition_store.find_transition_ids_for_address(address, 5..6).unwrap();
+            assert!(candidates.contains(&(5, *fee_transition.id())));
+        }
+
+        // Remove the transitions, and ensure the index is cleared.
+        for transition in transaction.transitions() {
+            transition_store.remove(transition.id()).unwrap();
+        }
+        for address in addresses.iter() {
+            assert!(transition_store.find_transition_ids_for_address(address, 0..10).unwrap().is_empty());
+        }
+    }
 }
@@ -16,16 +16,63 @@
 #![forbid(unsafe_code)]
 
 const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const COUNTER_NAMES: [&str; 6] = [
+    ledger::ACCEPTED_TRANSACTIONS,
+    ledger::REJECTED_TRANSACTIONS,
+    ledger::ABORTED_TRANSACTIONS,
+    ledger::TRANSACTION_FEES,
+    vm::PARTIALLY_VERIFIED_CACHE_HITS,
+    vm::PARTIALLY_VERIFIED_CACHE_MISSES,
+];
+const HISTOGRAM_NAMES: [&str; 8] = [
+    ledger::BLOCK_ACCEPTED_TRANSACTIONS,
+    ledger::BLOCK_REJECTED_TRANSACTIONS,
+    ledger::BLOCK_ABORTED_TRANSACTIONS,
+    ledger::BLOCK_TRANSACTION_FEES,
+    vm::CHECK_TRANSACTION_LATENCY,
+    vm::SPECULATE_LATENCY,
+    vm::FINALIZE_LATENCY,
+    store::ROCKSDB_BATCH_WRITE_SIZE,
+];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod ledger {
+    pub const ACCEPTED_TRANSACTIONS: &str = "snarkvm_ledger_accepted_transactions_total";
+    pub const REJECTED_TRANSACTIONS: &str = "snarkvm_ledger_rejected_transactions_total";
+    pub const ABORTED_TRANSACTIONS: &str = "snarkvm_ledger_aborted_transactions_total";
+    pub const TRANSACTION_FEES: &str = "snarkvm_ledger_transaction_fees_microcredits_total";
+    pub const BLOCK_ACCEPTED_TRANSACTIONS: &str = "snarkvm_ledger_block_accepted_transactions";
+    pub const BLOCK_REJECTED_TRANSACTIONS: &str = "snarkvm_ledger_block_rejected_transactions";
+    pub const BLOCK_ABORTED_TRANSACTIONS: &str = "snarkvm_ledger_block_aborted_transactions";
+    pub const BLOCK_TRANSACTION_FEES: &str = "snarkvm_ledger_block_transaction_fees_microcredits";
+}
+
+pub mod vm {
+    pub const CHECK_TRANSACTION_LATENCY: &str = "snarkvm_vm_check_transaction_latency_secs";
+    pub const SPECULATE_LATENCY: &str = "snarkvm_vm_speculate_latency_secs";
+    pub const FINALIZE_LATENCY: &str = "snarkvm_vm_finalize_latency_secs";
+    pub const PARTIALLY_VERIFIED_CACHE_HITS: &str = "snarkvm_vm_partially_verified_cache_hits_total";
+    pub const PARTIALLY_VERIFIED_CACHE_MISSES: &str = "snarkvm_vm_partially_verified_cache_misses_total";
+}
+
+pub mod store {
+    pub const ROCKSDB_BATCH_WRITE_SIZE: &str = "snarkvm_store_rocksdb_batch_write_size_bytes";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -53,6 +100,15 @@ pub fn increment_counter(name: &'static str) {
     counter.increment(1);
 }
 
+/// Increments a counter with the given name by the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn increment_counter_by<V: Into<u64>>(name: &'static str, value: V) {
+    let counter = ::metrics::counter!(name);
+    counter.increment(value.into());
+}
+
 /******** Gauge ********/
 
 /// Registers a gauge with the given name.
@@ -66,8 +66,28 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     // Get the root transition.
     let transition = execution.peek()?;
 
+    // Retrieve the stack of the root transition.
+    let stack = process.get_stack(transition.program_id())?;
     // Get the finalize cost for the root transition.
-    let finalize_cost = process.get_stack(transition.program_id())?.get_finalize_cost(transition.function_name())?;
+    let finalize_cost = match stack.contains_dynamic_calls(transition.function_name()) {
+        // If the root transition invokes a dynamic call, then its finalize cost depends on the callees,
+        // so the finalize cost is the sum of the finalize commands of each transition.
+        true => {
+            let finalize_cost = execution.transitions().try_fold(0u64, |acc, transition| {
+                let stack = process.get_stack(transition.program_id())?;
+                let cost = cost_of_finalize_commands(stack, transition.function_name())?;
+                acc.checked_add(cost).ok_or(anyhow!("The finalize cost computation overflowed for an execution"))
+            })?;
+            // Ensure the finalize cost does not exceed the transaction spend limit.
+            ensure!(
+                finalize_cost <= N::TRANSACTION_SPEND_LIMIT,
+                "The finalize cost '{finalize_cost}' exceeds the transaction spend limit '{}'",
+                N::TRANSACTION_SPEND_LIMIT
+            );
+            finalize_cost
+        }
+        false => stack.get_finalize_cost(transition.function_name())?,
+    };
 
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
@@ -92,6 +112,8 @@ fn execution_storage_cost<N: Network>(size_in_bytes: u64) -> u64 {
 const CAST_BASE_COST: u64 = 500;
 const CAST_PER_BYTE_COST: u64 = 30;
 
+const ECDSA_BASE_COST: u64 = 100_000;
+
 const HASH_BASE_COST: u64 = 10_000;
 const HASH_PER_BYTE_COST: u64 = 30;
 
@@ -179,6 +201,8 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+        Command::Instruction(Instruction::CallDynamic(_)) => bail!("'call.dynamic' is not supported in finalize"),
+        Command::Instruction(Instruction::Repeat(_)) => bail!("'repeat' instructions are not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
@@ -346,6 +370,54 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Ternary(_)) => Ok(500),
         Command::Instruction(Instruction::Xor(_)) => Ok(500),
+        Command::Instruction(Instruction::ECDSAVerifyKeccak256(ecdsa)) => {
+            cost_in_size(stack, finalize, ecdsa.operands(), HASH_PER_BYTE_COST, ECDSA_BASE_COST)
+        }
+        Command::Instruction(Instruction::ECDSAVerifyDigest(ecdsa)) => {
+            cost_in_size(stack, finalize, ecdsa.operands(), HASH_PER_BYTE_COST, ECDSA_BASE_COST)
+        }
+        Command::Instruction(Instruction::SerializeBits(serialize)) => {
+            cost_in_size(stack, finalize, serialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::SerializeRawBits(serialize)) => {
+            cost_in_size(stack, finalize, serialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::SerializeBytes(serialize)) => {
+            cost_in_size(stack, finalize, serialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::SerializeRawBytes(serialize)) => {
+            cost_in_size(stack, finalize, serialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::DeserializeBits(deserialize)) => {
+            cost_in_size(stack, finalize, deserialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::DeserializeRawBits(deserialize)) => {
+            cost_in_size(stack, finalize, deserialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::DeserializeBytes(deserialize)) => {
+            cost_in_size(stack, finalize, deserialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::DeserializeRawBytes(deserialize)) => {
+            cost_in_size(stack, finalize, deserialize.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringLength(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringConcat(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringSubstring(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringByteAt(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringToBytes(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringFromBytes(string)) => {
+            cost_in_size(stack, finalize, string.operands(), CAST_PER_BYTE_COST, CAST_BASE_COST)
+        }
         Command::Await(_) => Ok(500),
         Command::Contains(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
@@ -363,6 +435,22 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         }
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(500),
         Command::Position(_) => Ok(100),
+        Command::Length(_) => Ok(MAPPING_BASE_COST),
+        Command::GetNext(command) => {
+            cost_in_size(stack, finalize, command.key(), MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
+        }
+        Command::Emit(command) => cost_in_size(stack, finalize, [command.operand()], SET_PER_BYTE_COST, SET_BASE_COST),
+        Command::Repeat(repeat) => {
+            // The cost of a `repeat` command is the cost of its body, for each iteration.
+            let body_cost = repeat
+                .body()
+                .iter()
+                .map(|command| cost_per_command(stack, finalize, command))
+                .try_fold(0u64, |acc, res| {
+                    res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed")))
+                })?;
+            body_cost.checked_mul(**repeat.bound() as u64).ok_or(anyhow!("Finalize cost overflowed"))
+        }
     }
 }
 
@@ -386,13 +474,22 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         }
     }
     // Aggregate the cost of all commands in the program.
+    future_cost.checked_add(cost_of_finalize_commands(stack, function_name)?).ok_or(anyhow!("Finalize cost overflowed"))
+}
+
+/// Returns the number of microcredits required to run the commands of the finalize, excluding the awaited futures.
+fn cost_of_finalize_commands<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
+    // Retrieve the finalize logic.
+    let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
+        // Return a finalize cost of 0, if the function does not have a finalize scope.
+        return Ok(0);
+    };
+    // Aggregate the cost of all commands in the program.
     finalize
         .commands()
         .iter()
         .map(|command| cost_per_command(stack, finalize, command))
-        .try_fold(future_cost, |acc, res| {
-            res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed")))
-        })
+        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
 }
 
 #[cfg(test)]
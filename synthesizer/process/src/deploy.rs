@@ -16,7 +16,7 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program ID, if it does not exist, or the next edition of the program, if it is upgradable.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -26,7 +26,10 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::deploy");
 
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = match self.contains_program(program.id()) {
+            true => Stack::new_upgrade(self, program)?,
+            false => Stack::new(self, program)?,
+        };
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -45,7 +48,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = Stack::initialize_edition(self, deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,8 +57,11 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Insert the verifying keys");
 
-        // Add the stack to the process.
-        self.add_stack(stack);
+        // Add the stack to the process, replacing the previous edition if the program is upgraded.
+        match self.contains_program(deployment.program_id()) {
+            true => self.upgrade_stack(stack)?,
+            false => self.add_stack(stack),
+        }
 
         finish!(timer);
 
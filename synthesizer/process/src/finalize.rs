@@ -35,7 +35,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,7 +54,7 @@ impl<N: Network> Process<N> {
             // Retrieve the fee stack.
             let fee_stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+            finalize_operations.extend(finalize_fee_transition(state, store, self, fee_stack, fee)?);
             lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
 
             /* Finalize the deployment. */
@@ -63,8 +63,10 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
-                // Initialize the mapping.
-                finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+                // Initialize the mapping, if it was not initialized by a previous edition.
+                if !store.contains_mapping_speculative(program_id, mapping.name())? {
+                    finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+                }
             }
             finish!(timer, "Initialize the program mappings");
 
@@ -73,7 +75,7 @@ impl<N: Network> Process<N> {
         })
     }
 
-    /// Finalizes the execution and fee.
+    /// Finalizes the execution and fee, returning the finalize operations and the emitted events.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -83,7 +85,7 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -95,9 +97,12 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(transition.program_id())?;
         // Ensure the number of calls matches the number of transitions.
+        // Note: If the function invokes a dynamic call, the number of calls is a lower bound,
+        // and the number of transitions is checked when constructing the call graph.
         let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
         ensure!(
-            number_of_calls == execution.len(),
+            number_of_calls == execution.len()
+                || (stack.contains_dynamic_calls(transition.function_name()) && number_of_calls < execution.len()),
             "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
             execution.len()
         );
@@ -105,12 +110,16 @@ impl<N: Network> Process<N> {
 
         // Construct the call graph.
         let call_graph = self.construct_call_graph(execution)?;
+        // Construct a map of transition IDs to transitions.
+        let transitions =
+            execution.transitions().map(|transition| (*transition.id(), transition)).collect::<HashMap<_, _>>();
 
         atomic_batch_scope!(store, {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let (mut finalize_operations, events) =
+                finalize_transition(state, store, self, stack, transition, &transitions, call_graph)?;
 
             /* Finalize the fee. */
 
@@ -118,13 +127,13 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(finalize_fee_transition(state, store, self, fee_stack, fee)?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
             finish!(timer);
-            // Return the finalize operations.
-            Ok(finalize_operations)
+            // Return the finalize operations and the events.
+            Ok((finalize_operations, events))
         })
     }
 
@@ -144,7 +153,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = finalize_fee_transition(state, store, self, stack, fee);
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -152,10 +161,59 @@ impl<N: Network> Process<N> {
     }
 }
 
+/// An error from a command that failed to finalize, along with its location in the finalize scope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeError<N: Network> {
+    /// The program ID of the finalize scope.
+    program_id: ProgramID<N>,
+    /// The function name of the finalize scope.
+    function_name: Identifier<N>,
+    /// The index of the failed command in the finalize scope.
+    command_index: usize,
+    /// The error message.
+    message: String,
+}
+
+impl<N: Network> FinalizeError<N> {
+    /// Initializes a new finalize error for the command at the given index.
+    fn new(stack: &Stack<N>, finalize: &Finalize<N>, command_index: usize, message: String) -> Self {
+        Self { program_id: *stack.program_id(), function_name: *finalize.name(), command_index, message }
+    }
+
+    /// Returns the program ID of the finalize scope.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the finalize scope.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the failed command in the finalize scope.
+    pub const fn command_index(&self) -> usize {
+        self.command_index
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<N: Network> Display for FinalizeError<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl<N: Network> std::error::Error for FinalizeError<N> {}
+
 /// Finalizes the given fee transition.
 fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
+    process: &Process<N>,
     stack: &Stack<N>,
     fee: &Fee<N>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
@@ -163,24 +221,30 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     let mut call_graph = HashMap::new();
     // Insert the fee transition.
     call_graph.insert(*fee.transition_id(), Vec::new());
+    // Construct the map of transition IDs to transitions.
+    let transitions = HashMap::from([(*fee.transition_id(), fee.transition())]);
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
-        // If the evaluation succeeds, return the finalize operations.
-        Ok(finalize_operations) => Ok(finalize_operations),
+    match finalize_transition(state, store, process, stack, fee, &transitions, call_graph) {
+        // If the evaluation succeeds without events, return the finalize operations.
+        Ok((finalize_operations, events)) if events.is_empty() => Ok(finalize_operations),
+        // If the fee emits events, bail.
+        Ok(_) => bail!("'finalize' on '{}/{}' must not emit events", fee.program_id(), fee.function_name()),
         // If the evaluation fails, bail and return the error.
         Err(error) => bail!("'finalize' failed on '{}/{}' - {error}", fee.program_id(), fee.function_name()),
     }
 }
 
-/// Finalizes the given transition.
-fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
+/// Finalizes the given transition, returning the finalize operations and the emitted events.
+fn finalize_transition<'a, N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
-    stack: &Stack<N>,
+    process: &'a Process<N>,
+    stack: &'a Stack<N>,
     transition: &Transition<N>,
+    transitions: &HashMap<N::TransitionID, &Transition<N>>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
-) -> Result<Vec<FinalizeOperation<N>>> {
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
     // Retrieve the function name.
@@ -193,7 +257,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     // If the last output of the transition is a future, retrieve and finalize it. Otherwise, there are no operations to finalize.
     let future = match transition.outputs().last().and_then(|output| output.future()) {
         Some(future) => future,
-        _ => return Ok(Vec::new()),
+        _ => return Ok((Vec::new(), Vec::new())),
     };
 
     // Check that the program ID and function name of the transition match those in the future.
@@ -204,6 +268,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
     // Initialize a list for finalize operations.
     let mut finalize_operations = Vec::new();
+    // Initialize a list for the emitted events.
+    let mut events = Vec::new();
 
     // Initialize a stack of active finalize states.
     let mut states = Vec::new();
@@ -234,9 +300,19 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
                     }
                 }
                 Command::BranchNeq(branch_neq) => {
@@ -246,9 +322,19 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
                     }
                 }
                 Command::Await(await_) => {
@@ -273,16 +359,38 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         },
                         None => bail!("Transition ID '{transition_id}' not found in call graph"),
                     };
+                    // Get the child transition.
+                    let child_transition = match transitions.get(&child_transition_id) {
+                        Some(transition) => *transition,
+                        None => bail!("Transition ID '{child_transition_id}' not found in the execution"),
+                    };
 
                     // Set up the finalize state for the await.
-                    let callee_state =
-                        match try_vm_runtime!(|| setup_await(state, await_, stack, &registers, child_transition_id)) {
-                            Ok(Ok(callee_state)) => callee_state,
-                            // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                            // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
-                        };
+                    let callee_state = match try_vm_runtime!(|| setup_await(
+                        state,
+                        await_,
+                        process,
+                        stack,
+                        &registers,
+                        child_transition,
+                        call_counter
+                    )) {
+                        Ok(Ok(callee_state)) => callee_state,
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
+                    };
 
                     // Increment the call counter.
                     call_counter += 1;
@@ -301,6 +409,53 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
                     continue 'outer;
                 }
+                Command::Emit(emit) => {
+                    let result = try_vm_runtime!(|| emit.finalize(stack, &mut registers));
+                    match result {
+                        // If the evaluation succeeds, add the event to the list.
+                        Ok(Ok(event)) => events.push(event),
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
+                    }
+                    counter += 1;
+                }
+                Command::Repeat(repeat) => {
+                    let result = try_vm_runtime!(|| repeat.finalize(stack, store, &mut registers));
+                    match result {
+                        // If the evaluation succeeds, add the operations and events to the lists.
+                        Ok(Ok((operations, repeat_events))) => {
+                            finalize_operations.extend(operations);
+                            events.extend(repeat_events);
+                        }
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
+                    }
+                    counter += 1;
+                }
                 _ => {
                     let result = try_vm_runtime!(|| command.finalize(stack, store, &mut registers));
                     match result {
@@ -309,9 +464,19 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation succeeds with no operation, continue.
                         Ok(Ok(None)) => {}
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command}): {error}")
+                        )),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => bail!(FinalizeError::new(
+                            stack,
+                            finalize,
+                            counter,
+                            format!("'finalize' failed to evaluate command ({command})")
+                        )),
                     }
                     counter += 1;
                 }
@@ -331,8 +496,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         );
     }
 
-    // Return the finalize operations.
-    Ok(finalize_operations)
+    // Return the finalize operations and the events.
+    Ok((finalize_operations, events))
 }
 
 // A helper struct to track the execution of a finalize block.
@@ -394,22 +559,73 @@ fn initialize_finalize_state<'a, N: Network>(
     Ok(FinalizeState { counter: 0, finalize, registers, stack, call_counter: 0, awaited: Default::default() })
 }
 
-// A helper function that sets up the await operation.
+// A helper function that sets up the await operation, given the transition and index of the function call.
 #[inline]
 fn setup_await<'a, N: Network>(
     state: FinalizeGlobalState,
     await_: &Await<N>,
+    process: &'a Process<N>,
     stack: &'a Stack<N>,
     registers: &FinalizeRegisters<N>,
-    transition_id: N::TransitionID,
+    transition: &Transition<N>,
+    call_index: usize,
 ) -> Result<FinalizeState<'a, N>> {
     // Retrieve the input as a future.
     let future = match registers.load(stack, &Operand::Register(await_.register().clone()))? {
         Value::Future(future) => future,
         _ => bail!("The input to 'await' is not a future"),
     };
-    // Initialize the state.
-    initialize_finalize_state(state, &future, stack, transition_id)
+
+    // Determine if the function call is a dynamic call.
+    let mut calls = Vec::new();
+    for instruction in stack.get_function_ref(registers.function_name())?.instructions() {
+        match instruction {
+            Instruction::Call(call) => {
+                if call.is_function_call(stack)? {
+                    calls.push(false);
+                }
+            }
+            Instruction::CallDynamic(..) => calls.push(true),
+            _ => (),
+        }
+    }
+    let is_dynamic_call = match calls.get(call_index) {
+        Some(is_dynamic_call) => *is_dynamic_call,
+        None => bail!("The function call for '{}' was not found", await_.register()),
+    };
+
+    match is_dynamic_call {
+        // If the function call is a dynamic call, then the future holds the output ID of the callee's future,
+        // which is retrieved from the transition of the callee.
+        true => {
+            // Retrieve the future of the callee.
+            let (future_id, callee_future) = match transition.outputs().last() {
+                Some(output) => match output.future() {
+                    Some(callee_future) => (output.id(), callee_future),
+                    None => bail!("The callee of the dynamic call does not output a future"),
+                },
+                None => bail!("The callee of the dynamic call does not output a future"),
+            };
+            // Ensure the future matches the future of the callee.
+            ensure!(
+                future.dynamic_future_id() == Some(future_id),
+                "The future '{}' does not match the future of the dynamic call",
+                await_.register()
+            );
+            // Ensure the future of the callee matches the transition.
+            ensure!(
+                callee_future.program_id() == transition.program_id()
+                    && callee_future.function_name() == transition.function_name(),
+                "The program ID and function name of the future do not match the transition"
+            );
+            // Retrieve the stack of the callee.
+            let callee_stack = process.get_stack(transition.program_id())?;
+            // Initialize the state.
+            initialize_finalize_state(state, callee_future, callee_stack, *transition.id())
+        }
+        // Otherwise, initialize the state from the future.
+        false => initialize_finalize_state(state, &future, stack, *transition.id()),
+    }
 }
 
 // A helper function that returns the index to branch to.
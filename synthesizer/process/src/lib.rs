@@ -36,6 +36,7 @@ mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+pub use finalize::FinalizeError;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -44,7 +45,7 @@ mod verify_fee;
 mod tests;
 
 use console::{
-    account::PrivateKey,
+    account::{MultisigKey, PrivateKey},
     network::prelude::*,
     program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
     types::{Field, U16, U64},
@@ -55,6 +56,7 @@ use synthesizer_program::{
     Branch,
     Closure,
     Command,
+    Event,
     Finalize,
     FinalizeGlobalState,
     FinalizeOperation,
@@ -64,7 +66,7 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{PairingCheck, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -80,6 +82,9 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The registry of stacks, used to resolve the callee of a dynamic call.
+    /// Note: The registry is shared with the stacks in the process, and between clones of the process.
+    registry: StackRegistry<N>,
 }
 
 impl<N: Network> Process<N> {
@@ -89,7 +94,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            registry: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -132,8 +141,69 @@ impl<N: Network> Process<N> {
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        let stack = Arc::new(stack);
+        self.registry.write().insert(program_id, Arc::downgrade(&stack));
+        self.stacks.insert(program_id, stack);
+    }
+
+    /// Replaces the stack of an upgraded program in the process, and recomputes the stacks that import it.
+    /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
+    #[inline]
+    pub fn upgrade_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Compute the stacks of the upgraded process, so that the process is unchanged if an error occurs.
+        self.stacks = self.upgraded_stacks(stack)?;
+        // Update the registry with the replaced stacks.
+        let mut registry = self.registry.write();
+        for (id, stack) in &self.stacks {
+            registry.insert(*id, Arc::downgrade(stack));
+        }
+        Ok(())
+    }
+
+    /// Returns the stacks of the process after the stack of an upgraded program is replaced,
+    /// and the stacks that import it are recomputed.
+    ///
+    /// Note: The registry is not updated, so this method may be used to check an upgrade
+    /// without changing the stacks that dynamic calls resolve to.
+    #[inline]
+    pub(crate) fn upgraded_stacks(&self, stack: Stack<N>) -> Result<IndexMap<ProgramID<N>, Arc<Stack<N>>>> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Ensure the program exists.
+        ensure!(self.contains_program(&program_id), "Cannot upgrade program '{program_id}' because it does not exist");
+        // Initialize a copy of the process, whose stacks are replaced.
+        let mut process = self.clone();
+        // Replace the stack of the upgraded program.
+        process.stacks.insert(program_id, Arc::new(stack));
+        // Recompute the stacks that depend on the upgraded program, in the order they were added.
+        // Note: The stacks are ordered such that every import is added before the programs that import it.
+        for (id, stack) in &self.stacks {
+            if stack.depends_on(&program_id) {
+                let stack = stack.reinitialize(&process)?;
+                process.stacks.insert(*id, Arc::new(stack));
+            }
+        }
+        Ok(process.stacks)
+    }
+
+    /// Removes the stack for the given program ID from the process.
+    /// Note: This method is intended to revert a `finalize_deployment`, and does not check for dependent programs.
+    #[inline]
+    pub fn remove_stack(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::<N>::from_str("credits.aleo")?, "Cannot remove the 'credits.aleo' stack");
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => {
+                // Remove the stack from the registry.
+                self.registry.write().shift_remove(program_id);
+                Ok(())
+            }
+            None => bail!("Program '{program_id}' does not exist"),
+        }
     }
 }
 
@@ -144,7 +214,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            registry: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -182,7 +256,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            registry: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -203,6 +281,12 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the registry of stacks.
+    #[inline]
+    pub(crate) const fn registry(&self) -> &StackRegistry<N> {
+        &self.registry
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -287,6 +371,18 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes the multisig proving and verifying key for the given program ID and function name.
+    #[inline]
+    pub fn synthesize_multisig_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Synthesize the multisig proving and verifying key.
+        self.get_stack(program_id)?.synthesize_multisig_key::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -17,7 +17,12 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, program::Request, types::Field};
+use console::{
+    account::{MultisigKey, PrivateKey},
+    network::prelude::*,
+    program::Request,
+    types::Field,
+};
 use ledger_block::{Transaction, Transition};
 
 use indexmap::IndexMap;
@@ -47,6 +52,24 @@ impl<N: Network> Authorization<N> {
     }
 }
 
+impl<N: Network> Authorization<N> {
+    /// Returns the multisig key of the first request, if it is signed by a multisig account.
+    /// Note: In authorize mode, the first request is the root request, and its multisig key signs every call.
+    pub fn multisig_key(&self) -> Option<MultisigKey<N>> {
+        self.requests.read().front().and_then(|request| request.multisig_key().cloned())
+    }
+
+    /// Cosigns every request in the authorization, using the private key of a signer of the multisig key.
+    pub fn cosign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<()> {
+        let mut requests = self.requests.write();
+        // Ensure the authorization is not empty.
+        ensure!(!requests.is_empty(), "Cannot cosign an empty authorization");
+        // Cosign each request.
+        // Note: The transitions do not commit to the cosignatures, so they remain unchanged.
+        requests.iter_mut().try_for_each(|request| request.cosign(private_key, rng))
+    }
+}
+
 impl<N: Network> TryFrom<(Vec<Request<N>>, Vec<Transition<N>>)> for Authorization<N> {
     type Error = Error;
 
@@ -246,6 +269,11 @@ fn ensure_request_and_transition_matches<N: Network>(
         request.scm(),
         transition.scm(),
     );
+    // Ensure the request and transition are both signed by a multisig account, or neither is.
+    ensure!(
+        request.multisig_key().is_some() == transition.is_multisig(),
+        "The request and transition at index {index} must both be multisig, or neither, in the authorization."
+    );
     Ok(())
 }
 
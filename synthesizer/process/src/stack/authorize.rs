@@ -24,6 +24,32 @@ impl<N: Network> Stack<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_internal::<A, R>(private_key, None, function_name, inputs, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, on behalf of the given multisig key.
+    /// Note: The authorization must be cosigned by a quorum of signers using `Authorization::cosign` before execution.
+    #[inline]
+    pub fn authorize_multisig<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        multisig_key: &MultisigKey<N>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_internal::<A, R>(private_key, Some(multisig_key), function_name, inputs, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, on behalf of the private key or multisig key.
+    fn authorize_internal<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        multisig_key: Option<&MultisigKey<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
 
@@ -42,13 +68,27 @@ impl<N: Network> Stack<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Compute the request.
-        let request =
-            Request::sign(private_key, program_id, function_name, inputs, &input_types, root_tvk, is_root, rng)?;
+        let request = match multisig_key {
+            Some(multisig_key) => Request::sign_multisig(
+                private_key,
+                multisig_key,
+                program_id,
+                function_name,
+                inputs,
+                &input_types,
+                root_tvk,
+                is_root,
+                rng,
+            )?,
+            None => {
+                Request::sign(private_key, program_id, function_name, inputs, &input_types, root_tvk, is_root, rng)?
+            }
+        };
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
+        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone(), 0);
         // Construct the authorization from the function.
         let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
         finish!(timer, "Construct the authorization from the function");
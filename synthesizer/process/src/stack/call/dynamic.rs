@@ -0,0 +1,556 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{
+    compute_function_id,
+    Future,
+    Identifier,
+    Literal,
+    Locator,
+    OutputID,
+    Plaintext,
+    PlaintextType,
+    ProgramID,
+    Response,
+};
+use synthesizer_program::{CallDynamic, Function};
+
+use std::sync::Arc;
+
+/// Returns the program ID and function name of the callee of a dynamic call,
+/// given the program name and function name as field elements.
+pub(crate) fn resolve_dynamic_callee<N: Network>(
+    program_name: &Field<N>,
+    function_name: &Field<N>,
+) -> Result<(ProgramID<N>, Identifier<N>)> {
+    // Construct the program ID of the callee.
+    let program_id = ProgramID::try_from((Identifier::from_field(program_name)?, Identifier::from_str("aleo")?))?;
+    // Construct the function name of the callee.
+    let function_name = Identifier::from_field(function_name)?;
+    Ok((program_id, function_name))
+}
+
+/// Ensures the function of the callee stack may be dynamically called through the given interface function.
+pub(crate) fn check_dynamic_callee<N: Network, S: StackProgram<N>>(
+    interface_stack: &S,
+    interface: &Function<N>,
+    callee: &S,
+    function_name: &Identifier<N>,
+) -> Result<()> {
+    // Retrieve the program ID of the callee.
+    let program_id = callee.program_id();
+    // Ensure the callee is not 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.
+    let is_credits_program = &program_id.to_string() == "credits.aleo";
+    let is_fee_private = &function_name.to_string() == "fee_private";
+    let is_fee_public = &function_name.to_string() == "fee_public";
+    if is_credits_program && (is_fee_private || is_fee_public) {
+        bail!("Cannot perform a dynamic call to 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.")
+    }
+    // Retrieve the function of the callee.
+    let function = callee.get_function_ref(function_name)?;
+    // Ensure the function may be dynamically called.
+    CallDynamic::check_interface(function)?;
+    // Ensure the input types match the interface.
+    ensure!(
+        matches_value_types(interface_stack, &interface.input_types(), callee, &function.input_types())?,
+        "The inputs of '{program_id}/{function_name}' do not match the interface '{}'",
+        interface.name()
+    );
+    // Ensure the output types match the interface.
+    ensure!(
+        matches_value_types(interface_stack, &interface.output_types(), callee, &function.output_types())?,
+        "The outputs of '{program_id}/{function_name}' do not match the interface '{}'",
+        interface.name()
+    );
+    Ok(())
+}
+
+/// Returns `true` if the value types of the callee match the value types of the interface.
+/// Note: A future output may differ in its locator, as the caller only holds the output ID of the callee's future.
+fn matches_value_types<N: Network, S: StackProgram<N>>(
+    interface_stack: &S,
+    interface_types: &[ValueType<N>],
+    callee: &S,
+    callee_types: &[ValueType<N>],
+) -> Result<bool> {
+    // Ensure the number of value types matches.
+    if interface_types.len() != callee_types.len() {
+        return Ok(false);
+    }
+    for (interface_type, callee_type) in interface_types.iter().zip_eq(callee_types) {
+        let is_match = match (interface_type, callee_type) {
+            (ValueType::Public(interface_type), ValueType::Public(callee_type))
+            | (ValueType::Private(interface_type), ValueType::Private(callee_type)) => {
+                matches_plaintext_type(interface_stack, interface_type, callee, callee_type, 0)?
+            }
+            (ValueType::Future(..), ValueType::Future(..)) => true,
+            _ => false,
+        };
+        if !is_match {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns `true` if the plaintext type of the callee matches the plaintext type of the interface.
+/// Note: A struct must have the same name and definition in both programs.
+fn matches_plaintext_type<N: Network, S: StackProgram<N>>(
+    interface_stack: &S,
+    interface_type: &PlaintextType<N>,
+    callee: &S,
+    callee_type: &PlaintextType<N>,
+    depth: usize,
+) -> Result<bool> {
+    // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+    ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+    match (interface_type, callee_type) {
+        (PlaintextType::Literal(interface_type), PlaintextType::Literal(callee_type)) => {
+            Ok(interface_type == callee_type)
+        }
+        (PlaintextType::Struct(interface_name), PlaintextType::Struct(callee_name)) => {
+            // Ensure the struct names match.
+            if interface_name != callee_name {
+                return Ok(false);
+            }
+            // Retrieve the struct definitions.
+            let interface_struct = interface_stack.program().get_struct(interface_name)?;
+            let callee_struct = callee.program().get_struct(callee_name)?;
+            // Ensure the number of members matches.
+            if interface_struct.members().len() != callee_struct.members().len() {
+                return Ok(false);
+            }
+            // Ensure the members match, in the same order.
+            for ((interface_member, interface_type), (callee_member, callee_type)) in
+                interface_struct.members().iter().zip_eq(callee_struct.members())
+            {
+                if interface_member != callee_member
+                    || !matches_plaintext_type(interface_stack, interface_type, callee, callee_type, depth + 1)?
+                {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        (PlaintextType::Array(interface_type), PlaintextType::Array(callee_type)) => {
+            // Ensure the lengths match, and the element types match.
+            Ok(interface_type.length() == callee_type.length()
+                && matches_plaintext_type(
+                    interface_stack,
+                    interface_type.next_element_type(),
+                    callee,
+                    callee_type.next_element_type(),
+                    depth + 1,
+                )?)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Returns the outputs of the callee of a dynamic call, as seen by the caller.
+/// Note: The future of the callee is replaced with a future of the interface function,
+/// which holds the output ID of the callee's future, and is resolved from the callee's transition in finalize.
+fn to_dynamic_outputs<N: Network>(interface: &Locator<N>, response: &Response<N>) -> Result<Vec<Value<N>>> {
+    response
+        .outputs()
+        .iter()
+        .zip_eq(response.output_ids())
+        .map(|(output, output_id)| match (output, output_id) {
+            (Value::Future(..), OutputID::Future(future_id)) => {
+                Ok(Value::Future(Future::new_dynamic(*interface.program_id(), *interface.resource(), *future_id)))
+            }
+            (Value::Future(..), _) => bail!("Expected the output ID of a future output"),
+            (output, _) => Ok(output.clone()),
+        })
+        .collect()
+}
+
+/// Returns the field element of the given value, if it is a field literal.
+fn field_from_value<N: Network>(value: &Value<N>) -> Result<Field<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => Ok(*field),
+        _ => bail!("Expected a field element in a 'call.dynamic' instruction, found '{value}'"),
+    }
+}
+
+/// Returns the stack and function name of the callee of a dynamic call.
+fn get_dynamic_callee<N: Network, S: StackProgram<N>>(
+    stack: &S,
+    interface_stack: &S,
+    interface: &Function<N>,
+    program_name: &Field<N>,
+    function_name: &Field<N>,
+) -> Result<(Arc<S>, Identifier<N>)> {
+    // Resolve the callee.
+    let (program_id, function_name) = resolve_dynamic_callee(program_name, function_name)?;
+    // Retrieve the stack of the callee.
+    let substack = stack.get_dynamic_stack(&program_id)?;
+    // Ensure the callee matches the interface.
+    check_dynamic_callee(interface_stack, interface, substack.as_ref(), &function_name)?;
+    Ok((substack, function_name))
+}
+
+/// Ensures the next request in the call stack is for the given callee, and returns it.
+fn peek_dynamic_request<N: Network>(
+    call_stack: &CallStack<N>,
+    program_id: &ProgramID<N>,
+    function_name: &Identifier<N>,
+) -> Result<Request<N>> {
+    // Retrieve the next request (without popping it).
+    let request = match call_stack {
+        CallStack::Evaluate(authorization) | CallStack::Execute(authorization, ..) => authorization.peek_next()?,
+        _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` to peek the next request."),
+    };
+    // Ensure the request is for the callee.
+    ensure!(
+        request.program_id() == program_id && request.function_name() == function_name,
+        "Expected a request for '{program_id}/{function_name}', found '{}/{}'",
+        request.program_id(),
+        request.function_name()
+    );
+    Ok(request)
+}
+
+impl<N: Network> CallTrait<N> for CallDynamic<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::evaluate");
+
+        // Load the operands values.
+        let program_name = field_from_value(&registers.load(stack, self.program_name())?)?;
+        let function_name = field_from_value(&registers.load(stack, self.function_name())?)?;
+        let inputs: Vec<_> = self.arguments().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Retrieve the interface function.
+        let interface_stack = stack.get_external_stack(self.interface().program_id())?;
+        let interface = interface_stack.get_function_ref(self.interface().resource())?;
+        // Retrieve the substack and function name of the callee.
+        let (substack, function_name) =
+            get_dynamic_callee(stack, interface_stack.as_ref(), interface, &program_name, &function_name)?;
+        lap!(timer, "Retrieved the substack and function name");
+
+        // Ensure the next request is for the callee, and that its inputs match.
+        let request = peek_dynamic_request(&registers.call_stack(), substack.program_id(), &function_name)?;
+        ensure!(request.inputs() == inputs, "Inputs do not match in a 'call.dynamic' instruction.");
+
+        // Set the (console) caller.
+        let console_caller = Some(*stack.program_id());
+        // Evaluate the function, and load the outputs.
+        let response = substack.evaluate_function::<A>(registers.call_stack(), console_caller)?;
+        let outputs = to_dynamic_outputs(self.interface(), &response)?;
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::execute");
+
+        use circuit::{Eject, Inject};
+
+        // Load the operands values.
+        let circuit_program_name = match registers.load_circuit(stack, self.program_name())? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(field), _)) => field,
+            _ => bail!("Expected a field element for the program name in a 'call.dynamic' instruction"),
+        };
+        let circuit_function_name = match registers.load_circuit(stack, self.function_name())? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(field), _)) => field,
+            _ => bail!("Expected a field element for the function name in a 'call.dynamic' instruction"),
+        };
+        let inputs: Vec<_> =
+            self.arguments().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // Retrieve the interface stack and function.
+        let interface_stack = stack.get_external_stack(self.interface().program_id())?;
+        let interface = interface_stack.get_function_ref(self.interface().resource())?;
+
+        // Retrieve the substack and function name of the callee.
+        let (program_name, function_name) = (circuit_program_name.eject_value(), circuit_function_name.eject_value());
+        let callee = get_dynamic_callee(stack, interface_stack.as_ref(), interface, &program_name, &function_name);
+        let (substack, function_name) = match (callee, registers.call_stack()) {
+            (Ok(callee), _) => callee,
+            // If the circuit is in synthesize or check deployment mode, the operands are sampled,
+            // so the interface is used in place of the callee, as both share the same circuit shape.
+            (Err(_), CallStack::Synthesize(..) | CallStack::CheckDeployment(..)) => {
+                (interface_stack.clone(), *self.interface().resource())
+            }
+            (Err(error), _) => return Err(error),
+        };
+        let function = substack.get_function(&function_name)?;
+        lap!(timer, "Retrieve the substack and function");
+
+        // If we are not handling the root request, retrieve the root request's tvk
+        let root_tvk = registers.root_tvk().ok();
+
+        // Retrieve the number of inputs.
+        let num_inputs = function.inputs().len();
+        // Ensure the number of inputs matches the number of input statements.
+        if num_inputs != inputs.len() {
+            bail!("Expected {} inputs, found {}", num_inputs, inputs.len())
+        }
+
+        // Retrieve the number of public variables in the circuit.
+        let num_public = A::num_public();
+
+        // Indicate that dynamic calls are never a root request.
+        let is_root = false;
+
+        // Eject the existing circuit.
+        let r1cs = A::eject_r1cs_and_reset();
+        let (request, response) = {
+            // Eject the circuit inputs.
+            let inputs = inputs.eject_value();
+
+            // Set the (console) caller.
+            let console_caller = Some(*stack.program_id());
+
+            // Ensure the dynamic call does not exceed the maximum call depth or number of transitions.
+            // Note: A dynamic call is not part of the static call graph, so these limits are enforced at each call.
+            registers.call_stack().check_dynamic_call()?;
+
+            match registers.call_stack() {
+                // If the circuit is in authorize or synthesize mode, then add the dynamic call to the stack.
+                CallStack::Authorize(_, private_key, authorization, _)
+                | CallStack::Synthesize(_, private_key, authorization, _) => {
+                    // Compute the request, on behalf of the multisig account if it signed the root request.
+                    let request = match authorization.multisig_key() {
+                        Some(multisig_key) => Request::sign_multisig(
+                            &private_key,
+                            &multisig_key,
+                            *substack.program_id(),
+                            *function.name(),
+                            inputs.iter(),
+                            &function.input_types(),
+                            root_tvk,
+                            is_root,
+                            rng,
+                        )?,
+                        None => Request::sign(
+                            &private_key,
+                            *substack.program_id(),
+                            *function.name(),
+                            inputs.iter(),
+                            &function.input_types(),
+                            root_tvk,
+                            is_root,
+                            rng,
+                        )?,
+                    };
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Add the request to the authorization.
+                    authorization.push(request.clone());
+
+                    // Execute the request.
+                    let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                CallStack::PackageRun(_, private_key, ..) => {
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        inputs.iter(),
+                        &function.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Evaluate the request.
+                    let response = substack.execute_function::<A, _>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                CallStack::CheckDeployment(_, private_key, ..) => {
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        inputs.iter(),
+                        &function.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Compute the address.
+                    let address = Address::try_from(&private_key)?;
+                    // Sample dummy outputs.
+                    // Note: A dynamic call does not output records.
+                    let outputs = function
+                        .outputs()
+                        .iter()
+                        .map(|output| substack.sample_value(&address, output.value_type(), rng))
+                        .collect::<Result<Vec<_>>>()?;
+                    // Map the output operands to registers.
+                    let output_registers = function
+                        .outputs()
+                        .iter()
+                        .map(|output| match output.operand() {
+                            Operand::Register(register) => Some(register.clone()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+
+                    // Compute the response.
+                    let response = crate::Response::new(
+                        request.network_id(),
+                        substack.program().id(),
+                        function.name(),
+                        request.inputs().len(),
+                        request.tvk(),
+                        request.tcm(),
+                        outputs,
+                        &function.output_types(),
+                        &output_registers,
+                    )?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                // If the circuit is in evaluate mode, then throw an error.
+                CallStack::Evaluate(..) => {
+                    bail!("Cannot 'execute' a function in 'evaluate' mode.")
+                }
+                // If the circuit is in execute mode, then evaluate and execute the instructions.
+                CallStack::Execute(..) => {
+                    // Ensure the next request is for the callee.
+                    let request = peek_dynamic_request(&registers.call_stack(), substack.program_id(), &function_name)?;
+                    // Ensure the inputs match the original inputs.
+                    ensure!(request.inputs() == inputs, "Inputs do not match in a 'call.dynamic' instruction.");
+
+                    // Evaluate the function, and load the outputs.
+                    let console_response =
+                        substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+                    // Execute the request.
+                    let response =
+                        substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
+                    // Ensure the values are equal.
+                    if console_response.outputs() != response.outputs() {
+                        #[cfg(debug_assertions)]
+                        eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
+                        bail!("Function '{}' outputs do not match in a 'call.dynamic' instruction.", function.name())
+                    }
+                    // Return the request and response.
+                    (request, response)
+                }
+            }
+        };
+        lap!(timer, "Computed the request and response");
+
+        // Inject the existing circuit.
+        A::inject_r1cs(r1cs);
+
+        // Ensure the number of public variables remains the same.
+        ensure!(A::num_public() == num_public, "Forbidden: 'call.dynamic' injected excess public variables");
+
+        // Inject the program name of the callee as `Mode::Public`.
+        let program_name = circuit::Field::<A>::new(circuit::Mode::Public, substack.program_id().name().to_field()?);
+        // Ensure the program name matches the operand.
+        A::assert_eq(program_name, &circuit_program_name);
+        // Inject the function name of the callee as `Mode::Public`.
+        let callee_function_name = circuit::Field::<A>::new(circuit::Mode::Public, function.name().to_field()?);
+        // Ensure the function name matches the operand.
+        A::assert_eq(callee_function_name, &circuit_function_name);
+        // Inject the function ID of the callee as `Mode::Public`.
+        let function_id = circuit::Field::new(
+            circuit::Mode::Public,
+            compute_function_id(request.network_id(), substack.program_id(), function.name())?,
+        );
+
+        // Inject the `tvk` (from the request) as `Mode::Private`.
+        let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
+        // Inject the `tcm` (from the request) as `Mode::Public`.
+        let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
+        // Compute the transition commitment as `Hash(tvk)`.
+        let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
+        // Ensure the transition commitment matches the computed transition commitment.
+        A::assert_eq(&tcm, candidate_tcm);
+        // Inject the input IDs (from the request) as `Mode::Public`.
+        let input_ids = request
+            .input_ids()
+            .iter()
+            .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the candidate input IDs match their computed inputs.
+        A::assert(circuit::Request::check_dynamic_input_ids(&function_id, &input_ids, &inputs, &tvk, &tcm));
+        lap!(timer, "Checked the input ids");
+
+        // Inject the outputs as `Mode::Private` (with the output IDs as `Mode::Public`).
+        // Note: The output types of the interface are used, so that a future output is a future of the interface.
+        let outputs = circuit::Response::process_dynamic_outputs_from_callback(
+            &function_id,
+            num_inputs,
+            &tvk,
+            &tcm,
+            response.outputs().to_vec(),
+            response.output_ids(),
+            &interface.output_types(),
+        );
+        lap!(timer, "Checked the outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        lap!(timer, "Assigned the outputs to registers");
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
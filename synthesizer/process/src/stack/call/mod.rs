@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dynamic;
+pub(crate) use dynamic::*;
+
 use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
@@ -228,19 +231,32 @@ impl<N: Network> CallTrait<N> for Call<N> {
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
-                    | CallStack::Synthesize(_, private_key, authorization) => {
-                        // Compute the request.
-                        let request = Request::sign(
-                            &private_key,
-                            *substack.program_id(),
-                            *function.name(),
-                            inputs.iter(),
-                            &function.input_types(),
-                            root_tvk,
-                            is_root,
-                            rng,
-                        )?;
+                    CallStack::Authorize(_, private_key, authorization, _)
+                    | CallStack::Synthesize(_, private_key, authorization, _) => {
+                        // Compute the request, on behalf of the multisig account if it signed the root request.
+                        let request = match authorization.multisig_key() {
+                            Some(multisig_key) => Request::sign_multisig(
+                                &private_key,
+                                &multisig_key,
+                                *substack.program_id(),
+                                *function.name(),
+                                inputs.iter(),
+                                &function.input_types(),
+                                root_tvk,
+                                is_root,
+                                rng,
+                            )?,
+                            None => Request::sign(
+                                &private_key,
+                                *substack.program_id(),
+                                *function.name(),
+                                inputs.iter(),
+                                &function.input_types(),
+                                root_tvk,
+                                is_root,
+                                rng,
+                            )?,
+                        };
 
                         // Retrieve the call stack.
                         let mut call_stack = registers.call_stack();
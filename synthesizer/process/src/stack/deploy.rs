@@ -51,7 +51,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+        Deployment::new(self.edition, self.program.clone(), verifying_keys)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -61,7 +61,27 @@ impl<N: Network> Stack<N> {
         deployment: &Deployment<N>,
         rng: &mut R,
     ) -> Result<()> {
-        let timer = timer!("Stack::verify_deployment");
+        // Check the deployment, deferring the pairing checks of the certificates.
+        let pairing_checks = self.prepare_verify_deployment::<A, R>(deployment, rng)?;
+        // Verify the pairing check of each certificate.
+        for (pairing_check, (function_name, _)) in pairing_checks.iter().zip_eq(deployment.verifying_keys()) {
+            if !pairing_check.verify() {
+                bail!("The certificate for function '{function_name}' is invalid in '{}'", self.program.id())
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// except for the final pairing check of each certificate, which is returned instead.
+    /// The pairing checks are returned in the order of the verifying keys in the deployment.
+    #[inline]
+    pub fn prepare_verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<PairingCheck<N>>> {
+        let timer = timer!("Stack::prepare_verify_deployment");
 
         // Sanity Checks //
 
@@ -160,10 +180,12 @@ impl<N: Network> Stack<N> {
             call_stacks.push((function.name(), call_stack, assignments));
         }
 
-        // Verify the certificates.
+        // Check the certificates, deferring their pairing checks.
         let rngs = (0..call_stacks.len()).map(|_| StdRng::from_seed(seeded_rng.gen())).collect::<Vec<_>>();
-        cfg_into_iter!(call_stacks).zip_eq(deployment.verifying_keys()).zip_eq(rngs).try_for_each(
-            |(((function_name, call_stack, assignments), (_, (verifying_key, certificate))), mut rng)| {
+        let pairing_checks = cfg_into_iter!(call_stacks)
+            .zip_eq(deployment.verifying_keys())
+            .zip_eq(rngs)
+            .map(|(((function_name, call_stack, assignments), (_, (verifying_key, certificate))), mut rng)| {
                 // Synthesize the circuit.
                 if let Err(err) = self.execute_function::<A, _>(call_stack, caller, root_tvk, &mut rng) {
                     bail!("Failed to synthesize the circuit for '{function_name}': {err}")
@@ -172,18 +194,20 @@ impl<N: Network> Stack<N> {
                 match assignments.read().last() {
                     None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
                     Some((assignment, _metrics)) => {
-                        // Ensure the certificate is valid.
-                        if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
-                            bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                        // Ensure the certificate is well-formed, and prepare its pairing check.
+                        match certificate.prepare_verify(&function_name.to_string(), assignment, verifying_key) {
+                            Ok(pairing_check) => Ok(pairing_check),
+                            Err(_) => {
+                                bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                            }
                         }
                     }
-                };
-                Ok(())
-            },
-        )?;
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         finish!(timer);
 
-        Ok(())
+        Ok(pairing_checks)
     }
 }
@@ -120,6 +120,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -205,7 +209,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Verify the input types");
 
         // Ensure the request is well-formed.
-        ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
+        // Note: A multisig request is cosigned after it is authorized, so its quorum is only checked on execution.
+        let is_request_valid = match call_stack {
+            CallStack::Authorize(..) | CallStack::Synthesize(..) => {
+                console_request.verify_without_quorum(&input_types, console_is_root)
+            }
+            _ => console_request.verify(&input_types, console_is_root),
+        };
+        ensure!(is_request_valid, "Request is invalid");
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
@@ -290,6 +301,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                    Instruction::CallDynamic(call) => CallTrait::evaluate(call, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -303,6 +316,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                Instruction::CallDynamic(call) => CallTrait::execute(call, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
@@ -312,11 +327,16 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
+            match instruction {
                 // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
+                Instruction::Call(call) => {
+                    if call.is_function_call(self)? {
+                        contains_function_call = true;
+                    }
                 }
+                // A dynamic call is always a function call.
+                Instruction::CallDynamic(..) => contains_function_call = true,
+                _ => (),
             }
         }
         lap!(timer, "Execute the instructions");
@@ -355,6 +375,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -433,15 +457,27 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
             || matches!(registers.call_stack(), CallStack::Execute(..))
         {
-            // If the proving key does not exist, then synthesize it.
-            if !self.contains_proving_key(function.name()) {
-                // Add the circuit key to the mapping.
-                self.synthesize_from_assignment(function.name(), &assignment)?;
-                lap!(timer, "Synthesize the {} circuit key", function.name());
+            match console_request.multisig_key().is_some() {
+                // If the multisig proving key does not exist, then synthesize it.
+                true => {
+                    if !self.contains_multisig_proving_key(function.name()) {
+                        // Add the multisig circuit key to the mapping.
+                        self.synthesize_multisig_from_assignment(function.name(), &assignment)?;
+                        lap!(timer, "Synthesize the {} multisig circuit key", function.name());
+                    }
+                }
+                // If the proving key does not exist, then synthesize it.
+                false => {
+                    if !self.contains_proving_key(function.name()) {
+                        // Add the circuit key to the mapping.
+                        self.synthesize_from_assignment(function.name(), &assignment)?;
+                        lap!(timer, "Synthesize the {} circuit key", function.name());
+                    }
+                }
             }
         }
         // If the circuit is in `Authorize` mode, then save the transition.
-        if let CallStack::Authorize(_, _, authorization) = registers.call_stack() {
+        if let CallStack::Authorize(_, _, authorization, _) = registers.call_stack() {
             // Construct the transition.
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
             // Add the transition to the authorization.
@@ -470,8 +506,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             // Construct the transition.
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
 
-            // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function.name())?;
+            // Retrieve the proving key, which differs for a request signed by a multisig account.
+            let proving_key = match transition.is_multisig() {
+                true => self.get_multisig_proving_key(function.name())?,
+                false => self.get_proving_key(function.name())?,
+            };
             // Construct the call metrics.
             let metrics = CallMetrics {
                 program_id: *self.program_id(),
@@ -491,7 +530,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             )?;
         }
         // If the circuit is in `PackageRun` mode, then save the assignment.
-        else if let CallStack::PackageRun(_, _, ref assignments) = registers.call_stack() {
+        else if let CallStack::PackageRun(_, _, ref assignments, _) = registers.call_stack() {
             // Construct the call metrics.
             let metrics = CallMetrics {
                 program_id: *self.program_id(),
@@ -46,8 +46,15 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::NetworkID => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID)))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
         };
 
+        // Resolve the loop indices in the register.
+        let register = &self.resolve_loop_indices(register)?;
+
         // Retrieve the value.
         let value = self.registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
 
@@ -78,3 +85,27 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
         Ok(value)
     }
 }
+
+impl<N: Network> FinalizeRegisters<N> {
+    /// Returns the register, with each loop-index access replaced by an index access at the current loop index.
+    fn resolve_loop_indices(&self, register: &Register<N>) -> Result<Register<N>> {
+        match register {
+            Register::Access(locator, path) if path.iter().any(|access| matches!(access, Access::Register(..))) => {
+                let path = path
+                    .iter()
+                    .map(|access| match access {
+                        Access::Register(index) => match self.registers.get(index) {
+                            Some(Value::Plaintext(Plaintext::Literal(Literal::U32(index), ..))) => {
+                                Ok(Access::Index(*index))
+                            }
+                            _ => bail!("Register 'r{index}' is not a loop index in '{register}'"),
+                        },
+                        access => Ok(*access),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Register::Access(*locator, path))
+            }
+            _ => Ok(register.clone()),
+        }
+    }
+}
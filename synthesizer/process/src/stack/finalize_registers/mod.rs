@@ -19,8 +19,8 @@ mod store;
 use crate::FinalizeTypes;
 use console::{
     network::prelude::*,
-    program::{Identifier, Literal, Plaintext, Register, Value},
-    types::{U16, U32},
+    program::{Access, Identifier, Literal, Plaintext, Register, Value},
+    types::{I64, U16, U32},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -76,4 +76,12 @@ impl<N: Network> RegistersStore<N> for FinalizeRegisters<N> {
             (Register::Access(..), _) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
+
+    /// Clears every register from the given locator onwards.
+    #[inline]
+    fn rewind(&mut self, locator: u64) {
+        self.registers.retain(|register, _| *register < locator);
+        // Reset the last register locator to the highest remaining register.
+        self.last_register = self.registers.keys().max().copied();
+    }
 }
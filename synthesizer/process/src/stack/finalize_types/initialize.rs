@@ -30,7 +30,12 @@ impl<N: Network> FinalizeTypes<N> {
         finalize: &Finalize<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut finalize_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut finalize_types = Self {
+            inputs: IndexMap::new(),
+            destinations: IndexMap::new(),
+            loop_indices: IndexMap::new(),
+            num_events: 0,
+        };
 
         // Initialize a list of input futures.
         let mut input_futures = Vec::new();
@@ -179,6 +184,10 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::Length(length) => self.check_length(stack, length)?,
+            Command::GetNext(get_next) => self.check_get_next(stack, get_next)?,
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
+            Command::Repeat(repeat) => self.check_repeat(stack, finalize, repeat)?,
         }
         Ok(())
     }
@@ -482,6 +491,124 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `get.next` command is well-formed.
+    #[inline]
+    fn check_get_next(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        get_next: &GetNext<N>,
+    ) -> Result<()> {
+        // Retrieve the mapping.
+        let mapping = match get_next.mapping() {
+            CallOperator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+                // Retrieve the mapping from the program.
+                external.get_mapping(mapping_name)?
+            }
+            CallOperator::Resource(mapping_name) => {
+                // Ensure the declared mapping in `get.next` is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)?
+            }
+        };
+
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Get the mapping value type.
+        let mapping_value_type = mapping.value().plaintext_type();
+        // If a key is given, ensure it matches the key type in the mapping.
+        if let Some(key) = get_next.key() {
+            // Retrieve the register type of the key.
+            let key_type = match self.get_type_from_operand(stack, key)? {
+                // If the register is a plaintext type, return it.
+                FinalizeType::Plaintext(plaintext_type) => plaintext_type,
+                // If the register is a future, throw an error.
+                FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get.next` command"),
+            };
+            // Check that the key type in the mapping matches the key type in the instruction.
+            if *mapping_key_type != key_type {
+                bail!(
+                    "Key type in `get.next` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+                )
+            }
+        }
+        // Get the destination registers.
+        let key_destination = get_next.key_destination().clone();
+        let value_destination = get_next.value_destination().clone();
+        // Ensure the destination registers are locators (and do not reference an access).
+        ensure!(matches!(key_destination, Register::Locator(..)), "Destination '{key_destination}' must be a locator.");
+        ensure!(
+            matches!(value_destination, Register::Locator(..)),
+            "Destination '{value_destination}' must be a locator."
+        );
+        // Insert the destination registers.
+        self.add_destination(key_destination, FinalizeType::Plaintext(mapping_key_type.clone()))?;
+        self.add_destination(value_destination, FinalizeType::Plaintext(mapping_value_type.clone()))?;
+        Ok(())
+    }
+
+    /// Ensures the given `length` command is well-formed.
+    #[inline]
+    fn check_length(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), length: &Length<N>) -> Result<()> {
+        // Ensure the mapping is defined.
+        match length.mapping() {
+            CallOperator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Ensure the mapping exists in the external program.
+                if !stack.get_external_program(program_id)?.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+            }
+            CallOperator::Resource(mapping_name) => {
+                // Ensure the declared mapping in `length` is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+            }
+        }
+
+        // Get the destination register.
+        let destination = length.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
     /// Ensures the given `set` command is well-formed.
     #[inline]
     fn check_set(
@@ -559,6 +686,85 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the payload is a plaintext type.
+        if let FinalizeType::Future(..) = self.get_type_from_operand(stack, emit.operand())? {
+            bail!("A future cannot be used as the payload of an `emit` command")
+        }
+        // Ensure the number of events does not exceed the maximum.
+        self.add_events(1)
+    }
+
+    /// Adds the given number of events, and ensures the number of events in finalize does not exceed the maximum.
+    fn add_events(&mut self, num_events: usize) -> Result<()> {
+        self.num_events = self.num_events.saturating_add(num_events);
+        ensure!(
+            self.num_events <= N::MAX_EVENTS as usize,
+            "Finalize emits {} events, exceeding the maximum of {}",
+            self.num_events,
+            N::MAX_EVENTS
+        );
+        Ok(())
+    }
+
+    /// Ensures the given `repeat` command is well-formed.
+    #[inline]
+    fn check_repeat(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize: &Finalize<N>,
+        repeat: &RepeatCommand<N>,
+    ) -> Result<()> {
+        // Retrieve the bound.
+        let bound = **repeat.bound() as usize;
+        // Ensure the unrolled block does not exceed the maximum number of commands.
+        let num_unrolled = repeat.body().len().saturating_mul(bound);
+        ensure!(
+            num_unrolled <= N::MAX_COMMANDS,
+            "The 'repeat' block unrolls to {num_unrolled} commands, exceeding the maximum of {}",
+            N::MAX_COMMANDS
+        );
+        // Ensure the unrolled block does not exceed the maximum number of write commands.
+        let num_writes = repeat.body().iter().filter(|command| command.is_write()).count().saturating_mul(bound);
+        ensure!(
+            num_writes <= N::MAX_WRITES as usize,
+            "The 'repeat' block unrolls to {num_writes} 'set' & 'remove' commands, exceeding the maximum of {}",
+            N::MAX_WRITES
+        );
+
+        // Insert the loop-index register, and record the bound of the loop index.
+        self.add_destination(
+            repeat.index().clone(),
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+        )?;
+        self.loop_indices.insert(repeat.index().locator(), **repeat.bound());
+
+        // Check the commands in the body.
+        let num_events = self.num_events;
+        for command in repeat.body() {
+            // Ensure the body contains no nested blocks, control flow, or `await` commands.
+            ensure!(
+                !matches!(
+                    command,
+                    Command::Repeat(..)
+                        | Command::Await(..)
+                        | Command::BranchEq(..)
+                        | Command::BranchNeq(..)
+                        | Command::Position(..)
+                ),
+                "Command '{command}' is not allowed in a 'repeat' block"
+            );
+            // Check the command opcode, operands, and destinations.
+            self.check_command(stack, finalize, command)?;
+        }
+        // Ensure the unrolled block does not exceed the maximum number of events.
+        // Note: The events in the body were counted once, so they are counted for the remaining iterations.
+        let num_body_events = self.num_events - num_events;
+        self.add_events(num_body_events.saturating_mul(bound.saturating_sub(1)))
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -637,6 +843,9 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
+            Opcode::CallDynamic => {
+                bail!("Instruction 'call.dynamic' is not allowed in 'finalize'");
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -709,6 +918,25 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::Deserialize(opcode) => RegisterTypes::check_deserialize_opcode(stack, opcode, instruction)?,
+            Opcode::ECDSA(opcode) => {
+                match opcode {
+                    "ecdsa.verify.keccak256" => ensure!(
+                        matches!(instruction, Instruction::ECDSAVerifyKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "ecdsa.verify.digest" => ensure!(
+                        matches!(instruction, Instruction::ECDSAVerifyDigest(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
             Opcode::Hash(opcode) => RegisterTypes::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -721,6 +949,10 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Repeat => {
+                bail!("Forbidden operation: Finalize must use a 'repeat' command instead of '{instruction}'.");
+            }
+            Opcode::Serialize(opcode) => RegisterTypes::check_serialize_opcode(opcode, instruction)?,
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -728,6 +960,7 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::String(opcode) => RegisterTypes::check_string_opcode(opcode, instruction)?,
         }
         Ok(())
     }
@@ -107,6 +107,16 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -199,6 +209,17 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
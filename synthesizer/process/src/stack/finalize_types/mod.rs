@@ -39,17 +39,22 @@ use synthesizer_program::{
     CallOperator,
     CastType,
     Command,
+    CommandTrait,
     Contains,
+    Emit,
     Finalize,
     Get,
+    GetNext,
     GetOrUse,
     Instruction,
     InstructionTrait,
+    Length,
     Opcode,
     Operand,
     Program,
     RandChaCha,
     Remove,
+    RepeatCommand,
     Set,
     StackMatches,
     StackProgram,
@@ -67,6 +72,10 @@ pub struct FinalizeTypes<N: Network> {
     /// The mapping of all destination registers to their defined types.
     /// Note that in a finalize context, all registers are finalize types.
     destinations: IndexMap<u64, FinalizeType<N>>,
+    /// The mapping of all loop-index registers to the bounds of their `repeat` blocks.
+    loop_indices: IndexMap<u64, u32>,
+    /// The number of events emitted in finalize, with the `repeat` blocks unrolled.
+    num_events: usize,
 }
 
 impl<N: Network> FinalizeTypes<N> {
@@ -105,6 +114,7 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
             Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
         })
     }
 
@@ -173,6 +183,20 @@ impl<N: Network> FinalizeTypes<N> {
                         false => bail!("Index out of bounds"),
                     }
                 }
+                // Access the member on the path to output the register type and check that the loop index is in bounds.
+                (FinalizeRefType::Plaintext(PlaintextType::Array(array_type)), Access::Register(locator)) => {
+                    // Retrieve the bound of the loop index.
+                    let bound = self
+                        .loop_indices
+                        .get(locator)
+                        .ok_or_else(|| anyhow!("Register 'r{locator}' in '{register}' is not a loop index"))?;
+                    match *bound <= **array_type.length() {
+                        // Retrieve the element type and update `finalize_type` for the next iteration.
+                        true => finalize_type = FinalizeRefType::Plaintext(array_type.next_element_type()),
+                        // Halts if the loop index may be out of bounds.
+                        false => bail!("Loop index 'r{locator}' may be out of bounds for '{register}'"),
+                    }
+                }
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (FinalizeRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
@@ -200,9 +224,9 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("Index out of bounds"),
                     }
                 }
-                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..) | Access::Register(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (FinalizeRefType::Future(..), Access::Member(..)) => {
+                | (FinalizeRefType::Future(..), Access::Member(..) | Access::Register(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
@@ -22,15 +22,20 @@ impl<N: Network> Stack<N> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition: N::EDITION,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            multisig_proving_keys: Default::default(),
+            multisig_verifying_keys: Default::default(),
             number_of_calls: Default::default(),
+            dynamic_calls: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
+            registry: process.registry().clone(),
         };
 
         // Add all the imports into the stack.
@@ -62,19 +67,32 @@ impl<N: Network> Stack<N> {
             stack.insert_function(function)?;
             // Determine the number of calls for the function.
             let mut num_calls = 1;
+            // Determine if the function invokes a dynamic call.
+            let mut is_dynamic = false;
             for instruction in function.instructions() {
                 if let Instruction::Call(call) = instruction {
                     // Determine if this is a function call.
                     if call.is_function_call(&stack)? {
                         // Increment by the number of calls.
                         num_calls += match call.operator() {
-                            CallOperator::Locator(locator) => stack
-                                .get_external_stack(locator.program_id())?
-                                .get_number_of_calls(locator.resource())?,
-                            CallOperator::Resource(resource) => stack.get_number_of_calls(resource)?,
+                            CallOperator::Locator(locator) => {
+                                let external_stack = stack.get_external_stack(locator.program_id())?;
+                                is_dynamic |= external_stack.contains_dynamic_calls(locator.resource());
+                                external_stack.get_number_of_calls(locator.resource())?
+                            }
+                            CallOperator::Resource(resource) => {
+                                is_dynamic |= stack.contains_dynamic_calls(resource);
+                                stack.get_number_of_calls(resource)?
+                            }
                         };
                     }
                 }
+                // Increment by one for a dynamic call.
+                // Note: This is a lower bound, as the callee may call other functions.
+                if let Instruction::CallDynamic(..) = instruction {
+                    num_calls += 1;
+                    is_dynamic = true;
+                }
             }
             // Check that the number of calls does not exceed the maximum.
             // Note that one transition is reserved for the fee.
@@ -84,6 +102,10 @@ impl<N: Network> Stack<N> {
             );
             // Add the number of calls to the stack.
             stack.number_of_calls.insert(*function.name(), num_calls);
+            // Add the function to the set of functions that invoke a dynamic call.
+            if is_dynamic {
+                stack.dynamic_calls.insert(*function.name());
+            }
 
             // Get the finalize cost.
             let finalize_cost = cost_in_microcredits(&stack, function.name())?;
@@ -27,7 +27,53 @@ impl<N: Network> Stack<N> {
         if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
             return Ok(());
         }
+        // Synthesize the circuit for a single-signer request.
+        self.synthesize_key_internal::<A, R>(function_name, false, rng)?;
 
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Synthesizes the multisig proving key and verifying key for the given function name.
+    ///
+    /// The multisig circuit has a fixed shape for every threshold and number of signers,
+    /// so the keys are synthesized from a burner 1-of-1 multisig account.
+    #[inline]
+    pub fn synthesize_multisig_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // If the multisig proving and verifying key already exist, skip the synthesis for this function.
+        if self.contains_multisig_proving_key(function_name) && self.contains_multisig_verifying_key(function_name) {
+            return Ok(());
+        }
+        // Synthesize the circuit for a multisig request.
+        self.synthesize_key_internal::<A, R>(function_name, true, rng)?;
+
+        // Ensure the multisig proving key exists.
+        ensure!(
+            self.contains_multisig_proving_key(function_name),
+            "Function '{function_name}' is missing a multisig proving key."
+        );
+        // Ensure the multisig verifying key exists.
+        ensure!(
+            self.contains_multisig_verifying_key(function_name),
+            "Function '{function_name}' is missing a multisig verifying key."
+        );
+        Ok(())
+    }
+
+    /// Synthesizes the circuit for the given function name, from a burner request.
+    fn synthesize_key_internal<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        is_multisig: bool,
+        rng: &mut R,
+    ) -> Result<()> {
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -35,8 +81,22 @@ impl<N: Network> Stack<N> {
 
         // Initialize a burner private key.
         let burner_private_key = PrivateKey::new(rng)?;
+        // Initialize a burner 1-of-1 multisig key, if the request is signed by a multisig account.
+        let (burner_multisig_key, burner_signer_key) = match is_multisig {
+            true => {
+                let burner_signer_key = PrivateKey::new(rng)?;
+                let compute_key = ComputeKey::try_from(&burner_private_key)?;
+                let signers = vec![ComputeKey::try_from(&burner_signer_key)?];
+                let multisig_key = MultisigKey::new(compute_key, 1, signers)?;
+                (Some(multisig_key), Some(burner_signer_key))
+            }
+            false => (None, None),
+        };
         // Compute the burner address.
-        let burner_address = Address::try_from(&burner_private_key)?;
+        let burner_address = match &burner_multisig_key {
+            Some(multisig_key) => multisig_key.to_address(),
+            None => Address::try_from(&burner_private_key)?,
+        };
         // Sample the inputs.
         let inputs = input_types
             .iter()
@@ -60,27 +120,40 @@ impl<N: Network> Stack<N> {
         let caller = None;
 
         // Compute the request, with a burner private key.
-        let request = Request::sign(
-            &burner_private_key,
-            *program_id,
-            *function_name,
-            inputs.into_iter(),
-            &input_types,
-            root_tvk,
-            is_root,
-            rng,
-        )?;
+        let request = match (&burner_multisig_key, &burner_signer_key) {
+            (Some(multisig_key), Some(signer_key)) => {
+                let mut request = Request::sign_multisig(
+                    &burner_private_key,
+                    multisig_key,
+                    *program_id,
+                    *function_name,
+                    inputs.into_iter(),
+                    &input_types,
+                    root_tvk,
+                    is_root,
+                    rng,
+                )?;
+                // Cosign the request with the burner signer.
+                request.cosign(signer_key, rng)?;
+                request
+            }
+            _ => Request::sign(
+                &burner_private_key,
+                *program_id,
+                *function_name,
+                inputs.into_iter(),
+                &input_types,
+                root_tvk,
+                is_root,
+                rng,
+            )?,
+        };
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Initialize the call stack.
-        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
+        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization, 0);
         // Synthesize the circuit.
         let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
         Ok(())
     }
 
@@ -103,4 +176,26 @@ impl<N: Network> Stack<N> {
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Synthesizes and stores the multisig `(proving_key, verifying_key)` for the given function name and assignment.
+    #[inline]
+    pub fn synthesize_multisig_from_assignment(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // If the multisig proving and verifying key already exist, skip the synthesis for this function.
+        if self.contains_multisig_proving_key(function_name) && self.contains_multisig_verifying_key(function_name) {
+            return Ok(());
+        }
+
+        // Synthesize the multisig proving and verifying key.
+        let (proving_key, verifying_key) =
+            self.universal_srs.to_circuit_key(&format!("{function_name}.multisig"), assignment)?;
+        // Insert the multisig proving key.
+        self.multisig_proving_keys.write().insert(*function_name, proving_key);
+        // Insert the multisig verifying key.
+        self.multisig_verifying_keys.write().insert(*function_name, verifying_key);
+        Ok(())
+    }
 }
@@ -0,0 +1,123 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Initializes a new stack for the next edition of a deployed program, given the process and the upgraded program.
+    ///
+    /// The upgrade must be permitted by the upgrade policy of the deployed edition, and must retain
+    /// the mappings, structs, records, and function signatures of the deployed edition.
+    #[inline]
+    pub fn new_upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Retrieve the stack of the deployed edition.
+        let Ok(previous) = process.get_stack(program_id) else {
+            bail!("Cannot upgrade program '{program_id}' because it does not exist")
+        };
+        // Ensure the deployed edition may be upgraded.
+        ensure!(!previous.program().upgrade_policy().is_immutable(), "Program '{program_id}' is immutable");
+        // Ensure the upgraded program is compatible with the deployed edition.
+        previous.check_upgrade(program)?;
+        // Ensure the upgraded program does not import a program that depends on it.
+        for import in program.imports().keys() {
+            if process.get_stack(import)?.depends_on(program_id) {
+                bail!("Cannot upgrade program '{program_id}' to import '{import}', as '{import}' depends on it")
+            }
+        }
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+
+        // Determine the next edition.
+        let Some(edition) = previous.edition().checked_add(1) else {
+            bail!("Program '{program_id}' has reached the maximum edition")
+        };
+        // Return the stack.
+        Self::initialize_edition(process, program, edition)
+    }
+
+    /// Initializes a new stack for the given edition of the program.
+    /// Note: This method does not check that the edition is a valid upgrade, and should only be used
+    /// for deployments that are already accepted.
+    #[inline]
+    pub(crate) fn initialize_edition(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        let mut stack = Self::initialize(process, program)?;
+        stack.edition = edition;
+        Ok(stack)
+    }
+
+    /// Recomputes the stack from the given process, retaining its edition, proving keys, and verifying keys.
+    /// Note: This method is intended to refresh the external stacks after one of the imports is upgraded.
+    #[inline]
+    pub(crate) fn reinitialize(&self, process: &Process<N>) -> Result<Self> {
+        let mut stack = Self::initialize_edition(process, &self.program, self.edition)?;
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        stack.multisig_proving_keys = self.multisig_proving_keys.clone();
+        stack.multisig_verifying_keys = self.multisig_verifying_keys.clone();
+        Ok(stack)
+    }
+
+    /// Returns `true` if the stack imports the given program ID, directly or indirectly.
+    pub fn depends_on(&self, program_id: &ProgramID<N>) -> bool {
+        self.external_stacks.iter().any(|(id, stack)| id == program_id || stack.depends_on(program_id))
+    }
+
+    /// Ensures the given program is a compatible upgrade of the program in this stack.
+    fn check_upgrade(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+        // Ensure the program IDs match.
+        ensure!(program.id() == program_id, "Cannot upgrade program '{program_id}' with program '{}'", program.id());
+
+        // Ensure the mappings are retained, with the same key and value types.
+        for (name, mapping) in self.program.mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) => ensure!(candidate == mapping, "Mapping '{program_id}/{name}' must not change"),
+                None => bail!("Mapping '{program_id}/{name}' must not be removed"),
+            }
+        }
+        // Ensure the structs are retained, with the same layout.
+        for (name, struct_) in self.program.structs() {
+            match program.structs().get(name) {
+                Some(candidate) => ensure!(candidate == struct_, "Struct '{program_id}/{name}' must not change"),
+                None => bail!("Struct '{program_id}/{name}' must not be removed"),
+            }
+        }
+        // Ensure the records are retained, with the same layout.
+        for (name, record) in self.program.records() {
+            match program.records().get(name) {
+                Some(candidate) => ensure!(candidate == record, "Record '{program_id}/{name}' must not change"),
+                None => bail!("Record '{program_id}/{name}' must not be removed"),
+            }
+        }
+        // Ensure the functions are retained, with the same inputs, outputs, and finalize inputs.
+        // Note: This ensures the programs that call these functions remain valid.
+        for (name, function) in self.program.functions() {
+            let Some(candidate) = program.functions().get(name) else {
+                bail!("Function '{program_id}/{name}' must not be removed")
+            };
+            ensure!(
+                candidate.input_types() == function.input_types()
+                    && candidate.output_types() == function.output_types()
+                    && candidate.finalize_logic().map(|finalize| finalize.input_types())
+                        == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "The signature of function '{program_id}/{name}' must not change"
+            );
+        }
+        Ok(())
+    }
+}
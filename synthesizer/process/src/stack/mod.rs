@@ -39,7 +39,7 @@ mod helpers;
 
 use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, ComputeKey, MultisigKey, PrivateKey},
     network::prelude::*,
     program::{
         Argument,
@@ -66,26 +66,32 @@ use console::{
 };
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
-use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{Certificate, PairingCheck, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The registry of stacks in a process, used to resolve the callee of a dynamic call.
+pub(crate) type StackRegistry<N> = Arc<RwLock<IndexMap<ProgramID<N>, Weak<Stack<N>>>>>;
+
 pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>::Field>, CallMetrics<N>)>>>;
 
+/// The call stack of a request.
+/// Note: The `usize` in the `Authorize`, `Synthesize`, and `PackageRun` modes is the call depth,
+/// i.e. the number of calls between the root request and the current request.
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
-    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
-    Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
+    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>, usize),
+    Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>, usize),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>, Option<u64>),
     Evaluate(Authorization<N>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
-    PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
+    PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, usize),
 }
 
 impl<N: Network> CallStack<N> {
@@ -104,11 +110,11 @@ impl<N: Network> CallStack<N> {
     /// Returns a new and independent replica of the call stack.
     pub fn replicate(&self) -> Self {
         match self {
-            CallStack::Authorize(requests, private_key, authorization) => {
-                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate())
+            CallStack::Authorize(requests, private_key, authorization, depth) => {
+                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate(), *depth)
             }
-            CallStack::Synthesize(requests, private_key, authorization) => {
-                CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate())
+            CallStack::Synthesize(requests, private_key, authorization, depth) => {
+                CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate(), *depth)
             }
             CallStack::CheckDeployment(requests, private_key, assignments, constraint_limit, variable_limit) => {
                 CallStack::CheckDeployment(
@@ -123,19 +129,26 @@ impl<N: Network> CallStack<N> {
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
-            CallStack::PackageRun(requests, private_key, assignments) => {
-                CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
-            }
+            CallStack::PackageRun(requests, private_key, assignments, depth) => CallStack::PackageRun(
+                requests.clone(),
+                *private_key,
+                Arc::new(RwLock::new(assignments.read().clone())),
+                *depth,
+            ),
         }
     }
 
     /// Pushes the request to the stack.
+    /// Note: In the `Authorize`, `Synthesize`, and `PackageRun` modes, this increments the call depth.
     pub fn push(&mut self, request: Request<N>) -> Result<()> {
         match self {
-            CallStack::Authorize(requests, ..)
-            | CallStack::Synthesize(requests, ..)
-            | CallStack::CheckDeployment(requests, ..)
-            | CallStack::PackageRun(requests, ..) => requests.push(request),
+            CallStack::Authorize(requests, _, _, depth)
+            | CallStack::Synthesize(requests, _, _, depth)
+            | CallStack::PackageRun(requests, _, _, depth) => {
+                requests.push(request);
+                *depth += 1;
+            }
+            CallStack::CheckDeployment(requests, ..) => requests.push(request),
             CallStack::Evaluate(authorization) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
@@ -156,6 +169,30 @@ impl<N: Network> CallStack<N> {
         }
     }
 
+    /// Ensures a dynamic call can be added to the stack without exceeding the maximum call depth,
+    /// or the maximum number of transitions in a transaction.
+    pub fn check_dynamic_call(&self) -> Result<()> {
+        // Ensure the call depth does not exceed the maximum program depth.
+        if let CallStack::Authorize(.., depth) | CallStack::Synthesize(.., depth) | CallStack::PackageRun(.., depth) =
+            self
+        {
+            ensure!(
+                *depth < N::MAX_PROGRAM_DEPTH,
+                "Dynamic call exceeds the maximum call depth of {}",
+                N::MAX_PROGRAM_DEPTH
+            );
+        }
+        // Ensure the number of transitions does not exceed the maximum number of transitions.
+        if let CallStack::Authorize(_, _, authorization, _) | CallStack::Synthesize(_, _, authorization, _) = self {
+            ensure!(
+                authorization.len() < ledger_block::Transaction::<N>::MAX_TRANSITIONS,
+                "Dynamic call exceeds the maximum number of transitions of {}",
+                ledger_block::Transaction::<N>::MAX_TRANSITIONS
+            );
+        }
+        Ok(())
+    }
+
     /// Peeks at the next request from the stack.
     pub fn peek(&mut self) -> Result<Request<N>> {
         match self {
@@ -175,6 +212,8 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
     /// The mapping of closure and function names to their register types.
@@ -187,12 +226,20 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to proving key, for requests signed by a multisig account.
+    multisig_proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
+    /// The mapping of function name to verifying key, for requests signed by a multisig account.
+    multisig_verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
+    /// The set of function names that invoke a dynamic call, directly or through the functions they call.
+    dynamic_calls: IndexSet<Identifier<N>>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
     /// The program depth.
     program_depth: usize,
+    /// The registry of stacks in the process.
+    registry: StackRegistry<N>,
 }
 
 impl<N: Network> Stack<N> {
@@ -203,6 +250,17 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+
+        // Return the stack.
+        Stack::initialize(process, program)
+    }
+
+    /// Ensures the program contains functions, and serializes correctly.
+    fn check_program(program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -216,8 +274,20 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
-        // Return the stack.
-        Stack::initialize(process, program)
+        Ok(())
+    }
+
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns `true` if the given function invokes a dynamic call, directly or through the functions it calls.
+    /// Note: If so, the number of calls for the function is a lower bound, as the callee may call other functions.
+    #[inline]
+    pub fn contains_dynamic_calls(&self, function_name: &Identifier<N>) -> bool {
+        self.dynamic_calls.contains(function_name)
     }
 }
 
@@ -259,6 +329,19 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         self.external_stacks.get(program_id).ok_or_else(|| anyhow!("External program '{program_id}' does not exist."))
     }
 
+    /// Returns the stack of the callee of a dynamic call, for the given program ID.
+    #[inline]
+    fn get_dynamic_stack(&self, program_id: &ProgramID<N>) -> Result<Arc<Self>> {
+        // Ensure the callee is not the main program.
+        ensure!(self.program.id() != program_id, "Program '{program_id}' cannot dynamically call itself");
+        // Retrieve the stack from the registry.
+        self.registry
+            .read()
+            .get(program_id)
+            .and_then(Weak::upgrade)
+            .ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))
+    }
+
     /// Returns the external program for the given program ID.
     #[inline]
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>> {
@@ -441,6 +524,42 @@ impl<N: Network> Stack<N> {
     }
 }
 
+impl<N: Network> Stack<N> {
+    /// Returns `true` if the multisig proving key for the given function name exists.
+    #[inline]
+    pub fn contains_multisig_proving_key(&self, function_name: &Identifier<N>) -> bool {
+        self.multisig_proving_keys.read().contains_key(function_name)
+    }
+
+    /// Returns `true` if the multisig verifying key for the given function name exists.
+    #[inline]
+    pub fn contains_multisig_verifying_key(&self, function_name: &Identifier<N>) -> bool {
+        self.multisig_verifying_keys.read().contains_key(function_name)
+    }
+
+    /// Returns the multisig proving key for the given function name.
+    #[inline]
+    pub fn get_multisig_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
+        // Return the multisig proving key, if it exists.
+        match self.multisig_proving_keys.read().get(function_name) {
+            Some(proving_key) => Ok(proving_key.clone()),
+            None => bail!("Multisig proving key not found for: {}/{function_name}", self.program.id()),
+        }
+    }
+
+    /// Returns the multisig verifying key for the given function name.
+    ///
+    /// Note: Multisig keys are not part of a deployment. Use `Stack::synthesize_multisig_key` to synthesize them.
+    #[inline]
+    pub fn get_multisig_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        // Return the multisig verifying key, if it exists.
+        match self.multisig_verifying_keys.read().get(function_name) {
+            Some(verifying_key) => Ok(verifying_key.clone()),
+            None => bail!("Multisig verifying key not found for: {}/{function_name}", self.program.id()),
+        }
+    }
+}
+
 impl<N: Network> Stack<N> {
     /// Inserts the proving key if the program ID is 'credits.aleo'.
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
@@ -24,7 +24,8 @@ impl<N: Network> RegisterTypes<N> {
         closure: &Closure<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types =
+            Self { inputs: IndexMap::new(), destinations: IndexMap::new(), loop_indices: IndexMap::new() };
 
         // Step 1. Check the inputs are well-formed.
         for input in closure.inputs() {
@@ -65,7 +66,8 @@ impl<N: Network> RegisterTypes<N> {
         function: &Function<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types =
+            Self { inputs: IndexMap::new(), destinations: IndexMap::new(), loop_indices: IndexMap::new() };
 
         /* Step 1. Check the inputs are well-formed. */
 
@@ -363,6 +365,11 @@ impl<N: Network> RegisterTypes<N> {
         // Ensure the opcode is well-formed.
         self.check_instruction_opcode(stack, closure_or_function_name, instruction)?;
 
+        // If the instruction is a `repeat` block, check the block separately.
+        if let Instruction::Repeat(repeat) = instruction {
+            return self.check_repeat(stack, closure_or_function_name, repeat);
+        }
+
         // Initialize a vector to store the register types of the operands.
         let mut operand_types = Vec::with_capacity(instruction.operands().len());
         // Iterate over the operands, and retrieve the register type of each operand.
@@ -386,6 +393,43 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `repeat` block is well-formed.
+    #[inline]
+    fn check_repeat(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure_or_function_name: &Identifier<N>,
+        repeat: &Repeat<N>,
+    ) -> Result<()> {
+        // Ensure the unrolled block does not exceed the maximum number of instructions.
+        let num_unrolled = repeat.body().len().saturating_mul(**repeat.bound() as usize);
+        ensure!(
+            num_unrolled <= N::MAX_INSTRUCTIONS,
+            "The 'repeat' block unrolls to {num_unrolled} instructions, exceeding the maximum of {}",
+            N::MAX_INSTRUCTIONS
+        );
+
+        // Insert the loop-index register, and record the bound of the loop index.
+        self.add_destination(
+            repeat.index().clone(),
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+        )?;
+        self.loop_indices.insert(repeat.index().locator(), **repeat.bound());
+
+        // Check the instructions in the body.
+        for instruction in repeat.body() {
+            // Ensure the body contains no nested blocks, calls, or async instructions.
+            ensure!(
+                !matches!(instruction.opcode(), Opcode::Repeat | Opcode::Async | Opcode::Call | Opcode::CallDynamic),
+                "Instruction '{}' is not allowed in a 'repeat' block",
+                instruction.opcode()
+            );
+            // Check the instruction opcode, operands, and destinations.
+            self.check_instruction(stack, closure_or_function_name, instruction)?;
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the correct instruction.
     /// This method is called when adding a new closure or function to the program.
     #[inline]
@@ -498,6 +542,32 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
+            Opcode::CallDynamic => {
+                // Retrieve the dynamic call operation.
+                let call = match instruction {
+                    Instruction::CallDynamic(call) => call,
+                    _ => bail!("Instruction '{instruction}' is not a dynamic call operation."),
+                };
+                // Ensure the dynamic call is invoked from a function.
+                if !stack.program().contains_function(closure_or_function_name) {
+                    bail!("Closure '{closure_or_function_name}' cannot invoke 'call.dynamic'.")
+                }
+
+                // Retrieve the interface function.
+                let interface = call.interface();
+                // Ensure the interface does not reference the current program.
+                if stack.program_id() == interface.program_id() {
+                    bail!("Interface '{interface}' does not reference an external program.");
+                }
+                // Ensure the current program contains an import for the interface program.
+                if !stack.program().imports().keys().contains(interface.program_id()) {
+                    bail!("External program '{}' is not imported by '{}'.", interface.program_id(), stack.program_id());
+                }
+                // Ensure the interface is a function.
+                if !stack.get_external_program(interface.program_id())?.contains_function(interface.resource()) {
+                    bail!("Interface '{interface}' is not a function.")
+                }
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -577,6 +647,10 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::Deserialize(opcode) => Self::check_deserialize_opcode(stack, opcode, instruction)?,
+            Opcode::ECDSA(opcode) => {
+                bail!("Forbidden operation: Instruction '{instruction}' ('{opcode}') is only supported in finalize.");
+            }
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -589,6 +663,14 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Repeat => {
+                // Ensure the instruction is a `repeat` block.
+                ensure!(
+                    matches!(instruction, Instruction::Repeat(..)),
+                    "Instruction '{instruction}' is not a 'repeat'."
+                );
+            }
+            Opcode::Serialize(opcode) => Self::check_serialize_opcode(opcode, instruction)?,
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -596,6 +678,7 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::String(opcode) => Self::check_string_opcode(opcode, instruction)?,
         }
         Ok(())
     }
@@ -800,4 +883,88 @@ impl<N: Network> RegisterTypes<N> {
         }
         Ok(())
     }
+
+    /// Ensures the opcode is a valid opcode and corresponds to the `serialize` instruction.
+    #[inline]
+    pub(crate) fn check_serialize_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "serialize.bits" => ensure!(
+                matches!(instruction, Instruction::SerializeBits(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "serialize.raw.bits" => ensure!(
+                matches!(instruction, Instruction::SerializeRawBits(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "serialize.bytes" => ensure!(
+                matches!(instruction, Instruction::SerializeBytes(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "serialize.raw.bytes" => ensure!(
+                matches!(instruction, Instruction::SerializeRawBytes(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to the `deserialize` instruction.
+    /// This method also ensures the destination type is defined in the program.
+    #[inline]
+    pub(crate) fn check_deserialize_opcode(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        opcode: &str,
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        // Retrieve the destination type, ensuring the instruction is the correct one.
+        let destination_type = match (opcode, instruction) {
+            ("deserialize.bits", Instruction::DeserializeBits(operation)) => operation.destination_type(),
+            ("deserialize.raw.bits", Instruction::DeserializeRawBits(operation)) => operation.destination_type(),
+            ("deserialize.bytes", Instruction::DeserializeBytes(operation)) => operation.destination_type(),
+            ("deserialize.raw.bytes", Instruction::DeserializeRawBytes(operation)) => operation.destination_type(),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        };
+        // Ensure the destination type is defined.
+        match destination_type {
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name),
+            PlaintextType::Array(array_type) => Self::check_array(stack, array_type),
+        }
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to the `str` instruction.
+    #[inline]
+    pub(crate) fn check_string_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "str.len" => ensure!(
+                matches!(instruction, Instruction::StringLength(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.concat" => ensure!(
+                matches!(instruction, Instruction::StringConcat(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.substr" => ensure!(
+                matches!(instruction, Instruction::StringSubstring(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.at" => ensure!(
+                matches!(instruction, Instruction::StringByteAt(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.to.bytes" => ensure!(
+                matches!(instruction, Instruction::StringToBytes(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.from.bytes" => ensure!(
+                matches!(instruction, Instruction::StringFromBytes(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
 }
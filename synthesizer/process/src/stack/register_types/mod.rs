@@ -42,6 +42,7 @@ use synthesizer_program::{
     Opcode,
     Operand,
     Program,
+    Repeat,
     StackMatches,
     StackProgram,
 };
@@ -55,6 +56,8 @@ pub struct RegisterTypes<N: Network> {
     inputs: IndexMap<u64, RegisterType<N>>,
     /// The mapping of all destination registers to their defined types.
     destinations: IndexMap<u64, RegisterType<N>>,
+    /// The mapping of all loop-index registers to the bounds of their `repeat` blocks.
+    loop_indices: IndexMap<u64, u32>,
 }
 
 impl<N: Network> RegisterTypes<N> {
@@ -100,6 +103,7 @@ impl<N: Network> RegisterTypes<N> {
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
             Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp => bail!("'block.timestamp' is not a valid operand in a non-finalize context."),
         })
     }
 
@@ -162,7 +166,7 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into a record"),
+                        Access::Index(_) | Access::Register(_) => bail!("Attempted to index into a record"),
                     };
                     // Retrieve the entry type from the record.
                     match stack.program().get_record(record_name)?.entries().get(path_name) {
@@ -186,7 +190,7 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into an external record"),
+                        Access::Index(_) | Access::Register(_) => bail!("Attempted to index into an external record"),
                     };
                     // Retrieve the entry type from the external record.
                     match stack.get_external_record(locator)?.entries().get(path_name) {
@@ -223,6 +227,18 @@ impl<N: Network> RegisterTypes<N> {
                         false => bail!("'{index}' is out of bounds for '{register}'"),
                     }
                 }
+                // Traverse the path to output the register type, ensuring every loop index is in bounds.
+                (RegisterRefType::Plaintext(PlaintextType::Array(array_type)), Access::Register(locator)) => {
+                    // Retrieve the bound of the loop index.
+                    let bound = self
+                        .loop_indices
+                        .get(locator)
+                        .ok_or_else(|| anyhow!("Register 'r{locator}' in '{register}' is not a loop index"))?;
+                    match *bound <= **array_type.length() {
+                        true => register_type = RegisterRefType::Plaintext(array_type.next_element_type()),
+                        false => bail!("Loop index 'r{locator}' may be out of bounds for '{register}'"),
+                    }
+                }
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (RegisterRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
@@ -250,9 +266,9 @@ impl<N: Network> RegisterTypes<N> {
                         None => bail!("Index out of bounds"),
                     }
                 }
-                (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..) | Access::Register(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (RegisterRefType::Future(..), Access::Member(..)) => {
+                | (RegisterRefType::Future(..), Access::Member(..) | Access::Register(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
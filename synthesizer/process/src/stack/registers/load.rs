@@ -41,8 +41,13 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
         };
 
+        // Resolve the loop indices in the register.
+        let register = &self.resolve_loop_indices(register)?;
+
         // Retrieve the stack value.
         let stack_value =
             self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
@@ -126,8 +131,13 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
         };
 
+        // Resolve the loop indices in the register.
+        let register = &self.resolve_loop_indices_circuit(register)?;
+
         // Retrieve the circuit value.
         let circuit_value =
             self.circuit_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
@@ -169,3 +179,53 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
         Ok(circuit_value)
     }
 }
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns the register, with each loop-index access replaced by an index access at the current loop index.
+    fn resolve_loop_indices(&self, register: &Register<N>) -> Result<Register<N>> {
+        match register {
+            Register::Access(locator, path) if path.iter().any(|access| matches!(access, Access::Register(..))) => {
+                let path = path
+                    .iter()
+                    .map(|access| match access {
+                        Access::Register(index) => match self.console_registers.get(index) {
+                            Some(Value::Plaintext(Plaintext::Literal(Literal::U32(index), ..))) => {
+                                Ok(Access::Index(*index))
+                            }
+                            _ => bail!("Register 'r{index}' is not a loop index in '{register}'"),
+                        },
+                        access => Ok(*access),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Register::Access(*locator, path))
+            }
+            _ => Ok(register.clone()),
+        }
+    }
+
+    /// Returns the register, with each loop-index access replaced by an index access at the current loop index.
+    /// Note: As the loop index is a constant in the circuit, the resolved access is also a constant.
+    fn resolve_loop_indices_circuit(&self, register: &Register<N>) -> Result<Register<N>> {
+        use circuit::Eject;
+
+        match register {
+            Register::Access(locator, path) if path.iter().any(|access| matches!(access, Access::Register(..))) => {
+                let path = path
+                    .iter()
+                    .map(|access| match access {
+                        Access::Register(index) => match self.circuit_registers.get(index) {
+                            Some(circuit::Value::Plaintext(circuit::Plaintext::Literal(
+                                circuit::Literal::U32(index),
+                                ..,
+                            ))) if index.is_constant() => Ok(Access::Index(index.eject_value())),
+                            _ => bail!("Register 'r{index}' is not a loop index in '{register}'"),
+                        },
+                        access => Ok(*access),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Register::Access(*locator, path))
+            }
+            _ => Ok(register.clone()),
+        }
+    }
+}
@@ -21,7 +21,7 @@ mod store;
 use crate::{CallStack, RegisterTypes, RegistersCall};
 use console::{
     network::prelude::*,
-    program::{Entry, Literal, Plaintext, Register, Value},
+    program::{Access, Entry, Literal, Plaintext, Register, Value},
     types::{Address, Field},
 };
 use synthesizer_program::{
@@ -60,6 +60,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
             Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
+
+    /// Clears every register from the given locator onwards.
+    #[inline]
+    fn rewind(&mut self, locator: u64) {
+        self.console_registers.retain(|register, _| *register < locator);
+    }
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for Registers<N, A> {
@@ -109,4 +115,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
             Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
+
+    /// Clears every circuit register from the given locator onwards.
+    #[inline]
+    fn rewind_circuit(&mut self, locator: u64) {
+        self.circuit_registers.retain(|register, _| *register < locator);
+    }
 }
@@ -14,18 +14,19 @@
 // limitations under the License.
 
 use crate::{
-    traits::{StackEvaluate, StackExecute},
+    traits::{StackEvaluate, StackExecute, StackProgramTypes},
     CallStack,
+    FinalizeRegisters,
     Process,
     Stack,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
-    account::{Address, PrivateKey, ViewKey},
+    account::{Address, ComputeKey, MultisigKey, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Register, Value},
+    types::{Field, U32, U64},
 };
 use ledger_block::{Fee, Transaction};
 use ledger_query::Query;
@@ -36,8 +37,17 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
-use synthesizer_snark::UniversalSRS;
+use synthesizer_program::{
+    Command,
+    FinalizeGlobalState,
+    FinalizeStoreTrait,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersStore,
+    StackProgram,
+};
+use synthesizer_snark::{ProvingKey, UniversalSRS};
 
 use indexmap::IndexMap;
 use parking_lot::RwLock;
@@ -48,7 +58,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, block_height as i64, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -572,6 +582,70 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_execute_distributed_proof() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program testing.aleo;
+
+function compute:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    mul r0 r2 into r3;
+    output r3 as u32.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3u32").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u32").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Prepare the trace.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+
+    // Start two workers of the distributed prover on localhost, which serve a single session each.
+    let workers = (0..2)
+        .map(|_| {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let address = listener.local_addr().unwrap();
+            let worker = ProvingKey::to_prover_worker(&trace.proving_keys());
+            (address, std::thread::spawn(move || worker.serve_session(listener.accept().unwrap().0)))
+        })
+        .collect::<Vec<_>>();
+
+    // Prove the execution, with the witnesses synthesized by the workers.
+    trace.set_prover_workers(workers.iter().map(|(address, _)| *address).collect());
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    for (_, handle) in workers {
+        handle.join().unwrap().unwrap();
+    }
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -695,6 +769,115 @@ fn test_process_multirecords() {
     // assert_eq!(178189, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_multisig_record() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program multisig_token.aleo;
+
+  record token:
+    owner as address.private;
+    amount as u64.private;
+
+  function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a 2-of-3 multisig account.
+    let shared_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let signer_private_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+    let signers = signer_private_keys.iter().map(|private_key| ComputeKey::try_from(private_key).unwrap()).collect();
+    let multisig_key = MultisigKey::new(ComputeKey::try_from(&shared_private_key).unwrap(), 2, signers).unwrap();
+    let multisig_address = multisig_key.to_address();
+
+    // Initialize a recipient account.
+    let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Declare the inputs, spending a record owned by the multisig account.
+    let token = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {multisig_address}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&recipient.to_string()).unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("40u64").unwrap();
+
+    // Authorize the function call, on behalf of the multisig account.
+    let authorization = process
+        .authorize_multisig::<CurrentAleo, _>(
+            &shared_private_key,
+            &multisig_key,
+            program.id(),
+            function_name,
+            [token, r1, r2].iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+    assert!(authorization.transitions().values().all(|transition| transition.is_multisig()));
+
+    // Ensure the authorization cannot be executed before it is cosigned.
+    assert!(process.execute::<CurrentAleo, _>(authorization.replicate(), rng).is_err());
+
+    // Ensure an account that is not a signer cannot cosign the authorization.
+    assert!(authorization.cosign(&shared_private_key, rng).is_err());
+
+    // Cosign the authorization with the first signer.
+    authorization.cosign(&signer_private_keys[0], rng).unwrap();
+    // Ensure the same signer cannot cosign twice.
+    assert!(authorization.replicate().cosign(&signer_private_keys[0], rng).is_err());
+    // Ensure the authorization cannot be executed below the threshold.
+    assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+    assert!(process.execute::<CurrentAleo, _>(authorization.replicate(), rng).is_err());
+
+    // Cosign the authorization with the third signer, reaching the threshold.
+    authorization.cosign(&signer_private_keys[2], rng).unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(2, response.outputs().len());
+
+    // Execute the request.
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+
+    // Ensure the change record is owned by the multisig account.
+    let Value::Record(change) = &candidate[1] else { panic!("Expected a record output") };
+    assert_eq!(**change.owner(), multisig_address);
+
+    // Ensure the transition is a multisig transition, proven with the multisig circuit key.
+    assert_eq!(trace.transitions().len(), 1);
+    assert!(trace.transitions()[0].is_multisig());
+    let stack = process.get_stack(program.id()).unwrap();
+    assert!(stack.contains_multisig_proving_key(&function_name));
+    assert!(!stack.contains_proving_key(&function_name));
+
+    // Ensure a verifier synthesizes the same multisig verifying key locally.
+    let verifier = crate::test_helpers::sample_process(&program);
+    verifier.synthesize_multisig_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    assert_eq!(
+        verifier.get_stack(program.id()).unwrap().get_multisig_verifying_key(&function_name).unwrap(),
+        stack.get_multisig_verifying_key(&function_name).unwrap()
+    );
+}
+
 #[test]
 fn test_process_self_caller() {
     // Initialize a new program.
@@ -1833,161 +2016,417 @@ finalize compute:
 }
 
 #[test]
-fn test_execution_order() {
+fn test_process_execute_and_finalize_length_get_next() {
     // Initialize a new program.
-    let (string, program0) = Program::<CurrentNetwork>::parse(
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program zero.aleo;
+program testing.aleo;
 
-function c:
-    input r0 as u8.private;
-    input r1 as u8.private;
-    add r0 r1 into r2;
-    output r2 as u8.private;",
-    )
-    .unwrap();
-    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+mapping totals:
+    key as u8.public;
+    value as u64.public;
 
-    // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
+mapping counts:
+    key as u8.public;
+    value as u32.public;
 
-    // Initialize another program.
-    let (string, program1) = Program::<CurrentNetwork>::parse(
-        r"
-import zero.aleo;
+mapping sums:
+    key as u8.public;
+    value as u64.public;
 
-program one.aleo;
+function compute:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    async compute r0 r1 into r2;
+    output r2 as testing.aleo/compute.future;
 
-function b:
-    input r0 as u8.private;
-    input r1 as u8.private;
-    call zero.aleo/c r0 r1 into r2;
-    output r2 as u8.private;",
+finalize compute:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    set r1 into totals[r0];
+    add r0 1u8 into r2;
+    add r1 r1 into r3;
+    set r3 into totals[r2];
+    length totals into r4;
+    set r4 into counts[0u8];
+    get.next totals into r5 r6;
+    get.next totals[r5] into r7 r8;
+    add r6 r8 into r9;
+    set r9 into sums[r7];
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Add the program to the process.
-    process.add_program(&program1).unwrap();
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mappings.
+    let counts = Identifier::from_str("counts").unwrap();
+    let sums = Identifier::from_str("sums").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
 
-    // Initialize another program.
-    let (string, program2) = Program::<CurrentNetwork>::parse(
-        r"
-import one.aleo;
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
 
-program two.aleo;
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
-function a:
-    input r0 as u8.private;
-    input r1 as u8.private;
-    call one.aleo/b r0 r1 into r2;
-    output r2 as u8.private;",
-    )
-    .unwrap();
-    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+    // Reset the process.
+    let mut process = Process::load().unwrap();
 
-    // Add the program to the process.
-    process.add_program(&program2).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
 
-    // Initialize the RNG.
-    let rng = &mut TestRng::default();
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
 
-    // Initialize the caller.
+    // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Declare the function name.
-    let function_name = Identifier::from_str("a").unwrap();
-
     // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("2u8").unwrap();
+    let r0 = Value::<CurrentNetwork>::from_str("3u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
 
     // Authorize the function call.
     let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program2.id(), function_name, [r0, r1].iter(), rng)
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
         .unwrap();
-    assert_eq!(authorization.len(), 3);
-    println!("\nAuthorize\n{:#?}\n\n", authorization.to_vec_deque());
-
-    let output = Value::<CurrentNetwork>::from_str("3u8").unwrap();
+    assert_eq!(authorization.len(), 1);
 
     // Compute the output value.
     let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
-    assert_eq!(output, candidate[0]);
 
     // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 3);
+    assert_eq!(authorization.len(), 1);
 
     // Execute the request.
     let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
-    assert_eq!(output, candidate[0]);
-
-    // Construct the expected transition order.
-    let expected_order = [
-        (program0.id(), Identifier::<MainnetV0>::from_str("c").unwrap()),
-        (program1.id(), Identifier::from_str("b").unwrap()),
-        (program2.id(), Identifier::from_str("a").unwrap()),
-    ];
-
-    // Check the expected transition order.
-    for (transition, (expected_program_id, expected_function_name)) in
-        trace.transitions().iter().zip_eq(expected_order.iter())
-    {
-        assert_eq!(transition.program_id(), *expected_program_id);
-        assert_eq!(transition.function_name(), expected_function_name);
-    }
 
-    // Initialize a new block store.
-    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
     // Prepare the trace.
     trace.prepare(Query::from(block_store)).unwrap();
     // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("two", rng).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that both entries were counted.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, counts, &Plaintext::from_str("0u8").unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("2u32").unwrap());
+
+    // Check that the entries were visited in key order, and their values summed under the last key.
+    let candidate =
+        finalize_store.get_value_speculative(*program_id, sums, &Plaintext::from_str("4u8").unwrap()).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("15u64").unwrap());
 }
 
 #[test]
-fn test_complex_execution_order() {
-    // This test checks that the execution order is correct.
-    // The functions are invoked in the following order:
-    // "four::a"
-    //   --> "two::b"
-    //        --> "zero::c"
-    //        --> "one::d"
-    //   --> "three::e"
-    //        --> "two::b"
-    //             --> "zero::c"
-    //             --> "one::d"
-    //        --> "one::d"
-    //        --> "zero::c"
-    // The linearized order is:
-    //  - [a, b, c, d, e, b, c, d, d, c]
-    // The transitions must be included in the `Execution` in the order they finish.
-    // The execution order is:
-    //  - [c, d, b, c, d, b, d, c, e, a]
-
+fn test_process_execute_and_finalize_emit() {
     // Initialize a new program.
-    let (string, program0) = Program::<CurrentNetwork>::parse(
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-    program zero.aleo;
+program testing.aleo;
 
-    function c:
-        input r0 as u8.private;
-        input r1 as u8.private;
-        add r0 r1 into r2;
-        output r2 as u8.private;",
-    )
-    .unwrap();
-    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+struct transfer_event:
+    receiver as address;
+    amount as u64;
 
-    // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer r0 r1 into r2;
+    output r2 as testing.aleo/transfer.future;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+    cast r0 r1 into r4 as transfer_event;
+    emit transfer r4;
+    emit balance r3;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let receiver = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&receiver.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("7u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(1, response.outputs().len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    let (_, events) = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the events were emitted in order, with the expected payloads.
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].program_id(), program.id());
+    assert_eq!(events[0].name(), &Identifier::from_str("transfer").unwrap());
+    assert_eq!(
+        events[0].payload(),
+        &Plaintext::from_str(&format!("{{ receiver: {receiver}, amount: 7u64 }}")).unwrap()
+    );
+    assert_eq!(events[1].program_id(), program.id());
+    assert_eq!(events[1].name(), &Identifier::from_str("balance").unwrap());
+    assert_eq!(events[1].payload(), &Plaintext::from_str("7u64").unwrap());
+
+    // Ensure a finalize may emit at most `MAX_EVENTS` events, with its `repeat` blocks unrolled.
+    let program = |bound: u32| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"program events_{bound}.aleo;
+
+function emit_all:
+    async emit_all into r0;
+    output r0 as events_{bound}.aleo/emit_all.future;
+
+finalize emit_all:
+    repeat {bound}u32 as r0 {{
+        emit index r0;
+        emit again r0;
+    }}"
+        ))
+        .unwrap()
+    };
+    assert_eq!(CurrentNetwork::MAX_EVENTS, 16);
+    process.add_program(&program(8)).unwrap();
+    assert!(process.add_program(&program(9)).is_err());
+}
+
+#[test]
+fn test_execution_order() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program zero.aleo;
+
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import zero.aleo;
+
+program one.aleo;
+
+function b:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call zero.aleo/c r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize another program.
+    let (string, program2) = Program::<CurrentNetwork>::parse(
+        r"
+import one.aleo;
+
+program two.aleo;
+
+function a:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call one.aleo/b r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program2).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("a").unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("2u8").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program2.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 3);
+    println!("\nAuthorize\n{:#?}\n\n", authorization.to_vec_deque());
+
+    let output = Value::<CurrentNetwork>::from_str("3u8").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(output, candidate[0]);
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 3);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(output, candidate[0]);
+
+    // Construct the expected transition order.
+    let expected_order = [
+        (program0.id(), Identifier::<MainnetV0>::from_str("c").unwrap()),
+        (program1.id(), Identifier::from_str("b").unwrap()),
+        (program2.id(), Identifier::from_str("a").unwrap()),
+    ];
+
+    // Check the expected transition order.
+    for (transition, (expected_program_id, expected_function_name)) in
+        trace.transitions().iter().zip_eq(expected_order.iter())
+    {
+        assert_eq!(transition.program_id(), *expected_program_id);
+        assert_eq!(transition.function_name(), expected_function_name);
+    }
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("two", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_complex_execution_order() {
+    // This test checks that the execution order is correct.
+    // The functions are invoked in the following order:
+    // "four::a"
+    //   --> "two::b"
+    //        --> "zero::c"
+    //        --> "one::d"
+    //   --> "three::e"
+    //        --> "two::b"
+    //             --> "zero::c"
+    //             --> "one::d"
+    //        --> "one::d"
+    //        --> "zero::c"
+    // The linearized order is:
+    //  - [a, b, c, d, e, b, c, d, d, c]
+    // The transitions must be included in the `Execution` in the order they finish.
+    // The execution order is:
+    //  - [c, d, b, c, d, b, d, c, e, a]
+
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+    program zero.aleo;
+
+    function c:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        add r0 r1 into r2;
+        output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
 
     // Initialize another program.
     let (string, program1) = Program::<CurrentNetwork>::parse(
@@ -2364,8 +2803,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        registry: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2646,3 +3088,1146 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_upgrade_stack() {
+    // Initialize a `Process`.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add an upgradable program, and a program that imports it.
+    let program = Program::from_str(
+        r"program upgradable.aleo;
+upgrade owner;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+    let dependent = Program::from_str(
+        r"import upgradable.aleo;
+program dependent.aleo;
+
+function quadruple:
+    input r0 as u64.public;
+    call upgradable.aleo/twice r0 into r1;
+    call upgradable.aleo/twice r1 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    process.add_program(&dependent).unwrap();
+
+    // Upgrade the program with a new function, which should succeed.
+    let upgrade = Program::from_str(
+        r"program upgradable.aleo;
+upgrade owner;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function twice:
+    input r0 as u64.public;
+    mul r0 2u64 into r1;
+    output r1 as u64.public;
+
+function thrice:
+    input r0 as u64.public;
+    mul r0 3u64 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    let stack = Stack::new_upgrade(&process, &upgrade).unwrap();
+    assert_eq!(stack.edition(), 1);
+    process.upgrade_stack(stack).unwrap();
+
+    // Ensure the process and the dependent program use the upgraded program.
+    let thrice = Identifier::from_str("thrice").unwrap();
+    assert_eq!(process.get_stack("upgradable.aleo").unwrap().edition(), 1);
+    let external_stack = process.get_stack("dependent.aleo").unwrap().get_external_stack(upgrade.id()).unwrap();
+    assert!(external_stack.program().contains_function(&thrice));
+
+    // Ensure checking a further upgrade does not change the stack that dynamic calls resolve to.
+    let stack = Stack::new_upgrade(&process, &upgrade).unwrap();
+    assert_eq!(stack.edition(), 2);
+    process.upgraded_stacks(stack).unwrap();
+    let registered = process.registry().read().get(upgrade.id()).and_then(std::sync::Weak::upgrade).unwrap();
+    assert!(Arc::ptr_eq(&registered, process.get_stack("upgradable.aleo").unwrap()));
+    assert_eq!(registered.edition(), 1);
+
+    // Ensure an upgrade that changes a function signature fails.
+    let result = Program::from_str(
+        r"program upgradable.aleo;
+upgrade owner;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function twice:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .and_then(|program| Stack::new_upgrade(&process, &program));
+    assert!(result.is_err());
+
+    // Ensure an upgrade that removes a mapping fails.
+    let result = Program::from_str(
+        r"program upgradable.aleo;
+upgrade owner;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+    )
+    .and_then(|program| Stack::new_upgrade(&process, &program));
+    assert!(result.is_err());
+
+    // Ensure an upgrade that imports a dependent program fails.
+    let result = Program::from_str(
+        r"import dependent.aleo;
+program upgradable.aleo;
+upgrade owner;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+    )
+    .and_then(|program| Stack::new_upgrade(&process, &program));
+    assert!(result.is_err());
+
+    // Ensure an immutable program cannot be upgraded.
+    let result = Program::from_str(
+        r"import upgradable.aleo;
+program dependent.aleo;
+
+function quadruple:
+    input r0 as u64.public;
+    call upgradable.aleo/thrice r0 into r1;
+    output r1 as u64.public;",
+    )
+    .and_then(|program| Stack::new_upgrade(&process, &program));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_process_block_timestamp() {
+    // Initialize a `Process`.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add a program that reads the block timestamp in finalize.
+    let program = Program::from_str(
+        r"program timestamp.aleo;
+
+function unlock:
+    input r0 as i64.public;
+    async unlock r0 into r1;
+    output r1 as timestamp.aleo/unlock.future;
+
+finalize unlock:
+    input r0 as i64.public;
+    gte block.timestamp r0 into r1;
+    assert.eq r1 true;",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+
+    // Load the block timestamp from a set of finalize registers.
+    let stack = process.get_stack(program.id()).unwrap();
+    let function_name = Identifier::from_str("unlock").unwrap();
+    let finalize_types = stack.get_finalize_types(&function_name).unwrap().clone();
+    let state = FinalizeGlobalState::from(1, 1, 1_700_000_000, [0u8; 32]);
+    let registers = FinalizeRegisters::new(state, Default::default(), function_name, finalize_types);
+    let value = registers.load(stack.as_ref(), &Operand::BlockTimestamp).unwrap();
+    assert_eq!(value, Value::from_str("1700000000i64").unwrap());
+
+    // Ensure the block timestamp is typed as an `i64`.
+    let program = Program::from_str(
+        r"program timestamp_u32.aleo;
+
+function unlock:
+    async unlock into r0;
+    output r0 as timestamp_u32.aleo/unlock.future;
+
+finalize unlock:
+    add block.timestamp 1u32 into r0;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the block timestamp is not available in a function scope.
+    let program = Program::from_str(
+        r"program timestamp_function.aleo;
+
+function now:
+    add block.timestamp 0i64 into r0;
+    output r0 as i64.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_ecdsa_verify() {
+    // The web3.js `accounts.sign` example for the message "Some data".
+    const ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+    const DIGEST: &str = "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655";
+    const SIGNATURE: &str = "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+
+    /// Returns the given bytes as a `[[u8; chunk_size]; N]` value.
+    fn to_value(bytes: &[u8], chunk_size: usize) -> Value<CurrentNetwork> {
+        let chunks = bytes
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let elements = chunk.iter().map(|byte| format!("{byte}u8")).collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            })
+            .collect::<Vec<_>>();
+        match chunks.len() {
+            1 => Value::from_str(&chunks[0]).unwrap(),
+            _ => Value::from_str(&format!("[{}]", chunks.join(", "))).unwrap(),
+        }
+    }
+
+    /// Returns the EIP-2098 compact encoding of the given `r || s || v` signature.
+    fn to_compact(signature: &[u8]) -> Vec<u8> {
+        let mut compact = signature[0..64].to_vec();
+        compact[32] |= (signature[64] - 27) << 7;
+        compact
+    }
+
+    /// Returns the bytes of the given hex string.
+    fn from_hex(string: &str) -> Vec<u8> {
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    // Initialize a `Process`.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add a program that verifies ECDSA signatures in finalize.
+    let program = Program::from_str(
+        r"program ecdsa_bridge.aleo;
+
+function verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [[u8; 18u32]; 2u32].public;
+    input r3 as [u8; 32u32].public;
+    async verify r0 r1 r2 r3 into r4;
+    output r4 as ecdsa_bridge.aleo/verify.future;
+
+finalize verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [[u8; 18u32]; 2u32].public;
+    input r3 as [u8; 32u32].public;
+    ecdsa.verify.keccak256 r0 r1 r2 into r4;
+    ecdsa.verify.digest r0 r1 r3 into r5;",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+
+    // Evaluates the finalize scope on the given digest, returning the outputs of both instructions.
+    let stack = process.get_stack(program.id()).unwrap();
+    let function_name = Identifier::from_str("verify").unwrap();
+    let evaluate = |digest: &[u8]| {
+        let finalize_types = stack.get_finalize_types(&function_name).unwrap().clone();
+        let state = sample_finalize_state(1);
+        let mut registers = FinalizeRegisters::new(state, Default::default(), function_name, finalize_types);
+
+        // Store the compact signature, the address, the prefixed message, and the digest.
+        let message = [b"\x19Ethereum Signed Message:\n9".as_slice(), b"Some data"].concat();
+        let inputs =
+            [(to_compact(&from_hex(SIGNATURE)), 32), (from_hex(ADDRESS), 20), (message, 18), (digest.to_vec(), 32)];
+        for (index, (input, chunk_size)) in inputs.iter().enumerate() {
+            registers.store(stack.as_ref(), &Register::Locator(index as u64), to_value(input, *chunk_size)).unwrap();
+        }
+
+        // Evaluate the `ecdsa.verify` instructions.
+        let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+        for command in finalize.commands() {
+            match command {
+                Command::Instruction(instruction) => instruction.finalize(stack.as_ref(), &mut registers).unwrap(),
+                _ => unreachable!("The finalize scope only contains instructions"),
+            }
+        }
+        [4, 5].map(|locator| registers.load(stack.as_ref(), &Operand::Register(Register::Locator(locator))).unwrap())
+    };
+
+    // Ensure the signature is valid for both the message and its digest.
+    let [keccak256, digest] = evaluate(&from_hex(DIGEST));
+    assert_eq!(keccak256, Value::from_str("true").unwrap());
+    assert_eq!(digest, Value::from_str("true").unwrap());
+
+    // Ensure the signature is invalid for a tampered digest.
+    let mut tampered = from_hex(DIGEST);
+    tampered[0] ^= 1;
+    let [keccak256, digest] = evaluate(&tampered);
+    assert_eq!(keccak256, Value::from_str("true").unwrap());
+    assert_eq!(digest, Value::from_str("false").unwrap());
+
+    // Ensure the digest variant requires a 32-byte digest.
+    let program = Program::from_str(
+        r"program ecdsa_short.aleo;
+
+function verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 31u32].public;
+    async verify r0 r1 r2 into r3;
+    output r3 as ecdsa_short.aleo/verify.future;
+
+finalize verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 31u32].public;
+    ecdsa.verify.digest r0 r1 r2 into r3;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure `ecdsa.verify` is not available in a function scope.
+    let program = Program::from_str(
+        r"program ecdsa_function.aleo;
+
+function verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 32u32].public;
+    ecdsa.verify.digest r0 r1 r2 into r3;
+    output r3 as boolean.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_serialize_deserialize() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the program.
+    let program = Program::from_str(
+        r"program serialize_test.aleo;
+
+struct pair:
+    a as u8;
+    b as u16;
+
+function roundtrip:
+    input r0 as pair.private;
+    serialize.raw.bytes r0 into r1 as [u8; 3u32];
+    deserialize.raw.bytes r1 into r2 as pair;
+    serialize.bits r0.a into r3 as [[boolean; 17u32]; 2u32];
+    deserialize.bits r3 into r4 as u8;
+    serialize.bytes r0 into r5 as [u8; 19u32];
+    deserialize.bytes r5 into r6 as pair;
+    serialize.raw.bits r0 into r7 as [boolean; 24u32];
+    deserialize.raw.bits r7 into r8 as pair;
+    output r1 as [u8; 3u32].private;
+    output r2 as pair.private;
+    output r4 as u8.private;
+    output r6 as pair.private;
+    output r8 as pair.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Authorize the function call.
+    let input = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: 515u16 }").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("roundtrip").unwrap(),
+            [input.clone()].iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Ensure the serialized literals are little-endian, and that each deserialization recovers the input.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let outputs = response.outputs();
+    assert_eq!(outputs[0], Value::from_str("[1u8, 3u8, 2u8]").unwrap());
+    assert_eq!(outputs[1], input);
+    assert_eq!(outputs[2], Value::from_str("1u8").unwrap());
+    assert_eq!(outputs[3], input);
+    assert_eq!(outputs[4], input);
+
+    // Ensure the circuit is satisfied on the same request.
+    let stack = process.get_stack(program.id()).unwrap();
+    let request = authorization.peek_next().unwrap();
+    let call_stack = CallStack::PackageRun(vec![request], caller_private_key, Default::default(), 0);
+    let response = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
+    assert_eq!(response.outputs(), outputs);
+
+    // Ensure the destination type must match the serialized size.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program serialize_size.aleo;
+
+function serialize:
+    input r0 as u8.private;
+    serialize.bits r0 into r1 as [[boolean; 11u32]; 3u32];
+    output r1 as [[boolean; 11u32]; 3u32].private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the operand must match the serialized size of the destination type.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program deserialize_size.aleo;
+
+function deserialize:
+    input r0 as [u8; 2u32].private;
+    deserialize.raw.bytes r0 into r1 as u8;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure strings cannot be serialized.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program serialize_string.aleo;
+
+function serialize:
+    input r0 as string.private;
+    serialize.raw.bits r0 into r1 as [boolean; 8u32];
+    output r1 as [boolean; 8u32].private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_string_operations() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the program.
+    let program = Program::from_str(
+        r#"program string_test.aleo;
+
+function name:
+    input r0 as string.private;
+    input r1 as u32.private;
+    str.concat r0 ".aleo" into r2;
+    str.len r2 into r3;
+    str.substr r2 0u32 5u32 into r4;
+    str.at r2 r1 into r5;
+    str.to.bytes r2 into r6 as [u8; 12u32];
+    str.from.bytes r6 r3 into r7;
+    output r2 as string.private;
+    output r3 as u32.private;
+    output r4 as string.private;
+    output r5 as u8.private;
+    output r6 as [u8; 12u32].private;
+    output r7 as string.private;"#,
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("\"alice\"").unwrap(), Value::from_str("5u32").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("name").unwrap(),
+            inputs.iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Ensure the outputs are correct.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let outputs = response.outputs();
+    assert_eq!(outputs[0], Value::from_str("\"alice.aleo\"").unwrap());
+    assert_eq!(outputs[1], Value::from_str("10u32").unwrap());
+    assert_eq!(outputs[2], inputs[0]);
+    assert_eq!(outputs[3], Value::from_str("46u8").unwrap());
+    assert_eq!(
+        outputs[4],
+        Value::from_str("[97u8, 108u8, 105u8, 99u8, 101u8, 46u8, 97u8, 108u8, 101u8, 111u8, 0u8, 0u8]").unwrap()
+    );
+    assert_eq!(outputs[5], outputs[0]);
+
+    // Ensure the circuit is satisfied on the same request.
+    let stack = process.get_stack(program.id()).unwrap();
+    let request = authorization.peek_next().unwrap();
+    let call_stack = CallStack::PackageRun(vec![request], caller_private_key, Default::default(), 0);
+    let response = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
+    assert_eq!(response.outputs(), outputs);
+
+    // Ensure the bytes must be a `[u8; N]` array.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program string_from_u16.aleo;
+
+function convert:
+    input r0 as [u16; 2u32].private;
+    str.from.bytes r0 2u32 into r1;
+    output r1 as string.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the bytes must be a one-dimensional `u8` array.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program string_from_nested.aleo;
+
+function convert:
+    input r0 as [[u8; 2u32]; 2u32].private;
+    str.from.bytes r0 2u32 into r1;
+    output r1 as string.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the offsets must be `u32` values.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program string_substr_u8.aleo;
+
+function slice:
+    input r0 as string.private;
+    str.substr r0 0u8 1u8 into r1;
+    output r1 as string.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_call_dynamic() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the interface program.
+    let interface = Program::<CurrentNetwork>::from_str(
+        r"program math_interface.aleo;
+
+function op:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program that implements the interface.
+    let product = Program::<CurrentNetwork>::from_str(
+        r"program math_product.aleo;
+
+function op:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.public;
+
+function op_private:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    // Initialize the program that dynamically calls the interface.
+    let router = Program::<CurrentNetwork>::from_str(
+        r"import math_interface.aleo;
+
+program math_router.aleo;
+
+function route:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as u64.public;
+    input r3 as u64.private;
+    call.dynamic math_interface.aleo/op r0 r1 r2 r3 into r4;
+    output r4 as u64.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&interface).unwrap();
+    process.add_program(&router).unwrap();
+    // Note: The callee is added after the router, as it does not need to be imported.
+    process.add_program(&product).unwrap();
+
+    // Prepare the inputs for a dynamic call to the given function.
+    let inputs = |program_name: &str, function_name: &str| {
+        [
+            Value::<CurrentNetwork>::from(Literal::Field(
+                Identifier::from_str(program_name).unwrap().to_field().unwrap(),
+            )),
+            Value::<CurrentNetwork>::from(Literal::Field(
+                Identifier::from_str(function_name).unwrap().to_field().unwrap(),
+            )),
+            Value::<CurrentNetwork>::from_str("6u64").unwrap(),
+            Value::<CurrentNetwork>::from_str("7u64").unwrap(),
+        ]
+    };
+    let function_name = Identifier::from_str("route").unwrap();
+
+    // Ensure the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(router.id(), &function_name, rng).unwrap();
+
+    // Authorize a dynamic call to 'math_product.aleo/op'.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            router.id(),
+            function_name,
+            inputs("math_product", "op").iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("42u64").unwrap()]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("42u64").unwrap()]);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("math_router", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure a dynamic call to the interface itself succeeds.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            router.id(),
+            function_name,
+            inputs("math_interface", "op").iter(),
+            rng,
+        )
+        .unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("13u64").unwrap()]);
+
+    // Ensure a dynamic call to a function with a different signature fails.
+    let result = process.authorize::<CurrentAleo, _>(
+        &caller_private_key,
+        router.id(),
+        function_name,
+        inputs("math_product", "op_private").iter(),
+        rng,
+    );
+    assert!(result.is_err());
+
+    // Ensure a dynamic call to a program that does not exist fails.
+    let result = process.authorize::<CurrentAleo, _>(
+        &caller_private_key,
+        router.id(),
+        function_name,
+        inputs("math_missing", "op").iter(),
+        rng,
+    );
+    assert!(result.is_err());
+
+    // Ensure a dynamic call cannot be made from a closure.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"import math_interface.aleo;
+
+program math_closure.aleo;
+
+closure route:
+    input r0 as field;
+    input r1 as field;
+    input r2 as u64;
+    input r3 as u64;
+    call.dynamic math_interface.aleo/op r0 r1 r2 r3 into r4;
+    output r4 as u64;
+
+function main:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the interface must be imported.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program math_unimported.aleo;
+
+function route:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as u64.public;
+    input r3 as u64.private;
+    call.dynamic math_interface.aleo/op r0 r1 r2 r3 into r4;
+    output r4 as u64.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_call_dynamic_cycle() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the interface program.
+    let interface = Program::<CurrentNetwork>::from_str(
+        r"program loop_interface.aleo;
+
+function op:
+    input r0 as field.public;
+    input r1 as field.public;
+    output r0 as field.public;",
+    )
+    .unwrap();
+    // Initialize two programs that dynamically call each other, through the interface.
+    let op = Identifier::<CurrentNetwork>::from_str("op").unwrap().to_field().unwrap();
+    let program = |name: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"import loop_interface.aleo;
+
+program {name}.aleo;
+
+function op:
+    input r0 as field.public;
+    input r1 as field.public;
+    call.dynamic loop_interface.aleo/op r0 {op} r1 r0 into r2;
+    output r2 as field.public;"
+        ))
+        .unwrap()
+    };
+    let (ping, pong) = (program("loop_ping"), program("loop_pong"));
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&interface).unwrap();
+    process.add_program(&ping).unwrap();
+    process.add_program(&pong).unwrap();
+
+    // Ensure a cycle of dynamic calls fails, instead of recursing without bound.
+    let inputs = ["loop_pong", "loop_ping"].map(|name| {
+        Value::<CurrentNetwork>::from(Literal::Field(Identifier::from_str(name).unwrap().to_field().unwrap()))
+    });
+    let result = process.authorize::<CurrentAleo, _>(
+        &caller_private_key,
+        ping.id(),
+        Identifier::from_str("op").unwrap(),
+        inputs.iter(),
+        rng,
+    );
+    assert!(result.unwrap_err().to_string().contains("maximum number of transitions"));
+}
+
+#[test]
+fn test_process_call_dynamic_and_finalize() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the program that is called by the callee.
+    let counter = Program::<CurrentNetwork>::from_str(
+        r"program dyn_counter.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function bump:
+    input r0 as address.public;
+    async bump r0 into r1;
+    output r1 as dyn_counter.aleo/bump.future;
+
+finalize bump:
+    input r0 as address.public;
+    get.or_use counts[r0] 0u64 into r1;
+    add r1 1u64 into r2;
+    set r2 into counts[r0];",
+    )
+    .unwrap();
+    // Initialize the interface program.
+    let interface = Program::<CurrentNetwork>::from_str(
+        r"program dyn_interface.aleo;
+
+struct transfer:
+    receiver as address;
+    amount as u64;
+
+function transfer_public:
+    input r0 as transfer.public;
+    async transfer_public r0 into r1;
+    output r1 as dyn_interface.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as transfer.public;
+    assert.neq r0.amount 0u64;",
+    )
+    .unwrap();
+    // Initialize a program that implements the interface, with a finalize scope and a nested call.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"import dyn_counter.aleo;
+
+program dyn_token.aleo;
+
+struct transfer:
+    receiver as address;
+    amount as u64;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function transfer_public:
+    input r0 as transfer.public;
+    call dyn_counter.aleo/bump r0.receiver into r1;
+    async transfer_public r1 r0 into r2;
+    output r2 as dyn_token.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as dyn_counter.aleo/bump.future;
+    input r1 as transfer.public;
+    await r0;
+    get.or_use account[r1.receiver] 0u64 into r2;
+    add r2 r1.amount into r3;
+    set r3 into account[r1.receiver];",
+    )
+    .unwrap();
+    // Initialize the program that dynamically calls the interface, and awaits the future of the callee.
+    let router = Program::<CurrentNetwork>::from_str(
+        r"import dyn_interface.aleo;
+
+program dyn_router.aleo;
+
+struct transfer:
+    receiver as address;
+    amount as u64;
+
+function route:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as transfer.public;
+    call.dynamic dyn_interface.aleo/transfer_public r0 r1 r2 into r3;
+    async route r3 into r4;
+    output r4 as dyn_router.aleo/route.future;
+
+finalize route:
+    input r0 as dyn_interface.aleo/transfer_public.future;
+    await r0;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the programs.
+    for (index, program) in [&counter, &interface, &token, &router].into_iter().enumerate() {
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Compute the fee.
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        // Finalize the deployment.
+        let state = sample_finalize_state(index as u32 + 1);
+        let (stack, _) = process.finalize_deployment(state, &finalize_store, &deployment, &fee).unwrap();
+        // Add the stack *manually* to the process.
+        process.add_stack(stack);
+    }
+
+    // Initialize the caller and the receiver.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let receiver = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Prepare the inputs for a dynamic call to 'dyn_token.aleo/transfer_public'.
+    let inputs = [
+        Value::<CurrentNetwork>::from(Literal::Field(Identifier::from_str("dyn_token").unwrap().to_field().unwrap())),
+        Value::<CurrentNetwork>::from(Literal::Field(
+            Identifier::from_str("transfer_public").unwrap().to_field().unwrap(),
+        )),
+        Value::<CurrentNetwork>::from_str(&format!("{{ receiver: {receiver}, amount: 100u64 }}")).unwrap(),
+    ];
+    let function_name = Identifier::from_str("route").unwrap();
+
+    // Authorize the dynamic call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 3);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs().len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs().len(), 1);
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("dyn_router", rng).unwrap();
+    assert_eq!(execution.len(), 3);
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure the finalize cost is the cost of the callee, and not the cost of the interface.
+    let (_, (_, finalize_cost)) = crate::execution_cost(&process, &execution).unwrap();
+    let token_stack = process.get_stack(token.id()).unwrap();
+    let token_cost =
+        crate::cost_in_microcredits(token_stack, &Identifier::from_str("transfer_public").unwrap()).unwrap();
+    // Note: The finalize scope of the router only contains a single `await`, which costs 500 microcredits.
+    assert_eq!(finalize_cost, token_cost + 500);
+
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(5), &finalize_store, &execution, None).unwrap();
+
+    // Ensure the finalize scope of the callee was run.
+    let key = Plaintext::from(Literal::Address(receiver));
+    let candidate = finalize_store
+        .get_value_speculative(*token.id(), Identifier::from_str("account").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("100u64").unwrap());
+    // Ensure the finalize scope of the nested call was run.
+    let candidate = finalize_store
+        .get_value_speculative(*counter.id(), Identifier::from_str("counts").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("1u64").unwrap());
+}
+
+#[test]
+fn test_process_repeat() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the program.
+    let program = Program::from_str(
+        r"program repeat_test.aleo;
+
+function double_last:
+    input r0 as [u8; 4u32].private;
+    repeat 4u32 as r1 {
+        assert.neq r0[r1] 0u8;
+        mul r0[r1] 2u8 into r2;
+    }
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Authorize the function call.
+    let input = Value::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8, 4u8]").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("double_last").unwrap(),
+            [input].iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Ensure the output is taken from the last iteration.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let outputs = response.outputs();
+    assert_eq!(outputs[0], Value::from_str("8u8").unwrap());
+
+    // Ensure the circuit is satisfied on the same request.
+    let stack = process.get_stack(program.id()).unwrap();
+    let request = authorization.peek_next().unwrap();
+    let call_stack = CallStack::PackageRun(vec![request], caller_private_key, Default::default(), 0);
+    let response = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
+    assert_eq!(response.outputs(), outputs);
+
+    // Ensure every iteration is evaluated.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("double_last").unwrap(),
+            [Value::<CurrentNetwork>::from_str("[1u8, 0u8, 3u8, 4u8]").unwrap()].iter(),
+            rng,
+        )
+        .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+
+    // Ensure the loop index cannot exceed the length of the array.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program repeat_bound.aleo;
+
+function out_of_bounds:
+    input r0 as [u8; 4u32].private;
+    repeat 5u32 as r1 {
+        add r0[r1] 1u8 into r2;
+    }
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure `repeat` blocks cannot be nested.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program repeat_nested.aleo;
+
+function nested:
+    input r0 as [u8; 4u32].private;
+    repeat 2u32 as r1 {
+        repeat 2u32 as r2 {
+            add r0[r2] 1u8 into r3;
+        }
+    }
+    output r3 as u8.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_repeat() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping counts:
+    key as u32.public;
+    value as u8.public;
+
+function tally:
+    input r0 as [u8; 3u32].public;
+    async tally r0 into r1;
+    output r1 as testing.aleo/tally.future;
+
+finalize tally:
+    input r0 as [u8; 3u32].public;
+    repeat 3u32 as r1 {
+        get.or_use counts[r1] 0u8 into r2;
+        add r2 r0[r1] into r3;
+        set r3 into counts[r1];
+        emit count r3;
+    }
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("tally").unwrap();
+    // Declare the mapping name.
+    let mapping_name = Identifier::from_str("counts").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(1, response.outputs().len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    let (_, events) = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that each iteration updated its own entry, and emitted an event.
+    assert_eq!(events.len(), 3);
+    for (index, expected) in ["1u8", "2u8", "3u8"].iter().enumerate() {
+        let candidate = finalize_store
+            .get_value_speculative(*program.id(), mapping_name, &Plaintext::from(Literal::U32(U32::new(index as u32))))
+            .unwrap()
+            .unwrap();
+        assert_eq!(candidate, Value::from_str(expected).unwrap());
+        assert_eq!(events[index].payload(), &Plaintext::from_str(expected).unwrap());
+    }
+
+    // Check that the finalize cost accounts for every iteration.
+    let stack = process.get_stack(program.id()).unwrap();
+    let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+    let repeat_cost = crate::cost_per_command(stack, finalize, &finalize.commands()[0]).unwrap();
+    let body_cost = match &finalize.commands()[0] {
+        Command::Repeat(repeat) => {
+            repeat.body().iter().map(|command| crate::cost_per_command(stack, finalize, command).unwrap()).sum::<u64>()
+        }
+        _ => panic!("Expected a 'repeat' command"),
+    };
+    assert_eq!(repeat_cost, 3 * body_cost);
+}
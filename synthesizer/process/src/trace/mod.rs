@@ -26,21 +26,23 @@ use console::{
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use synthesizer_snark::{PairingCheck, Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr};
 
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
     /// The list of transitions.
     transitions: Vec<Transition<N>>,
-    /// A map of locators to (proving key, assignments) pairs.
-    transition_tasks: HashMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)>,
+    /// A map of (locator, is_multisig) pairs to (proving key, assignments) pairs.
+    transition_tasks: HashMap<(Locator<N>, bool), (ProvingKey<N>, Vec<Assignment<N::Field>>)>,
     /// A tracker for all inclusion tasks.
     inclusion_tasks: Inclusion<N>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// The addresses of the workers of the distributed prover, if the proof is distributed.
+    prover_workers: Vec<SocketAddr>,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
@@ -58,6 +60,7 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            prover_workers: Vec::new(),
         }
     }
 
@@ -70,6 +73,21 @@ impl<N: Network> Trace<N> {
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the proving keys of the circuits in the trace, including the inclusion circuit.
+    /// Note: A worker of the distributed prover must serve these circuits, see `ProvingKey::to_prover_worker`.
+    pub fn proving_keys(&self) -> Vec<ProvingKey<N>> {
+        let mut proving_keys =
+            self.transition_tasks.values().map(|(proving_key, _)| proving_key.clone()).collect::<Vec<_>>();
+        proving_keys.push(ProvingKey::new(N::inclusion_proving_key().clone()));
+        proving_keys
+    }
+
+    /// Sets the addresses of the workers of the distributed prover.
+    /// If any workers are set, the proof is computed with the witnesses synthesized by the workers.
+    pub fn set_prover_workers(&mut self, prover_workers: Vec<SocketAddr>) {
+        self.prover_workers = prover_workers;
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -91,7 +109,12 @@ impl<N: Network> Trace<N> {
         // Construct the locator.
         let locator = Locator::new(*transition.program_id(), *transition.function_name());
         // Insert the assignment (and proving key if the entry does not exist), for the specified locator.
-        self.transition_tasks.entry(locator).or_insert((proving_key, vec![])).1.push(assignment);
+        // Note: A multisig transition is proven with a separate circuit key, so it is batched separately.
+        self.transition_tasks
+            .entry((locator, transition.is_multisig()))
+            .or_insert((proving_key, vec![]))
+            .1
+            .push(assignment);
         // Insert the transition into the list.
         self.transitions.push(transition.clone());
         // Insert the call metrics into the list.
@@ -169,8 +192,14 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+            locator,
+            proving_tasks,
+            inclusion_assignments,
+            *global_state_root,
+            &self.prover_workers,
+            rng,
+        )?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
@@ -202,6 +231,7 @@ impl<N: Network> Trace<N> {
             proving_tasks,
             inclusion_assignments,
             *global_state_root,
+            &self.prover_workers,
             rng,
         )?;
         // Return the fee.
@@ -215,6 +245,34 @@ impl<N: Network> Trace<N> {
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
     ) -> Result<()> {
+        // Check the execution proof, deferring the pairing check.
+        let pairing_check = Self::prepare_execution_proof(locator, verifier_inputs, execution)?;
+        // Verify the pairing check.
+        match pairing_check.verify() {
+            true => Ok(()),
+            false => bail!("Execution is invalid - Failed to verify proof - '{locator}'"),
+        }
+    }
+
+    /// Checks the proof for the fee.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+        // Check the fee proof, deferring the pairing check.
+        let pairing_check = Self::prepare_fee_proof(verifier_inputs, fee)?;
+        // Verify the pairing check.
+        match pairing_check.verify() {
+            true => Ok(()),
+            false => bail!("Fee is invalid - Failed to verify proof"),
+        }
+    }
+
+    /// Checks the proof for the execution, except for the final pairing check, which is returned instead.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn prepare_execution_proof(
+        locator: &str,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+    ) -> Result<PairingCheck<N>> {
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
         // Ensure the global state root is not zero.
@@ -223,16 +281,19 @@ impl<N: Network> Trace<N> {
         }
         // Retrieve the proof.
         let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
-        // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
-            Ok(()) => Ok(()),
+        // Check the execution proof.
+        match Self::prepare_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
+            Ok(pairing_check) => Ok(pairing_check),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
     }
 
-    /// Checks the proof for the fee.
+    /// Checks the proof for the fee, except for the final pairing check, which is returned instead.
     /// Note: This does *not* check that the global state root exists in the ledger.
-    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+    pub fn prepare_fee_proof(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+    ) -> Result<PairingCheck<N>> {
         // Retrieve the global state root.
         let global_state_root = fee.global_state_root();
         // Ensure the global state root is not zero.
@@ -241,15 +302,15 @@ impl<N: Network> Trace<N> {
         }
         // Retrieve the proof.
         let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
-        // Verify the fee proof.
-        match Self::verify_batch(
+        // Check the fee proof.
+        match Self::prepare_batch(
             "credits.aleo/fee (private or public)",
             vec![verifier_inputs],
             global_state_root,
             [fee.transition()].into_iter(),
             proof,
         ) {
-            Ok(()) => Ok(()),
+            Ok(pairing_check) => Ok(pairing_check),
             Err(e) => bail!("Fee is invalid - {e}"),
         }
     }
@@ -262,6 +323,7 @@ impl<N: Network> Trace<N> {
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
         global_state_root: N::StateRoot,
+        prover_workers: &[SocketAddr],
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
         // Ensure the global state root is not zero.
@@ -290,21 +352,24 @@ impl<N: Network> Trace<N> {
             proving_tasks.push((proving_key, batch_inclusions));
         }
 
-        // Compute the proof.
-        let proof = ProvingKey::prove_batch(locator, &proving_tasks, rng)?;
+        // Compute the proof, with the witnesses synthesized by the workers if the proof is distributed.
+        let proof = match prover_workers.is_empty() {
+            true => ProvingKey::prove_batch(locator, &proving_tasks, rng)?,
+            false => ProvingKey::prove_batch_distributed(locator, &proving_tasks, prover_workers, rng)?,
+        };
         // Return the global state root and proof.
         Ok((global_state_root, proof))
     }
 
-    /// Checks the proof for the given inputs.
+    /// Checks the proof for the given inputs, except for the final pairing check, which is returned instead.
     /// Note: This does *not* check that the global state root exists in the ledger.
-    fn verify_batch<'a>(
+    fn prepare_batch<'a>(
         locator: &str,
         mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
-    ) -> Result<()> {
+    ) -> Result<PairingCheck<N>> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
@@ -318,7 +383,8 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((VerifyingKey::<N>::new(verifying_key, num_variables), batch_inclusion_inputs));
         }
-        // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        // Check the proof, deferring the pairing check.
+        VerifyingKey::prepare_verify_batch(locator, verifier_inputs, proof)
+            .map_err(|e| anyhow!("Failed to verify proof - {e}"))
     }
 }
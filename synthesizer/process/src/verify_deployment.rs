@@ -25,13 +25,8 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
 
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
-
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -41,6 +36,58 @@ impl<N: Network> Process<N> {
         finish!(timer);
         verification
     }
+
+    /// Checks the given deployment is well-formed, except for the final pairing checks of the certificates,
+    /// which are returned instead.
+    #[inline]
+    pub fn prepare_verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<PairingCheck<N>>> {
+        let timer = timer!("Process::prepare_verify_deployment");
+
+        // Ensure the program is well-formed, by computing the stack.
+        let stack = self.deployment_stack(deployment)?;
+        lap!(timer, "Compute the stack");
+
+        // Ensure the verifying keys are well-formed, and prepare the pairing checks of the certificates.
+        let pairing_checks = stack.prepare_verify_deployment::<A, R>(deployment, rng);
+        lap!(timer, "Prepare the deployment");
+
+        finish!(timer);
+        pairing_checks
+    }
+
+    /// Returns the stack for the given deployment, ensuring the deployment edition is the next edition of the program.
+    #[inline]
+    pub(crate) fn deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        // Retrieve the program ID.
+        let program_id = deployment.program().id();
+        // Compute the stack.
+        let stack = match deployment.edition() == N::EDITION {
+            true => {
+                // Ensure the program does not already exist in the process.
+                ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+                Stack::new(self, deployment.program())?
+            }
+            false => {
+                let stack = Stack::new_upgrade(self, deployment.program())?;
+                // Ensure the programs that import the program remain valid after the upgrade.
+                // Note: The upgraded stacks are discarded, and are only registered once the deployment is finalized.
+                self.upgraded_stacks(stack.clone())?;
+                stack
+            }
+        };
+        // Ensure the deployment edition matches the stack edition.
+        ensure!(
+            stack.edition() == deployment.edition(),
+            "Invalid edition for program '{program_id}' (expected {}, found {})",
+            stack.edition(),
+            deployment.edition()
+        );
+        Ok(stack)
+    }
 }
 
 #[cfg(test)]
@@ -20,7 +20,18 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
-        let timer = timer!("Process::verify_execution");
+        // Check the execution, deferring the pairing check of the proof.
+        let pairing_check = self.prepare_verify_execution(execution)?;
+        // Verify the pairing check.
+        ensure!(pairing_check.verify(), "Execution is invalid - Failed to verify proof");
+        Ok(())
+    }
+
+    /// Checks the given execution is valid, except for the final pairing check of the proof, which is returned instead.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn prepare_verify_execution(&self, execution: &Execution<N>) -> Result<PairingCheck<N>> {
+        let timer = timer!("Process::prepare_verify_execution");
 
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
@@ -32,9 +43,12 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(transition.program_id())?;
             // Ensure the number of calls matches the number of transitions.
+            // Note: If the function invokes a dynamic call, the number of calls is a lower bound,
+            // and the number of transitions is checked when constructing the call graph.
             let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
             ensure!(
-                number_of_calls == execution.len(),
+                number_of_calls == execution.len()
+                    || (stack.contains_dynamic_calls(transition.function_name()) && number_of_calls < execution.len()),
                 "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
                 execution.len()
             );
@@ -60,11 +74,13 @@ impl<N: Network> Process<N> {
             #[cfg(debug_assertions)]
             println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
-            debug_assert_eq!(
-                **transition.id(),
-                N::hash_bhp512(&(transition.to_root()?, *transition.tcm()).to_bits_le())?,
-                "The transition ID is incorrect"
-            );
+            // Note: A multisig transition appends a flag to the preimage of its ID.
+            #[cfg(debug_assertions)]
+            {
+                let mut preimage = (transition.to_root()?, *transition.tcm()).to_bits_le();
+                preimage.extend(transition.is_multisig().then_some(true));
+                debug_assert_eq!(**transition.id(), N::hash_bhp512(&preimage)?, "The transition ID is incorrect");
+            }
 
             // Ensure the transition is not a fee transition.
             let is_fee_transition = transition.is_fee_private() || transition.is_fee_public();
@@ -120,11 +136,16 @@ impl<N: Network> Process<N> {
             let inputs = self.to_transition_verifier_inputs(transition, parent, &call_graph, &mut transition_map)?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
+            // Retrieve the verifying key, which differs for a transition signed by a multisig account.
+            let verifying_key = match transition.is_multisig() {
+                true => stack.get_multisig_verifying_key(function.name())?,
+                false => stack.get_verifying_key(function.name())?,
+            };
             // Save the verifying key and its inputs.
             verifier_inputs
-                .entry(Locator::new(*stack.program_id(), *function.name()))
+                .entry((Locator::new(*stack.program_id(), *function.name()), transition.is_multisig()))
                 // Retrieve the verifying key, if it does not already exist.
-                .or_insert((stack.get_verifying_key(function.name())?, vec![]))
+                .or_insert((verifying_key, vec![]))
                 .1
                 .push(inputs);
             lap!(timer, "Stored the verifier inputs for a transition of {}", function.name());
@@ -150,13 +171,13 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        // Check the execution proof, deferring the pairing check.
+        let pairing_check = Trace::prepare_execution_proof(&locator, verifier_inputs, execution)?;
 
-        lap!(timer, "Verify the proof");
+        lap!(timer, "Prepare the proof");
 
         finish!(timer);
-        Ok(())
+        Ok(pairing_check)
     }
 }
 
@@ -189,11 +210,37 @@ impl<N: Network> Process<N> {
         // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*is_root, *parent_x, *parent_y]);
 
+        // Determine which of the function calls are dynamic calls, in the order they are called.
+        let stack = self.get_stack(transition.program_id())?;
+        let mut is_dynamic_calls = Vec::new();
+        for instruction in stack.get_function_ref(transition.function_name())?.instructions() {
+            match instruction {
+                Instruction::Call(call) => {
+                    if call.is_function_call(stack.as_ref())? {
+                        is_dynamic_calls.push(false);
+                    }
+                }
+                Instruction::CallDynamic(..) => is_dynamic_calls.push(true),
+                _ => (),
+            }
+        }
+
         // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
+        let transition_ids = call_graph.get(transition.id()).unwrap();
+        ensure!(transition_ids.len() == is_dynamic_calls.len(), "The number of function calls is incorrect");
+        for (transition_id, is_dynamic_call) in transition_ids.iter().zip_eq(is_dynamic_calls) {
             // Note: This unwrap is safe, as we are processing transitions in post-order,
             // which implies that all child transition IDs have been added to `transition_map`.
             let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            // [Inputs] Extend the verifier inputs with the program name, function name, and function ID of the dynamic call.
+            if is_dynamic_call {
+                let (program_id, function_name) = (transition.program_id(), transition.function_name());
+                inputs.extend([
+                    *program_id.name().to_field()?,
+                    *function_name.to_field()?,
+                    *compute_function_id(&U16::new(N::ID), program_id, function_name)?,
+                ]);
+            }
             // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
             inputs.extend([**transition.tcm()]);
             // [Inputs] Extend the verifier inputs with the input IDs of the external call.
@@ -245,13 +292,22 @@ impl<N: Network> Process<N> {
             fname: Identifier<N>,
             tid: Option<N::TransitionID>,
             children: Option<Vec<usize>>,
+            /// The caller and interface function, if this is the callee of a dynamic call.
+            dynamic: Option<(ProgramID<N>, Locator<N>)>,
         }
 
         impl<N: Network> TransitionMetadata<N> {
             fn new(counter: &mut usize, pid: ProgramID<N>, fname: Identifier<N>, tid: Option<N::TransitionID>) -> Self {
                 let uid = *counter;
                 *counter += 1;
-                Self { uid, pid, fname, tid, children: None }
+                Self { uid, pid, fname, tid, children: None, dynamic: None }
+            }
+
+            /// Initializes the metadata for the callee of a dynamic call, which is resolved from its transition.
+            fn new_dynamic(counter: &mut usize, caller: ProgramID<N>, interface: Locator<N>) -> Self {
+                let mut metadata = Self::new(counter, *interface.program_id(), *interface.resource(), None);
+                metadata.dynamic = Some((caller, interface));
+                metadata
             }
 
             /// Returns 'true' if the subgraph starting from this transition has been fully-indexed.
@@ -311,9 +367,30 @@ impl<N: Network> Process<N> {
                     ));
                 }
                 // If the stack is not empty, then add the current transition ID to the entry.
-                Some(head) => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
-                    true => head.tid = Some(*transition.id()),
-                    false => bail!("Invalid traversal - unexpected transition in the execution"),
+                Some(head) => match head.dynamic {
+                    // If the entry is the callee of a dynamic call, then resolve it from the transition.
+                    Some((caller, interface)) => {
+                        // Ensure the callee is not the caller.
+                        ensure!(caller != *transition.program_id(), "Invalid traversal - dynamic call to the caller");
+                        // Ensure the callee matches the interface.
+                        let interface_stack = self.get_stack(interface.program_id())?;
+                        let interface = interface_stack.get_function_ref(interface.resource())?;
+                        let callee = self.get_stack(transition.program_id())?;
+                        check_dynamic_callee(
+                            interface_stack.as_ref(),
+                            interface,
+                            callee.as_ref(),
+                            transition.function_name(),
+                        )?;
+                        // Update the entry.
+                        head.pid = *transition.program_id();
+                        head.fname = *transition.function_name();
+                        head.tid = Some(*transition.id());
+                    }
+                    None => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
+                        true => head.tid = Some(*transition.id()),
+                        false => bail!("Invalid traversal - unexpected transition in the execution"),
+                    },
                 },
             }
 
@@ -332,17 +409,24 @@ impl<N: Network> Process<N> {
                 // Collect the children of the current transition.
                 let mut children = Vec::new();
                 for instruction in function.instructions() {
-                    if let Instruction::Call(call) = instruction {
-                        let (pid, fname) = match call.operator() {
-                            synthesizer_program::CallOperator::Locator(locator) => {
-                                (locator.program_id(), locator.resource())
+                    match instruction {
+                        Instruction::Call(call) => {
+                            let (pid, fname) = match call.operator() {
+                                synthesizer_program::CallOperator::Locator(locator) => {
+                                    (locator.program_id(), locator.resource())
+                                }
+                                synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
+                            };
+                            // Add the child to the traversal stack, only if it is a call to a transition.
+                            if self.get_stack(pid)?.get_function(fname).is_ok() {
+                                children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                             }
-                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
-                        };
-                        // Add the child to the traversal stack, only if it is a call to a transition.
-                        if self.get_stack(pid)?.get_function(fname).is_ok() {
-                            children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                         }
+                        // Add the callee of a dynamic call to the traversal stack.
+                        Instruction::CallDynamic(call) => {
+                            children.push(TransitionMetadata::new_dynamic(&mut counter, top.pid, *call.interface()));
+                        }
+                        _ => (),
                     }
                 }
 
@@ -20,7 +20,18 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
-        let timer = timer!("Process::verify_fee");
+        // Check the fee, deferring the pairing check of the proof.
+        let pairing_check = self.prepare_verify_fee(fee, deployment_or_execution_id)?;
+        // Verify the pairing check.
+        ensure!(pairing_check.verify(), "Fee is invalid - Failed to verify proof");
+        Ok(())
+    }
+
+    /// Checks the given fee is valid, except for the final pairing check of the proof, which is returned instead.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn prepare_verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<PairingCheck<N>> {
+        let timer = timer!("Process::prepare_verify_fee");
 
         #[cfg(debug_assertions)]
         {
@@ -51,6 +62,8 @@ impl<N: Network> Process<N> {
         ensure!(fee.inputs().len() <= N::MAX_INPUTS, "Fee exceeded maximum number of inputs");
         // Ensure the number of outputs is within the allowed range.
         ensure!(fee.outputs().len() <= N::MAX_INPUTS, "Fee exceeded maximum number of outputs");
+        // Ensure the fee is not signed by a multisig account.
+        ensure!(!fee.is_multisig(), "The fee transition cannot be signed by a multisig account");
 
         // Retrieve the candidate deployment or execution ID.
         let Ok(candidate_id) = fee.deployment_or_execution_id() else {
@@ -62,20 +75,20 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the deployment or execution ID");
 
-        // Verify the fee transition is well-formed.
-        match is_fee_private {
-            true => self.verify_fee_private(&fee)?,
-            false => self.verify_fee_public(&fee)?,
-        }
-        finish!(timer, "Verify the fee transition");
-        Ok(())
+        // Check the fee transition is well-formed.
+        let pairing_check = match is_fee_private {
+            true => self.prepare_verify_fee_private(&fee)?,
+            false => self.prepare_verify_fee_public(&fee)?,
+        };
+        finish!(timer, "Check the fee transition");
+        Ok(pairing_check)
     }
 }
 
 impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
-        let timer = timer!("Process::verify_fee_private");
+    /// Checks the transition for `credits.aleo/fee_private` is well-formed, except for the final pairing check of the proof.
+    fn prepare_verify_fee_private(&self, fee: &&Fee<N>) -> Result<PairingCheck<N>> {
+        let timer = timer!("Process::prepare_verify_fee_private");
 
         // Retrieve the network ID.
         let network_id = U16::new(N::ID);
@@ -135,16 +148,16 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        // Check the fee proof, deferring the pairing check.
+        let pairing_check = Trace::prepare_fee_proof((verifying_key, vec![inputs]), fee)?;
+        finish!(timer, "Prepare the fee proof");
+        Ok(pairing_check)
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Checks the transition for `credits.aleo/fee_public` is well-formed, except for the final pairing check of the proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
-        let timer = timer!("Process::verify_fee_public");
+    fn prepare_verify_fee_public(&self, fee: &&Fee<N>) -> Result<PairingCheck<N>> {
+        let timer = timer!("Process::prepare_verify_fee_public");
 
         // Retrieve the network ID.
         let network_id = U16::new(N::ID);
@@ -204,10 +217,10 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        // Check the fee proof, deferring the pairing check.
+        let pairing_check = Trace::prepare_fee_proof((verifying_key, vec![inputs]), fee)?;
+        finish!(timer, "Prepare the fee proof");
+        Ok(pairing_check)
     }
 }
 
@@ -249,8 +262,8 @@ mod tests {
                     process.verify_fee(&fee.unwrap(), execution_id).unwrap();
                 }
                 Transaction::Fee(_, fee) => match fee.is_fee_private() {
-                    true => process.verify_fee_private(&&fee).unwrap(),
-                    false => process.verify_fee_public(&&fee).unwrap(),
+                    true => assert!(process.prepare_verify_fee_private(&&fee).unwrap().verify()),
+                    false => assert!(process.prepare_verify_fee_public(&&fee).unwrap().verify()),
                 },
             }
         }
@@ -78,7 +78,7 @@
 //     finalize_string.push_str(&finalize_body.to_string());
 //     let finalize = Finalize::<MainnetV0>::from_str(&finalize_string).unwrap();
 //     // Construct the finalize state.
-//     let state = FinalizeGlobalState::new::<MainnetV0>(0, 0, 0, 0, <MainnetV0 as Network>::BlockHash::default()).unwrap();
+//     let state = FinalizeGlobalState::new::<MainnetV0>(0, 0, 0, 0, 0, <MainnetV0 as Network>::BlockHash::default()).unwrap();
 //     // Initialize a fresh set of finalize registers.
 //     let mut registers = FinalizeRegisters::new(state, <MainnetV0 as Network>::TransitionID::default(), Identifier::from_str("test").unwrap(),  FinalizeTypes::from_finalize(stack, &finalize).unwrap());
 //     // Add the arguments into the registers.
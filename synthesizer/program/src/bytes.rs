@@ -22,12 +22,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid program version"));
         }
 
         // Read the program ID.
         let id = ProgramID::read_le(&mut reader)?;
+        // Read the upgrade policy.
+        // Note: Version 1 programs are immutable, and version 2 programs declare an upgrade policy.
+        let upgrade_policy = match version {
+            1 => UpgradePolicy::Immutable,
+            _ => match UpgradePolicy::read_le(&mut reader)? {
+                UpgradePolicy::Immutable => return Err(error("Invalid upgrade policy for a version 2 program")),
+                upgrade_policy => upgrade_policy,
+            },
+        };
 
         // Initialize the program.
         let mut program = ProgramCore::new(id).map_err(|e| error(e.to_string()))?;
@@ -60,6 +69,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
         }
+        // Set the upgrade policy, as it may refer to a mapping.
+        program.set_upgrade_policy(upgrade_policy).map_err(|e| error(e.to_string()))?;
 
         Ok(program)
     }
@@ -69,11 +80,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
     for ProgramCore<N, Instruction, Command>
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-
-        // Write the program ID.
-        self.id.write_le(&mut writer)?;
+        // Write the version, and the program ID.
+        // Note: The version 1 format is retained for immutable programs.
+        match self.upgrade_policy.is_immutable() {
+            true => {
+                1u8.write_le(&mut writer)?;
+                self.id.write_le(&mut writer)?;
+            }
+            false => {
+                2u8.write_le(&mut writer)?;
+                self.id.write_le(&mut writer)?;
+                self.upgrade_policy.write_le(&mut writer)?;
+            }
+        }
 
         // Write the number of program imports.
         u8::try_from(self.imports.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
@@ -173,4 +192,32 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_upgrade_policy() -> Result<()> {
+        let program = r"
+program token.aleo;
+upgrade mapping approvals;
+
+mapping approvals:
+    key as u16.public;
+    value as address.public;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let expected = Program::<CurrentNetwork>::from_str(program)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the upgradable program is written in version 2.
+        assert_eq!(expected_bytes[0], 2);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(candidate.upgrade_policy(), &UpgradePolicy::Mapping(Identifier::from_str("approvals")?));
+
+        Ok(())
+    }
 }
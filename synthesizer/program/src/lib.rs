@@ -43,6 +43,9 @@ pub use mapping::*;
 pub mod traits;
 pub use traits::*;
 
+mod upgrade;
+pub use upgrade::*;
+
 mod bytes;
 mod parse;
 mod serialize;
@@ -60,6 +63,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -110,6 +114,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     id: ProgramID<N>,
     /// A map of the declared imports for the program.
     imports: IndexMap<ProgramID<N>, Import<N>>,
+    /// The declared upgrade policy for the program.
+    upgrade_policy: UpgradePolicy<N>,
     /// A map of identifiers to their program declaration.
     identifiers: IndexMap<Identifier<N>, ProgramDefinition>,
     /// A map of the declared mappings for the program.
@@ -134,6 +140,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(Self {
             id,
             imports: IndexMap::new(),
+            upgrade_policy: UpgradePolicy::default(),
             identifiers: IndexMap::new(),
             mappings: IndexMap::new(),
             structs: IndexMap::new(),
@@ -159,6 +166,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.imports
     }
 
+    /// Returns the upgrade policy of the program.
+    pub const fn upgrade_policy(&self) -> &UpgradePolicy<N> {
+        &self.upgrade_policy
+    }
+
     /// Returns the mappings in the program.
     pub const fn mappings(&self) -> &IndexMap<Identifier<N>, Mapping<N>> {
         &self.mappings
@@ -287,6 +299,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Sets the upgrade policy of the program.
+    ///
+    /// # Errors
+    /// This method will halt if the policy refers to a mapping that is not defined as `u16 => address`.
+    #[inline]
+    fn set_upgrade_policy(&mut self, upgrade_policy: UpgradePolicy<N>) -> Result<()> {
+        // Ensure the upgrade mapping is defined in the program.
+        if let UpgradePolicy::Mapping(name) = &upgrade_policy {
+            UpgradePolicy::check_mapping(&self.get_mapping(name)?)?;
+        }
+        // Set the upgrade policy.
+        self.upgrade_policy = upgrade_policy;
+        Ok(())
+    }
+
     /// Adds a new import statement to the program.
     ///
     /// # Errors
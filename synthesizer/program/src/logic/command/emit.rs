@@ -0,0 +1,187 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    Event,
+    Opcode,
+    Operand,
+};
+use console::{network::prelude::*, program::Identifier};
+
+/// An emit command, e.g. `emit transfer r0;`
+/// Records the `operand` as the payload of an event with the given `name`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Emit<N: Network> {
+    /// The event name.
+    name: Identifier<N>,
+    /// The event payload.
+    operand: Operand<N>,
+}
+
+impl<N: Network> Emit<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("emit")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.operand.clone()]
+    }
+
+    /// Returns the event name.
+    #[inline]
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the operand containing the event payload.
+    #[inline]
+    pub const fn operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+}
+
+impl<N: Network> Emit<N> {
+    /// Finalizes the command, returning the emitted event.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<Event<N>> {
+        // Load the payload.
+        let payload = registers.load_plaintext(stack, &self.operand)?;
+        // Return the event.
+        Ok(Event::new(*stack.program_id(), self.name, payload))
+    }
+}
+
+impl<N: Network> Parser for Emit<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the event name from the string.
+        let (string, name) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { name, operand }))
+    }
+}
+
+impl<N: Network> FromStr for Emit<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Emit<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Emit<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command, event name, and operand.
+        write!(f, "{} {} {};", Self::opcode(), self.name, self.operand)
+    }
+}
+
+impl<N: Network> FromBytes for Emit<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the event name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { name, operand })
+    }
+}
+
+impl<N: Network> ToBytes for Emit<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the event name.
+        self.name.write_le(&mut writer)?;
+        // Write the operand.
+        self.operand.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit transfer r0;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.name, Identifier::from_str("transfer").unwrap());
+        assert_eq!(emit.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(emit.operand, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit minted 100u64;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.name, Identifier::from_str("minted").unwrap());
+        assert_eq!(emit.operand, Operand::from_str("100u64").unwrap(), "The first operand is incorrect");
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit transfer r0;").unwrap();
+        assert!(string.is_empty());
+        let bytes_le = emit.to_bytes_le().unwrap();
+        let result = Emit::<CurrentNetwork>::from_bytes_le(&bytes_le[..]);
+        assert!(result.is_ok())
+    }
+}
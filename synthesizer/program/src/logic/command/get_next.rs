@@ -0,0 +1,304 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    CallOperator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Register, Value},
+};
+
+/// A get next command, e.g. `get.next accounts[r0] into r1 r2;`.
+/// Gets the (`key`, `value`) entry that follows the `key` operand in `mapping`, and stores the results in
+/// `key_destination` and `value_destination`. If the `key` operand is omitted, e.g. `get.next accounts into r1 r2;`,
+/// the first entry in `mapping` is retrieved instead. If there is no such entry, then `get.next` will halt.
+///
+/// Entries are ordered by the serialized bytes of their keys, so the order is identical on every validator.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GetNext<N: Network> {
+    /// The mapping name.
+    mapping: CallOperator<N>,
+    /// The key to start after, if any.
+    key: Option<Operand<N>>,
+    /// The destination register for the key.
+    key_destination: Register<N>,
+    /// The destination register for the value.
+    value_destination: Register<N>,
+}
+
+impl<N: Network> GetNext<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("get.next")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        self.key.iter().cloned().collect()
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the key to start after, if any.
+    #[inline]
+    pub const fn key(&self) -> Option<&Operand<N>> {
+        self.key.as_ref()
+    }
+
+    /// Returns the destination register for the key.
+    #[inline]
+    pub const fn key_destination(&self) -> &Register<N> {
+        &self.key_destination
+    }
+
+    /// Returns the destination register for the value.
+    #[inline]
+    pub const fn value_destination(&self) -> &Register<N> {
+        &self.value_destination
+    }
+}
+
+impl<N: Network> GetNext<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Load the operand as a plaintext, if one is given.
+        let key = match &self.key {
+            Some(key) => Some(registers.load_plaintext(stack, key)?),
+            None => None,
+        };
+
+        // Retrieve the next entry from storage.
+        let (next_key, value) = match store.get_next_key_value_speculative(program_id, mapping_name, key.as_ref())? {
+            Some((next_key, Value::Plaintext(plaintext))) => (next_key, Value::Plaintext(plaintext)),
+            Some((_, Value::Record(..))) => bail!("Cannot 'get.next' a 'record'"),
+            Some((_, Value::Future(..))) => bail!("Cannot 'get.next' a 'future'"),
+            // If there is no next entry, then bail.
+            None => match key {
+                Some(key) => bail!("No entry follows key '{key}' in mapping '{program_id}/{mapping_name}'"),
+                None => bail!("Mapping '{program_id}/{mapping_name}' is empty"),
+            },
+        };
+
+        // Assign the key and value to the destination registers.
+        registers.store(stack, &self.key_destination, Value::Plaintext(next_key))?;
+        registers.store(stack, &self.value_destination, value)?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for GetNext<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses the optional key operand, e.g. `[r0]`.
+        fn parse_key<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the key operand from the string.
+            let (string, key) = Operand::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            Ok((string, key))
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+        // Parse the key operand from the string, if it is present.
+        let (string, key) = opt(parse_key)(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key destination register from the string.
+        let (string, key_destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the value destination register from the string.
+        let (string, value_destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, key, key_destination, value_destination }))
+    }
+}
+
+impl<N: Network> FromStr for GetNext<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for GetNext<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for GetNext<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command and mapping.
+        write!(f, "{} {}", Self::opcode(), self.mapping)?;
+        // Print the key operand, if it is present.
+        if let Some(key) = &self.key {
+            write!(f, "[{key}]")?;
+        }
+        // Print the destination registers.
+        write!(f, " into {} {};", self.key_destination, self.value_destination)
+    }
+}
+
+impl<N: Network> FromBytes for GetNext<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the key operand, if it is present.
+        let key = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Operand::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid key variant for 'get.next': {variant}"))),
+        };
+        // Read the destination registers.
+        let key_destination = Register::read_le(&mut reader)?;
+        let value_destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, key, key_destination, value_destination })
+    }
+}
+
+impl<N: Network> ToBytes for GetNext<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the key operand, if it is present.
+        match &self.key {
+            Some(key) => {
+                1u8.write_le(&mut writer)?;
+                key.write_le(&mut writer)?;
+            }
+            None => 0u8.write_le(&mut writer)?,
+        }
+        // Write the destination registers.
+        self.key_destination.write_le(&mut writer)?;
+        self.value_destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, get_next) = GetNext::<CurrentNetwork>::parse("get.next account into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get_next.mapping, CallOperator::from_str("account").unwrap());
+        assert_eq!(get_next.operands().len(), 0, "The number of operands is incorrect");
+        assert_eq!(get_next.key, None, "The key operand is incorrect");
+        assert_eq!(get_next.key_destination, Register::Locator(1), "The key destination is incorrect");
+        assert_eq!(get_next.value_destination, Register::Locator(2), "The value destination is incorrect");
+
+        let (string, get_next) =
+            GetNext::<CurrentNetwork>::parse("get.next credits.aleo/account[r0] into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get_next.mapping, CallOperator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(get_next.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(get_next.key, Some(Operand::Register(Register::Locator(0))), "The key operand is incorrect");
+        assert_eq!(get_next.key_destination, Register::Locator(1), "The key destination is incorrect");
+        assert_eq!(get_next.value_destination, Register::Locator(2), "The value destination is incorrect");
+
+        // Ensure both destination registers are required.
+        assert!(GetNext::<CurrentNetwork>::parse("get.next account[r0] into r1;").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for string in ["get.next account into r1 r2;", "get.next credits.aleo/account[r0] into r1 r2;"] {
+            assert_eq!(GetNext::<CurrentNetwork>::from_str(string).unwrap().to_string(), string);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        for string in ["get.next account into r1 r2;", "get.next account[r0] into r1 r2;"] {
+            let get_next = GetNext::<CurrentNetwork>::from_str(string).unwrap();
+            let bytes_le = get_next.to_bytes_le().unwrap();
+            assert_eq!(GetNext::<CurrentNetwork>::from_bytes_le(&bytes_le[..]).unwrap(), get_next);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    CallOperator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Register, Value},
+    types::U32,
+};
+
+/// A length command, e.g. `length accounts into r0;`.
+/// Retrieves the number of (`key`, `value`) entries in `mapping`, and stores the result as a `u32` in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Length<N: Network> {
+    /// The mapping name.
+    mapping: CallOperator<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Length<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("length")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> Length<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Retrieve the number of entries in the mapping.
+        let length = store.get_mapping_length_speculative(program_id, mapping_name)?;
+        // Ensure the number of entries fits in a `u32`.
+        let Ok(length) = u32::try_from(length) else {
+            bail!("Mapping '{program_id}/{mapping_name}' has more than {} entries", u32::MAX);
+        };
+
+        // Assign the value to the destination register.
+        registers.store(stack, &self.destination, Value::from(Literal::U32(U32::new(length))))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for Length<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Length<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Length<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Length<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command and mapping.
+        write!(f, "{} {} into ", Self::opcode(), self.mapping)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Length<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Length<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, length) = Length::<CurrentNetwork>::parse("length account into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(length.mapping, CallOperator::from_str("account").unwrap());
+        assert_eq!(length.operands().len(), 0, "The number of operands is incorrect");
+        assert_eq!(length.destination, Register::Locator(1), "The destination is incorrect");
+
+        let (string, length) = Length::<CurrentNetwork>::parse("length credits.aleo/account into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(length.mapping, CallOperator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(length.operands().len(), 0, "The number of operands is incorrect");
+        assert_eq!(length.destination, Register::Locator(1), "The destination is incorrect");
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let (string, length) = Length::<CurrentNetwork>::parse("length account into r1;").unwrap();
+        assert!(string.is_empty());
+        let bytes_le = length.to_bytes_le().unwrap();
+        let result = Length::<CurrentNetwork>::from_bytes_le(&bytes_le[..]);
+        assert!(result.is_ok())
+    }
+}
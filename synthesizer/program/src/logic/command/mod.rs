@@ -22,12 +22,21 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
+mod get_next;
+pub use get_next::*;
+
 mod get_or_use;
 pub use get_or_use::*;
 
+mod length;
+pub use length::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -54,6 +63,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    RepeatCommand,
 };
 use console::{
     network::prelude::*,
@@ -85,6 +95,15 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Counts the number of entries in `mapping` and stores the result into `destination`.
+    Length(Length<N>),
+    /// Gets the entry that follows the `key` operand in `mapping`, or the first entry if `key` is omitted,
+    /// and stores the key and value into `key_destination` and `value_destination`.
+    GetNext(GetNext<N>),
+    /// Emits an event named `name` with the `operand` as its payload.
+    Emit(Emit<N>),
+    /// Executes the commands in `body` once for each loop index in `0..bound`, storing the index in `index`.
+    Repeat(RepeatCommand<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -96,11 +115,17 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Contains(contains) => vec![contains.destination().clone()],
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::Length(length) => vec![length.destination().clone()],
+            Command::GetNext(get_next) => {
+                vec![get_next.key_destination().clone(), get_next.value_destination().clone()]
+            }
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
+            Command::Repeat(repeat) => repeat.destinations(),
             Command::Await(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
             | Command::Position(_)
+            | Command::Emit(_)
             | Command::Remove(_)
             | Command::Set(_) => vec![],
         }
@@ -130,18 +155,31 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a call instruction.
     #[inline]
     fn is_call(&self) -> bool {
-        matches!(self, Command::Instruction(Instruction::Call(_)))
+        match self {
+            Command::Instruction(Instruction::Call(_) | Instruction::CallDynamic(_)) => true,
+            Command::Repeat(repeat) => repeat.body().iter().any(|command| command.is_call()),
+            _ => false,
+        }
     }
 
     /// Returns `true` if the command is a cast to record instruction.
     fn is_cast_to_record(&self) -> bool {
-        matches!(self, Command::Instruction(Instruction::Cast(cast)) if matches!(cast.cast_type(), CastType::Record(_) | CastType::ExternalRecord(_)))
+        match self {
+            Command::Instruction(Instruction::Cast(cast)) => {
+                matches!(cast.cast_type(), CastType::Record(_) | CastType::ExternalRecord(_))
+            }
+            Command::Repeat(repeat) => repeat.body().iter().any(|command| command.is_cast_to_record()),
+            _ => false,
+        }
     }
 
     /// Returns `true` if the command is a write operation.
     #[inline]
     fn is_write(&self) -> bool {
-        matches!(self, Command::Set(_) | Command::Remove(_))
+        match self {
+            Command::Repeat(repeat) => repeat.is_write(),
+            _ => matches!(self, Command::Set(_) | Command::Remove(_)),
+        }
     }
 }
 
@@ -177,6 +215,14 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // Finalize the 'length' command, and return no finalize operation.
+            Command::Length(length) => length.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'get.next' command, and return no finalize operation.
+            Command::GetNext(get_next) => get_next.finalize(stack, store, registers).map(|_| None),
+            // 'emit' commands are processed by the caller of this method.
+            Command::Emit(_) => bail!("`emit` commands cannot be finalized directly."),
+            // 'repeat' commands are processed by the caller of this method.
+            Command::Repeat(_) => bail!("`repeat` commands cannot be finalized directly."),
         }
     }
 }
@@ -209,8 +255,16 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `length` command.
+            11 => Ok(Self::Length(Length::read_le(&mut reader)?)),
+            // Read the `get.next` command.
+            12 => Ok(Self::GetNext(GetNext::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            13 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
+            // Read the `repeat` command.
+            14 => Ok(Self::Repeat(RepeatCommand::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            15.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -285,6 +339,30 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Length(length) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `length` command.
+                length.write_le(&mut writer)
+            }
+            Self::GetNext(get_next) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `get.next` command.
+                get_next.write_le(&mut writer)
+            }
+            Self::Emit(emit) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
+            Self::Repeat(repeat) => {
+                // Write the variant.
+                14u8.write_le(&mut writer)?;
+                // Write the `repeat` command.
+                repeat.write_le(&mut writer)
+            }
         }
     }
 }
@@ -296,8 +374,10 @@ impl<N: Network> Parser for Command<N> {
         // Parse the command.
         // Note that the order of the parsers is important.
         alt((
+            map(RepeatCommand::parse, |repeat| Self::Repeat(repeat)),
             map(Await::parse, |await_| Self::Await(await_)),
             map(Contains::parse, |contains| Self::Contains(contains)),
+            map(GetNext::parse, |get_next| Self::GetNext(get_next)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
@@ -306,6 +386,8 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(Length::parse, |length| Self::Length(length)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -351,6 +433,10 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::Length(length) => Display::fmt(length, f),
+            Self::GetNext(get_next) => Display::fmt(get_next, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
+            Self::Repeat(repeat) => Display::fmt(repeat, f),
         }
     }
 }
@@ -443,6 +529,36 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Length
+        let expected = "length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetNext
+        let expected = "get.next object into r0 r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetNext
+        let expected = "get.next object[r0] into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Emit
+        let expected = "emit transfer r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Repeat
+        let expected = "repeat 2u32 as r1 { get object[r0[r1]] into r2; set r2 into object[r1]; }";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -520,5 +636,36 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Length
+        let expected = "length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Length(Length::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetNext
+        let expected = "get.next object into r0 r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetNext(GetNext::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetNext
+        let expected = "get.next object[r0] into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetNext(GetNext::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Emit
+        let expected = "emit transfer r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Repeat
+        let expected =
+            "repeat 2u32 as r1 {\n        get object[r0[r1]] into r2;\n        set r2 into object[r1];\n    }";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Repeat(RepeatCommand::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -0,0 +1,56 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Event<N> {
+    /// Reads the event from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the event name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the payload.
+        let payload = Plaintext::read_le(&mut reader)?;
+        // Return the event.
+        Ok(Self::new(program_id, name, payload))
+    }
+}
+
+impl<N: Network> ToBytes for Event<N> {
+    /// Writes the event to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the event name.
+        self.name.write_le(&mut writer)?;
+        // Write the payload.
+        self.payload.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for expected in crate::logic::event::test_helpers::sample_events() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Event::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bits;
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+};
+
+/// An event emitted in finalize, e.g. `emit transfer r0;`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Event<N: Network> {
+    /// The program ID of the emitting program.
+    program_id: ProgramID<N>,
+    /// The event name.
+    name: Identifier<N>,
+    /// The event payload.
+    payload: Plaintext<N>,
+}
+
+impl<N: Network> Event<N> {
+    /// Initializes a new event.
+    pub const fn new(program_id: ProgramID<N>, name: Identifier<N>, payload: Plaintext<N>) -> Self {
+        Self { program_id, name, payload }
+    }
+
+    /// Returns the program ID of the emitting program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the event name.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the event payload.
+    pub const fn payload(&self) -> &Plaintext<N> {
+        &self.payload
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a list of `Event`s.
+    pub(crate) fn sample_events() -> Vec<Event<CurrentNetwork>> {
+        vec![
+            Event::new(
+                ProgramID::from_str("token.aleo").unwrap(),
+                Identifier::from_str("transfer").unwrap(),
+                Plaintext::from_str(
+                    "{ sender: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 10u64 }",
+                )
+                .unwrap(),
+            ),
+            Event::new(
+                ProgramID::from_str("token.aleo").unwrap(),
+                Identifier::from_str("mint").unwrap(),
+                Plaintext::from_str("100u64").unwrap(),
+            ),
+            Event::new(
+                ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("flags").unwrap(),
+                Plaintext::from_str("[true, false, true]").unwrap(),
+            ),
+        ]
+    }
+}
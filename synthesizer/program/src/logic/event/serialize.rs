@@ -0,0 +1,86 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for Event<N> {
+    /// Serializes the event to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut event = serializer.serialize_struct("Event", 3)?;
+                event.serialize_field("program_id", &self.program_id)?;
+                event.serialize_field("name", &self.name)?;
+                event.serialize_field("payload", &self.payload)?;
+                event.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Event<N> {
+    /// Deserializes the event from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut event = serde_json::Value::deserialize(deserializer)?;
+                // Recover the event.
+                Ok(Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut event, "program_id")?,
+                    // Retrieve the event name.
+                    DeserializeExt::take_from_value::<D>(&mut event, "name")?,
+                    // Retrieve the payload.
+                    DeserializeExt::take_from_value::<D>(&mut event, "payload")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "event"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for expected in crate::logic::event::test_helpers::sample_events() {
+            // Serialize
+            let expected_string = expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+            assert_eq!(expected_string, candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, Event::from_str(&expected_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for expected in crate::logic::event::test_helpers::sample_events() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, Event::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
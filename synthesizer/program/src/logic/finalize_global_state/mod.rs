@@ -21,6 +21,8 @@ pub struct FinalizeGlobalState {
     block_round: u64,
     /// The block height.
     block_height: u32,
+    /// The block timestamp, in Unix seconds.
+    block_timestamp: i64,
     /// The block-specific random seed.
     random_seed: [u8; 32],
 }
@@ -32,6 +34,7 @@ impl FinalizeGlobalState {
         // Initialize the parameters.
         let block_round = 0;
         let block_height = 0;
+        let block_timestamp = N::GENESIS_TIMESTAMP;
         let block_cumulative_weight = 0;
         let block_cumulative_proof_target = 0;
         let previous_block_hash = N::BlockHash::default();
@@ -39,6 +42,7 @@ impl FinalizeGlobalState {
         Self::new::<N>(
             block_round,
             block_height,
+            block_timestamp,
             block_cumulative_weight,
             block_cumulative_proof_target,
             previous_block_hash,
@@ -50,11 +54,13 @@ impl FinalizeGlobalState {
     pub fn new<N: Network>(
         block_round: u64,
         block_height: u32,
+        block_timestamp: i64,
         block_cumulative_weight: u128,
         block_cumulative_proof_target: u128,
         previous_block_hash: N::BlockHash,
     ) -> Result<Self> {
         // Initialize the preimage.
+        // Note: The block timestamp is not included, so that the random seed is unchanged.
         let preimage = to_bits_le![
             block_round,
             block_height,
@@ -72,13 +78,13 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_round, block_height, random_seed })
+        Ok(Self { block_round, block_height, block_timestamp, random_seed })
     }
 
     /// Initializes a new global state.
     #[inline]
-    pub const fn from(block_round: u64, block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_round, block_height, random_seed }
+    pub const fn from(block_round: u64, block_height: u32, block_timestamp: i64, random_seed: [u8; 32]) -> Self {
+        Self { block_round, block_height, block_timestamp, random_seed }
     }
 
     /// Returns the block round.
@@ -93,6 +99,12 @@ impl FinalizeGlobalState {
         self.block_height
     }
 
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
     /// Returns the random seed.
     #[inline]
     pub const fn random_seed(&self) -> &[u8; 32] {
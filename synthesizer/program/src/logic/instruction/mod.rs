@@ -25,16 +25,19 @@ pub use operation::*;
 mod bytes;
 mod parse;
 
-use crate::traits::{
-    InstructionTrait,
-    RegistersLoad,
-    RegistersLoadCircuit,
-    RegistersSigner,
-    RegistersSignerCircuit,
-    RegistersStore,
-    RegistersStoreCircuit,
-    StackMatches,
-    StackProgram,
+use crate::{
+    traits::{
+        InstructionTrait,
+        RegistersLoad,
+        RegistersLoadCircuit,
+        RegistersSigner,
+        RegistersSignerCircuit,
+        RegistersStore,
+        RegistersStoreCircuit,
+        StackMatches,
+        StackProgram,
+    },
+    Repeat,
 };
 use console::{
     network::Network,
@@ -202,6 +205,42 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes whether the ECDSA `signature` is valid for the given `address` and the Keccak-256 hash of `message`.
+    ECDSAVerifyKeccak256(ECDSAVerifyKeccak256<N>),
+    /// Computes whether the ECDSA `signature` is valid for the given `address` and `digest`.
+    ECDSAVerifyDigest(ECDSAVerifyDigest<N>),
+    /// Serializes `first` into a `[boolean; N]` array, storing the outcome in `destination`.
+    SerializeBits(SerializeBits<N>),
+    /// Serializes the literals of `first` into a `[boolean; N]` array, storing the outcome in `destination`.
+    SerializeRawBits(SerializeRawBits<N>),
+    /// Serializes `first` into a `[u8; N]` array, storing the outcome in `destination`.
+    SerializeBytes(SerializeBytes<N>),
+    /// Serializes the literals of `first` into a `[u8; N]` array, storing the outcome in `destination`.
+    SerializeRawBytes(SerializeRawBytes<N>),
+    /// Deserializes the `[boolean; N]` array `first` into a plaintext, storing the outcome in `destination`.
+    DeserializeBits(DeserializeBits<N>),
+    /// Deserializes the literals in the `[boolean; N]` array `first` into a plaintext, storing the outcome in `destination`.
+    DeserializeRawBits(DeserializeRawBits<N>),
+    /// Deserializes the `[u8; N]` array `first` into a plaintext, storing the outcome in `destination`.
+    DeserializeBytes(DeserializeBytes<N>),
+    /// Deserializes the literals in the `[u8; N]` array `first` into a plaintext, storing the outcome in `destination`.
+    DeserializeRawBytes(DeserializeRawBytes<N>),
+    /// Calls a function of a program given at runtime, matching the signature of the interface function.
+    CallDynamic(CallDynamic<N>),
+    /// Executes the instructions in `body` once for each loop index in `0..bound`, storing the index in `index`.
+    Repeat(Repeat<N>),
+    /// Computes the number of bytes in `first`, storing the outcome in `destination`.
+    StringLength(StringLength<N>),
+    /// Concatenates `first` and `second`, storing the outcome in `destination`.
+    StringConcat(StringConcat<N>),
+    /// Extracts the bytes of `first` from `second` (inclusive) to `third` (exclusive), storing the outcome in `destination`.
+    StringSubstring(StringSubstring<N>),
+    /// Extracts the byte of `first` at index `second`, storing the outcome in `destination`.
+    StringByteAt(StringByteAt<N>),
+    /// Converts `first` into a zero-padded `[u8; N]` array, storing the outcome in `destination`.
+    StringToBytes(StringToBytes<N>),
+    /// Converts the UTF-8 bytes in the `[u8; N]` array `first` into a string, storing the outcome in `destination`.
+    StringFromBytes(StringFromBytes<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -301,6 +340,24 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            ECDSAVerifyKeccak256,
+            ECDSAVerifyDigest,
+            SerializeBits,
+            SerializeRawBits,
+            SerializeBytes,
+            SerializeRawBytes,
+            DeserializeBits,
+            DeserializeRawBits,
+            DeserializeBytes,
+            DeserializeRawBytes,
+            CallDynamic,
+            Repeat,
+            StringLength,
+            StringConcat,
+            StringSubstring,
+            StringByteAt,
+            StringToBytes,
+            StringFromBytes,
         }}
     };
     // A variant **without** curly braces:
@@ -455,7 +512,11 @@ impl<N: Network> Debug for Instruction<N> {
 impl<N: Network> Display for Instruction<N> {
     /// Prints the instruction as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        instruction!(self, |instruction| write!(f, "{instruction};"))
+        match self {
+            // Note: A `repeat` block is not terminated by a semicolon.
+            Self::Repeat(repeat) => Display::fmt(repeat, f),
+            _ => instruction!(self, |instruction| write!(f, "{instruction};")),
+        }
     }
 }
 
@@ -470,7 +531,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            86,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -24,20 +24,32 @@ pub enum Opcode {
     Async,
     /// The opcode is for a call operation (i.e. `call`).
     Call,
+    /// The opcode is for a dynamic call operation (i.e. `call.dynamic`).
+    CallDynamic,
     /// The opcode is for a cast operation (i.e. `cast`).
     Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for a deserialize operation (i.e. `deserialize.bits`).
+    Deserialize(&'static str),
+    /// The opcode is for ECDSA signature verification (i.e. `ecdsa.verify.keccak256`).
+    ECDSA(&'static str),
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a repeat block (i.e. `repeat`).
+    Repeat,
+    /// The opcode is for a serialize operation (i.e. `serialize.bits`).
+    Serialize(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for a string operation (i.e. `str.len`).
+    String(&'static str),
 }
 
 impl Deref for Opcode {
@@ -49,13 +61,19 @@ impl Deref for Opcode {
             Opcode::Assert(opcode) => opcode,
             Opcode::Async => &"async",
             Opcode::Call => &"call",
+            Opcode::CallDynamic => &"call.dynamic",
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::Deserialize(opcode) => opcode,
+            Opcode::ECDSA(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Repeat => &"repeat",
+            Opcode::Serialize(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::String(opcode) => opcode,
         }
     }
 }
@@ -74,13 +92,19 @@ impl Display for Opcode {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Async => write!(f, "{}", self.deref()),
             Self::Call => write!(f, "{}", self.deref()),
+            Self::CallDynamic => write!(f, "{}", self.deref()),
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::Deserialize(opcode) => write!(f, "{opcode}"),
+            Self::ECDSA(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Repeat => write!(f, "{}", self.deref()),
+            Self::Serialize(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::String(opcode) => write!(f, "{opcode}"),
         }
     }
 }
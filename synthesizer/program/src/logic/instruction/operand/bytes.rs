@@ -25,6 +25,7 @@ impl<N: Network> FromBytes for Operand<N> {
             4 => Ok(Self::Caller),
             5 => Ok(Self::BlockHeight),
             6 => Ok(Self::NetworkID),
+            7 => Ok(Self::BlockTimestamp),
             variant => Err(error(format!("Failed to deserialize operand variant {variant}"))),
         }
     }
@@ -49,6 +50,7 @@ impl<N: Network> ToBytes for Operand<N> {
             Self::Caller => 4u8.write_le(&mut writer),
             Self::BlockHeight => 5u8.write_le(&mut writer),
             Self::NetworkID => 6u8.write_le(&mut writer),
+            Self::BlockTimestamp => 7u8.write_le(&mut writer),
         }
     }
 }
@@ -0,0 +1,415 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Function,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{LiteralType, Locator, PlaintextType, Register, RegisterType, ValueType},
+};
+
+/// Calls a function, whose program ID and function name are given at runtime, on the operands.
+/// The first two operands are the program name and the function name, as field elements,
+/// and the callee must match the signature of the declared interface function.
+/// i.e. `call.dynamic token.aleo/transfer_public r0 r1 r2 r3 into r4;` calls the function named `r1`
+/// in the program named `r0` with the arguments `r2` and `r3`, where the callee matches the interface
+/// `token.aleo/transfer_public`, and stores the future of the callee in `r4`, to be awaited in finalize.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallDynamic<N: Network> {
+    /// The interface function.
+    interface: Locator<N>,
+    /// The operands, as `(program name, function name, arguments)`.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallDynamic
+    }
+
+    /// Returns the interface function.
+    #[inline]
+    pub const fn interface(&self) -> &Locator<N> {
+        &self.interface
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the operand for the program name.
+    #[inline]
+    pub fn program_name(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the operand for the function name.
+    #[inline]
+    pub fn function_name(&self) -> &Operand<N> {
+        &self.operands[1]
+    }
+
+    /// Returns the operands for the arguments of the call.
+    #[inline]
+    pub fn arguments(&self) -> &[Operand<N>] {
+        &self.operands[2..]
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Ensures the given function may be the interface of a dynamic call.
+    ///
+    /// The interface may only have public or private plaintext inputs and outputs, and a future output
+    /// if it contains a finalize block. Records are not supported, as a record commitment depends on
+    /// the program ID of the callee, which the caller only knows at runtime.
+    pub fn check_interface(function: &Function<N>) -> Result<()> {
+        // Ensure the input types are public or private plaintexts.
+        for value_type in function.input_types() {
+            match value_type {
+                ValueType::Public(..) | ValueType::Private(..) => {}
+                _ => bail!("Function '{}' has an unsupported input type '{value_type}'", function.name()),
+            }
+        }
+        // Ensure the output types are public or private plaintexts, or a future.
+        for value_type in function.output_types() {
+            match value_type {
+                ValueType::Public(..) | ValueType::Private(..) | ValueType::Future(..) => {}
+                _ => bail!("Function '{}' has an unsupported output type '{value_type}'", function.name()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'call.dynamic' directly. Use 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'call.dynamic' directly. Use 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.dynamic'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Retrieve the interface function.
+        let function =
+            stack.get_external_program(self.interface.program_id())?.get_function_ref(self.interface.resource())?;
+        // Ensure the interface function is supported.
+        Self::check_interface(function)?;
+
+        // Ensure the number of operands matches the number of input statements.
+        if function.inputs().len() + 2 != self.operands.len() {
+            bail!("Expected {} inputs, found {}", function.inputs().len(), self.operands.len().saturating_sub(2))
+        }
+        // Ensure the number of input types matches the number of operands.
+        if self.operands.len() != input_types.len() {
+            bail!("Expected {} input types, found {}", self.operands.len(), input_types.len())
+        }
+        // Ensure the number of destinations matches the number of output statements.
+        if function.outputs().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", function.outputs().len(), self.destinations.len())
+        }
+
+        // Ensure the program name and function name are field elements.
+        let field_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field));
+        for (name, input_type) in ["program name", "function name"].iter().zip_eq(&input_types[..2]) {
+            if input_type != &field_type {
+                bail!("Instruction '{}' expects the {name} to be a 'field', found '{input_type}'", Self::opcode())
+            }
+        }
+        // Ensure the arguments match the input types of the interface function.
+        for (input_type, expected_type) in input_types[2..].iter().zip_eq(function.input_types()) {
+            let expected_type = RegisterType::from(expected_type);
+            if input_type != &expected_type {
+                bail!(
+                    "Instruction '{}' expects an argument of type '{expected_type}', found '{input_type}'",
+                    Self::opcode()
+                )
+            }
+        }
+
+        // Return the output register types.
+        Ok(function.output_types().into_iter().map(RegisterType::from).collect())
+    }
+}
+
+impl<N: Network> Parser for CallDynamic<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the interface function from the string.
+        let (string, interface) = Locator::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the program name and function name are present.
+            if operands.len() < 2 {
+                return Err(error("Failed to parse 'call.dynamic' opcode: missing the program name or function name"));
+            }
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'call.dynamic' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the destinations from the string.
+                let (string, destinations) =
+                    map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                        // Ensure the number of destinations is within the bounds.
+                        match destinations.len() <= N::MAX_OPERANDS {
+                            true => Ok(destinations),
+                            false => Err(error("Failed to parse 'call.dynamic' opcode: too many destinations")),
+                        }
+                    })(string)?;
+                // Return the string and the destinations.
+                (string, destinations)
+            }
+        };
+
+        Ok((string, Self { interface, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for CallDynamic<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallDynamic<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallDynamic<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() < 2 || self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.interface)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CallDynamic<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the interface function.
+        let interface = Locator::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if !(2..=N::MAX_OPERANDS).contains(&num_operands) {
+            return Err(error(format!("The number of operands must be in [2, {}]", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the destinations.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        // Read the destination registers.
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { interface, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for CallDynamic<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() < 2 || self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be in [2, {}]", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the interface function.
+        self.interface.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.dynamic token.aleo/transfer_public r0 r1",
+        "call.dynamic token.aleo/transfer_public r0 r1 r2 r3",
+        "call.dynamic token.aleo/transfer_public r0 r1 into r2",
+        "call.dynamic token.aleo/transfer_public r0 r1 r2 r3 into r4 r5",
+        "call.dynamic oracle.aleo/price 123field r0.name r1 into r2",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, call) =
+            CallDynamic::<CurrentNetwork>::parse("call.dynamic token.aleo/transfer_public r0 r1 r2 r3 into r4")
+                .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(call.interface, Locator::from_str("token.aleo/transfer_public").unwrap());
+        assert_eq!(call.program_name(), &Operand::Register(Register::Locator(0)));
+        assert_eq!(call.function_name(), &Operand::Register(Register::Locator(1)));
+        assert_eq!(
+            call.arguments(),
+            &[Operand::Register(Register::Locator(2)), Operand::Register(Register::Locator(3))]
+        );
+        assert_eq!(call.destinations, vec![Register::Locator(4)]);
+
+        // Ensure the program name and function name are required.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic token.aleo/transfer_public r0").is_err());
+        // Ensure the interface must be a locator.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic transfer_public r0 r1").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallDynamic::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallDynamic::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallDynamic::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,463 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::serialize::{
+    check_serialized_type,
+    serialized_layout,
+    serialized_size_in_bits,
+    unpack_bits,
+    unpack_bits_circuit,
+    Segment,
+};
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+use indexmap::IndexMap;
+
+/// Deserializes a `[boolean; N]` array in the `Plaintext::to_bits_le` representation into a plaintext.
+pub type DeserializeBits<N> = DeserializeInstruction<N, { DeserializeVariant::Bits as u8 }>;
+/// Deserializes a `[boolean; N]` array of literal bits (without variant and size headers) into a plaintext.
+pub type DeserializeRawBits<N> = DeserializeInstruction<N, { DeserializeVariant::RawBits as u8 }>;
+/// Deserializes a zero-padded `[u8; N]` array in the `Plaintext::to_bits_le` representation into a plaintext.
+pub type DeserializeBytes<N> = DeserializeInstruction<N, { DeserializeVariant::Bytes as u8 }>;
+/// Deserializes a zero-padded `[u8; N]` array of literal bits (without variant and size headers) into a plaintext.
+pub type DeserializeRawBytes<N> = DeserializeInstruction<N, { DeserializeVariant::RawBytes as u8 }>;
+
+enum DeserializeVariant {
+    Bits,
+    RawBits,
+    Bytes,
+    RawBytes,
+}
+
+/// Deserializes the operand, a `[boolean; N]` or `[u8; N]` array, into a plaintext of the destination type.
+///
+/// Serializations longer than `N::MAX_ARRAY_ELEMENTS` are read from nested arrays in row-major order.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DeserializeInstruction<N: Network, const VARIANT: u8> {
+    /// The operand as `input`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination plaintext type.
+    destination_type: PlaintextType<N>,
+}
+
+impl<N: Network, const VARIANT: u8> DeserializeInstruction<N, VARIANT> {
+    /// Initializes a new `deserialize` instruction.
+    #[inline]
+    pub fn new(
+        operands: Vec<Operand<N>>,
+        destination: Register<N>,
+        destination_type: PlaintextType<N>,
+    ) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Deserialize("deserialize.bits"),
+            1 => Opcode::Deserialize("deserialize.raw.bits"),
+            2 => Opcode::Deserialize("deserialize.bytes"),
+            3 => Opcode::Deserialize("deserialize.raw.bytes"),
+            4.. => panic!("Invalid 'deserialize' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operand.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination plaintext type.
+    #[inline]
+    pub const fn destination_type(&self) -> &PlaintextType<N> {
+        &self.destination_type
+    }
+}
+
+impl<N: Network, const VARIANT: u8> DeserializeInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operand.
+        let input = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the operand to be a plaintext", Self::opcode()),
+        };
+        let bits = unpack_bits(&input, is_bytes(VARIANT))?;
+
+        // Retrieve the serialization layout of the destination type.
+        let layout = serialized_layout(stack, &self.destination_type, is_raw(VARIANT))?;
+        let size_in_bits = serialized_size_in_bits::<N>(&layout);
+        // Ensure the number of bits is correct, and that the padding bits are zero.
+        ensure!(bits.len() == padded_size_in_bits(size_in_bits, VARIANT), "Invalid serialized length");
+        ensure!(bits[size_in_bits..].iter().all(|bit| !bit), "The padding bits must be zero");
+
+        // Read the literals, ensuring the headers match the destination type.
+        let mut literals = Vec::new();
+        let mut index = 0;
+        for segment in &layout {
+            match segment {
+                Segment::Header(header) => {
+                    ensure!(
+                        bits[index..index + header.len()] == header[..],
+                        "Invalid header for '{}'",
+                        self.destination_type
+                    );
+                    index += header.len();
+                }
+                Segment::Literal(literal_type) => {
+                    let size_in_bits = literal_type.size_in_bits::<N>() as usize;
+                    literals.push(Literal::from_bits_le(literal_type.type_id(), &bits[index..index + size_in_bits])?);
+                    index += size_in_bits;
+                }
+            }
+        }
+        // Construct the plaintext from the literals.
+        let output = to_plaintext(stack, &self.destination_type, &mut literals.into_iter())?;
+        // Ensure the output matches the destination type.
+        stack.matches_plaintext(&output, &self.destination_type)?;
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operand.
+        let input = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the operand to be a plaintext", Self::opcode()),
+        };
+        let bits = unpack_bits_circuit(&input, is_bytes(VARIANT))?;
+
+        // Retrieve the serialization layout of the destination type.
+        let layout = serialized_layout(stack, &self.destination_type, is_raw(VARIANT))?;
+        let size_in_bits = serialized_size_in_bits::<N>(&layout);
+        // Ensure the number of bits is correct, and enforce that the padding bits are zero.
+        ensure!(bits.len() == padded_size_in_bits(size_in_bits, VARIANT), "Invalid serialized length");
+        bits[size_in_bits..].iter().for_each(|bit| A::assert_eq(bit, circuit::Boolean::<A>::constant(false)));
+
+        // Read the literals, enforcing that the headers match the destination type.
+        let mut literals = Vec::new();
+        let mut index = 0;
+        for segment in &layout {
+            match segment {
+                Segment::Header(header) => {
+                    for (bit, expected) in bits[index..index + header.len()].iter().zip_eq(header) {
+                        A::assert_eq(bit, circuit::Boolean::<A>::constant(*expected));
+                    }
+                    index += header.len();
+                }
+                Segment::Literal(literal_type) => {
+                    let size_in_bits = literal_type.size_in_bits::<N>() as usize;
+                    let variant = circuit::U8::constant(console::types::U8::new(literal_type.type_id()));
+                    literals.push(circuit::Literal::from_bits_le(&variant, &bits[index..index + size_in_bits]));
+                    index += size_in_bits;
+                }
+            }
+        }
+        // Construct the plaintext from the literals.
+        let output = to_plaintext_circuit(stack, &self.destination_type, &mut literals.into_iter())?;
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the input is the serialization of the destination type.
+        let layout = serialized_layout(stack, &self.destination_type, is_raw(VARIANT))?;
+        let size_in_bits = serialized_size_in_bits::<N>(&layout);
+        let is_valid_input = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                check_serialized_type(array_type, size_in_bits, is_bytes(VARIANT)).is_ok()
+            }
+            _ => false,
+        };
+        if !is_valid_input {
+            bail!(
+                "Instruction '{}' cannot deserialize '{}' from '{}'",
+                Self::opcode(),
+                self.destination_type,
+                input_types[0]
+            )
+        }
+        Ok(vec![RegisterType::Plaintext(self.destination_type.clone())])
+    }
+}
+
+/// Returns `true` if the variant deserializes only the bits of the literals.
+const fn is_raw(variant: u8) -> bool {
+    variant == DeserializeVariant::RawBits as u8 || variant == DeserializeVariant::RawBytes as u8
+}
+
+/// Returns `true` if the variant deserializes from a `[u8; N]` array.
+const fn is_bytes(variant: u8) -> bool {
+    variant == DeserializeVariant::Bytes as u8 || variant == DeserializeVariant::RawBytes as u8
+}
+
+/// Returns the number of bits in the operand, given the number of bits in the serialization.
+const fn padded_size_in_bits(size_in_bits: usize, variant: u8) -> usize {
+    match is_bytes(variant) {
+        true => size_in_bits.div_ceil(8) * 8,
+        false => size_in_bits,
+    }
+}
+
+/// Returns the plaintext of the given type, consuming its literals in order.
+fn to_plaintext<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+    literals: &mut impl Iterator<Item = Literal<N>>,
+) -> Result<Plaintext<N>> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => {
+            Ok(Plaintext::from(literals.next().ok_or_else(|| anyhow!("Missing literal for '{plaintext_type}'"))?))
+        }
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = stack.program().get_struct(struct_name)?;
+            let mut members = IndexMap::with_capacity(struct_.members().len());
+            for (identifier, member_type) in struct_.members() {
+                members.insert(*identifier, to_plaintext(stack, member_type, literals)?);
+            }
+            Ok(Plaintext::Struct(members, Default::default()))
+        }
+        PlaintextType::Array(array_type) => {
+            let elements = (0..**array_type.length())
+                .map(|_| to_plaintext(stack, array_type.next_element_type(), literals))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Plaintext::Array(elements, Default::default()))
+        }
+    }
+}
+
+/// Returns the circuit plaintext of the given type, consuming its literals in order.
+fn to_plaintext_circuit<N: Network, A: circuit::Aleo<Network = N>>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+    literals: &mut impl Iterator<Item = circuit::Literal<A>>,
+) -> Result<circuit::Plaintext<A>> {
+    use circuit::Inject;
+
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(circuit::Plaintext::from(
+            literals.next().ok_or_else(|| anyhow!("Missing literal for '{plaintext_type}'"))?,
+        )),
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = stack.program().get_struct(struct_name)?;
+            let mut members = IndexMap::with_capacity(struct_.members().len());
+            for (identifier, member_type) in struct_.members() {
+                members.insert(
+                    circuit::Identifier::constant(*identifier),
+                    to_plaintext_circuit(stack, member_type, literals)?,
+                );
+            }
+            Ok(circuit::Plaintext::Struct(members, Default::default()))
+        }
+        PlaintextType::Array(array_type) => {
+            let elements = (0..**array_type.length())
+                .map(|_| to_plaintext_circuit(stack, array_type.next_element_type(), literals))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(circuit::Plaintext::Array(elements, Default::default()))
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for DeserializeInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination plaintext type from the string.
+        let (string, destination_type) = PlaintextType::parse(string)?;
+
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for DeserializeInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for DeserializeInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for DeserializeInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {} as {}", Self::opcode(), self.operands[0], self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for DeserializeInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination plaintext type.
+        let destination_type = PlaintextType::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destination, destination_type })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for DeserializeInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination plaintext type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, deserialize) =
+            DeserializeBits::<CurrentNetwork>::parse("deserialize.bits r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(deserialize.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(deserialize.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(deserialize.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(deserialize.destination_type, PlaintextType::from_str("u8").unwrap());
+
+        let (string, deserialize) =
+            DeserializeRawBytes::<CurrentNetwork>::parse("deserialize.raw.bytes r0 into r1 as [u64; 4u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(deserialize.destination_type, PlaintextType::from_str("[u64; 4u32]").unwrap());
+
+        // Ensure the bits variant does not parse the raw variant.
+        assert!(DeserializeBits::<CurrentNetwork>::from_str("deserialize.raw.bits r0 into r1 as u8").is_err());
+    }
+}
@@ -0,0 +1,369 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    algorithms::{ECDSASecp256k1, ECDSA_COMPACT_SIGNATURE_SIZE_IN_BYTES, ETHEREUM_ADDRESS_SIZE_IN_BYTES},
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, U32},
+};
+
+/// Verifies a secp256k1 ECDSA signature over the Keccak-256 hash of a (possibly nested) `u8` array message.
+pub type ECDSAVerifyKeccak256<N> = ECDSAVerify<N, { ECDSAVariant::Keccak256 as u8 }>;
+/// Verifies a secp256k1 ECDSA signature over a `[u8; 32u32]` digest.
+pub type ECDSAVerifyDigest<N> = ECDSAVerify<N, { ECDSAVariant::Digest as u8 }>;
+
+enum ECDSAVariant {
+    Keccak256,
+    Digest,
+}
+
+/// Computes whether the secp256k1 ECDSA `signature` is valid for the given Ethereum `address` and `message`.
+///
+/// The signature is an EIP-2098 compact signature given as a `[[u8; 32u32]; 2u32]` array (`[r, yParityAndS]`),
+/// and the address is a `[u8; 20u32]` array. A message is hashed as the concatenation of its bytes in order,
+/// so messages longer than `N::MAX_ARRAY_ELEMENTS` bytes are given as nested arrays (e.g. `[[u8; 32u32]; 4u32]`).
+///
+/// Note: This instruction is only supported in finalize scope. Verifying secp256k1 signatures in a function
+/// scope would require non-native field arithmetic in the circuit, which is not implemented.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ECDSAVerify<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Initializes a new `ecdsa.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::ECDSA("ecdsa.verify.keccak256"),
+            1 => Opcode::ECDSA("ecdsa.verify.digest"),
+            2.. => panic!("Invalid 'ecdsa.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let compact: [u8; ECDSA_COMPACT_SIGNATURE_SIZE_IN_BYTES] =
+            to_byte_array(registers.load(stack, &self.operands[0])?)
+                .map_err(|e| anyhow!("Expected the first operand to be a '[[u8; 32u32]; 2u32]' signature - {e}"))?;
+        let signature = ECDSASecp256k1::from_compact_signature(&compact);
+        let address: [u8; ETHEREUM_ADDRESS_SIZE_IN_BYTES] = to_byte_array(registers.load(stack, &self.operands[1])?)
+            .map_err(|e| anyhow!("Expected the second operand to be a '[u8; 20u32]' address - {e}"))?;
+        let message = to_bytes(registers.load(stack, &self.operands[2])?)?;
+
+        // Verify the signature.
+        let is_valid = match VARIANT {
+            0 => ECDSASecp256k1::verify_keccak256(&message, &signature, &address),
+            1 => match <[u8; 32]>::try_from(message) {
+                Ok(digest) => ECDSASecp256k1::verify_digest(&digest, &signature, &address),
+                Err(_) => bail!("Expected the third operand to be a '[u8; 32u32]' digest"),
+            },
+            2.. => bail!("Invalid 'ecdsa.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(is_valid)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' is only supported in finalize", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a signature.
+        if input_types[0] != signature_type()? {
+            bail!(
+                "Instruction '{}' expects the first input to be a '[[u8; 32u32]; 2u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is an address.
+        if input_types[1] != byte_array_type(ETHEREUM_ADDRESS_SIZE_IN_BYTES)? {
+            bail!(
+                "Instruction '{}' expects the second input to be a '[u8; 20u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a (possibly nested) byte array, or a `[u8; 32u32]` for a digest.
+        let is_valid_message = match (VARIANT, &input_types[2]) {
+            (0, RegisterType::Plaintext(PlaintextType::Array(array_type))) => {
+                array_type.base_element_type() == &PlaintextType::Literal(LiteralType::U8)
+            }
+            (1, input_type) => input_type == &byte_array_type(32)?,
+            _ => false,
+        };
+        if !is_valid_message {
+            bail!(
+                "Instruction '{}' expects the third input to be a '{}'. Found input of type '{}'",
+                Self::opcode(),
+                match VARIANT {
+                    1 => "[u8; 32u32]",
+                    _ => "u8 array",
+                },
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+/// Returns the register type for a `[u8; length]` array.
+fn byte_array_type<N: Network>(length: usize) -> Result<RegisterType<N>> {
+    let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(u32::try_from(length)?)])?;
+    Ok(RegisterType::Plaintext(PlaintextType::Array(array_type)))
+}
+
+/// Returns the register type for a `[[u8; 32u32]; 2u32]` compact signature.
+fn signature_type<N: Network>() -> Result<RegisterType<N>> {
+    let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(2), U32::new(32)])?;
+    Ok(RegisterType::Plaintext(PlaintextType::Array(array_type)))
+}
+
+/// Returns the bytes of the given (possibly nested) `u8` array value, in order.
+fn to_bytes<N: Network>(value: Value<N>) -> Result<Vec<u8>> {
+    match value {
+        Value::Plaintext(plaintext @ Plaintext::Array(..)) => {
+            let mut bytes = Vec::new();
+            append_bytes(&plaintext, &mut bytes)?;
+            Ok(bytes)
+        }
+        _ => bail!("Expected a 'u8' array, found '{value}'"),
+    }
+}
+
+/// Appends the bytes of the given (possibly nested) `u8` array element to the given list.
+fn append_bytes<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+    match plaintext {
+        Plaintext::Literal(Literal::U8(byte), _) => bytes.push(**byte),
+        Plaintext::Array(elements, _) => elements.iter().try_for_each(|element| append_bytes(element, bytes))?,
+        _ => bail!("Expected a 'u8' array element, found '{plaintext}'"),
+    }
+    Ok(())
+}
+
+/// Returns the bytes of the given (possibly nested) `u8` array value of `SIZE` bytes.
+fn to_byte_array<N: Network, const SIZE: usize>(value: Value<N>) -> Result<[u8; SIZE]> {
+    let bytes = to_bytes(value)?;
+    let num_bytes = bytes.len();
+    bytes.try_into().map_err(|_| anyhow!("Expected {SIZE} bytes, found {num_bytes} bytes"))
+}
+
+impl<N: Network, const VARIANT: u8> Parser for ECDSAVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for ECDSAVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for ECDSAVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for ECDSAVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) =
+            ECDSAVerifyKeccak256::<CurrentNetwork>::parse("ecdsa.verify.keccak256 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, is) = ECDSAVerifyDigest::<CurrentNetwork>::parse("ecdsa.verify.digest r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the variants do not parse each other's opcode.
+        assert!(ECDSAVerifyDigest::<CurrentNetwork>::parse("ecdsa.verify.keccak256 r0 r1 r2 into r3").is_err());
+        assert!(ECDSAVerifyKeccak256::<CurrentNetwork>::parse("ecdsa.verify.digest r0 r1 r2 into r3").is_err());
+    }
+}
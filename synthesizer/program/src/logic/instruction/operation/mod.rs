@@ -22,12 +22,21 @@ pub use async_::*;
 mod call;
 pub use call::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod cast;
 pub use cast::*;
 
 mod commit;
 pub use commit::*;
 
+mod deserialize;
+pub use deserialize::*;
+
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -39,9 +48,15 @@ pub use literals::*;
 
 mod macros;
 
+mod serialize;
+pub use serialize::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -0,0 +1,670 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, U8},
+};
+
+/// Serializes a plaintext into its `Plaintext::to_bits_le` representation, as a `[boolean; N]` array.
+pub type SerializeBits<N> = SerializeInstruction<N, { SerializeVariant::Bits as u8 }>;
+/// Serializes the literals of a plaintext (without variant and size headers), as a `[boolean; N]` array.
+pub type SerializeRawBits<N> = SerializeInstruction<N, { SerializeVariant::RawBits as u8 }>;
+/// Serializes a plaintext into its `Plaintext::to_bits_le` representation, as a zero-padded `[u8; N]` array.
+pub type SerializeBytes<N> = SerializeInstruction<N, { SerializeVariant::Bytes as u8 }>;
+/// Serializes the literals of a plaintext (without variant and size headers), as a zero-padded `[u8; N]` array.
+pub type SerializeRawBytes<N> = SerializeInstruction<N, { SerializeVariant::RawBytes as u8 }>;
+
+enum SerializeVariant {
+    Bits,
+    RawBits,
+    Bytes,
+    RawBytes,
+}
+
+/// Serializes the operand into a `[boolean; N]` or `[u8; N]` array.
+///
+/// Serializations longer than `N::MAX_ARRAY_ELEMENTS` are written into nested arrays in row-major order,
+/// e.g. the 34 bits of a `u8` may be written into a `[[boolean; 17u32]; 2u32]` array.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SerializeInstruction<N: Network, const VARIANT: u8> {
+    /// The operand as `input`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination array type.
+    destination_type: ArrayType<N>,
+}
+
+impl<N: Network, const VARIANT: u8> SerializeInstruction<N, VARIANT> {
+    /// Initializes a new `serialize` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, destination_type: ArrayType<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Serialize("serialize.bits"),
+            1 => Opcode::Serialize("serialize.raw.bits"),
+            2 => Opcode::Serialize("serialize.bytes"),
+            3 => Opcode::Serialize("serialize.raw.bytes"),
+            4.. => panic!("Invalid 'serialize' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operand.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination array type.
+    #[inline]
+    pub const fn destination_type(&self) -> &ArrayType<N> {
+        &self.destination_type
+    }
+}
+
+impl<N: Network, const VARIANT: u8> SerializeInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operand.
+        let plaintext = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the operand to be a plaintext", Self::opcode()),
+        };
+        // Serialize the plaintext.
+        let bits = match is_raw(VARIANT) {
+            true => raw_bits_le(&plaintext),
+            false => plaintext.to_bits_le(),
+        };
+        // Ensure the number of bits matches the destination type.
+        check_serialized_type(&self.destination_type, bits.len(), is_bytes(VARIANT))?;
+        // Pack the bits into the destination array.
+        let output = pack_bits(&bits, is_bytes(VARIANT), &self.destination_type);
+        // Ensure the output matches the destination type.
+        stack.matches_plaintext(&output, &PlaintextType::Array(self.destination_type.clone()))?;
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::ToBits;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operand.
+        let plaintext = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the operand to be a plaintext", Self::opcode()),
+        };
+        // Serialize the plaintext.
+        let bits = match is_raw(VARIANT) {
+            true => raw_bits_le_circuit(&plaintext),
+            false => plaintext.to_bits_le(),
+        };
+        // Ensure the number of bits matches the destination type.
+        check_serialized_type(&self.destination_type, bits.len(), is_bytes(VARIANT))?;
+        // Pack the bits into the destination array.
+        let output = pack_bits_circuit(&bits, is_bytes(VARIANT), &self.destination_type);
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the operand is a plaintext.
+        let input_type = match &input_types[0] {
+            RegisterType::Plaintext(plaintext_type) => plaintext_type,
+            input_type => {
+                bail!("Instruction '{}' expects a plaintext input. Found input of type '{input_type}'", Self::opcode())
+            }
+        };
+        // Ensure the destination type holds the serialized input.
+        let layout = serialized_layout(stack, input_type, is_raw(VARIANT))?;
+        if let Err(error) =
+            check_serialized_type(&self.destination_type, serialized_size_in_bits::<N>(&layout), is_bytes(VARIANT))
+        {
+            bail!("Instruction '{}' cannot serialize '{input_type}' - {error}", Self::opcode())
+        }
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Array(self.destination_type.clone()))])
+    }
+}
+
+/// Returns `true` if the variant serializes only the bits of the literals.
+const fn is_raw(variant: u8) -> bool {
+    variant == SerializeVariant::RawBits as u8 || variant == SerializeVariant::RawBytes as u8
+}
+
+/// Returns `true` if the variant serializes into a `[u8; N]` array.
+const fn is_bytes(variant: u8) -> bool {
+    variant == SerializeVariant::Bytes as u8 || variant == SerializeVariant::RawBytes as u8
+}
+
+/// A segment in the serialization of a plaintext type.
+pub(super) enum Segment {
+    /// The header bits, which are fixed by the plaintext type.
+    Header(Vec<bool>),
+    /// The bits of a literal of the given type.
+    Literal(LiteralType),
+}
+
+impl Segment {
+    /// Returns the number of bits in the segment.
+    fn size_in_bits<N: Network>(&self) -> usize {
+        match self {
+            Self::Header(bits) => bits.len(),
+            Self::Literal(literal_type) => literal_type.size_in_bits::<N>() as usize,
+        }
+    }
+}
+
+/// Returns the segments in the serialization of the given plaintext type, following the `Plaintext::to_bits_le` layout.
+/// If `is_raw` is `true`, the headers are omitted, and only the bits of the literals remain.
+pub(super) fn serialized_layout<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+    is_raw: bool,
+) -> Result<Vec<Segment>> {
+    let mut layout = Vec::new();
+    write_layout(stack, plaintext_type, is_raw, &mut layout)?;
+    Ok(layout)
+}
+
+/// Returns the number of bits in the given serialization layout.
+pub(super) fn serialized_size_in_bits<N: Network>(layout: &[Segment]) -> usize {
+    layout.iter().map(|segment| segment.size_in_bits::<N>()).sum()
+}
+
+/// Writes the segments of the given plaintext type into the layout, and returns the number of bits written.
+fn write_layout<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+    is_raw: bool,
+    layout: &mut Vec<Segment>,
+) -> Result<usize> {
+    // Writes the header bits into the layout, and returns the number of bits written.
+    let write_header = |layout: &mut Vec<Segment>, header: Vec<bool>| {
+        let size_in_bits = header.len();
+        if !is_raw {
+            layout.push(Segment::Header(header));
+        }
+        if is_raw {
+            0
+        } else {
+            size_in_bits
+        }
+    };
+
+    match plaintext_type {
+        PlaintextType::Literal(LiteralType::String) => {
+            bail!("A 'string' cannot be serialized, as its size is not fixed")
+        }
+        PlaintextType::Literal(literal_type) => {
+            // Write the variant bits, the literal variant, and the literal size.
+            let mut header = vec![false, false];
+            literal_type.type_id().write_bits_le(&mut header);
+            literal_type.size_in_bits::<N>().write_bits_le(&mut header);
+            let header_size = write_header(layout, header);
+            // Write the literal.
+            layout.push(Segment::Literal(*literal_type));
+            Ok(header_size + literal_type.size_in_bits::<N>() as usize)
+        }
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = stack.program().get_struct(struct_name)?;
+            // Write the variant bits and the number of members.
+            let mut header = vec![false, true];
+            u8::try_from(struct_.members().len())?.write_bits_le(&mut header);
+            let mut size_in_bits = write_header(layout, header);
+            // Write each member.
+            for (identifier, member_type) in struct_.members() {
+                let mut member_layout = Vec::new();
+                let member_size = write_layout(stack, member_type, is_raw, &mut member_layout)?;
+                // Write the identifier size, the identifier, and the member size.
+                let mut header = Vec::new();
+                identifier.size_in_bits().write_bits_le(&mut header);
+                identifier.write_bits_le(&mut header);
+                u16::try_from(member_size)
+                    .map_err(|_| anyhow!("Member '{identifier}' exceeds u16::MAX bits"))?
+                    .write_bits_le(&mut header);
+                size_in_bits += write_header(layout, header) + member_size;
+                layout.extend(member_layout);
+            }
+            Ok(size_in_bits)
+        }
+        PlaintextType::Array(array_type) => {
+            // Write the variant bits and the number of elements.
+            let mut header = vec![true, false];
+            array_type.length().write_bits_le(&mut header);
+            let mut size_in_bits = write_header(layout, header);
+            // Write each element.
+            for _ in 0..**array_type.length() {
+                let mut element_layout = Vec::new();
+                let element_size = write_layout(stack, array_type.next_element_type(), is_raw, &mut element_layout)?;
+                // Write the element size.
+                let mut header = Vec::new();
+                u16::try_from(element_size)
+                    .map_err(|_| anyhow!("Array element exceeds u16::MAX bits"))?
+                    .write_bits_le(&mut header);
+                size_in_bits += write_header(layout, header) + element_size;
+                layout.extend(element_layout);
+            }
+            Ok(size_in_bits)
+        }
+    }
+}
+
+/// Ensures the given array type holds the given number of bits, as a (possibly nested) `boolean` array,
+/// or as a (possibly nested) zero-padded `u8` array.
+pub(super) fn check_serialized_type<N: Network>(
+    array_type: &ArrayType<N>,
+    size_in_bits: usize,
+    is_bytes: bool,
+) -> Result<()> {
+    let (element_type, num_elements) = match is_bytes {
+        true => (LiteralType::U8, size_in_bits.div_ceil(8)),
+        false => (LiteralType::Boolean, size_in_bits),
+    };
+    ensure!(
+        array_type.base_element_type() == &PlaintextType::Literal(element_type),
+        "Expected an array of '{element_type}' elements, found '{array_type}'"
+    );
+    ensure!(
+        num_base_elements(array_type) == num_elements,
+        "Expected an array of {num_elements} '{element_type}' elements, found '{array_type}'"
+    );
+    Ok(())
+}
+
+/// Returns the total number of base elements in the given (possibly nested) array type.
+fn num_base_elements<N: Network>(array_type: &ArrayType<N>) -> usize {
+    match array_type.next_element_type() {
+        PlaintextType::Array(element_type) => **array_type.length() as usize * num_base_elements(element_type),
+        _ => **array_type.length() as usize,
+    }
+}
+
+/// Returns the bits of the literals in the given plaintext, in order.
+fn raw_bits_le<N: Network>(plaintext: &Plaintext<N>) -> Vec<bool> {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => literal.to_bits_le(),
+        Plaintext::Struct(members, ..) => members.values().flat_map(raw_bits_le).collect(),
+        Plaintext::Array(elements, ..) => elements.iter().flat_map(raw_bits_le).collect(),
+    }
+}
+
+/// Returns the bits of the literals in the given circuit plaintext, in order.
+fn raw_bits_le_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> Vec<circuit::Boolean<A>> {
+    use circuit::traits::ToBits;
+
+    match plaintext {
+        circuit::Plaintext::Literal(literal, ..) => literal.to_bits_le(),
+        circuit::Plaintext::Struct(members, ..) => members.values().flat_map(raw_bits_le_circuit).collect(),
+        circuit::Plaintext::Array(elements, ..) => elements.iter().flat_map(raw_bits_le_circuit).collect(),
+    }
+}
+
+/// Packs the given bits into a (possibly nested) `boolean` array, or a zero-padded `u8` array, of the given type.
+fn pack_bits<N: Network>(bits: &[bool], is_bytes: bool, array_type: &ArrayType<N>) -> Plaintext<N> {
+    let elements = match is_bytes {
+        true => bits
+            .chunks(8)
+            .map(|chunk| {
+                let byte = chunk.iter().enumerate().fold(0u8, |byte, (i, bit)| byte | ((*bit as u8) << i));
+                Plaintext::from(Literal::U8(U8::new(byte)))
+            })
+            .collect(),
+        false => bits.iter().map(|bit| Plaintext::from(Literal::Boolean(Boolean::new(*bit)))).collect(),
+    };
+    reshape(elements, array_type)
+}
+
+/// Returns the given base elements as an array of the given type, in row-major order.
+fn reshape<N: Network>(elements: Vec<Plaintext<N>>, array_type: &ArrayType<N>) -> Plaintext<N> {
+    match array_type.next_element_type() {
+        PlaintextType::Array(element_type) => {
+            let chunk_size = num_base_elements(element_type).max(1);
+            let elements = elements.chunks(chunk_size).map(|chunk| reshape(chunk.to_vec(), element_type)).collect();
+            Plaintext::Array(elements, Default::default())
+        }
+        _ => Plaintext::Array(elements, Default::default()),
+    }
+}
+
+/// Packs the given circuit bits into a (possibly nested) `boolean` array, or a zero-padded `u8` array,
+/// of the given type.
+fn pack_bits_circuit<A: circuit::Aleo>(
+    bits: &[circuit::Boolean<A>],
+    is_bytes: bool,
+    array_type: &ArrayType<A::Network>,
+) -> circuit::Plaintext<A> {
+    use circuit::traits::{FromBits, Inject};
+
+    let elements = match is_bytes {
+        true => bits
+            .chunks(8)
+            .map(|chunk| {
+                let mut chunk = chunk.to_vec();
+                chunk.resize(8, circuit::Boolean::constant(false));
+                circuit::Plaintext::from(circuit::Literal::U8(circuit::U8::from_bits_le(&chunk)))
+            })
+            .collect(),
+        false => bits.iter().map(|bit| circuit::Plaintext::from(circuit::Literal::Boolean(bit.clone()))).collect(),
+    };
+    reshape_circuit(elements, array_type)
+}
+
+/// Returns the given circuit base elements as an array of the given type, in row-major order.
+fn reshape_circuit<A: circuit::Aleo>(
+    elements: Vec<circuit::Plaintext<A>>,
+    array_type: &ArrayType<A::Network>,
+) -> circuit::Plaintext<A> {
+    match array_type.next_element_type() {
+        PlaintextType::Array(element_type) => {
+            let chunk_size = num_base_elements(element_type).max(1);
+            let elements =
+                elements.chunks(chunk_size).map(|chunk| reshape_circuit(chunk.to_vec(), element_type)).collect();
+            circuit::Plaintext::Array(elements, Default::default())
+        }
+        _ => circuit::Plaintext::Array(elements, Default::default()),
+    }
+}
+
+/// Returns the bits of the given (possibly nested) `boolean` or `u8` array, in row-major order.
+pub(super) fn unpack_bits<N: Network>(plaintext: &Plaintext<N>, is_bytes: bool) -> Result<Vec<bool>> {
+    ensure!(matches!(plaintext, Plaintext::Array(..)), "Expected an array, found '{plaintext}'");
+    let mut bits = Vec::new();
+    append_bits(plaintext, is_bytes, &mut bits)?;
+    Ok(bits)
+}
+
+/// Appends the bits of the given (possibly nested) `boolean` or `u8` array element to the given list.
+fn append_bits<N: Network>(plaintext: &Plaintext<N>, is_bytes: bool, bits: &mut Vec<bool>) -> Result<()> {
+    match (plaintext, is_bytes) {
+        (Plaintext::Array(elements, ..), _) => {
+            elements.iter().try_for_each(|element| append_bits(element, is_bytes, bits))?
+        }
+        (Plaintext::Literal(Literal::Boolean(bit), ..), false) => bits.push(**bit),
+        (Plaintext::Literal(Literal::U8(byte), ..), true) => byte.write_bits_le(bits),
+        _ => bail!("Unexpected array element '{plaintext}'"),
+    }
+    Ok(())
+}
+
+/// Returns the bits of the given circuit (possibly nested) `boolean` or `u8` array, in row-major order.
+pub(super) fn unpack_bits_circuit<A: circuit::Aleo>(
+    plaintext: &circuit::Plaintext<A>,
+    is_bytes: bool,
+) -> Result<Vec<circuit::Boolean<A>>> {
+    ensure!(matches!(plaintext, circuit::Plaintext::Array(..)), "Expected an array");
+    let mut bits = Vec::new();
+    append_bits_circuit(plaintext, is_bytes, &mut bits)?;
+    Ok(bits)
+}
+
+/// Appends the bits of the given circuit (possibly nested) `boolean` or `u8` array element to the given list.
+fn append_bits_circuit<A: circuit::Aleo>(
+    plaintext: &circuit::Plaintext<A>,
+    is_bytes: bool,
+    bits: &mut Vec<circuit::Boolean<A>>,
+) -> Result<()> {
+    use circuit::traits::ToBits;
+
+    match (plaintext, is_bytes) {
+        (circuit::Plaintext::Array(elements, ..), _) => {
+            elements.iter().try_for_each(|element| append_bits_circuit(element, is_bytes, bits))?
+        }
+        (circuit::Plaintext::Literal(circuit::Literal::Boolean(bit), ..), false) => bits.push(bit.clone()),
+        (circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..), true) => byte.write_bits_le(bits),
+        _ => bail!("Unexpected array element"),
+    }
+    Ok(())
+}
+
+impl<N: Network, const VARIANT: u8> Parser for SerializeInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination array type from the string.
+        let (string, destination_type) = ArrayType::parse(string)?;
+
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for SerializeInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for SerializeInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for SerializeInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {} as {}", Self::opcode(), self.operands[0], self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for SerializeInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination array type.
+        let destination_type = ArrayType::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destination, destination_type })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for SerializeInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination array type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, serialize) =
+            SerializeBits::<CurrentNetwork>::parse("serialize.bits r0 into r1 as [[boolean; 17u32]; 2u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(serialize.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(serialize.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(serialize.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(serialize.destination_type, ArrayType::from_str("[[boolean; 17u32]; 2u32]").unwrap());
+
+        let (string, serialize) =
+            SerializeRawBytes::<CurrentNetwork>::parse("serialize.raw.bytes r0 into r1 as [u8; 4u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(serialize.destination_type, ArrayType::from_str("[u8; 4u32]").unwrap());
+
+        // Ensure the bits variant does not parse the raw variant.
+        assert!(SerializeBits::<CurrentNetwork>::from_str("serialize.raw.bits r0 into r1 as [boolean; 8u32]").is_err());
+    }
+
+    /// Returns `true` if the given plaintext has the dimensions of the given array type.
+    fn has_shape(plaintext: &Plaintext<CurrentNetwork>, array_type: &ArrayType<CurrentNetwork>) -> bool {
+        match (plaintext, array_type.next_element_type()) {
+            (Plaintext::Array(elements, _), element_type) if elements.len() == **array_type.length() as usize => {
+                match element_type {
+                    PlaintextType::Array(element_type) => elements.iter().all(|e| has_shape(e, element_type)),
+                    _ => elements.iter().all(|e| matches!(e, Plaintext::Literal(..))),
+                }
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_pack_and_unpack() {
+        let rng = &mut TestRng::default();
+
+        for (num_bits, bits_type, bytes_type) in [
+            (1usize, "[boolean; 1u32]", "[u8; 1u32]"),
+            (7, "[boolean; 7u32]", "[u8; 1u32]"),
+            (8, "[boolean; 8u32]", "[u8; 1u32]"),
+            (9, "[boolean; 9u32]", "[u8; 2u32]"),
+            (34, "[[boolean; 17u32]; 2u32]", "[u8; 5u32]"),
+            (256, "[[boolean; 32u32]; 8u32]", "[u8; 32u32]"),
+            (264, "[[[boolean; 11u32]; 4u32]; 6u32]", "[[u8; 11u32]; 3u32]"),
+        ] {
+            let bits = (0..num_bits).map(|_| rng.gen::<bool>()).collect::<Vec<_>>();
+            let bits_type = ArrayType::<CurrentNetwork>::from_str(bits_type).unwrap();
+            let bytes_type = ArrayType::<CurrentNetwork>::from_str(bytes_type).unwrap();
+            check_serialized_type(&bits_type, num_bits, false).unwrap();
+            check_serialized_type(&bytes_type, num_bits, true).unwrap();
+
+            // Check the bits round trip, and match the array type.
+            let packed = pack_bits::<CurrentNetwork>(&bits, false, &bits_type);
+            assert_eq!(unpack_bits(&packed, false).unwrap(), bits);
+            assert!(has_shape(&packed, &bits_type));
+
+            // Check the bytes round trip, with zero padding.
+            let packed = pack_bits::<CurrentNetwork>(&bits, true, &bytes_type);
+            let unpacked = unpack_bits(&packed, true).unwrap();
+            assert_eq!(unpacked.len(), num_bits.div_ceil(8) * 8);
+            assert_eq!(&unpacked[..num_bits], &bits[..]);
+            assert!(unpacked[num_bits..].iter().all(|bit| !bit));
+            assert!(has_shape(&packed, &bytes_type));
+
+            // Check the array types do not hold a different number of bits.
+            assert!(check_serialized_type(&bits_type, num_bits + 1, false).is_err());
+            assert!(check_serialized_type(&bytes_type, num_bits.div_ceil(8) * 8 + 1, true).is_err());
+            assert!(check_serialized_type(&bits_type, num_bits, true).is_err());
+        }
+    }
+}
@@ -0,0 +1,579 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{StringType, U32, U8},
+};
+
+/// Computes the number of bytes in `first`, storing the outcome as a `u32` in `destination`.
+/// Note: In a circuit, the number of bytes is a constant, as it is fixed when the circuit key is synthesized.
+pub type StringLength<N> = StringInstruction<N, { StringVariant::Length as u8 }>;
+/// Concatenates `first` and `second`, storing the outcome as a `string` in `destination`.
+pub type StringConcat<N> = StringInstruction<N, { StringVariant::Concat as u8 }>;
+/// Extracts the bytes of `first` from `second` (inclusive) to `third` (exclusive), storing the outcome as a `string` in `destination`.
+pub type StringSubstring<N> = StringInstruction<N, { StringVariant::Substring as u8 }>;
+/// Extracts the byte of `first` at index `second`, storing the outcome as a `u8` in `destination`.
+pub type StringByteAt<N> = StringInstruction<N, { StringVariant::ByteAt as u8 }>;
+/// Converts `first` into a zero-padded `[u8; N]` array, storing the outcome in `destination`.
+pub type StringToBytes<N> = StringInstruction<N, { StringVariant::ToBytes as u8 }>;
+/// Converts the first `second` UTF-8 bytes of a `[u8; N]` array in `first` into a `string`, where the remaining
+/// bytes must be zeros, storing the outcome in `destination`.
+/// Note: In a circuit, `second` must be a constant, such as a literal or the output of `str.len`.
+pub type StringFromBytes<N> = StringInstruction<N, { StringVariant::FromBytes as u8 }>;
+
+enum StringVariant {
+    Length,
+    Concat,
+    Substring,
+    ByteAt,
+    ToBytes,
+    FromBytes,
+}
+
+/// Returns the number of operands for the given variant.
+const fn num_operands(variant: u8) -> usize {
+    match variant {
+        1 | 3 | 5 => 2,
+        2 => 3,
+        _ => 1,
+    }
+}
+
+/// Returns `true` if the variant converts into a `[u8; N]` array.
+const fn is_to_bytes(variant: u8) -> bool {
+    variant == StringVariant::ToBytes as u8
+}
+
+/// Performs an operation on a `string`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination array type, for `str.to.bytes`.
+    destination_type: Option<ArrayType<N>>,
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Initializes a new `str` instruction.
+    #[inline]
+    pub fn new(
+        operands: Vec<Operand<N>>,
+        destination: Register<N>,
+        destination_type: Option<ArrayType<N>>,
+    ) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == num_operands(VARIANT),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            num_operands(VARIANT)
+        );
+        // Ensure the destination type is only given for `str.to.bytes`.
+        ensure!(
+            destination_type.is_some() == is_to_bytes(VARIANT),
+            "Instruction '{}' has an invalid destination type",
+            Self::opcode()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::String("str.len"),
+            1 => Opcode::String("str.concat"),
+            2 => Opcode::String("str.substr"),
+            3 => Opcode::String("str.at"),
+            4 => Opcode::String("str.to.bytes"),
+            5 => Opcode::String("str.from.bytes"),
+            6.. => panic!("Invalid 'str' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == num_operands(VARIANT),
+            "Invalid number of operands for '{}'",
+            Self::opcode()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination array type, for `str.to.bytes`.
+    #[inline]
+    pub const fn destination_type(&self) -> Option<&ArrayType<N>> {
+        self.destination_type.as_ref()
+    }
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != num_operands(VARIANT) {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                num_operands(VARIANT),
+                self.operands.len()
+            )
+        }
+
+        // Load the operands.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match (VARIANT, inputs.as_slice()) {
+            (0, [a]) => Plaintext::from(Literal::U32(to_string(a)?.length())),
+            (1, [a, b]) => Plaintext::from(Literal::String(to_string(a)?.concat(to_string(b)?))),
+            (2, [a, b, c]) => Plaintext::from(Literal::String(to_string(a)?.substring(to_u32(b)?, to_u32(c)?))),
+            (3, [a, b]) => Plaintext::from(Literal::U8(to_string(a)?.byte_at(to_u32(b)?))),
+            (4, [a]) => {
+                let destination_type = self.destination_type_or_bail()?;
+                let bytes = to_string(a)?.to_bytes(**destination_type.length());
+                let output = Plaintext::Array(
+                    bytes.into_iter().map(|byte| Plaintext::from(Literal::U8(byte))).collect(),
+                    Default::default(),
+                );
+                // Ensure the output matches the destination type.
+                stack.matches_plaintext(&output, &PlaintextType::Array(destination_type.clone()))?;
+                output
+            }
+            (5, [a, b]) => Plaintext::from(Literal::String(StringType::from_padded_bytes(&to_bytes(a)?, to_u32(b)?))),
+            _ => bail!("Invalid '{}' instruction", Self::opcode()),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != num_operands(VARIANT) {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                num_operands(VARIANT),
+                self.operands.len()
+            )
+        }
+
+        // Load the operands.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match (VARIANT, inputs.as_slice()) {
+            (0, [a]) => circuit::Plaintext::from(circuit::Literal::U32(to_string_circuit(a)?.length())),
+            (1, [a, b]) => {
+                circuit::Plaintext::from(circuit::Literal::String(to_string_circuit(a)?.concat(to_string_circuit(b)?)))
+            }
+            (2, [a, b, c]) => circuit::Plaintext::from(circuit::Literal::String(
+                to_string_circuit(a)?.substring(to_u32_circuit(b)?, to_u32_circuit(c)?),
+            )),
+            (3, [a, b]) => {
+                circuit::Plaintext::from(circuit::Literal::U8(to_string_circuit(a)?.byte_at(to_u32_circuit(b)?)))
+            }
+            (4, [a]) => {
+                let destination_type = self.destination_type_or_bail()?;
+                let bytes = to_string_circuit(a)?.to_bytes(**destination_type.length());
+                circuit::Plaintext::Array(
+                    bytes.into_iter().map(|byte| circuit::Plaintext::from(circuit::Literal::U8(byte))).collect(),
+                    Default::default(),
+                )
+            }
+            (5, [a, b]) => circuit::Plaintext::from(circuit::Literal::String(circuit::StringType::from_padded_bytes(
+                &to_bytes_circuit(a)?,
+                to_u32_circuit(b)?,
+            ))),
+            _ => bail!("Invalid '{}' instruction", Self::opcode()),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != num_operands(VARIANT) {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                num_operands(VARIANT),
+                input_types.len()
+            )
+        }
+
+        let string_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String));
+        let u32_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32));
+
+        // Ensure the input types are correct.
+        let expected_types = match VARIANT {
+            0 | 4 => vec![string_type.clone()],
+            1 => vec![string_type.clone(), string_type.clone()],
+            2 => vec![string_type.clone(), u32_type.clone(), u32_type.clone()],
+            3 => vec![string_type.clone(), u32_type.clone()],
+            5 => {
+                // Ensure the input is a `[u8; N]` array, where `N` is at most the maximum number of bytes in a string.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Array(array_type))
+                        if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8) =>
+                    {
+                        ensure!(
+                            **array_type.length() <= N::MAX_STRING_BYTES,
+                            "Instruction '{}' expects at most {} bytes, found '{array_type}'",
+                            Self::opcode(),
+                            N::MAX_STRING_BYTES
+                        );
+                        vec![input_types[0].clone(), u32_type.clone()]
+                    }
+                    input_type => bail!(
+                        "Instruction '{}' expects a '[u8; N]' input. Found input of type '{input_type}'",
+                        Self::opcode()
+                    ),
+                }
+            }
+            6.. => bail!("Invalid '{}' instruction", Self::opcode()),
+        };
+        for (index, (input_type, expected_type)) in input_types.iter().zip_eq(&expected_types).enumerate() {
+            if input_type != expected_type {
+                bail!(
+                    "Instruction '{}' expects input {index} to be a '{expected_type}'. Found input of type '{input_type}'",
+                    Self::opcode()
+                )
+            }
+        }
+
+        // Compute the output type.
+        let output_type = match VARIANT {
+            0 => u32_type,
+            1 | 2 | 5 => string_type,
+            3 => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)),
+            4 => {
+                // Ensure the destination type is a `[u8; N]` array.
+                let destination_type = self.destination_type_or_bail()?;
+                if destination_type.next_element_type() != &PlaintextType::Literal(LiteralType::U8) {
+                    bail!(
+                        "Instruction '{}' expects a '[u8; N]' destination, found '{destination_type}'",
+                        Self::opcode()
+                    )
+                }
+                RegisterType::Plaintext(PlaintextType::Array(destination_type.clone()))
+            }
+            6.. => bail!("Invalid '{}' instruction", Self::opcode()),
+        };
+        Ok(vec![output_type])
+    }
+
+    /// Returns the destination array type, or an error if it is missing.
+    fn destination_type_or_bail(&self) -> Result<&ArrayType<N>> {
+        self.destination_type
+            .as_ref()
+            .ok_or_else(|| anyhow!("Instruction '{}' is missing a destination type", Self::opcode()))
+    }
+}
+
+/// Returns the string in the given value.
+fn to_string<N: Network>(value: &Value<N>) -> Result<&StringType<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::String(string), ..)) => Ok(string),
+        _ => bail!("Expected a string, found '{value}'"),
+    }
+}
+
+/// Returns the `u32` in the given value.
+fn to_u32<N: Network>(value: &Value<N>) -> Result<&U32<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::U32(integer), ..)) => Ok(integer),
+        _ => bail!("Expected a u32, found '{value}'"),
+    }
+}
+
+/// Returns the bytes in the given `[u8; N]` value.
+fn to_bytes<N: Network>(value: &Value<N>) -> Result<Vec<U8<N>>> {
+    match value {
+        Value::Plaintext(Plaintext::Array(elements, ..)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::U8(byte), ..) => Ok(*byte),
+                _ => bail!("Expected a u8, found '{element}'"),
+            })
+            .collect(),
+        _ => bail!("Expected a '[u8; N]' array, found '{value}'"),
+    }
+}
+
+/// Returns the string in the given circuit value.
+fn to_string_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<&circuit::StringType<A>> {
+    match value {
+        circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::String(string), ..)) => Ok(string),
+        _ => bail!("Expected a string"),
+    }
+}
+
+/// Returns the `u32` in the given circuit value.
+fn to_u32_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<&circuit::U32<A>> {
+    match value {
+        circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U32(integer), ..)) => Ok(integer),
+        _ => bail!("Expected a u32"),
+    }
+}
+
+/// Returns the bytes in the given circuit `[u8; N]` value.
+fn to_bytes_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::U8<A>>> {
+    match value {
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, ..)) => elements
+            .iter()
+            .map(|element| match element {
+                circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => Ok(byte.clone()),
+                _ => bail!("Expected a u8"),
+            })
+            .collect(),
+        _ => bail!("Expected a '[u8; N]' array"),
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for StringInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (mut string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let mut operands = Vec::with_capacity(num_operands(VARIANT));
+        for _ in 0..num_operands(VARIANT) {
+            // Parse the whitespace from the string.
+            let (remainder, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            let (remainder, operand) = Operand::parse(remainder)?;
+            operands.push(operand);
+            string = remainder;
+        }
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the destination array type from the string, for `str.to.bytes`.
+        let (string, destination_type) = match is_to_bytes(VARIANT) {
+            true => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the "as" from the string.
+                let (string, _) = tag("as")(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the destination array type from the string.
+                let (string, destination_type) = ArrayType::parse(string)?;
+                (string, Some(destination_type))
+            }
+            false => (string, None),
+        };
+
+        Ok((string, Self { operands, destination, destination_type }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for StringInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for StringInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for StringInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != num_operands(VARIANT) {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{}", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, " into {}", self.destination)?;
+        // Print the destination array type, for `str.to.bytes`.
+        match &self.destination_type {
+            Some(destination_type) => write!(f, " as {destination_type}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for StringInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands =
+            (0..num_operands(VARIANT)).map(|_| Operand::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination array type, for `str.to.bytes`.
+        let destination_type = match is_to_bytes(VARIANT) {
+            true => Some(ArrayType::read_le(&mut reader)?),
+            false => None,
+        };
+        // Return the operation.
+        Ok(Self { operands, destination, destination_type })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for StringInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != num_operands(VARIANT) {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                num_operands(VARIANT),
+                self.operands.len()
+            )));
+        }
+        // Ensure the destination type is only given for `str.to.bytes`.
+        if self.destination_type.is_some() != is_to_bytes(VARIANT) {
+            return Err(error(format!("Invalid destination type for '{}'", Self::opcode())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination array type, for `str.to.bytes`.
+        match &self.destination_type {
+            Some(destination_type) => destination_type.write_le(&mut writer),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, length) = StringLength::<CurrentNetwork>::parse("str.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(length.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(length.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(length.destination_type, None);
+
+        let (string, substring) = StringSubstring::<CurrentNetwork>::parse("str.substr r0 0u32 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(substring.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(substring.operands[1], Operand::from_str("0u32").unwrap(), "The second operand is incorrect");
+        assert_eq!(substring.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, to_bytes) =
+            StringToBytes::<CurrentNetwork>::parse("str.to.bytes r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(to_bytes.destination_type, Some(ArrayType::from_str("[u8; 32u32]").unwrap()));
+
+        // Ensure the number of operands and the destination type are enforced.
+        assert!(StringConcat::<CurrentNetwork>::from_str("str.concat r0 into r1").is_err());
+        assert!(StringByteAt::<CurrentNetwork>::from_str("str.at r0 r1 r2 into r3").is_err());
+        assert!(StringToBytes::<CurrentNetwork>::from_str("str.to.bytes r0 into r1").is_err());
+        assert!(StringFromBytes::<CurrentNetwork>::from_str("str.from.bytes r0 r1 into r2 as [u8; 4u32]").is_err());
+        assert!(StringFromBytes::<CurrentNetwork>::from_str("str.from.bytes r0 into r1").is_err());
+    }
+
+    /// Checks that the given instruction round trips through its string and byte representations.
+    fn check_round_trip<const VARIANT: u8>(instruction: &str) {
+        let expected = StringInstruction::<CurrentNetwork, VARIANT>::from_str(instruction).unwrap();
+        assert_eq!(expected.to_string(), instruction);
+        let bytes = expected.to_bytes_le().unwrap();
+        let candidate = StringInstruction::<CurrentNetwork, VARIANT>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        check_round_trip::<{ StringVariant::Length as u8 }>("str.len r0 into r1");
+        check_round_trip::<{ StringVariant::Concat as u8 }>("str.concat r0 \"aleo\" into r1");
+        check_round_trip::<{ StringVariant::Substring as u8 }>("str.substr r0 1u32 3u32 into r1");
+        check_round_trip::<{ StringVariant::ByteAt as u8 }>("str.at r0 r1 into r2");
+        check_round_trip::<{ StringVariant::ToBytes as u8 }>("str.to.bytes r0 into r1 as [u8; 8u32]");
+        check_round_trip::<{ StringVariant::FromBytes as u8 }>("str.from.bytes r0 r1 into r2");
+    }
+}
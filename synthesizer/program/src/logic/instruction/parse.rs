@@ -54,6 +54,10 @@ impl<N: Network> Parser for Instruction<N> {
 
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
+        // Parse a `repeat` block from the string, which is not terminated by a semicolon.
+        if let Ok((string, repeat)) = Repeat::parse(string) {
+            return Ok((string, Self::Repeat(repeat)));
+        }
         // Parse the instruction from the string.
         let (string, instruction) = crate::instruction!(instruction_parsers!(self, _instruction))(string)?;
         // Parse the whitespace from the string.
@@ -98,4 +102,14 @@ mod tests {
         assert_eq!(instruction, candidate.to_string());
         Ok(())
     }
+
+    #[test]
+    fn test_parse_repeat() -> Result<()> {
+        let instruction = "repeat 4u32 as r1 {\n        add r0[r1] r2 into r3;\n    }";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::Repeat(_)));
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
 }
@@ -16,6 +16,9 @@
 pub mod command;
 pub use command::*;
 
+mod event;
+pub use event::*;
+
 mod finalize_global_state;
 pub use finalize_global_state::*;
 
@@ -24,3 +27,6 @@ pub use finalize_operation::*;
 
 pub mod instruction;
 pub use instruction::*;
+
+mod repeat;
+pub use repeat::*;
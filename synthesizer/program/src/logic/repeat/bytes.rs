@@ -0,0 +1,91 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Statement: FromBytes> FromBytes for RepeatCore<N, Statement> {
+    /// Reads the `repeat` block from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the bound.
+        let bound = U32::read_le(&mut reader)?;
+        // Read the loop-index register.
+        let index = Register::read_le(&mut reader)?;
+
+        // Read the body.
+        let num_statements = u32::read_le(&mut reader)?;
+        if num_statements > u32::try_from(N::MAX_INSTRUCTIONS).map_err(error)? {
+            return Err(error(format!("Failed to deserialize a repeat block: too many statements ({num_statements})")));
+        }
+        // Note: The statements are read through a trait object, as a statement may contain a `repeat` block,
+        // and a generic reader would be instantiated with an unbounded number of references.
+        let reader: &mut dyn Read = &mut reader;
+        let mut body = Vec::with_capacity(num_statements as usize);
+        for _ in 0..num_statements {
+            body.push(Statement::read_le(&mut *reader)?);
+        }
+
+        // Return the block.
+        Self::new(bound, index, body).map_err(error)
+    }
+}
+
+impl<N: Network, Statement: ToBytes> ToBytes for RepeatCore<N, Statement> {
+    /// Writes the `repeat` block to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the bound.
+        self.bound.write_le(&mut writer)?;
+        // Write the loop-index register.
+        self.index.write_le(&mut writer)?;
+
+        // Write the number of statements in the body.
+        let num_statements = self.body.len();
+        match 0 < num_statements && num_statements <= N::MAX_INSTRUCTIONS {
+            true => u32::try_from(num_statements).map_err(error)?.write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {num_statements} statements as bytes"))),
+        }
+
+        // Write the body.
+        // Note: The statements are written through a trait object, as a statement may contain a `repeat` block,
+        // and a generic writer would be instantiated with an unbounded number of references.
+        let writer: &mut dyn Write = &mut writer;
+        for statement in self.body.iter() {
+            statement.write_le(&mut *writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = Repeat::<CurrentNetwork>::from_str("repeat 4u32 as r1 { add r0[r1] r2 into r3; }")?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Repeat::read_le(&expected_bytes[..])?);
+
+        let expected = RepeatCommand::<CurrentNetwork>::from_str("repeat 2u32 as r2 { set r2 into counts[r2]; }")?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RepeatCommand::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
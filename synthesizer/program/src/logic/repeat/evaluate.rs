@@ -0,0 +1,110 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::traits::{
+    InstructionTrait,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersSigner,
+    RegistersSignerCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+use console::program::{Literal, LiteralType, PlaintextType, RegisterType};
+
+impl<N: Network> Repeat<N> {
+    /// Returns the destination registers of the block, starting with the loop-index register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        std::iter::once(self.index.clone())
+            .chain(self.body.iter().flat_map(|instruction| instruction.destinations()))
+            .collect()
+    }
+
+    /// Evaluates the block, by evaluating the body once for each loop index.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersSigner<N> + RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        for iteration in 0..*self.bound {
+            // Clear the registers assigned by the previous iteration.
+            registers.rewind(self.index.locator());
+            // Store the loop index.
+            registers.store_literal(stack, &self.index, Literal::U32(U32::new(iteration)))?;
+            // Evaluate the body.
+            for instruction in &self.body {
+                if let Err(error) = instruction.evaluate(stack, registers) {
+                    bail!("Failed to evaluate instruction ({instruction}) at loop index {iteration}: {error}")
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes the block, by unrolling the body into the circuit once for each loop index.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersSignerCircuit<N, A> + RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        for iteration in 0..*self.bound {
+            // Clear the registers assigned by the previous iteration.
+            registers.rewind_circuit(self.index.locator());
+            // Store the loop index, as a constant.
+            let index = circuit::Literal::U32(circuit::U32::constant(U32::new(iteration)));
+            registers.store_literal_circuit(stack, &self.index, index)?;
+            // Execute the body.
+            for instruction in &self.body {
+                if let Err(error) = instruction.execute::<A>(stack, registers) {
+                    bail!("Failed to execute instruction ({instruction}) at loop index {iteration}: {error}")
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finalizes the block.
+    /// Note: In a finalize scope, `repeat` blocks are declared as commands, not as instructions.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: a 'repeat' instruction cannot be finalized, use a 'repeat' command instead")
+    }
+
+    /// Returns the output type from the given program and input types.
+    /// Note: The only output of the block itself is the loop index.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        ensure!(input_types.is_empty(), "Instruction '{}' expects no inputs", Self::opcode());
+        // Return the type of the loop index.
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))])
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    traits::{CommandTrait, FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    Event,
+    FinalizeOperation,
+    FinalizeRegistersState,
+};
+use console::program::Literal;
+
+impl<N: Network> RepeatCommand<N> {
+    /// Returns the destination registers of the block, starting with the loop-index register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        std::iter::once(self.index.clone()).chain(self.body.iter().flat_map(|command| command.destinations())).collect()
+    }
+
+    /// Returns `true` if the body contains a write command.
+    #[inline]
+    pub fn is_write(&self) -> bool {
+        self.body.iter().any(|command| command.is_write())
+    }
+
+    /// Finalizes the block, by running the body once for each loop index.
+    /// Returns the finalize operations and the events, in the order they were produced.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + FinalizeRegistersState<N>),
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
+        // Initialize a list for the finalize operations.
+        let mut finalize_operations = Vec::new();
+        // Initialize a list for the emitted events.
+        let mut events = Vec::new();
+
+        for iteration in 0..*self.bound {
+            // Clear the registers assigned by the previous iteration.
+            registers.rewind(self.index.locator());
+            // Store the loop index.
+            registers.store_literal(stack, &self.index, Literal::U32(U32::new(iteration)))?;
+            // Finalize the body.
+            for command in &self.body {
+                let result = match command {
+                    // Finalize the `emit` command, and add the event to the list.
+                    Command::Emit(emit) => emit.finalize(stack, registers).map(|event| events.push(event)),
+                    // Finalize the command, and add the finalize operation to the list, if there is one.
+                    _ => {
+                        command.finalize(stack, store, registers).map(|operation| finalize_operations.extend(operation))
+                    }
+                };
+                if let Err(error) = result {
+                    bail!("Failed to finalize command ({command}) at loop index {iteration}: {error}")
+                }
+            }
+        }
+        Ok((finalize_operations, events))
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod evaluate;
+mod finalize;
+mod parse;
+
+use crate::{Command, Instruction, Opcode, Operand};
+use console::{
+    network::prelude::*,
+    program::{Register, U32},
+};
+
+/// A `repeat` block in a closure or function, e.g. `repeat 4u32 as r3 { ... }`.
+pub type Repeat<N> = RepeatCore<N, Instruction<N>>;
+/// A `repeat` block in a finalize scope, e.g. `repeat 4u32 as r3 { ... }`.
+pub type RepeatCommand<N> = RepeatCore<N, Command<N>>;
+
+/// A compile-time bounded loop, which executes its `body` once for each index in `0..bound`.
+///
+/// On each iteration, the loop index is stored as a `u32` in the `index` register, and the body
+/// assigns its destination registers afresh. After the loop, those registers hold the values from
+/// the final iteration.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RepeatCore<N: Network, Statement> {
+    /// The number of iterations.
+    bound: U32<N>,
+    /// The register holding the loop index.
+    index: Register<N>,
+    /// The statements in the loop body, in order of execution.
+    body: Vec<Statement>,
+}
+
+impl<N: Network, Statement> RepeatCore<N, Statement> {
+    /// Initializes a new `repeat` block.
+    #[inline]
+    pub fn new(bound: U32<N>, index: Register<N>, body: Vec<Statement>) -> Result<Self> {
+        // Ensure the loop has at least one iteration.
+        ensure!(*bound > 0, "A 'repeat' block must have at least one iteration");
+        // Ensure the loop-index register is a locator.
+        ensure!(matches!(index, Register::Locator(..)), "The loop-index register '{index}' must be a locator");
+        // Ensure the body is not empty.
+        ensure!(!body.is_empty(), "A 'repeat' block must contain at least one statement");
+        // Ensure the body does not exceed the maximum number of instructions.
+        ensure!(
+            body.len() <= N::MAX_INSTRUCTIONS,
+            "A 'repeat' block cannot contain more than {} statements",
+            N::MAX_INSTRUCTIONS
+        );
+        // Return the block.
+        Ok(Self { bound, index, body })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Repeat
+    }
+
+    /// Returns the operands of the block.
+    /// Note: The bound is a constant, so a `repeat` block has no operands.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub const fn bound(&self) -> &U32<N> {
+        &self.bound
+    }
+
+    /// Returns the register holding the loop index.
+    #[inline]
+    pub const fn index(&self) -> &Register<N> {
+        &self.index
+    }
+
+    /// Returns the statements in the loop body.
+    #[inline]
+    pub fn body(&self) -> &[Statement] {
+        &self.body
+    }
+}
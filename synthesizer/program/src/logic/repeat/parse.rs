@@ -0,0 +1,136 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Statement: Parser + Clone> Parser for RepeatCore<N, Statement> {
+    /// Parses a string into a `repeat` block.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the bound from the string.
+        let (string, bound) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" keyword from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the loop-index register from the string.
+        let (string, index) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "{" from the string.
+        let (string, _) = tag("{")(string)?;
+
+        // Parse the statements in the body from the string.
+        let (string, body) = many1(Statement::parse)(string)?;
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the "}" from the string.
+        let (string, _) = tag("}")(string)?;
+
+        map_res(take(0usize), move |_| Self::new(bound, index.clone(), body.clone()))(string)
+    }
+}
+
+impl<N: Network, Statement: Parser + Clone> FromStr for RepeatCore<N, Statement> {
+    type Err = Error;
+
+    /// Parses a string into a `repeat` block.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, Statement: Display> Debug for RepeatCore<N, Statement> {
+    /// Prints the `repeat` block as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, Statement: Display> Display for RepeatCore<N, Statement> {
+    /// Prints the `repeat` block as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the opcode, bound, and loop-index register.
+        write!(f, "{} {} as {} {{", Self::opcode(), self.bound, self.index)?;
+        // Print the body.
+        self.body.iter().try_for_each(|statement| write!(f, "\n        {statement}"))?;
+        // Print the closing brace.
+        write!(f, "\n    }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, repeat) =
+            Repeat::<CurrentNetwork>::parse("repeat 4u32 as r1 { add r0[r1] r2 into r3; mul r3 r3 into r4; }").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.bound(), U32::new(4));
+        assert_eq!(repeat.index(), &Register::Locator(1));
+        assert_eq!(repeat.body().len(), 2);
+        assert!(repeat.operands().is_empty());
+
+        let (string, repeat) =
+            RepeatCommand::<CurrentNetwork>::parse("repeat 2u32 as r2 { get balances[r0[r2]] into r3; }").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.bound(), U32::new(2));
+        assert!(matches!(repeat.body()[0], Command::Get(_)));
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // The bound must be a `u32`.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u64 as r1 { add r0 r0 into r2; }").is_err());
+        // The bound must be positive.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 0u32 as r1 { add r0 r0 into r2; }").is_err());
+        // The loop-index register must be a locator.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 as r1.owner { add r0 r0 into r2; }").is_err());
+        // The body must not be empty.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 as r1 { }").is_err());
+        // The body must be closed.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 as r1 { add r0 r0 into r2;").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = "repeat 4u32 as r1 {\n        add r0[r1] r2 into r3;\n        mul r3 r3 into r4;\n    }";
+        let repeat = Repeat::<CurrentNetwork>::from_str(expected).unwrap();
+        assert_eq!(expected, repeat.to_string());
+    }
+}
@@ -44,6 +44,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Parse the upgrade policy from the string, if one is declared.
+        let (string, upgrade_policy) = opt(UpgradePolicy::parse)(string)?;
 
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
@@ -84,6 +86,13 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
+            // Set the upgrade policy (if any), as it may refer to a mapping.
+            if let Some(upgrade_policy) = upgrade_policy {
+                if let Err(error) = program.set_upgrade_policy(upgrade_policy) {
+                    eprintln!("{error}");
+                    return Err(error);
+                }
+            }
             // Lastly, add the imports (if any) to the program.
             for import in imports.iter() {
                 match program.add_import(import.clone()) {
@@ -151,7 +160,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         }
 
         // Print the program name.
-        program += &format!("{} {};\n\n", Self::type_name(), self.id);
+        program += &format!("{} {};\n", Self::type_name(), self.id);
+        // Print the upgrade policy, if the program is upgradable.
+        if !self.upgrade_policy.is_immutable() {
+            program += &format!("{}\n", self.upgrade_policy);
+        }
+        program.push('\n');
 
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
@@ -260,6 +274,40 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_upgrade_policy() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+upgrade mapping approvals;
+
+mapping approvals:
+    key as u16.public;
+    value as address.public;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(program.upgrade_policy(), &UpgradePolicy::Mapping(Identifier::from_str("approvals")?));
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure an undeclared program is immutable.
+        let program = Program::<CurrentNetwork>::from_str(&expected.replace("upgrade mapping approvals;\n", ""))?;
+        assert!(program.upgrade_policy().is_immutable());
+
+        // Ensure the upgrade mapping must be defined.
+        assert!(
+            Program::<CurrentNetwork>::from_str(&expected.replace("mapping approvals;", "mapping missing;")).is_err()
+        );
+        // Ensure the upgrade mapping must be of type `u16 => address`.
+        assert!(Program::<CurrentNetwork>::from_str(&expected.replace("key as u16", "key as u32")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_size() {
         // Define variable name for easy experimentation with program sizes.
@@ -40,6 +40,18 @@ pub trait FinalizeStoreTrait<N: Network> {
         key: &Plaintext<N>,
     ) -> Result<Option<Value<N>>>;
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize>;
+
+    /// Returns the speculative entry that follows the given `key` for the given `program ID` and `mapping name`.
+    /// If no `key` is given, returns the first speculative entry of the mapping.
+    fn get_next_key_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Option<&Plaintext<N>>,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>>;
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -75,6 +75,9 @@ pub trait StackProgram<N: Network> {
     /// Returns the external stack for the given program ID.
     fn get_external_stack(&self, program_id: &ProgramID<N>) -> Result<&Arc<Self>>;
 
+    /// Returns the stack of the callee of a dynamic call, for the given program ID.
+    fn get_dynamic_stack(&self, program_id: &ProgramID<N>) -> Result<Arc<Self>>;
+
     /// Returns the external program for the given program ID.
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>>;
 
@@ -305,6 +308,10 @@ pub trait RegistersStore<N: Network> {
     ) -> Result<()> {
         self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
     }
+
+    /// Clears every register from the given locator onwards, so that the body of a `repeat` block
+    /// may assign its registers again on the next iteration.
+    fn rewind(&mut self, locator: u64);
 }
 
 pub trait RegistersStoreCircuit<N: Network, A: circuit::Aleo<Network = N>> {
@@ -336,4 +343,8 @@ pub trait RegistersStoreCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     ) -> Result<()> {
         self.store_circuit(stack, register, circuit::Value::Plaintext(circuit::Plaintext::from(literal)))
     }
+
+    /// Clears every circuit register from the given locator onwards, so that the body of a `repeat` block
+    /// may assign its registers again on the next iteration.
+    fn rewind_circuit(&mut self, locator: u64);
 }
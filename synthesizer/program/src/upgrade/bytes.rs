@@ -0,0 +1,63 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for UpgradePolicy<N> {
+    /// Reads the upgrade policy from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Match the variant.
+        match variant {
+            0 => Ok(Self::Immutable),
+            1 => Ok(Self::Owner),
+            2 => Ok(Self::Mapping(Identifier::read_le(&mut reader)?)),
+            _ => Err(error(format!("Failed to decode upgrade policy variant {variant}"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for UpgradePolicy<N> {
+    /// Writes the upgrade policy to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Immutable => 0u8.write_le(&mut writer),
+            Self::Owner => 1u8.write_le(&mut writer),
+            Self::Mapping(name) => {
+                2u8.write_le(&mut writer)?;
+                name.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["upgrade immutable;", "upgrade owner;", "upgrade mapping approvals;"] {
+            let expected = UpgradePolicy::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, UpgradePolicy::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, LiteralType, PlaintextType},
+};
+
+use crate::Mapping;
+
+/// An upgrade statement declares the upgrade policy of a program, and is of the form `upgrade {policy};`.
+/// If no upgrade statement is declared, the program is immutable.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UpgradePolicy<N: Network> {
+    /// The program cannot be upgraded, and is of the form `upgrade immutable;`.
+    #[default]
+    Immutable,
+    /// The program may be upgraded by its owner, and is of the form `upgrade owner;`.
+    Owner,
+    /// The program may be upgraded by the address approved for the new edition in the given mapping,
+    /// and is of the form `upgrade mapping {name};`. The mapping must be of type `u16 => address`.
+    Mapping(Identifier<N>),
+}
+
+impl<N: Network> UpgradePolicy<N> {
+    /// Returns `true` if the program cannot be upgraded.
+    #[inline]
+    pub const fn is_immutable(&self) -> bool {
+        matches!(self, Self::Immutable)
+    }
+
+    /// Ensures the given mapping may govern upgrades, by mapping an edition to the approved address.
+    pub fn check_mapping(mapping: &Mapping<N>) -> Result<()> {
+        ensure!(
+            mapping.key().plaintext_type() == &PlaintextType::Literal(LiteralType::U16),
+            "The upgrade mapping '{}' must have a key of type 'u16'",
+            mapping.name()
+        );
+        ensure!(
+            mapping.value().plaintext_type() == &PlaintextType::Literal(LiteralType::Address),
+            "The upgrade mapping '{}' must have a value of type 'address'",
+            mapping.name()
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> TypeName for UpgradePolicy<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "upgrade"
+    }
+}
@@ -85,6 +85,25 @@ impl<N: Network> Certificate<N> {
             }
         }
     }
+
+    /// Checks the certificate for the given assignment and verifying key, except for the final pairing check,
+    /// which is returned instead.
+    pub fn prepare_verify(
+        &self,
+        function_name: &str,
+        assignment: &circuit::Assignment<N::Field>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<PairingCheck<N>> {
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Check the certificate, deferring the pairing check.
+        match Varuna::<N>::prepare_verify_vk(universal_verifier, fiat_shamir, assignment, verifying_key, self) {
+            Ok(pairing_check) => Ok(PairingCheck::new(pairing_check)),
+            Err(error) => bail!("The certificate for '{function_name}' is invalid - {error}"),
+        }
+    }
 }
 
 impl<N: Network> Deref for Certificate<N> {
@@ -32,11 +32,14 @@ type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShami
 mod certificate;
 pub use certificate::Certificate;
 
+mod pairing_check;
+pub use pairing_check::PairingCheck;
+
 mod proof;
 pub use proof::Proof;
 
 mod proving_key;
-pub use proving_key::ProvingKey;
+pub use proving_key::{ProverWorker, ProvingKey};
 
 mod universal_srs;
 pub use universal_srs::UniversalSRS;
@@ -162,6 +165,35 @@ mod test {
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
 
+    #[test]
+    fn test_varuna_pairing_checks() {
+        let rng = &mut TestRng::default();
+        let assignment = crate::test_helpers::sample_assignment();
+
+        // Varuna setup, prove, and certify.
+        let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = srs.to_circuit_key("test", &assignment).unwrap();
+        let proof = proving_key.prove("test", &assignment, rng).unwrap();
+        let certificate = Certificate::certify("test", &proving_key, &verifying_key).unwrap();
+
+        // Prepare the pairing checks for the proof and the certificate.
+        let one = <Circuit as Environment>::BaseField::one();
+        let inputs = vec![(verifying_key.clone(), vec![vec![one, one]])];
+        let proof_check = VerifyingKey::prepare_verify_batch("test", inputs, &proof).unwrap();
+        let certificate_check = certificate.prepare_verify("test", &assignment, &verifying_key).unwrap();
+
+        // Ensure the pairing checks hold, individually and together.
+        assert!(proof_check.verify());
+        assert!(certificate_check.verify());
+        assert!(PairingCheck::verify_batch(&[proof_check.clone(), certificate_check.clone()], rng));
+
+        // Ensure a pairing check for incorrect inputs fails, individually and together.
+        let inputs = vec![(verifying_key, vec![vec![one, one + one]])];
+        let invalid_check = VerifyingKey::prepare_verify_batch("test", inputs, &proof).unwrap();
+        assert!(!invalid_check.verify());
+        assert!(!PairingCheck::verify_batch(&[proof_check, invalid_check, certificate_check], rng));
+    }
+
     #[test]
     fn test_varuna_verify_public_input_size() {
         /// Creates a simple circuit: a * b.
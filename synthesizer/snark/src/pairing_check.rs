@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::polycommit::sonic_pc;
+
+/// A pairing check from the verification of a proof or certificate, which has not been performed yet.
+///
+/// The pairing checks of proofs and certificates for different circuits can be verified together, with one multi-pairing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<N: Network> {
+    /// The pairing check.
+    pairing_check: sonic_pc::PairingCheck<N::PairingCurve>,
+}
+
+impl<N: Network> PairingCheck<N> {
+    /// Initializes a new pairing check.
+    pub(super) const fn new(pairing_check: sonic_pc::PairingCheck<N::PairingCurve>) -> Self {
+        Self { pairing_check }
+    }
+
+    /// Returns `true` if the pairing check holds.
+    pub fn verify(&self) -> bool {
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+
+        // Verify the pairing check.
+        Varuna::<N>::verify_pairing_check(universal_verifier, self.pairing_check.clone()).unwrap_or(false)
+    }
+
+    /// Returns `true` if all of the given pairing checks hold, checking them together with one multi-pairing.
+    /// The pairing checks are folded with coefficients sampled from `rng`, which must be unpredictable to the provers.
+    pub fn verify_batch<R: Rng + CryptoRng>(pairing_checks: &[Self], rng: &mut R) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+
+        // Verify the pairing checks.
+        let checks = pairing_checks.iter().map(|pairing_check| pairing_check.pairing_check.clone());
+        match Varuna::<N>::verify_pairing_checks(universal_verifier, checks, rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(
+                        " • Verified {} pairing checks: {is_valid} (in {} ms)",
+                        pairing_checks.len(),
+                        timer.elapsed().as_millis()
+                    )
+                    .dimmed()
+                );
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
@@ -19,7 +19,14 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, net::SocketAddr};
+
+/// A worker of the distributed prover, which synthesizes the witnesses of the assignments it is sent.
+pub type ProverWorker<N> = varuna::distributed::Worker<
+    <N as Environment>::Field,
+    varuna::VarunaHidingMode,
+    circuit::Assignment<<N as Environment>::Field>,
+>;
 
 #[derive(Clone)]
 pub struct ProvingKey<N: Network> {
@@ -66,12 +73,7 @@ impl<N: Network> ProvingKey<N> {
         let timer = std::time::Instant::now();
 
         // Prepare the instances.
-        let num_expected_instances = assignments.len();
-        let instances: BTreeMap<_, _> = assignments
-            .iter()
-            .map(|(proving_key, assignments)| (proving_key.deref(), assignments.as_slice()))
-            .collect();
-        ensure!(instances.len() == num_expected_instances, "Incorrect number of proving keys for batch proof");
+        let instances = Self::to_batch_instances(assignments)?;
 
         // Retrieve the proving parameters.
         let universal_prover = N::varuna_universal_prover();
@@ -85,6 +87,61 @@ impl<N: Network> ProvingKey<N> {
 
         Ok(batch_proof)
     }
+
+    /// Returns a proof for the given batch of proving keys and assignments,
+    /// with the witnesses synthesized by the workers at the given addresses.
+    ///
+    /// Each worker must serve the circuits of the batch, see `ProvingKey::to_prover_worker`.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_distributed<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        workers: &[SocketAddr],
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the instances.
+        let instances = Self::to_batch_instances(assignments)?;
+
+        // Retrieve the proving parameters.
+        let universal_prover = N::varuna_universal_prover();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Compute the proof.
+        let batch_proof =
+            Proof::new(Varuna::<N>::prove_batch_distributed(universal_prover, fiat_shamir, &instances, workers, rng)?);
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+
+        Ok(batch_proof)
+    }
+
+    /// Returns a worker of the distributed prover, which serves the circuits of the given proving keys.
+    pub fn to_prover_worker<'a>(proving_keys: impl IntoIterator<Item = &'a ProvingKey<N>>) -> ProverWorker<N> {
+        ProverWorker::<N>::new(proving_keys.into_iter().map(|proving_key| proving_key.circuit.clone()))
+    }
+
+    /// Returns the instances of a batch proof, for the given batch of proving keys and assignments.
+    #[allow(clippy::type_complexity)]
+    fn to_batch_instances(
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+    ) -> Result<
+        BTreeMap<
+            &varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>,
+            &[circuit::Assignment<N::Field>],
+        >,
+    > {
+        let num_expected_instances = assignments.len();
+        let instances: BTreeMap<_, _> = assignments
+            .iter()
+            .map(|(proving_key, assignments)| (proving_key.deref(), assignments.as_slice()))
+            .collect();
+        ensure!(instances.len() == num_expected_instances, "Incorrect number of proving keys for batch proof");
+        Ok(instances)
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
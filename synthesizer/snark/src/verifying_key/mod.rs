@@ -104,6 +104,30 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Checks the batch proof for the given public inputs, except for the final pairing check, which is returned instead.
+    #[allow(clippy::type_complexity)]
+    pub fn prepare_verify_batch(
+        locator: &str,
+        inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> Result<PairingCheck<N>> {
+        // Convert the instances.
+        let num_expected_keys = inputs.len();
+        let keys_to_inputs: BTreeMap<_, _> =
+            inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+        ensure!(keys_to_inputs.len() == num_expected_keys, "Incorrect number of verifying keys for batch proof");
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Check the batch proof, deferring the pairing check.
+        match Varuna::<N>::prepare_verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof)? {
+            Some(pairing_check) => Ok(PairingCheck::new(pairing_check)),
+            None => bail!("'verify_batch' failed for '{locator}'"),
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
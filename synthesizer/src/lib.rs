@@ -49,7 +49,7 @@ pub mod prelude {
     #[cfg(feature = "program")]
     pub use crate::program::{Closure, Finalize, Function, Instruction, Mapping, Program};
     #[cfg(feature = "snark")]
-    pub use crate::snark::{Certificate, Proof, ProvingKey, UniversalSRS, VerifyingKey};
+    pub use crate::snark::{Certificate, PairingCheck, Proof, ProvingKey, UniversalSRS, VerifyingKey};
     #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
     pub use crate::vm::*;
 }
@@ -63,7 +63,13 @@ impl<N: Network> Restrictions<N> {
     /// Initializes the `Restrictions` instance for the current network.
     pub fn load() -> Result<Self> {
         // Load the restrictions list from the network.
-        let restrictions = Self::from_str(N::restrictions_list_as_str())?;
+        Self::from_list(N::restrictions_list_as_str())
+    }
+
+    /// Initializes a `Restrictions` instance from the given restrictions list, as a JSON-string.
+    pub fn from_list(list: &str) -> Result<Self> {
+        // Parse the restrictions list.
+        let restrictions = Self::from_str(list)?;
         // Ensure the restrictions ID matches the computed value.
         let expected_restrictions_id =
             Self::compute_restrictions_id(&restrictions.programs, &restrictions.functions, &restrictions.arguments)?;
@@ -332,6 +338,25 @@ mod tests {
         assert!(!restrictions.is_argument_restricted(&transition, 25));
     }
 
+    #[test]
+    fn test_restrictions_from_list() {
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        restrictions.programs.insert(ProgramID::from_str("restricted.aleo").unwrap(), BlockRange::RangeFrom(10..));
+
+        // Ensure a list with a mismatched restrictions ID is rejected.
+        assert!(Restrictions::<CurrentNetwork>::from_list(&restrictions.to_string()).is_err());
+
+        // Ensure a list with the computed restrictions ID is accepted.
+        restrictions.restrictions_id = Restrictions::compute_restrictions_id(
+            &restrictions.programs,
+            &restrictions.functions,
+            &restrictions.arguments,
+        )
+        .unwrap();
+        let candidate = Restrictions::<CurrentNetwork>::from_list(&restrictions.to_string()).unwrap();
+        assert_eq!(restrictions, candidate);
+    }
+
     /// **Attention**: This method is used to auto-generate the restrictions lists for each network
     /// to be used by the `snarkvm_parameters` crate.
     #[test]
@@ -42,6 +42,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<(Ratifications<N>, Transactions<N>, Vec<N::TransactionID>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("VM::speculate");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Collect the candidate transactions into a vector.
         let candidate_transactions: Vec<_> = candidate_transactions.collect::<Vec<_>>();
@@ -86,6 +88,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             .collect();
 
         finish!(timer, "Finished dry-run of the transactions");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::vm::SPECULATE_LATENCY, start.elapsed().as_secs_f64());
 
         // Return the ratifications, confirmed transactions, aborted transaction IDs, and ratified finalize operations.
         Ok((
@@ -162,13 +166,114 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transactions: &Transactions<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::finalize");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
         let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
 
+        // Update the restrictions lists with the ratified restrictions.
+        for ratify in ratifications.iter() {
+            if let Ratify::Restrictions(activation_height, list, _) = ratify {
+                self.ratified_restrictions.write().insert(*activation_height, Restrictions::from_list(list)?);
+            }
+        }
+
         finish!(timer, "Finished real-run of finalize");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::vm::FINALIZE_LATENCY, start.elapsed().as_secs_f64());
         Ok(ratified_finalize_operations)
     }
+
+    /// Simulates the execution of the given authorization, without computing a proof.
+    ///
+    /// The function is evaluated to produce its outputs, and its finalize logic is speculatively run
+    /// against the current finalize store, as if the execution were included in the next block.
+    /// No changes are committed to storage.
+    ///
+    /// Note: The storage cost does not include the size of the execution proof,
+    /// and therefore underestimates the storage cost of the proven execution.
+    pub fn simulate_execution(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+    ) -> Result<SimulatedExecution<N>> {
+        let timer = timer!("VM::simulate_execution");
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        // Retrieve the global state root.
+        let global_state_root = query.current_state_root()?;
+        lap!(timer, "Prepare the query");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Evaluate the function.
+                let response = $process.evaluate::<$aleo>(authorization.replicate())?;
+                // Return the outputs.
+                Ok(cast_ref!(response as Response<N>).outputs().to_vec())
+            }};
+        }
+        // Evaluate the authorization.
+        let outputs: Result<Vec<Value<N>>> = process!(self, logic);
+        let outputs = outputs?;
+        lap!(timer, "Evaluate the function");
+
+        // Construct the execution from the transitions in the authorization, without a proof.
+        let execution = Execution::from(authorization.transitions().into_values(), global_state_root, None)?;
+        // Compute the execution cost.
+        let (_, costs) = execution_cost(&self.process().read(), &execution)?;
+        lap!(timer, "Compute the execution cost");
+
+        // Retrieve the latest block header.
+        let latest_height = self.block_store().current_block_height();
+        let Some(latest_hash) = self.block_store().get_block_hash(latest_height)? else {
+            bail!("Block {latest_height} does not exist in storage")
+        };
+        let Some(latest_header) = self.block_store().get_block_header(&latest_hash)? else {
+            bail!("Missing block header for block {latest_height}")
+        };
+        // Construct the finalize state for the next block.
+        let state = FinalizeGlobalState::new::<N>(
+            latest_header.round().saturating_add(1),
+            latest_height.saturating_add(1),
+            latest_header.timestamp(),
+            latest_header.cumulative_weight(),
+            latest_header.cumulative_proof_target(),
+            latest_hash,
+        )?;
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Perform a dry-run of finalize on the execution.
+        let store = self.finalize_store();
+        let outcome = atomic_finalize!(store, FinalizeMode::DryRun, {
+            let process = self.process.read();
+            Ok(Self::prepare_for_execution(store, &execution)
+                .and_then(|_| process.finalize_execution(state, store, &execution, None)))
+        })?;
+        finish!(timer, "Perform a dry-run of finalize");
+
+        // Return the simulated execution.
+        match outcome {
+            Ok(finalize) => Ok(SimulatedExecution::accepted(outputs, costs, finalize)),
+            Err(error) => {
+                // Determine the failed command, if finalize failed on a command.
+                let failed_command = error
+                    .downcast_ref::<FinalizeError<N>>()
+                    .map(|error| (Locator::new(*error.program_id(), *error.function_name()), error.command_index()));
+                Ok(SimulatedExecution::rejected(outputs, costs, error.to_string(), failed_command))
+            }
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -238,12 +343,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -353,7 +458,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 }
                             },
                             // If the program has not yet been deployed, attempt to deploy it.
-                            false => match process.finalize_deployment(state, store, deployment, fee) {
+                            false => match self
+                                .check_upgrade_policy(&process, program_owner, deployment)
+                                .and_then(|_| process.finalize_deployment(state, store, deployment, fee))
+                            {
                                 // Construct the accepted deploy transaction.
                                 Ok((_, finalize)) => {
                                     // Add the program id to the list of deployments.
@@ -385,8 +493,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             .and_then(|_| process.finalize_execution(state, store, execution, fee.as_ref()))
                         {
                             // Construct the accepted execute transaction.
-                            Ok(finalize) => {
-                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
+                            Ok((finalize, events)) => {
+                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize, events)
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
@@ -543,12 +651,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -591,14 +699,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
                 let outcome: Result<(), String> = match transaction {
                     ConfirmedTransaction::AcceptedDeploy(_, transaction, finalize) => {
-                        // Extract the deployment and fee from the transaction.
-                        let (deployment, fee) = match transaction {
-                            Transaction::Deploy(_, _, deployment, fee) => (deployment, fee),
+                        // Extract the owner, deployment, and fee from the transaction.
+                        let (program_owner, deployment, fee) = match transaction {
+                            Transaction::Deploy(_, program_owner, deployment, fee) => (program_owner, deployment, fee),
                             // Note: This will abort the entire atomic batch.
                             _ => return Err("Expected deploy transaction".to_string()),
                         };
                         // The finalize operation here involves appending the 'stack', and adding the program to the finalize tree.
-                        match process.finalize_deployment(state, store, deployment, fee) {
+                        match self
+                            .check_upgrade_policy(&process, program_owner, deployment)
+                            .and_then(|_| process.finalize_deployment(state, store, deployment, fee))
+                        {
                             // Ensure the finalize operations match the expected.
                             Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
                                 // Store the stack.
@@ -617,7 +728,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         Ok(())
                     }
-                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize) => {
+                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize, events) => {
                         // Extract the execution and fee from the transaction.
                         let (execution, fee) = match transaction {
                             Transaction::Execute(_, execution, fee) => (execution, fee),
@@ -627,14 +738,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // The finalize operation here involves calling 'update_key_value',
                         // and update the respective leaves of the finalize tree.
                         match process.finalize_execution(state, store, execution, fee.as_ref()) {
-                            // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            // Ensure the finalize operations and events match the expected.
+                            Ok((finalize_operations, candidate_events)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for an accepted execute - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                if events != &candidate_events {
+                                    // Note: This will abort the entire atomic batch.
+                                    return Err(format!(
+                                        "Mismatch in events for an accepted execute - (found: {candidate_events:?}, expected: {events:?})"
+                                    ));
+                                }
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
@@ -747,11 +864,29 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            /* Store the ratified finalize operations for the block. */
+
+            // Note: This will abort the entire atomic batch.
+            let operations = ratified_finalize_operations.clone();
+            if let Err(e) = store.insert_ratified_operations(state.block_height(), operations) {
+                return Err(format!("Failed to store the ratified finalize operations - {e}"));
+            }
+
+            /* Store the rollback operations for the block. */
+
+            // Note: This will abort the entire atomic batch.
+            if let Err(e) = store.store_rollback_operations(state.block_height()) {
+                return Err(format!("Failed to store the rollback operations - {e}"));
+            }
+
             /* Start the commit process. */
 
-            // Commit all of the stacks to the process.
-            if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+            // Commit all of the stacks to the process, replacing the previous edition of upgraded programs.
+            for stack in stacks {
+                match process.contains_program(stack.program_id()) {
+                    true => process.upgrade_stack(stack).map_err(|e| format!("Failed to upgrade a stack - {e}"))?,
+                    false => process.add_stack(stack),
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -1189,7 +1324,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the genesis ratification flag.
                     is_genesis_ratified = true;
                 }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => continue,
             }
         }
 
@@ -1224,6 +1359,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let mut is_block_reward_ratified = false;
         // Initialize a flag for the puzzle reward ratification.
         let mut is_puzzle_reward_ratified = false;
+        // Initialize a flag for the restrictions ratification.
+        let mut is_restrictions_ratified = false;
 
         // Iterate over the ratifications.
         for ratify in post_ratifications {
@@ -1351,6 +1488,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the puzzle reward ratification flag.
                     is_puzzle_reward_ratified = true;
                 }
+                Ratify::Restrictions(activation_height, list, signatures) => {
+                    // Ensure the restrictions have not been ratified yet.
+                    ensure!(!is_restrictions_ratified, "Ratify::Restrictions(..) has already been ratified");
+
+                    // Retrieve the committee as of the previous block.
+                    let Some(previous_height) = state.block_height().checked_sub(1) else {
+                        bail!("Ratify::Restrictions(..) cannot be ratified in the genesis block")
+                    };
+                    let Some(committee) = store.committee_store().get_committee(previous_height)? else {
+                        bail!("Ratify::Restrictions(..) requires the committee for block {previous_height}")
+                    };
+                    // Retrieve the ID of the restrictions list that this list replaces.
+                    let previous_restrictions_id = store.get_latest_restrictions_id_confirmed()?;
+                    // Ensure the restrictions list is authorized by a quorum of the committee.
+                    Ratify::verify_restrictions(
+                        previous_restrictions_id,
+                        *activation_height,
+                        list,
+                        signatures,
+                        &committee,
+                    )?;
+
+                    // Parse the restrictions list, which ensures the restrictions ID matches the list.
+                    let restrictions = Restrictions::<N>::from_list(list)?;
+                    // Store the restrictions list, which ensures it takes effect after the current block.
+                    store.insert_restrictions(
+                        state.block_height(),
+                        *activation_height,
+                        restrictions.restrictions_id(),
+                        list.to_string(),
+                    )?;
+
+                    // Set the restrictions ratification flag.
+                    is_restrictions_ratified = true;
+                }
             }
         }
 
@@ -2138,6 +2310,52 @@ function ped_hash:
         }
     }
 
+    #[test]
+    fn test_simulate_execution() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Retrieve the checksum prior to the simulations.
+        let expected_checksum = vm.finalize_store().get_checksum_confirmed().unwrap();
+
+        // Simulates a `transfer_public` of the given amount.
+        let simulate = |amount: &str, rng: &mut TestRng| {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&caller_address.to_string()).unwrap(),
+                Value::from_str(amount).unwrap(),
+            ];
+            let authorization =
+                vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+            vm.simulate_execution(authorization, None).unwrap()
+        };
+
+        // Simulate a transfer that is accepted.
+        let simulation = simulate("1u64", rng);
+        assert!(!simulation.is_rejected());
+        assert_eq!(simulation.outputs().len(), 1);
+        assert!(simulation.storage_cost() > 0);
+        assert!(simulation.finalize_cost() > 0);
+        assert_eq!(simulation.total_cost(), simulation.storage_cost() + simulation.finalize_cost());
+        assert!(!simulation.finalize_operations().is_empty());
+        assert!(simulation.failed_command().is_none());
+
+        // Simulate a transfer that exceeds the balance, and ensure the underflowing `sub` command is reported.
+        let simulation = simulate(&format!("{}u64", u64::MAX), rng);
+        assert!(simulation.is_rejected());
+        assert!(simulation.finalize_operations().is_empty());
+        let (locator, command_index) = simulation.failed_command().unwrap();
+        assert_eq!(locator.to_string(), "credits.aleo/transfer_public");
+        assert_eq!(*command_index, 1);
+
+        // Ensure the simulations did not modify the finalize store.
+        assert_eq!(vm.finalize_store().get_checksum_confirmed().unwrap(), expected_checksum);
+    }
+
     #[test]
     fn test_rejected_transaction_should_not_update_storage() {
         let rng = &mut TestRng::default();
@@ -25,3 +25,6 @@ mod macros;
 
 mod rewards;
 pub use rewards::*;
+
+mod simulation;
+pub use simulation::*;
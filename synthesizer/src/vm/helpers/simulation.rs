@@ -0,0 +1,121 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::Network,
+    program::{Locator, Value},
+};
+use synthesizer_program::{Event, FinalizeOperation};
+
+/// The outcome of a simulated execution, which is evaluated and finalized without a proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedExecution<N: Network> {
+    /// The outputs of the root function.
+    outputs: Vec<Value<N>>,
+    /// The storage cost in microcredits.
+    storage_cost: u64,
+    /// The finalize cost in microcredits.
+    finalize_cost: u64,
+    /// The finalize operations, if the execution is accepted.
+    finalize_operations: Vec<FinalizeOperation<N>>,
+    /// The events emitted in finalize, if the execution is accepted.
+    events: Vec<Event<N>>,
+    /// The reason the execution is rejected, if finalize fails.
+    rejection_reason: Option<String>,
+    /// The locator of the finalize scope and the index of the failed command, if finalize fails on a command.
+    failed_command: Option<(Locator<N>, usize)>,
+}
+
+impl<N: Network> SimulatedExecution<N> {
+    /// Initializes a new accepted simulated execution.
+    pub(crate) fn accepted(
+        outputs: Vec<Value<N>>,
+        (storage_cost, finalize_cost): (u64, u64),
+        (finalize_operations, events): (Vec<FinalizeOperation<N>>, Vec<Event<N>>),
+    ) -> Self {
+        Self {
+            outputs,
+            storage_cost,
+            finalize_cost,
+            finalize_operations,
+            events,
+            rejection_reason: None,
+            failed_command: None,
+        }
+    }
+
+    /// Initializes a new rejected simulated execution.
+    pub(crate) fn rejected(
+        outputs: Vec<Value<N>>,
+        (storage_cost, finalize_cost): (u64, u64),
+        rejection_reason: String,
+        failed_command: Option<(Locator<N>, usize)>,
+    ) -> Self {
+        Self {
+            outputs,
+            storage_cost,
+            finalize_cost,
+            finalize_operations: Vec::new(),
+            events: Vec::new(),
+            rejection_reason: Some(rejection_reason),
+            failed_command,
+        }
+    }
+
+    /// Returns the outputs of the root function.
+    pub fn outputs(&self) -> &[Value<N>] {
+        &self.outputs
+    }
+
+    /// Returns the storage cost in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the finalize cost in microcredits.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Returns the total cost in microcredits.
+    pub const fn total_cost(&self) -> u64 {
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+
+    /// Returns the finalize operations, if the execution is accepted.
+    pub fn finalize_operations(&self) -> &[FinalizeOperation<N>] {
+        &self.finalize_operations
+    }
+
+    /// Returns the events emitted in finalize, if the execution is accepted.
+    pub fn events(&self) -> &[Event<N>] {
+        &self.events
+    }
+
+    /// Returns `true` if the execution would be rejected in finalize.
+    pub const fn is_rejected(&self) -> bool {
+        self.rejection_reason.is_some()
+    }
+
+    /// Returns the reason the execution would be rejected, if finalize fails.
+    pub fn rejection_reason(&self) -> Option<&str> {
+        self.rejection_reason.as_deref()
+    }
+
+    /// Returns the locator of the finalize scope and the index of the failed command, if finalize fails on a command.
+    pub const fn failed_command(&self) -> Option<&(Locator<N>, usize)> {
+        self.failed_command.as_ref()
+    }
+}
@@ -26,8 +26,8 @@ use crate::{cast_mut_ref, cast_ref, convert, process, Restrictions};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Argument, Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
-    types::{Field, Group, U64},
+    program::{Argument, Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
+    types::{Field, Group, U16, U64},
 };
 use ledger_block::{
     Block,
@@ -47,7 +47,7 @@ use ledger_block::{
 use ledger_committee::Committee;
 use ledger_narwhal_data::Data;
 use ledger_puzzle::Puzzle;
-use ledger_query::Query;
+use ledger_query::{Query, QueryTrait};
 use ledger_store::{
     atomic_finalize,
     BlockStore,
@@ -58,9 +58,18 @@ use ledger_store::{
     TransactionStorage,
     TransactionStore,
     TransitionStore,
+    MAX_ROLLBACK_DEPTH,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
-use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_process::{deployment_cost, execution_cost, Authorization, FinalizeError, Process, Trace};
+use synthesizer_program::{
+    FinalizeGlobalState,
+    FinalizeOperation,
+    FinalizeStoreTrait,
+    Program,
+    StackProgram,
+    UpgradePolicy,
+};
+use synthesizer_snark::PairingCheck;
 use utilities::try_vm_runtime;
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -69,7 +78,11 @@ use itertools::Either;
 use lru::LruCache;
 use parking_lot::{Mutex, RwLock};
 use rand::{rngs::StdRng, SeedableRng};
-use std::{collections::HashSet, num::NonZeroUsize, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -86,6 +99,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, N::TransmissionChecksum>>>,
     /// The restrictions list.
     restrictions: Restrictions<N>,
+    /// The ratified restrictions lists, indexed by the block height from which they take effect.
+    ratified_restrictions: Arc<RwLock<BTreeMap<u32, Restrictions<N>>>>,
     /// The lock to guarantee atomicity over calls to speculate and finalize.
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
@@ -129,6 +144,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             if process.contains_program(program_id) {
                 return Ok(vec![]);
             }
+            // Return early if the deployment is not the latest edition of the program.
+            if transaction_store.deployment_store().get_edition(program_id)? != Some(deployment.edition()) {
+                return Ok(vec![]);
+            }
 
             // Prepare a vector for the deployments.
             let mut deployments = vec![];
@@ -186,6 +205,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Load the ratified restrictions lists.
+        let ratified_restrictions = Self::load_ratified_restrictions(&store)?;
+
         // Return the new VM.
         Ok(Self {
             process: Arc::new(RwLock::new(process)),
@@ -195,6 +217,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
             restrictions: Restrictions::load()?,
+            ratified_restrictions: Arc::new(RwLock::new(ratified_restrictions)),
             atomic_lock: Arc::new(Mutex::new(())),
             block_lock: Arc::new(Mutex::new(())),
         })
@@ -225,10 +248,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Returns the restrictions.
+    /// Note: These are the network restrictions, which are in effect until a ratified restrictions list takes effect.
     #[inline]
     pub const fn restrictions(&self) -> &Restrictions<N> {
         &self.restrictions
     }
+
+    /// Returns the restrictions in effect at the given block height.
+    #[inline]
+    pub fn restrictions_at(&self, block_height: u32) -> Restrictions<N> {
+        match self.ratified_restrictions.read().range(..=block_height).next_back() {
+            Some((_, restrictions)) => restrictions.clone(),
+            None => self.restrictions.clone(),
+        }
+    }
+
+    /// Loads the restrictions lists that were ratified in the ledger.
+    fn load_ratified_restrictions(store: &ConsensusStore<N, C>) -> Result<BTreeMap<u32, Restrictions<N>>> {
+        let mut restrictions_lists = BTreeMap::new();
+        // Load the ratified restrictions lists, in the order in which they were ratified.
+        for (_, activation_height, restrictions_id, list) in store.finalize_store().get_restrictions_confirmed()? {
+            let restrictions = Restrictions::from_list(&list)?;
+            // Ensure the restrictions ID matches the stored value.
+            if restrictions.restrictions_id() != restrictions_id {
+                bail!("The restrictions ID does not match the stored value (expected - {restrictions_id})");
+            }
+            restrictions_lists.insert(activation_height, restrictions);
+        }
+        Ok(restrictions_lists)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -402,6 +450,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -463,6 +512,92 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Removes all blocks above the given block height from the VM.
+    ///
+    /// The blocks are removed one at a time, in descending order, and each removal atomically reverts
+    /// the block, its transactions, transitions, committee, and finalize operations.
+    #[inline]
+    pub fn remove_blocks_after(&self, height: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of this function.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the latest block height.
+        let latest_height = self.block_store().current_block_height();
+        // Ensure the given block height exists.
+        ensure!(height <= latest_height, "Cannot remove blocks after {height}, as the latest block is {latest_height}");
+        // Ensure the rollback operations of the blocks have not been pruned.
+        ensure!(
+            latest_height - height <= MAX_ROLLBACK_DEPTH,
+            "Cannot remove blocks after {height}, as at most {MAX_ROLLBACK_DEPTH} blocks can be rolled back"
+        );
+
+        // Remove the blocks, in descending order.
+        for block_height in (height.saturating_add(1)..=latest_height).rev() {
+            self.remove_latest_block(block_height)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the latest block, at the given block height, from the VM.
+    fn remove_latest_block(&self, height: u32) -> Result<()> {
+        // Retrieve the block hash.
+        let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+            bail!("Failed to remove block {height} - missing block hash");
+        };
+        // Retrieve the block transactions.
+        let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+            bail!("Failed to remove block {height} - missing block transactions");
+        };
+        // Retrieve the program IDs that were deployed in the block.
+        let program_ids = transactions
+            .iter()
+            .filter_map(|transaction| match transaction {
+                ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) => {
+                    Some(*deployment.program_id())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Start an atomic batch, so that both the finalize and block removal belong to a single batch.
+        self.store.start_atomic();
+        // Revert the finalize store, and then remove the block.
+        // Note: The block is removed last, as its removal also reverts the block tree.
+        match self.finalize_store().rollback(height).and_then(|_| self.block_store().remove_last_n(1)) {
+            Ok(()) => self.store.finish_atomic()?,
+            Err(removal_error) => {
+                // Clear all pending atomic operations.
+                self.store.abort_atomic();
+                // Log the removal error.
+                error!("Failed to remove block {height} - {removal_error}");
+                return Err(removal_error);
+            }
+        }
+
+        // Remove the stacks of the programs deployed in the block, in reverse order.
+        // If a program was upgraded in the block, its previous edition is restored instead.
+        let mut process = self.process.write();
+        for program_id in program_ids.iter().rev() {
+            match self.transaction_store().deployment_store().find_transaction_id_from_program_id(program_id)? {
+                Some(transaction_id) => {
+                    let Some(deployment) = self.transaction_store().get_deployment(&transaction_id)? else {
+                        bail!("Failed to restore program '{program_id}' - missing deployment '{transaction_id}'");
+                    };
+                    process.load_deployment(&deployment)?;
+                }
+                None => process.remove_stack(program_id)?,
+            }
+        }
+        // Reload the restrictions lists, to remove any that were ratified in the block.
+        *self.ratified_restrictions.write() = Self::load_ratified_restrictions(&self.store)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -492,7 +627,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, block_height as i64, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -2540,4 +2675,252 @@ finalize transfer_public_to_private:
         // It should still be possible to insert the 1st block afterwards.
         vm.add_next_block(&block1).unwrap();
     }
+
+    /// Returns a counter program with the given upgrade policy, whose `increment` function adds `amount` to the counter.
+    fn sample_upgradable_program(policy: &str, amount: u64) -> Program<MainnetV0> {
+        Program::from_str(&format!(
+            r"
+program upgradable_counter.aleo;
+{policy}
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+mapping approvals:
+    key as u16.public;
+    value as address.public;
+
+function increment:
+    async increment into r0;
+    output r0 as upgradable_counter.aleo/increment.future;
+
+finalize increment:
+    get.or_use counter[0u8] 0u64 into r0;
+    add r0 {amount}u64 into r1;
+    set r1 into counter[0u8];
+
+function approve:
+    input r0 as u16.public;
+    input r1 as address.public;
+    async approve r0 r1 into r2;
+    output r2 as upgradable_counter.aleo/approve.future;
+
+finalize approve:
+    input r0 as u16.public;
+    input r1 as address.public;
+    set r1 into approvals[r0];"
+        ))
+        .unwrap()
+    }
+
+    /// Returns the edition of `upgradable_counter.aleo` in the process of the given VM.
+    fn upgradable_edition(vm: &VM<MainnetV0, ConsensusMemory<MainnetV0>>) -> u16 {
+        vm.process().read().get_stack("upgradable_counter.aleo").unwrap().edition()
+    }
+
+    /// Returns the value of the counter in `upgradable_counter.aleo`.
+    fn upgradable_counter(vm: &VM<MainnetV0, ConsensusMemory<MainnetV0>>) -> Option<Value<MainnetV0>> {
+        vm.finalize_store()
+            .get_value_confirmed(
+                ProgramID::from_str("upgradable_counter.aleo").unwrap(),
+                Identifier::from_str("counter").unwrap(),
+                &Plaintext::from_str("0u8").unwrap(),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_upgrade_preserves_mappings() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the first edition of the program.
+        let program = sample_upgradable_program("upgrade owner;", 1);
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+        assert_eq!(upgradable_edition(&vm), 0);
+
+        // Increment the counter.
+        let increment = |rng: &mut TestRng| {
+            let inputs = Vec::<Value<MainnetV0>>::new();
+            let execution = vm
+                .execute(&private_key, ("upgradable_counter.aleo", "increment"), inputs.iter(), None, 0, None, rng)
+                .unwrap();
+            vm.add_next_block(&sample_next_block(&vm, &private_key, &[execution], rng).unwrap()).unwrap();
+        };
+        increment(rng);
+        assert_eq!(upgradable_counter(&vm), Some(Value::from_str("1u64").unwrap()));
+        let height_before_upgrade = vm.block_store().current_block_height();
+
+        // Upgrade the program, so that it increments the counter by 10.
+        let upgrade = sample_upgradable_program("upgrade owner;", 10);
+        let transaction = vm.deploy(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction], rng).unwrap()).unwrap();
+        assert_eq!(upgradable_edition(&vm), 1);
+
+        // Ensure the mapping is preserved across the upgrade, and is updated by the new edition.
+        assert_eq!(upgradable_counter(&vm), Some(Value::from_str("1u64").unwrap()));
+        increment(rng);
+        assert_eq!(upgradable_counter(&vm), Some(Value::from_str("11u64").unwrap()));
+
+        // Remove the blocks of the upgrade, and ensure the previous edition is restored.
+        vm.remove_blocks_after(height_before_upgrade).unwrap();
+        assert_eq!(upgradable_edition(&vm), 0);
+        let program_id = ProgramID::from_str("upgradable_counter.aleo").unwrap();
+        assert_eq!(vm.transaction_store().deployment_store().get_edition(&program_id).unwrap(), Some(0));
+        assert_eq!(upgradable_counter(&vm), Some(Value::from_str("1u64").unwrap()));
+
+        // Ensure the previous edition is executed again.
+        increment(rng);
+        assert_eq!(upgradable_counter(&vm), Some(Value::from_str("2u64").unwrap()));
+    }
+
+    #[test]
+    fn test_upgrade_by_non_owner_is_rejected() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize a second account.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let other_address = Address::try_from(&other_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program, and fund the second account so that it can pay for a deployment.
+        let program = sample_upgradable_program("upgrade owner;", 1);
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        let inputs = [Value::from_str(&other_address.to_string()).unwrap(), Value::from_str("100000000u64").unwrap()];
+        let transfer =
+            vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment, transfer], rng).unwrap()).unwrap();
+
+        // Ensure an upgrade deployed by the second account is rejected.
+        let upgrade = sample_upgradable_program("upgrade owner;", 10);
+        let transaction = vm.deploy(&other_private_key, &upgrade, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Ensure the upgrade is not accepted in a block.
+        let block = sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+        assert_eq!(block.transactions().num_accepted(), 0);
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(upgradable_edition(&vm), 0);
+    }
+
+    #[test]
+    fn test_upgrade_with_mapping_approval() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program, whose upgrades are approved in the `approvals` mapping.
+        let program = sample_upgradable_program("upgrade mapping approvals;", 1);
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Approves the given edition for the given address.
+        let approve = |edition: &str, approved: Address<MainnetV0>, rng: &mut TestRng| {
+            let inputs =
+                [Value::<MainnetV0>::from_str(edition).unwrap(), Value::from_str(&approved.to_string()).unwrap()];
+            let execution = vm
+                .execute(&private_key, ("upgradable_counter.aleo", "approve"), inputs.iter(), None, 0, None, rng)
+                .unwrap();
+            vm.add_next_block(&sample_next_block(&vm, &private_key, &[execution], rng).unwrap()).unwrap();
+        };
+
+        // Ensure an upgrade is rejected before it is approved.
+        let upgrade = sample_upgradable_program("upgrade mapping approvals;", 10);
+        let transaction = vm.deploy(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Ensure the upgrade is accepted once it is approved.
+        approve("1u16", address, rng);
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        let block = sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+        assert_eq!(block.transactions().num_accepted(), 1);
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(upgradable_edition(&vm), 1);
+
+        // Ensure an upgrade that is approved for a different address is rejected.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        approve("2u16", other_address, rng);
+        let upgrade = sample_upgradable_program("upgrade mapping approvals;", 100);
+        let transaction = vm.deploy(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        assert_eq!(upgradable_edition(&vm), 1);
+    }
+
+    #[test]
+    fn test_upgrade_of_immutable_program_is_rejected() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy an immutable program.
+        let program = sample_upgradable_program("upgrade immutable;", 1);
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure the next edition cannot be deployed.
+        let upgrade = sample_upgradable_program("upgrade immutable;", 10);
+        assert!(vm.deploy(&private_key, &upgrade, None, 0, None, rng).is_err());
+
+        // Construct the next edition manually, from the deployment of the program in a new VM.
+        let deployment = sample_vm().deploy_raw(&upgrade, rng).unwrap();
+        let deployment = Deployment::new(
+            deployment.edition() + 1,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )
+        .unwrap();
+        let deployment_id = deployment.to_deployment_id().unwrap();
+        let owner = ProgramOwner::new(&private_key, deployment_id, rng).unwrap();
+        let (minimum_deployment_cost, _) = deployment_cost(&deployment).unwrap();
+        let fee_authorization =
+            vm.authorize_fee_public(&private_key, minimum_deployment_cost, 0, deployment_id, rng).unwrap();
+        let fee = vm.execute_fee_authorization(fee_authorization, None, rng).unwrap();
+        let transaction = Transaction::from_deployment(owner, deployment, fee).unwrap();
+
+        // Ensure the upgrade is rejected.
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        assert_eq!(upgradable_edition(&vm), 0);
+    }
 }
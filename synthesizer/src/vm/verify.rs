@@ -39,26 +39,68 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub(crate) const MAX_PARALLEL_EXECUTE_VERIFICATIONS: usize = 1000;
 
     /// Verifies the list of transactions in the VM. On failure, returns an error.
+    ///
+    /// The pairing checks of all proofs and certificates in the list are folded into a single
+    /// multi-pairing. If the folded check fails, each transaction is checked on its own,
+    /// in order to identify the invalid transaction.
     pub fn check_transactions<R: CryptoRng + Rng>(
         &self,
         transactions: &[(&Transaction<N>, Option<Field<N>>)],
         rng: &mut R,
     ) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
         // Separate the transactions into deploys and executions.
         let (deployments, executions): (Vec<_>, Vec<_>) = transactions.iter().partition(|(tx, _)| tx.is_deploy());
         // Chunk the deploys and executions into groups for parallel verification.
         let deployments_for_verification = deployments.chunks(Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS);
         let executions_for_verification = executions.chunks(Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS);
 
-        // Verify the transactions in batches.
+        // Initialize a vector for the prepared transactions.
+        let mut prepared_transactions = Vec::with_capacity(transactions.len());
+        // Prepare the transactions in batches.
         for transactions in deployments_for_verification.chain(executions_for_verification) {
-            // Ensure each transaction is well-formed and unique.
+            // Ensure each transaction is well-formed and unique, and prepare its pairing checks.
             let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-            cfg_iter!(transactions).zip(rngs).try_for_each(|((transaction, rejected_id), mut rng)| {
-                self.check_transaction(transaction, *rejected_id, &mut rng)
-                    .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-            })?;
+            let prepared = cfg_iter!(transactions)
+                .zip(rngs)
+                .map(|((transaction, rejected_id), mut rng)| {
+                    self.prepare_check_transaction(transaction, *rejected_id, &mut rng)
+                        .map(|(pairing_checks, checksum)| (*transaction, pairing_checks, checksum))
+                        .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            prepared_transactions.extend(prepared);
+        }
+        lap!(timer, "Prepare the transactions");
+
+        // Verify the pairing checks of all transactions at once.
+        let pairing_checks = prepared_transactions
+            .iter()
+            .flat_map(|(_, pairing_checks, _)| pairing_checks.iter().cloned())
+            .collect_vec();
+        if !PairingCheck::verify_batch(&pairing_checks, rng) {
+            // Identify the first transaction with an invalid pairing check.
+            let invalid_transaction = prepared_transactions
+                .iter()
+                .find(|(_, pairing_checks, _)| !PairingCheck::verify_batch(pairing_checks, rng))
+                .map(|(transaction, _, _)| transaction.id());
+            match invalid_transaction {
+                Some(id) => bail!(
+                    "Invalid transaction found in the transactions list: Transaction '{id}' contains an invalid proof or certificate"
+                ),
+                None => bail!("Invalid transaction found in the transactions list: failed to verify the pairing checks"),
+            }
+        }
+        lap!(timer, "Verify the pairing checks");
+
+        // Add the transactions to the partially-verified transactions cache.
+        for (transaction, _, checksum) in prepared_transactions {
+            if let Some(checksum) = checksum {
+                self.partially_verified_transactions.write().push(transaction.id(), checksum);
+            }
         }
+        finish!(timer);
 
         Ok(())
     }
@@ -74,6 +116,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        // Check the transaction, deferring the pairing checks of its proofs and certificates.
+        let (pairing_checks, checksum) = self.prepare_check_transaction(transaction, rejected_id, rng)?;
+        lap!(timer, "Prepare the transaction");
+
+        // Verify the pairing checks.
+        ensure!(
+            PairingCheck::verify_batch(&pairing_checks, rng),
+            "Transaction '{}' contains an invalid proof or certificate",
+            transaction.id()
+        );
+
+        // If the above checks have passed, then add the transaction ID to the partially-verified transactions cache.
+        if let Some(checksum) = checksum {
+            self.partially_verified_transactions.write().push(transaction.id(), checksum);
+        }
+
+        finish!(timer, "Verify the transaction");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::vm::CHECK_TRANSACTION_LATENCY, start.elapsed().as_secs_f64());
+        Ok(())
+    }
+
+    /// Checks the transaction in the VM, except for the pairing checks of its proofs and certificates.
+    /// On success, returns the pairing checks, and the checksum to add to the partially-verified transactions
+    /// cache once the pairing checks are verified. On failure, returns an error.
+    fn prepare_check_transaction<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<(Vec<PairingCheck<N>>, Option<N::TransmissionChecksum>)> {
+        let timer = timer!("VM::prepare_check_transaction");
 
         /* Transaction */
 
@@ -132,8 +209,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
-        // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        // Initialize a vector for the pairing checks.
+        let mut pairing_checks = Vec::new();
+
+        // First, check the fee.
+        pairing_checks.extend(self.prepare_check_fee(transaction, rejected_id)?);
 
         // Construct the transaction checksum.
         let checksum = Data::<Transaction<N>>::Buffer(transaction.to_bytes_le()?.into()).to_checksum::<N>()?;
@@ -142,6 +222,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let is_partially_verified =
             self.partially_verified_transactions.read().peek(&(transaction.id())) == Some(&checksum);
 
+        // Record whether the transaction was found in the partially-verified cache.
+        #[cfg(feature = "metrics")]
+        if !matches!(transaction, Transaction::Fee(..)) {
+            match is_partially_verified {
+                true => metrics::increment_counter(metrics::vm::PARTIALLY_VERIFIED_CACHE_HITS),
+                false => metrics::increment_counter(metrics::vm::PARTIALLY_VERIFIED_CACHE_MISSES),
+            }
+        }
+
         // Next, verify the deployment or execution.
         match transaction {
             Transaction::Deploy(id, owner, deployment, _) => {
@@ -151,23 +240,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                // Check the edition.
+                match deployment.edition() == N::EDITION {
+                    true => {
+                        // Ensure the program ID does not already exist in the store.
+                        if self.transaction_store().contains_program_id(deployment.program_id())? {
+                            bail!("Program ID '{}' is already deployed", deployment.program_id())
+                        }
+                        // Ensure the program does not already exist in the process.
+                        if self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' already exists", deployment.program_id());
+                        }
+                    }
+                    false => {
+                        // Retrieve the latest edition of the program.
+                        let latest_edition =
+                            self.transaction_store().deployment_store().get_edition(deployment.program_id())?;
+                        // Ensure the edition is the next edition of the program.
+                        if latest_edition.and_then(|edition| edition.checked_add(1)) != Some(deployment.edition()) {
+                            bail!("Invalid deployment transaction '{id}' - expected the next edition of the program")
+                        }
+                        // Ensure the upgrade is permitted by the upgrade policy of the program.
+                        self.check_upgrade_policy(&self.process().read(), owner, deployment)?;
+                    }
                 }
-                // Verify the deployment if it has not been verified before.
+                // Check the deployment if it has not been verified before.
                 if !is_partially_verified {
-                    // Verify the deployment.
+                    // Check the deployment.
                     match try_vm_runtime!(|| self.check_deployment_internal(deployment, rng)) {
-                        Ok(result) => result?,
+                        Ok(result) => pairing_checks.extend(result?),
                         Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
                     }
                 }
@@ -181,29 +282,45 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
-                // Verify the execution.
-                match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified)) {
-                    Ok(result) => result?,
+                // Check the execution.
+                match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified, rng)) {
+                    Ok(result) => pairing_checks.extend(result?),
                     Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
                 }
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
 
-        // If the above checks have passed and this is not a fee transaction,
-        // then add the transaction ID to the partially-verified transactions cache.
-        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
-            self.partially_verified_transactions.write().push(transaction.id(), checksum);
-        }
+        // If the above checks have passed and this is not a fee transaction, then return the checksum
+        // to add to the partially-verified transactions cache, once the pairing checks have passed.
+        let checksum = match !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
+            true => Some(checksum),
+            false => None,
+        };
 
-        finish!(timer, "Verify the transaction");
-        Ok(())
+        finish!(timer, "Prepare the transaction");
+        Ok((pairing_checks, checksum))
     }
 
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
-        match transaction {
+        // Check the fee, deferring the pairing check of its proof.
+        if let Some(pairing_check) = self.prepare_check_fee(transaction, rejected_id)? {
+            // Verify the pairing check.
+            ensure!(pairing_check.verify(), "Fee verification failed: failed to verify the fee proof");
+        }
+        Ok(())
+    }
+
+    /// Checks the `fee` in the given transaction, except for the pairing check of its proof, which is returned instead.
+    /// If the transaction does not contain a fee, then `None` is returned. On failure, returns an error.
+    fn prepare_check_fee(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+    ) -> Result<Option<PairingCheck<N>>> {
+        let pairing_check = match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
                 ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (deployment)");
@@ -217,8 +334,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if *fee.base_amount()? < cost {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
-                // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                // Check the fee.
+                Some(self.check_fee_internal(fee, deployment_id)?)
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -245,41 +362,49 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // Ensure the base fee amount is zero.
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
-                    // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    // Check the fee.
+                    Some(self.check_fee_internal(fee, execution_id)?)
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                    None
                 }
             }
             // Note: This transaction type does not need to check the fee amount, because:
             //  1. The fee is guaranteed to be non-zero by the constructor of `Transaction::Fee`.
             //  2. The fee may be less that the deployment or execution cost, as this is a valid reason it was rejected.
             Transaction::Fee(id, fee) => {
-                // Verify the fee.
+                // Check the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => Some(self.check_fee_internal(fee, rejected_id)?),
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
-        }
-        Ok(())
+        };
+        Ok(pairing_check)
     }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Verifies the given deployment. On failure, returns an error.
+    /// Checks the given deployment, except for the pairing checks of its certificates, which are returned instead.
+    /// On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the deployment are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_deployment_internal<R: CryptoRng + Rng>(&self, deployment: &Deployment<N>, rng: &mut R) -> Result<()> {
+    fn check_deployment_internal<R: CryptoRng + Rng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<PairingCheck<N>>> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
                 let deployment = cast_ref!(&deployment as Deployment<$network>);
-                // Verify the deployment.
-                $process.verify_deployment::<$aleo, _>(&deployment, rng)
+                // Check the deployment.
+                let pairing_checks = $process.prepare_verify_deployment::<$aleo, _>(&deployment, rng)?;
+                // Prepare the pairing checks.
+                Result::<Vec<PairingCheck<N>>>::Ok(cast_ref!(pairing_checks as Vec<PairingCheck<N>>).clone())
             }};
         }
 
@@ -290,34 +415,102 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
-    /// Verifies the given execution. On failure, returns an error.
+    /// Ensures the given deployment is permitted by the upgrade policy of the deployed program.
+    /// If the deployment is the initial edition of the program, this method returns `Ok(())`.
+    ///
+    /// Note: The approval in an upgrade mapping is read speculatively, and must be checked again in `finalize`.
+    #[inline]
+    pub(crate) fn check_upgrade_policy(
+        &self,
+        process: &Process<N>,
+        owner: &ProgramOwner<N>,
+        deployment: &Deployment<N>,
+    ) -> Result<()> {
+        // If this is the initial edition, there is nothing to check.
+        if deployment.edition() == N::EDITION {
+            return Ok(());
+        }
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the upgrade policy of the deployed program.
+        match process.get_program(program_id)?.upgrade_policy() {
+            UpgradePolicy::Immutable => bail!("Program '{program_id}' is immutable"),
+            UpgradePolicy::Owner => {
+                // Retrieve the owner of the latest edition.
+                let Some(previous_owner) = self.transaction_store().deployment_store().get_owner(program_id)? else {
+                    bail!("Failed to find the owner of program '{program_id}'")
+                };
+                // Ensure the upgrade is deployed by the same owner.
+                ensure!(
+                    previous_owner.address() == owner.address(),
+                    "Only the owner of program '{program_id}' may upgrade it"
+                );
+            }
+            UpgradePolicy::Mapping(mapping_name) => {
+                // Retrieve the approved address for the edition.
+                let key = Plaintext::from(Literal::U16(U16::new(deployment.edition())));
+                let approved = self.finalize_store().get_value_speculative(*program_id, *mapping_name, &key)?;
+                // Ensure the upgrade is deployed by the approved address.
+                ensure!(
+                    approved == Some(Value::from(Literal::Address(owner.address()))),
+                    "Edition {} of program '{program_id}' is not approved for '{}'",
+                    deployment.edition(),
+                    owner.address()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the given execution, except for the pairing check of its proof, which is returned instead.
+    /// If the execution has been partially-verified before, then `None` is returned. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_execution_internal(&self, execution: &Execution<N>, is_partially_verified: bool) -> Result<()> {
+    fn check_execution_internal<R: CryptoRng + Rng>(
+        &self,
+        execution: &Execution<N>,
+        is_partially_verified: bool,
+        rng: &mut R,
+    ) -> Result<Option<PairingCheck<N>>> {
         let timer = timer!("VM::check_execution");
 
         // Retrieve the block height.
         let block_height = self.block_store().current_block_height();
 
+        // Retrieve the restrictions in effect at the block height.
+        let ratified_restrictions = self.ratified_restrictions.read();
+        let restrictions = match ratified_restrictions.range(..=block_height).next_back() {
+            Some((_, restrictions)) => restrictions,
+            None => &self.restrictions,
+        };
+        let is_restricted = restrictions.contains_restricted_transitions(execution, block_height);
+        drop(ratified_restrictions);
+
         // Ensure the execution does not contain any restricted transitions.
-        if self.restrictions.contains_restricted_transitions(execution, block_height) {
+        if is_restricted {
             bail!("Execution verification failed - restricted transition found");
         }
 
-        // Verify the execution proof, if it has not been partially-verified before.
+        // Synthesize the multisig circuit keys of the execution, if it has not been partially-verified before.
+        if !is_partially_verified {
+            self.synthesize_multisig_keys(execution, rng)?;
+            lap!(timer, "Synthesize the multisig circuit keys");
+        }
+
+        // Check the execution proof, if it has not been partially-verified before.
         let verification = match is_partially_verified {
-            true => Ok(()),
-            false => self.process.read().verify_execution(execution),
+            true => Ok(None),
+            false => self.process.read().prepare_verify_execution(execution).map(Some),
         };
-        lap!(timer, "Verify the execution");
+        lap!(timer, "Check the execution");
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => Ok(()),
+            Ok(pairing_check) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+                Ok(true) => Ok(pairing_check),
                 Ok(false) => bail!("Execution verification failed - global state root does not exist (yet)"),
                 Err(error) => bail!("Execution verification failed - {error}"),
             },
@@ -327,21 +520,45 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
-    /// Verifies the given fee. On failure, returns an error.
+    /// Synthesizes the multisig circuit keys for the multisig transitions in the given execution, if they do not exist.
+    ///
+    /// Note: Multisig circuit keys are not part of a deployment. The multisig circuit has a fixed shape,
+    /// and circuit keys are deterministic in the circuit, so each verifier synthesizes the keys locally.
+    #[inline]
+    fn synthesize_multisig_keys<R: CryptoRng + Rng>(&self, execution: &Execution<N>, rng: &mut R) -> Result<()> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                for transition in execution.transitions().filter(|transition| transition.is_multisig()) {
+                    // Retrieve the program ID and function name.
+                    let program_id = cast_ref!((*transition.program_id()) as ProgramID<$network>);
+                    let function_name = cast_ref!((*transition.function_name()) as Identifier<$network>);
+                    // Synthesize the multisig circuit key, if it does not exist.
+                    $process.synthesize_multisig_key::<$aleo, _>(program_id, function_name, rng)?;
+                }
+                Ok(())
+            }};
+        }
+
+        // Process the logic.
+        process!(self, logic)
+    }
+
+    /// Checks the given fee, except for the pairing check of its proof, which is returned instead.
+    /// On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<PairingCheck<N>> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
         let fee_amount = fee.amount()?;
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
-        // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
-        lap!(timer, "Verify the fee");
+        // Check the fee.
+        let verification = self.process.read().prepare_verify_fee(fee, deployment_or_execution_id);
+        lap!(timer, "Check the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
         //  to the speculation layer.
@@ -367,8 +584,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => Ok(()),
+            Ok(pairing_check) => match self.block_store().contains_state_root(&fee.global_state_root()) {
+                Ok(true) => Ok(pairing_check),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
             },
@@ -413,6 +630,43 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction and execution transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let private_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let public_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Ensure the transactions verify together.
+        let transactions =
+            [(&deployment_transaction, None), (&private_fee_transaction, None), (&public_fee_transaction, None)];
+        vm.check_transactions(&transactions, rng).unwrap();
+
+        // Construct an execution with the proof of a different execution.
+        let execution = private_fee_transaction.execution().unwrap();
+        let invalid_execution = Execution::from(
+            execution.transitions().cloned(),
+            execution.global_state_root(),
+            public_fee_transaction.execution().unwrap().proof().cloned(),
+        )
+        .unwrap();
+        let Transaction::Execute(_, _, fee) = &private_fee_transaction else {
+            panic!("Expected an execution transaction")
+        };
+        let invalid_transaction = Transaction::from_execution(invalid_execution, fee.clone()).unwrap();
+
+        // Ensure the transaction with the invalid proof is identified.
+        let transactions =
+            [(&deployment_transaction, None), (&invalid_transaction, None), (&public_fee_transaction, None)];
+        let error = vm.check_transactions(&transactions, rng).unwrap_err();
+        assert!(error.to_string().contains(&invalid_transaction.id().to_string()));
+        // Ensure the transaction with the invalid proof fails on its own.
+        assert!(vm.check_transaction(&invalid_transaction, None, rng).is_err());
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
@@ -425,12 +679,14 @@ mod tests {
         let deployment = vm.deploy_raw(&program, rng).unwrap();
 
         // Ensure the deployment is valid.
-        vm.check_deployment_internal(&deployment, rng).unwrap();
+        let pairing_checks = vm.check_deployment_internal(&deployment, rng).unwrap();
+        assert!(PairingCheck::verify_batch(&pairing_checks, rng));
 
         // Ensure that deserialization doesn't break the transaction verification.
         let serialized_deployment = deployment.to_string();
         let deployment_transaction: Deployment<CurrentNetwork> = serde_json::from_str(&serialized_deployment).unwrap();
-        vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
+        let pairing_checks = vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
+        assert!(PairingCheck::verify_batch(&pairing_checks, rng));
     }
 
     #[test]
@@ -450,13 +706,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    vm.check_execution_internal(&execution, false).unwrap();
+                    assert!(vm.check_execution_internal(&execution, false, rng).unwrap().unwrap().verify());
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    vm.check_execution_internal(&recovered_execution, false).unwrap();
+                    assert!(vm.check_execution_internal(&recovered_execution, false, rng).unwrap().unwrap().verify());
                 }
                 _ => panic!("Expected an execution transaction"),
             }
@@ -482,12 +738,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    assert!(vm.check_fee_internal(&fee, execution_id).unwrap().verify());
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    assert!(vm.check_fee_internal(&recovered_fee, execution_id).unwrap().verify());
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -24,6 +24,9 @@ pub use bigint_256::*;
 mod bigint_384;
 pub use bigint_384::*;
 
+mod bigint_768;
+pub use bigint_768::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,132 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_console::{
+    account,
+    program::{Ciphertext, Plaintext, Record},
+};
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An account private key.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct PrivateKey(pub(crate) account::PrivateKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, String> {
+        Ok(Self(account::PrivateKey::new(&mut rand::thread_rng()).map_err(to_js_error)?))
+    }
+
+    /// Parses a private key from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
+        Ok(Self(account::PrivateKey::from_str(private_key).map_err(to_js_error)?))
+    }
+
+    /// Returns the private key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, String> {
+        Ok(ViewKey(account::ViewKey::try_from(&self.0).map_err(to_js_error)?))
+    }
+
+    /// Returns the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(to_js_error)?))
+    }
+
+    /// Signs the given message, and returns the signature as a string.
+    pub fn sign(&self, message: &[u8]) -> Result<String, String> {
+        Ok(self.0.sign_bytes(message, &mut rand::thread_rng()).map_err(to_js_error)?.to_string())
+    }
+}
+
+/// An account view key.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ViewKey(pub(crate) account::ViewKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Parses a view key from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, String> {
+        Ok(Self(account::ViewKey::from_str(view_key).map_err(to_js_error)?))
+    }
+
+    /// Returns the view key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(to_js_error)?))
+    }
+
+    /// Returns `true` if the given record ciphertext is owned by the view key.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, record: &str) -> Result<bool, String> {
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(record).map_err(to_js_error)?;
+        Ok(record.is_owner(&self.0))
+    }
+
+    /// Decrypts the given record ciphertext, and returns the record plaintext as a string.
+    pub fn decrypt(&self, record: &str) -> Result<String, String> {
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(record).map_err(to_js_error)?;
+        let record: Record<CurrentNetwork, Plaintext<CurrentNetwork>> = record.decrypt(&self.0).map_err(to_js_error)?;
+        Ok(record.to_string())
+    }
+}
+
+/// An account address.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Address(pub(crate) account::Address<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Parses an address from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, String> {
+        Ok(Self(account::Address::from_str(address).map_err(to_js_error)?))
+    }
+
+    /// Returns the address as a string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given signature is valid for the given message and the address.
+    pub fn verify(&self, message: &[u8], signature: &str) -> Result<bool, String> {
+        let signature = account::Signature::<CurrentNetwork>::from_str(signature).map_err(to_js_error)?;
+        Ok(signature.verify_bytes(&self.0, message))
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_console::prelude::{FromBytes, ToBytes};
+use snarkvm_synthesizer::process;
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An authorization of a program function call, which may be executed by a third party.
+#[wasm_bindgen]
+pub struct Authorization(pub(crate) process::Authorization<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Parses an authorization from a JSON string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(authorization: &str) -> Result<Authorization, String> {
+        Ok(Self(process::Authorization::from_str(authorization).map_err(to_js_error)?))
+    }
+
+    /// Returns the authorization as a JSON string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Reads an authorization from bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Authorization, String> {
+        Ok(Self(process::Authorization::from_bytes_le(bytes).map_err(to_js_error)?))
+    }
+
+    /// Returns the authorization as bytes.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(to_js_error)
+    }
+
+    /// Returns the execution ID, which the fee of the execution must be authorized for.
+    #[wasm_bindgen(js_name = toExecutionId)]
+    pub fn to_execution_id(&self) -> Result<String, String> {
+        Ok(self.0.to_execution_id().map_err(to_js_error)?.to_string())
+    }
+
+    /// Returns `true` if the authorization is for a fee.
+    #[wasm_bindgen(js_name = isFee)]
+    pub fn is_fee(&self) -> bool {
+        self.0.is_fee_private() || self.0.is_fee_public()
+    }
+
+    /// Returns `true` if the authorization is for a split call, which does not require a fee.
+    #[wasm_bindgen(js_name = isSplit)]
+    pub fn is_split(&self) -> bool {
+        self.0.is_split()
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `wasm-bindgen` bindings for account management, program parsing, authorization,
+//! offline execution, and transaction serialization on the `MainnetV0` network.
+
+mod account;
+pub use account::*;
+
+mod authorization;
+pub use authorization::*;
+
+mod process;
+pub use process::*;
+
+mod program;
+pub use program::*;
+
+mod query;
+pub use query::*;
+
+mod transaction;
+pub use transaction::*;
+
+use snarkvm_circuit_network::AleoV0;
+use snarkvm_console::network::MainnetV0;
+
+/// The network of the bindings.
+type CurrentNetwork = MainnetV0;
+/// The circuit environment of the bindings.
+type CurrentAleo = AleoV0;
+
+/// Converts the given error into a JavaScript-compatible error message.
+fn to_js_error(error: impl core::fmt::Display) -> String {
+    error.to_string()
+}
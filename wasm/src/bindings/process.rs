@@ -0,0 +1,240 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, Authorization, CurrentAleo, CurrentNetwork, PrivateKey, Program, Query, Transaction};
+use snarkvm_console::{
+    prelude::{ensure, FromBytes, Result},
+    program::{Identifier, Locator, Plaintext, ProgramID, Record, Value},
+    types::Field,
+};
+use snarkvm_ledger_block as block;
+use snarkvm_synthesizer::{process, snark::ProvingKey};
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// A process for authorizing and executing program functions, without a connection to the network.
+///
+/// Note: The process does not download the circuit keys. The proving keys of each function that is
+/// executed, including `credits.aleo/fee_public` or `credits.aleo/fee_private`, must be inserted first.
+#[wasm_bindgen]
+pub struct Process(process::Process<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Process {
+    /// Initializes a new process with the `credits.aleo` program.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Process, String> {
+        Ok(Self(process::Process::load_web().map_err(to_js_error)?))
+    }
+
+    /// Adds the given program to the process.
+    ///
+    /// Note: The imports of the program must be added first.
+    #[wasm_bindgen(js_name = addProgram)]
+    pub fn add_program(&mut self, program: &Program) -> Result<(), String> {
+        self.0.add_program(&program.0).map_err(to_js_error)
+    }
+
+    /// Returns `true` if the process contains the program with the given ID.
+    #[wasm_bindgen(js_name = containsProgram)]
+    pub fn contains_program(&self, program_id: &str) -> Result<bool, String> {
+        Ok(self.0.contains_program(&ProgramID::from_str(program_id).map_err(to_js_error)?))
+    }
+
+    /// Inserts the given proving key bytes, for the given program ID and function name.
+    #[wasm_bindgen(js_name = insertProvingKey)]
+    pub fn insert_proving_key(&self, program_id: &str, function_name: &str, proving_key: &[u8]) -> Result<(), String> {
+        let program_id = ProgramID::from_str(program_id).map_err(to_js_error)?;
+        let function_name = Identifier::from_str(function_name).map_err(to_js_error)?;
+        let proving_key = ProvingKey::from_bytes_le(proving_key).map_err(to_js_error)?;
+        self.0.insert_proving_key(&program_id, &function_name, proving_key).map_err(to_js_error)
+    }
+
+    /// Authorizes a call to the program function for the given inputs.
+    pub fn authorize(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        function_name: &str,
+        inputs: Vec<String>,
+    ) -> Result<Authorization, String> {
+        // Parse the inputs.
+        let inputs = inputs
+            .iter()
+            .map(|input| Value::<CurrentNetwork>::from_str(input))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_js_error)?;
+        // Authorize the call.
+        let authorization = self
+            .0
+            .authorize::<CurrentAleo, _>(
+                &private_key.0,
+                program_id,
+                function_name,
+                inputs.into_iter(),
+                &mut rand::thread_rng(),
+            )
+            .map_err(to_js_error)?;
+        Ok(Authorization(authorization))
+    }
+
+    /// Authorizes a public fee, in microcredits, for the given execution ID.
+    #[wasm_bindgen(js_name = authorizeFeePublic)]
+    pub fn authorize_fee_public(
+        &self,
+        private_key: &PrivateKey,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        execution_id: &str,
+    ) -> Result<Authorization, String> {
+        let execution_id = Field::from_str(execution_id).map_err(to_js_error)?;
+        let authorization = self
+            .0
+            .authorize_fee_public::<CurrentAleo, _>(
+                &private_key.0,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                &mut rand::thread_rng(),
+            )
+            .map_err(to_js_error)?;
+        Ok(Authorization(authorization))
+    }
+
+    /// Authorizes a private fee, in microcredits, for the given execution ID, using the given credits record plaintext.
+    #[wasm_bindgen(js_name = authorizeFeePrivate)]
+    pub fn authorize_fee_private(
+        &self,
+        private_key: &PrivateKey,
+        fee_record: &str,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        execution_id: &str,
+    ) -> Result<Authorization, String> {
+        let fee_record =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(fee_record).map_err(to_js_error)?;
+        let execution_id = Field::from_str(execution_id).map_err(to_js_error)?;
+        let authorization = self
+            .0
+            .authorize_fee_private::<CurrentAleo, _>(
+                &private_key.0,
+                fee_record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                &mut rand::thread_rng(),
+            )
+            .map_err(to_js_error)?;
+        Ok(Authorization(authorization))
+    }
+
+    /// Returns a new execute transaction for the given authorization and optional fee authorization.
+    pub fn execute(
+        &self,
+        authorization: Authorization,
+        fee_authorization: Option<Authorization>,
+        query: &Query,
+    ) -> Result<Transaction, String> {
+        // Compute the execution.
+        let execution = self.execute_authorization(authorization.0, query).map_err(to_js_error)?;
+        // Compute the fee.
+        let fee = match fee_authorization {
+            Some(authorization) => Some(self.execute_fee_authorization(authorization.0, query).map_err(to_js_error)?),
+            None => None,
+        };
+        // Return the execute transaction.
+        Ok(Transaction(block::Transaction::from_execution(execution, fee).map_err(to_js_error)?))
+    }
+
+    /// Returns a new execute transaction for the given authorization, with a fee for the minimum execution cost.
+    ///
+    /// If a `fee_record` plaintext is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[wasm_bindgen(js_name = executeWithFee)]
+    pub fn execute_with_fee(
+        &self,
+        private_key: &PrivateKey,
+        authorization: Authorization,
+        fee_record: Option<String>,
+        priority_fee_in_microcredits: u64,
+        query: &Query,
+    ) -> Result<Transaction, String> {
+        // Compute the execution.
+        let execution = self.execute_authorization(authorization.0, query).map_err(to_js_error)?;
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = process::execution_cost(&self.0, &execution).map_err(to_js_error)?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id().map_err(to_js_error)?.to_string();
+        // Authorize the fee.
+        let fee_authorization = match fee_record {
+            Some(fee_record) => self.authorize_fee_private(
+                private_key,
+                &fee_record,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                &execution_id,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                &execution_id,
+            )?,
+        };
+        // Compute the fee.
+        let fee = self.execute_fee_authorization(fee_authorization.0, query).map_err(to_js_error)?;
+        // Return the execute transaction.
+        Ok(Transaction(block::Transaction::from_execution(execution, Some(fee)).map_err(to_js_error)?))
+    }
+}
+
+impl Process {
+    /// Executes a call to the program function for the given authorization, and returns the execution.
+    fn execute_authorization(
+        &self,
+        authorization: process::Authorization<CurrentNetwork>,
+        query: &Query,
+    ) -> Result<block::Execution<CurrentNetwork>> {
+        // Construct the locator of the main function.
+        let locator = {
+            let request = authorization.peek_next()?;
+            Locator::new(*request.program_id(), *request.function_name()).to_string()
+        };
+        // Execute the call.
+        let (_, mut trace) = self.0.execute::<CurrentAleo, _>(authorization, &mut rand::thread_rng())?;
+        // Prepare the assignments.
+        trace.prepare(query.0.clone())?;
+        // Compute the proof and construct the execution.
+        trace.prove_execution::<CurrentAleo, _>(&locator, &mut rand::thread_rng())
+    }
+
+    /// Executes the fee for the given authorization, and returns the fee.
+    fn execute_fee_authorization(
+        &self,
+        authorization: process::Authorization<CurrentNetwork>,
+        query: &Query,
+    ) -> Result<block::Fee<CurrentNetwork>> {
+        ensure!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
+        // Execute the call.
+        let (_, mut trace) = self.0.execute::<CurrentAleo, _>(authorization, &mut rand::thread_rng())?;
+        // Prepare the assignments.
+        trace.prepare(query.0.clone())?;
+        // Compute the proof and construct the fee.
+        trace.prove_fee::<CurrentAleo, _>(&mut rand::thread_rng())
+    }
+}
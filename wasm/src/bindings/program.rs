@@ -0,0 +1,72 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_synthesizer::program;
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An Aleo program.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Program(pub(crate) program::Program<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Program {
+    /// Parses a program from its source code.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(program: &str) -> Result<Program, String> {
+        Ok(Self(program::Program::from_str(program).map_err(to_js_error)?))
+    }
+
+    /// Returns the `credits.aleo` program.
+    pub fn credits() -> Result<Program, String> {
+        Ok(Self(program::Program::credits().map_err(to_js_error)?))
+    }
+
+    /// Returns the program source code.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the program ID.
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the program IDs of the imports.
+    pub fn imports(&self) -> Vec<String> {
+        self.0.imports().keys().map(|program_id| program_id.to_string()).collect()
+    }
+
+    /// Returns the names of the mappings.
+    pub fn mappings(&self) -> Vec<String> {
+        self.0.mappings().keys().map(|name| name.to_string()).collect()
+    }
+
+    /// Returns the names of the functions.
+    pub fn functions(&self) -> Vec<String> {
+        self.0.functions().keys().map(|name| name.to_string()).collect()
+    }
+
+    /// Returns the input types of the given function.
+    #[wasm_bindgen(js_name = functionInputs)]
+    pub fn function_inputs(&self, function_name: &str) -> Result<Vec<String>, String> {
+        let function = self.0.get_function_ref(&function_name.parse().map_err(to_js_error)?).map_err(to_js_error)?;
+        Ok(function.input_types().iter().map(|input_type| input_type.to_string()).collect())
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_console::prelude::{FromBytes, Network, ToBytes};
+use snarkvm_ledger_query::QuerySnapshot;
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An offline snapshot of the ledger state, used to prove executions without a network connection.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Query(pub(crate) QuerySnapshot<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Query {
+    /// Initializes a query for the given state root, without any state paths.
+    ///
+    /// Note: This query may only be used for executions and fees that do not consume records.
+    #[wasm_bindgen(js_name = fromStateRoot)]
+    pub fn from_state_root(state_root: &str) -> Result<Query, String> {
+        let state_root = <CurrentNetwork as Network>::StateRoot::from_str(state_root).map_err(to_js_error)?;
+        Ok(Self(QuerySnapshot::new(state_root, vec![]).map_err(to_js_error)?))
+    }
+
+    /// Reads a query snapshot from bytes, as exported by the ledger.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Query, String> {
+        Ok(Self(QuerySnapshot::from_bytes_le(bytes).map_err(to_js_error)?))
+    }
+
+    /// Returns the query snapshot as bytes.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(to_js_error)
+    }
+
+    /// Returns the state root of the query snapshot.
+    #[wasm_bindgen(js_name = stateRoot)]
+    pub fn state_root(&self) -> String {
+        self.0.state_root().to_string()
+    }
+}
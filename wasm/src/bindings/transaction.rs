@@ -0,0 +1,74 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_console::prelude::{FromBytes, ToBytes};
+use snarkvm_ledger_block as block;
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// A transaction, which may be broadcast to the network.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Transaction(pub(crate) block::Transaction<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Transaction {
+    /// Parses a transaction from a JSON string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(transaction: &str) -> Result<Transaction, String> {
+        Ok(Self(block::Transaction::from_str(transaction).map_err(to_js_error)?))
+    }
+
+    /// Returns the transaction as a JSON string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Reads a transaction from bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Transaction, String> {
+        Ok(Self(block::Transaction::from_bytes_le(bytes).map_err(to_js_error)?))
+    }
+
+    /// Returns the transaction as bytes.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(to_js_error)
+    }
+
+    /// Returns the transaction ID.
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the transaction type, as one of `deploy`, `execute`, or `fee`.
+    #[wasm_bindgen(js_name = transactionType)]
+    pub fn transaction_type(&self) -> String {
+        match &self.0 {
+            block::Transaction::Deploy(..) => "deploy".to_string(),
+            block::Transaction::Execute(..) => "execute".to_string(),
+            block::Transaction::Fee(..) => "fee".to_string(),
+        }
+    }
+
+    /// Returns the total fee amount of the transaction, in microcredits.
+    #[wasm_bindgen(js_name = feeAmount)]
+    pub fn fee_amount(&self) -> Result<u64, String> {
+        Ok(*self.0.fee_amount().map_err(to_js_error)?)
+    }
+}
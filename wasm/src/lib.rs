@@ -34,5 +34,8 @@ pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 
+#[cfg(feature = "bindings")]
+pub mod bindings;
+
 #[cfg(test)]
 mod tests;
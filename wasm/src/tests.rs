@@ -59,3 +59,207 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[cfg(feature = "bindings")]
+mod bindings {
+    use crate::bindings::{Address, PrivateKey, Process, Program, Query, Transaction, ViewKey};
+    use snarkvm_circuit_network::AleoV0;
+    use snarkvm_console::{
+        network::{MainnetV0, Network},
+        prelude::ToBytes,
+        program::{Identifier, ProgramID},
+    };
+    use snarkvm_synthesizer::process;
+
+    use core::str::FromStr;
+    use wasm_bindgen_test::*;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    const TOKEN_PROGRAM: &str = r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;";
+
+    /// Synthesizes the circuit keys of the given function on the native process, and returns the proving key bytes.
+    /// This stands in for the proving keys that a client supplies to the offline process.
+    fn synthesize_proving_key(native: &process::Process<MainnetV0>, program_id: &str, function_name: &str) -> Vec<u8> {
+        let program_id = ProgramID::<MainnetV0>::from_str(program_id).unwrap();
+        let function_name = Identifier::<MainnetV0>::from_str(function_name).unwrap();
+        native.synthesize_key::<AleoV0, _>(&program_id, &function_name, &mut rand::thread_rng()).unwrap();
+        native.get_proving_key(program_id, function_name).unwrap().to_bytes_le().unwrap()
+    }
+
+    /// Returns an offline query for executions that do not consume records.
+    fn sample_query() -> Query {
+        Query::from_state_root(&<MainnetV0 as Network>::StateRoot::default().to_string()).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_account() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(ALEO_PRIVATE_KEY, private_key.to_js_string());
+
+        let view_key = private_key.to_view_key().unwrap();
+        assert_eq!(ALEO_VIEW_KEY, view_key.to_js_string());
+        assert_eq!(ALEO_VIEW_KEY, ViewKey::from_string(ALEO_VIEW_KEY).unwrap().to_js_string());
+
+        let address = view_key.to_address().unwrap();
+        assert_eq!(ALEO_ADDRESS, address.to_js_string());
+        assert_eq!(ALEO_ADDRESS, private_key.to_address().unwrap().to_js_string());
+
+        // Ensure invalid strings are rejected.
+        assert!(PrivateKey::from_string(ALEO_VIEW_KEY).is_err());
+        assert!(Address::from_string(ALEO_PRIVATE_KEY).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_account_sign() {
+        let private_key = PrivateKey::new().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        // Sign a message with the account private key.
+        let signature = private_key.sign("hello world!".as_bytes()).unwrap();
+        // Verify the signed message.
+        assert!(address.verify("hello world!".as_bytes(), &signature).unwrap());
+        assert!(!address.verify("hello world?".as_bytes(), &signature).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_program() {
+        let program = Program::credits().unwrap();
+        assert_eq!("credits.aleo", program.id());
+        assert!(program.functions().contains(&"transfer_public".to_string()));
+        assert!(program.mappings().contains(&"account".to_string()));
+        assert_eq!(vec!["address.public", "u64.public"], program.function_inputs("transfer_public").unwrap());
+
+        // Ensure the program round-trips through its source code.
+        let candidate = Program::from_string(&program.to_js_string()).unwrap();
+        assert_eq!(program.to_js_string(), candidate.to_js_string());
+        assert!(Program::from_string("program invalid;").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_authorize() {
+        let process = Process::new().unwrap();
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+
+        // Authorize a public transfer.
+        let inputs = vec![ALEO_ADDRESS.to_string(), "1u64".to_string()];
+        let authorization = process.authorize(&private_key, "credits.aleo", "transfer_public", inputs).unwrap();
+        assert!(!authorization.is_fee());
+        assert!(!authorization.is_split());
+
+        // Ensure the authorization round-trips through its string and byte representations.
+        let execution_id = authorization.to_execution_id().unwrap();
+        let candidate = crate::bindings::Authorization::from_string(&authorization.to_js_string()).unwrap();
+        assert_eq!(execution_id, candidate.to_execution_id().unwrap());
+        let candidate = crate::bindings::Authorization::from_bytes(&authorization.to_bytes().unwrap()).unwrap();
+        assert_eq!(execution_id, candidate.to_execution_id().unwrap());
+
+        // Authorize a public fee for the execution.
+        let fee_authorization = process.authorize_fee_public(&private_key, 1000, 0, &execution_id).unwrap();
+        assert!(fee_authorization.is_fee());
+
+        // Ensure an offline query can be constructed for executions that do not consume records.
+        let state_root = <MainnetV0 as Network>::StateRoot::default().to_string();
+        let query = Query::from_state_root(&state_root).unwrap();
+        assert_eq!(state_root, query.state_root());
+        assert_eq!(query.state_root(), Query::from_bytes(&query.to_bytes().unwrap()).unwrap().state_root());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_execute_offline() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let view_key = private_key.to_view_key().unwrap();
+        let program = Program::from_string(TOKEN_PROGRAM).unwrap();
+
+        // Synthesize the proving key outside of the offline process.
+        let mut native = process::Process::<MainnetV0>::load_web().unwrap();
+        native.add_program(&program.0).unwrap();
+        let proving_key = synthesize_proving_key(&native, "token.aleo", "mint");
+
+        // Initialize the offline process.
+        let mut process = Process::new().unwrap();
+        process.add_program(&program).unwrap();
+        assert!(process.contains_program("token.aleo").unwrap());
+
+        // Ensure the execution fails without the proving key.
+        let authorization = process.authorize(&private_key, "token.aleo", "mint", vec!["100u64".to_string()]).unwrap();
+        assert!(process.execute(authorization, None, &sample_query()).is_err());
+
+        // Insert the proving key, and execute the function.
+        process.insert_proving_key("token.aleo", "mint", &proving_key).unwrap();
+        let authorization = process.authorize(&private_key, "token.aleo", "mint", vec!["100u64".to_string()]).unwrap();
+        let transaction = process.execute(authorization, None, &sample_query()).unwrap();
+        assert_eq!("execute", transaction.transaction_type());
+        assert!(transaction.0.fee_transition().is_none());
+
+        // Ensure the execution is valid.
+        native.verify_execution(transaction.0.execution().unwrap()).unwrap();
+
+        // Ensure the transaction round-trips through its string and byte representations.
+        assert_eq!(transaction.id(), Transaction::from_string(&transaction.to_js_string()).unwrap().id());
+        assert_eq!(transaction.id(), Transaction::from_bytes(&transaction.to_bytes().unwrap()).unwrap().id());
+
+        // Ensure the output record is owned by, and decrypts for, the caller.
+        let (_, record) = transaction.0.records().next().unwrap();
+        let record = record.to_string();
+        assert!(view_key.is_owner(&record).unwrap());
+        let plaintext = view_key.decrypt(&record).unwrap();
+        assert!(plaintext.contains(&format!("owner: {ALEO_ADDRESS}.private")));
+        assert!(plaintext.contains("amount: 100u64.private"));
+
+        // Ensure the record does not decrypt for another account.
+        let other_view_key = PrivateKey::new().unwrap().to_view_key().unwrap();
+        assert!(!other_view_key.is_owner(&record).unwrap());
+        assert!(other_view_key.decrypt(&record).is_err());
+        assert!(view_key.decrypt("record1invalid").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_execute_with_fee_offline() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let program = Program::from_string(TOKEN_PROGRAM).unwrap();
+
+        // Synthesize the proving keys outside of the offline process.
+        let mut native = process::Process::<MainnetV0>::load_web().unwrap();
+        native.add_program(&program.0).unwrap();
+        let proving_key = synthesize_proving_key(&native, "token.aleo", "mint");
+        let fee_proving_key = synthesize_proving_key(&native, "credits.aleo", "fee_public");
+
+        // Initialize the offline process.
+        let mut process = Process::new().unwrap();
+        process.add_program(&program).unwrap();
+        process.insert_proving_key("token.aleo", "mint", &proving_key).unwrap();
+
+        // Ensure the fee fails without the fee proving key.
+        let authorization = process.authorize(&private_key, "token.aleo", "mint", vec!["1u64".to_string()]).unwrap();
+        assert!(process.execute_with_fee(&private_key, authorization, None, 0, &sample_query()).is_err());
+
+        // Insert the fee proving key, and execute the function with a public fee.
+        process.insert_proving_key("credits.aleo", "fee_public", &fee_proving_key).unwrap();
+        let authorization = process.authorize(&private_key, "token.aleo", "mint", vec!["1u64".to_string()]).unwrap();
+        let transaction = process.execute_with_fee(&private_key, authorization, None, 5, &sample_query()).unwrap();
+        assert_eq!("execute", transaction.transaction_type());
+
+        // Ensure the fee covers the minimum execution cost and the priority fee.
+        let execution = transaction.0.execution().unwrap();
+        let (minimum_execution_cost, _) = process::execution_cost(&native, execution).unwrap();
+        assert_eq!(minimum_execution_cost + 5, transaction.fee_amount().unwrap());
+
+        // Ensure the execution and fee are valid.
+        native.verify_execution(execution).unwrap();
+        let fee = transaction.0.fee_transition().unwrap();
+        native.verify_fee(&fee, execution.to_execution_id().unwrap()).unwrap();
+    }
+}